@@ -9,33 +9,59 @@
 "int"         return "INT";
 "bool"        return "BOOL";
 "string"      return "STRING";
+"char"        return "CHAR";
+"double"      return "DOUBLE";
+"long"        return "LONG";
 "new"         return "NEW";
 "null"        return "NULL";
 "true"        return "TRUE";
 "false"       return "FALSE";
 "class"       return "CLASS";
 "extends"     return "EXTENDS";
+"interface"   return "INTERFACE";
+"implements"  return "IMPLEMENTS";
+"abstract"    return "ABSTRACT";
+"super"       return "SUPER";
 "this"        return "THIS";
 "while"       return "WHILE";
+"do"          return "DO";
 "foreach"     return "FOREACH";
 "for"         return "FOR";
 "if"          return "IF";
 "else"        return "ELSE";
 "return"      return "RETURN";
 "break"       return "BREAK";
+"continue"    return "CONTINUE";
+"switch"      return "SWITCH";
+"case"        return "CASE";
 "Print"       return "PRINT";
 "ReadInteger" return "READ_INTEGER";
 "ReadLine"    return "READ_LINE";
+"CharToInt"   return "CHAR_TO_INT";
+"IntToChar"   return "INT_TO_CHAR";
+"StringToChars" return "STRING_TO_CHARS";
+"CharsToString" return "CHARS_TO_STRING";
 "static"      return "STATIC";
+"public"      return "PUBLIC";
+"private"     return "PRIVATE";
+"protected"   return "PROTECTED";
 "instanceof"  return "INSTANCEOF";
 "scopy"       return "SCOPY";
 "sealed"      return "SEALED";
 "var"         return "VAR";
 "default"     return "DEFAULT";
 "in"          return "IN";
+"try"         return "TRY";
+"catch"       return "CATCH";
+"finally"     return "FINALLY";
+"throw"       return "THROW";
+"assert"      return "ASSERT";
+"enum"        return "ENUM";
+"lambda"      return "LAMBDA";
 
 // operators
 "|||"         return "GUARD_SPLIT";
+"=>"          return "ARROW";
 "<="          return "LESS_EQUAL";
 ">="          return "GREATER_EQUAL";
 "=="          return "EQUAL";
@@ -47,6 +73,16 @@
 "--"          return "DEC";
 "<<"          return "SHL";
 ">>"          return "SHR";
+"+="          return "ADD_ASSIGN";
+"-="          return "SUB_ASSIGN";
+"*="          return "MUL_ASSIGN";
+"/="          return "DIV_ASSIGN";
+"%="          return "MOD_ASSIGN";
+"&="          return "AND_ASSIGN";
+"|="          return "OR_ASSIGN";
+"^="          return "XOR_ASSIGN";
+"<<="         return "SHL_ASSIGN";
+">>="         return "SHR_ASSIGN";
 
 // simple operators
 "+"           return "'+'";
@@ -71,6 +107,7 @@
 "{"           return "'{'";
 "}"           return "'}'";
 ":"           return "':'";
+"?"           return "'?'";
 
 <INITIAL>\"   {
                 self.begin("S");
@@ -82,7 +119,7 @@
 <S>\n         {
                 let loc = Loc(self.string_builder.1, self.string_builder.2);
                 let string = print::quote(&self.string_builder.0.clone());
-                self.report_error(Error::new(loc, NewlineInStr{ string }));
+                self.report_error(Error::new(loc, NewlineInStr { string }));
                 return "";
                 }
 // it must be accompanied by \n, so no-op here
@@ -90,7 +127,7 @@
 <S>$          {
                 let loc = Loc(self.string_builder.1, self.string_builder.2);
                 let string = print::quote(&self.string_builder.0.clone());
-                self.report_error(Error::new(loc, UnterminatedStr{ string }));
+                self.report_error(Error::new(loc, UnterminatedStr { string }));
                 self.begin("INITIAL");
                 return "";
               }
@@ -105,12 +142,18 @@
 \u002f\u002f[^\n]*  return "";
 \s+         return "";
 
+\d+(\.\d*([eE][+-]?\d+)?|[eE][+-]?\d+) return "DOUBLE_CONST";
+\d+[lL]     return "LONG_CONST";
 \d+         return "INT_CONST";
 
+\u0027([^\u0027\\\n]|\\.)\u0027 return "CHAR_CONST";
+
 [A-Za-z][_0-9A-Za-z]* return "IDENTIFIER";
 
 /lex
 
+%right ARROW
+%right '?' ':'
 %left OR
 %left AND
 %left '|'
@@ -123,7 +166,10 @@
 %left '+' '-'
 %left '*' '/' '%'
 %nonassoc UMINUS '!' INC DEC
-%nonassoc '[' '.' DEFAULT
+%nonassoc NEW_ARRAY
+// a name followed by '(' is a method call, rather than a function value to apply
+%nonassoc NAME
+%nonassoc '[' '.' '(' DEFAULT
 %nonassoc ')' EMPTY
 %nonassoc ELSE
 
@@ -133,6 +179,7 @@ use std::process;
 use std::mem;
 use std::ptr;
 use std::default::Default as D;
+use std::iter;
 
 use super::ast::*;
 use super::types::*;
@@ -156,12 +203,63 @@ impl Token {
 
 fn gen_binary(l: Expr, opt: Token, r: Expr, op: Operator) -> Expr {
   Expr::new(opt.get_loc(),
-    ExprData::Binary(Binary { op, l: Box::new(l), r: Box::new(r), }))
+            ExprData::Binary(Binary { op, l: Box::new(l), r: Box::new(r) }))
+}
+
+fn gen_compound(dst: Expr, opt: Token, src: Expr, op: Operator) -> Simple {
+  Simple::Assign(Assign { loc: opt.get_loc(), dst, src, op: Some(op) })
+}
+
+fn gen_convert(opt: Token, expr: Expr, op: ConvertOp) -> Expr {
+  Expr::new(opt.get_loc(),
+            ExprData::Convert(Convert { op, expr: Box::new(expr) }))
+}
+
+// the value of a char literal like 'a' or '\n', None if it is not a single ascii char
+fn parse_char(s: &str) -> Option<u8> {
+  Some(match &s[1..s.len() - 1] {
+    "\\n" => b'\n',
+    "\\t" => b'\t',
+    "\\r" => b'\r',
+    "\\0" => 0,
+    "\\\\" => b'\\',
+    "\\'" => b'\'',
+    "\\\"" => b'"',
+    s if s.len() == 1 => s.as_bytes()[0],
+    _ => return None,
+  })
+}
+
+// one more dimension of `new T[e1]...`, dim is None for an empty `[]`
+fn gen_new_array_dim(mut arr: Expr, dim: Option<Expr>) -> Expr {
+  if let ExprData::NewArray { elem_t, len: _, dims } = &mut arr.data {
+    elem_t.sem = SemanticType::Array(Box::new(mem::replace(&mut elem_t.sem, SemanticType::Error)));
+    if let Some(dim) = dim { dims.push(dim); }
+  }
+  arr
+}
+
+// `(T) e` is a numeric cast or a class cast depending on T, Err gives them back for other types
+fn gen_cast(t: Type, expr: Expr) -> Result<Expr, (Type, Expr)> {
+  let op = match &t.sem {
+    SemanticType::Int => ConvertOp::ToInt,
+    SemanticType::Double => ConvertOp::ToDouble,
+    SemanticType::Long => ConvertOp::ToLong,
+    &SemanticType::Named(name, ref arg) if arg.is_empty() => return Ok(Expr::new(expr.loc, ExprData::TypeCast { name, expr: Box::new(expr) })),
+    _ => return Err((t, expr)),
+  };
+  Ok(Expr::new(expr.loc, ExprData::Convert(Convert { op, expr: Box::new(expr) })))
+}
+
+// the return type is inferred by type checker
+fn gen_lambda(loc: Loc, param: Vec<VarDef>, body: Block, expr: bool) -> Expr {
+  let def = MethodDef::new(loc, "lambda", Type { loc, sem: SemanticType::Var }, param, false, body);
+  Expr::new(loc, ExprData::Lambda(Box::new(Lambda { loc, def, expr, capture: Vec::new(), llvm_env_t: ptr::null_mut() })))
 }
 
 fn gen_unary(opt: Token, r: Expr, op: Operator) -> Expr {
   Expr::new(opt.get_loc(),
-    ExprData::Unary(Unary { op, r: Box::new(r), }))
+            ExprData::Unary(Unary { op, r: Box::new(r) }))
 }
 
 fn on_parse_error(parser: &Parser, token: &Token) {
@@ -189,6 +287,12 @@ type VarDefList = Vec<VarDef>;
 type StmtList = Vec<Stmt>;
 type ExprList = Vec<Expr>;
 type GuardedList = Vec<(Expr, Block)>;
+type CaseList = Vec<Case>;
+type CatchList = Vec<Catch>;
+type IdentList = Vec<&'static str>;
+type ConstList = Vec<(Loc, &'static str)>;
+type TypeList = Vec<Type>;
+type TypeParamList = Vec<TypeParam>;
 type Flag = bool;
 
 %}
@@ -219,17 +323,99 @@ ClassList
   ;
 
 ClassDef
-  : MaybeSealed CLASS IDENTIFIER MaybeExtends  '{' FieldList '}' {
-    |$1: Flag, $2: Token, $3: Token, $4: Option<Str>, $6: FieldList| -> ClassDef;
+  : MaybeSealed CLASS IDENTIFIER MaybeTypeParams MaybeExtends MaybeImplements '{' FieldList '}' {
+    |$1: Flag, $2: Token, $3: Token, $4: TypeParamList, $5: Option<Str>, $6: IdentList, $8: FieldList| -> ClassDef;
     $$ = ClassDef {
       loc: $2.get_loc(),
       name: $3.value,
-      parent: $4,
-      field: $6,
+      parent: $5,
+      type_param: $4,
+      field: $8,
       sealed: $1,
+      implements: $6,
       ..D::default()
     };
   }
+  | ABSTRACT CLASS IDENTIFIER MaybeTypeParams MaybeExtends MaybeImplements '{' FieldList '}' {
+    |$2: Token, $3: Token, $4: TypeParamList, $5: Option<Str>, $6: IdentList, $8: FieldList| -> ClassDef;
+    $$ = ClassDef {
+      loc: $2.get_loc(),
+      name: $3.value,
+      parent: $5,
+      type_param: $4,
+      field: $8,
+      abstract_: true,
+      implements: $6,
+      ..D::default()
+    };
+  }
+  | INTERFACE IDENTIFIER '{' InterfaceFieldList '}' {
+    |$1: Token, $2: Token, $4: FieldList| -> ClassDef;
+    $$ = ClassDef {
+      loc: $1.get_loc(),
+      name: $2.value,
+      field: $4,
+      interface: true,
+      ..D::default()
+    };
+  }
+  | ENUM IDENTIFIER '{' ConstList '}' {
+    |$1: Token, $2: Token, $4: ConstList| -> ClassDef;
+    $$ = ClassDef {
+      loc: $1.get_loc(),
+      name: $2.value,
+      sealed: true,
+      enum_: Some($4),
+      ..D::default()
+    };
+  }
+  ;
+
+ConstList
+  : ConstList ',' IDENTIFIER {
+    |$1: ConstList, $3: Token| -> ConstList;
+    $1.push(($3.get_loc(), $3.value));
+    $$ = $1;
+  }
+  | IDENTIFIER {
+    |$1: Token| -> ConstList;
+    $$ = vec![($1.get_loc(), $1.value)];
+  }
+  ;
+
+MaybeTypeParams
+  : '<' TypeParamList '>' {
+    $$ = $2;
+  }
+  | /* empty */ {
+    || -> TypeParamList;
+    $$ = Vec::new();
+  }
+  ;
+
+TypeParamList
+  : TypeParamList ',' TypeParam {
+    |$1: TypeParamList, $3: TypeParam| -> TypeParamList;
+    $3.index = $1.len();
+    $1.push($3);
+    $$ = $1;
+  }
+  | TypeParam {
+    |$1: TypeParam| -> TypeParamList;
+    $$ = vec![$1];
+  }
+  ;
+
+TypeParam
+  : IDENTIFIER MaybeExtends {
+    |$1: Token, $2: Option<Str>| -> TypeParam;
+    $$ = TypeParam {
+      loc: $1.get_loc(),
+      name: $1.value,
+      bound: $2.map(|name| Type { loc: $1.get_loc(), sem: SemanticType::Named(name, Vec::new()) }),
+      index: 0,
+    };
+  }
   ;
 
 MaybeSealed
@@ -254,15 +440,53 @@ MaybeExtends
   }
   ;
 
+MaybeImplements
+  : IMPLEMENTS IdentList {
+    $$ = $2;
+  }
+  | /* empty */ {
+    || -> IdentList;
+    $$ = Vec::new();
+  }
+  ;
+
+IdentList
+  : IdentList ',' IDENTIFIER {
+    |$1: IdentList, $3: Token| -> IdentList;
+    $1.push($3.value);
+    $$ = $1;
+  }
+  | IDENTIFIER {
+    |$1: Token| -> IdentList;
+    $$ = vec![$1.value];
+  }
+  ;
+
+// only abstract methods are allowed in an interface
+InterfaceFieldList
+  : InterfaceFieldList Type IDENTIFIER '(' VarDefListOrEmpty ')' ';' {
+    |$1: FieldList, $2: Type, $3: Token, $5: VarDefList| -> FieldList;
+    let mut method_def = MethodDef::new($3.get_loc(), $3.value, $2, $5, false, D::default());
+    method_def.abstract_ = true;
+    $1.push(FieldDef::MethodDef(method_def));
+    $$ = $1;
+  }
+  | /* empty */ {
+    || -> FieldList;
+    $$ = Vec::new();
+  }
+  ;
+
 FieldList
-  : FieldList VarDef ';' {
-    |$1: FieldList, $2: VarDef| -> FieldList;
-    $1.push(FieldDef::VarDef($2));
+  : FieldList Field {
+    |$1: FieldList, $2: FieldDef| -> FieldList;
+    $1.push($2);
     $$ = $1;
   }
-  | FieldList MethodDef {
-    |$1: FieldList, $2: MethodDef| -> FieldList;
-    $1.push(FieldDef::MethodDef($2));
+  | FieldList Access Field {
+    |$1: FieldList, $2: Access, $3: FieldDef| -> FieldList;
+    $3.set_access($2);
+    $1.push($3);
     $$ = $1;
   }
   | /* empty */ {
@@ -271,35 +495,63 @@ FieldList
   }
   ;
 
+Access
+  : PUBLIC {
+    || -> Access;
+    $$ = Access::Public;
+  }
+  | PROTECTED {
+    || -> Access;
+    $$ = Access::Protected;
+  }
+  | PRIVATE {
+    || -> Access;
+    $$ = Access::Private;
+  }
+  ;
+
+Field
+  : VarDef ';' {
+    |$1: VarDef| -> FieldDef;
+    $$ = FieldDef::VarDef($1);
+  }
+  | STATIC VarDef ';' {
+    |$2: VarDef| -> FieldDef;
+    $2.static_ = true;
+    $$ = FieldDef::VarDef($2);
+  }
+  | STATIC Type IDENTIFIER '=' Expr ';' {
+    |$2: Type, $3: Token, $5: Expr| -> FieldDef;
+    let mut var_def = VarDef::new($3.get_loc(), $3.value, $2, Some($5), self.get_loc());
+    var_def.static_ = true;
+    $$ = FieldDef::VarDef(var_def);
+  }
+  | MethodDef {
+    |$1: MethodDef| -> FieldDef;
+    $$ = FieldDef::MethodDef($1);
+  }
+  | IDENTIFIER '(' VarDefListOrEmpty ')' Block {
+    |$1: Token, $3: VarDefList, $5: Block| -> FieldDef;
+    let ret_t = Type { loc: $1.get_loc(), sem: VOID };
+    $$ = FieldDef::Ctor(MethodDef::new($1.get_loc(), $1.value, ret_t, $3, false, $5));
+  }
+  ;
+
 // I don't know why use 'MaybeStatic -> eps | STATIC' will cause shift-reduce conflict
 MethodDef
   : STATIC Type IDENTIFIER '(' VarDefListOrEmpty ')' Block {
     |$2: Type, $3: Token, $5: VarDefList, $7: Block| -> MethodDef;
-    $$ = MethodDef {
-      loc: $3.get_loc(),
-      name: $3.value,
-      ret_t: $2,
-      param: $5,
-      static_: true,
-      body: $7,
-      scope: D::default(),
-      class: ptr::null(),
-      offset: -1,
-    };
+    $$ = MethodDef::new($3.get_loc(), $3.value, $2, $5, true, $7);
   }
   | Type IDENTIFIER '(' VarDefListOrEmpty ')' Block {
     |$1: Type, $2: Token, $4: VarDefList, $6: Block| -> MethodDef;
-    $$ = MethodDef {
-      loc: $2.get_loc(),
-      name: $2.value,
-      ret_t: $1,
-      param: $4,
-      static_: false,
-      body: $6,
-      scope: D::default(),
-      class: ptr::null(),
-      offset: -1,
-    };
+    $$ = MethodDef::new($2.get_loc(), $2.value, $1, $4, false, $6);
+  }
+  | ABSTRACT Type IDENTIFIER '(' VarDefListOrEmpty ')' ';' {
+    |$2: Type, $3: Token, $5: VarDefList| -> MethodDef;
+    let mut method_def = MethodDef::new($3.get_loc(), $3.value, $2, $5, false, D::default());
+    method_def.abstract_ = true;
+    $$ = method_def;
   }
   ;
 
@@ -356,10 +608,7 @@ Stmt
   | If {
     $$ = $1;
   }
-  | While {
-    $$ = $1;
-  }
-  | For {
+  | Loop {
     $$ = $1;
   }
   | Return ';' {
@@ -371,19 +620,50 @@ Stmt
   | Break ';' {
     $$ = $1;
   }
-  | SCopy ';' {
+  | Continue ';' {
     $$ = $1;
   }
-  | Foreach {
+  | IDENTIFIER ':' Loop {
+    |$1: Token, $3: Stmt| -> Stmt;
+    $3.set_label($1.value);
+    $$ = $3;
+  }
+  | SCopy ';' {
     $$ = $1;
   }
   | Guarded {
     $$ = $1;
   }
+  | Switch {
+    $$ = $1;
+  }
   | Block {
     |$1: Block| -> Stmt;
     $$ = Stmt::Block($1);
   }
+  | Try {
+    $$ = $1;
+  }
+  | THROW Expr ';' {
+    |$1: Token, $2: Expr| -> Stmt;
+    $$ = Stmt::Throw(Throw { loc: $1.get_loc(), expr: $2 });
+  }
+  | ASSERT '(' Expr ')' ';' {
+    |$1: Token, $3: Expr| -> Stmt;
+    $$ = Stmt::Assert(Assert { loc: $1.get_loc(), cond: $3, msg: None });
+  }
+  | ASSERT '(' Expr ',' Expr ')' ';' {
+    |$1: Token, $3: Expr, $5: Expr| -> Stmt;
+    $$ = Stmt::Assert(Assert { loc: $1.get_loc(), cond: $3, msg: Some($5) });
+  }
+  | SUPER '(' ExprListOrEmpty ')' ';' {
+    |$1: Token, $3: ExprList| -> Stmt;
+    $$ = Stmt::SuperCall(SuperCall {
+      loc: $1.get_loc(),
+      arg: $3,
+      ctor: ptr::null(),
+    });
+  }
   ;
 
 Blocked
@@ -400,22 +680,51 @@ Blocked
   }
   ;
 
+Loop
+  : While {
+    $$ = $1;
+  }
+  | DoWhile ';' {
+    $$ = $1;
+  }
+  | For {
+    $$ = $1;
+  }
+  | Foreach {
+    $$ = $1;
+  }
+  ;
+
 While
   : WHILE '(' Expr ')' Blocked {
     |$1: Token, $3: Expr, $5: Block| -> Stmt;
     $$ = Stmt::While(While {
       loc: $1.get_loc(),
+      label: None,
       cond: $3,
       body: $5,
     });
   }
   ;
 
+DoWhile
+  : DO Blocked WHILE '(' Expr ')' {
+    |$1: Token, $2: Block, $5: Expr| -> Stmt;
+    $$ = Stmt::DoWhile(DoWhile {
+      loc: $1.get_loc(),
+      label: None,
+      body: $2,
+      cond: $5,
+    });
+  }
+  ;
+
 For
   : FOR '(' Simple ';' Expr ';' Simple ')' Blocked {
     |$1: Token, $3: Simple, $5: Expr, $7: Simple, $9: Block| -> Stmt;
     $$ = Stmt::For(For {
       loc: $1.get_loc(),
+      label: None,
       init: $3,
       cond: $5,
       update: $7,
@@ -428,16 +737,8 @@ Foreach
   : FOREACH '(' TypeOrVar IDENTIFIER IN Expr MaybeForeachCond ')' Blocked {
     |$3: Type, $4: Token, $6: Expr, $7: Option<Expr>, $9: Block| -> Stmt;
     $$ = Stmt::Foreach(Foreach {
-      def: VarDef {
-        loc: $4.get_loc(),
-        name: $4.value,
-        type_: $3,
-        finish_loc: $4.get_loc(),
-        src: None,
-        scope: ptr::null(),
-        index: D::default(),
-        offset: -1,
-      },
+      label: None,
+      def: VarDef::new($4.get_loc(), $4.value, $3, None, $4.get_loc()),
       arr: $6,
       cond: $7,
       body: $9,
@@ -448,7 +749,22 @@ Foreach
 Break
   : BREAK {
     |$1: Token| -> Stmt;
-    $$ = Stmt::Break(Break { loc: $1.get_loc(), });
+    $$ = Stmt::Break(Break { loc: $1.get_loc(), label: None });
+  }
+  | BREAK IDENTIFIER {
+    |$1: Token, $2: Token| -> Stmt;
+    $$ = Stmt::Break(Break { loc: $1.get_loc(), label: Some($2.value) });
+  }
+  ;
+
+Continue
+  : CONTINUE {
+    |$1: Token| -> Stmt;
+    $$ = Stmt::Continue(Continue { loc: $1.get_loc(), label: None });
+  }
+  | CONTINUE IDENTIFIER {
+    |$1: Token, $2: Token| -> Stmt;
+    $$ = Stmt::Continue(Continue { loc: $1.get_loc(), label: Some($2.value) });
   }
   ;
 
@@ -480,7 +796,7 @@ SCopy
     |$1: Token, $3: Token, $5: Expr| -> Stmt;
     $$ = Stmt::SCopy(SCopy {
       loc: $1.get_loc(),
-      dst_loc:$3.get_loc(),
+      dst_loc: $3.get_loc(),
       dst: $3.value,
       dst_sym: ptr::null(),
       src: $5,
@@ -519,6 +835,61 @@ Guarded
   }
   ;
 
+Switch
+  : SWITCH '(' Expr ')' '{' CaseList '}' {
+    |$1: Token, $3: Expr, $6: CaseList| -> Stmt;
+    $$ = Stmt::Switch(Switch {
+      loc: $1.get_loc(),
+      cond: $3,
+      case: $6,
+    });
+  }
+  ;
+
+CaseList
+  : CaseList Case {
+    |$1: CaseList, $2: Case| -> CaseList;
+    $1.push($2);
+    $$ = $1;
+  }
+  | /* empty */ {
+    || -> CaseList;
+    $$ = Vec::new();
+  }
+  ;
+
+Case
+  : CASE CaseValue ':' StmtList {
+    |$1: Token, $2: i32, $4: StmtList| -> Case;
+    $$ = Case { loc: $1.get_loc(), value: Some($2), name: None, body: Block { loc: NO_LOC, stmt: $4, ..D::default() } };
+  }
+  | CASE IDENTIFIER ':' StmtList {
+    |$1: Token, $2: Token, $4: StmtList| -> Case;
+    $$ = Case { loc: $1.get_loc(), value: Some(0), name: Some($2.value), body: Block { loc: NO_LOC, stmt: $4, ..D::default() } };
+  }
+  | DEFAULT ':' StmtList {
+    |$1: Token, $3: StmtList| -> Case;
+    $$ = Case { loc: $1.get_loc(), value: None, name: None, body: Block { loc: NO_LOC, stmt: $3, ..D::default() } };
+  }
+  ;
+
+CaseValue
+  : INT_CONST {
+    |$1: Token| -> i32;
+    $$ = $1.value.parse::<i32>().unwrap_or_else(|_| {
+      self.errors.push(Error::new($1.get_loc(), IntTooLarge { string: $1.value.to_string() }));
+      0
+    });
+  }
+  | '-' INT_CONST {
+    |$2: Token| -> i32;
+    $$ = format!("-{}", $2.value).parse::<i32>().unwrap_or_else(|_| {
+      self.errors.push(Error::new($2.get_loc(), IntTooLarge { string: format!("-{}", $2.value) }));
+      0
+    });
+  }
+  ;
+
 GuardedBranchesOrEmpty
   :  GuardedBranches {
     $$ = $1;
@@ -541,6 +912,44 @@ GuardedBranches
   }
   ;
 
+Try
+  : TRY Block CatchList {
+    |$1: Token, $2: Block, $3: CatchList| -> Stmt;
+    $$ = Stmt::Try(Try { loc: $1.get_loc(), body: $2, catch: $3, finally: None });
+  }
+  | TRY Block CatchList FINALLY Block {
+    |$1: Token, $2: Block, $3: CatchList, $5: Block| -> Stmt;
+    $$ = Stmt::Try(Try { loc: $1.get_loc(), body: $2, catch: $3, finally: Some($5) });
+  }
+  | TRY Block FINALLY Block {
+    |$1: Token, $2: Block, $4: Block| -> Stmt;
+    $$ = Stmt::Try(Try { loc: $1.get_loc(), body: $2, catch: Vec::new(), finally: Some($4) });
+  }
+  ;
+
+CatchList
+  : CatchList Catch {
+    |$1: CatchList, $2: Catch| -> CatchList;
+    $1.push($2);
+    $$ = $1;
+  }
+  | Catch {
+    |$1: Catch| -> CatchList;
+    $$ = vec![$1];
+  }
+  ;
+
+Catch
+  : CATCH '(' Type IDENTIFIER ')' Block {
+    |$1: Token, $3: Type, $4: Token, $6: Block| -> Catch;
+    $$ = Catch {
+      loc: $1.get_loc(),
+      def: VarDef::new($4.get_loc(), $4.value, $3, None, $4.get_loc()),
+      body: $6,
+    };
+  }
+  ;
+
 Return
   : RETURN Expr {
     |$1: Token, $2: Expr| -> Stmt;
@@ -588,33 +997,56 @@ Simple
       loc: $2.get_loc(),
       dst: $1,
       src: $3,
+      op: None,
     });
   }
+  | LValue ADD_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::Add);
+  }
+  | LValue SUB_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::Sub);
+  }
+  | LValue MUL_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::Mul);
+  }
+  | LValue DIV_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::Div);
+  }
+  | LValue MOD_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::Mod);
+  }
+  | LValue AND_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::BAnd);
+  }
+  | LValue OR_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::BOr);
+  }
+  | LValue XOR_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::BXor);
+  }
+  | LValue SHL_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::Shl);
+  }
+  | LValue SHR_ASSIGN Expr {
+    |$1: Expr, $2: Token, $3: Expr| -> Simple;
+    $$ = gen_compound($1, $2, $3, Operator::Shr);
+  }
   | Type IDENTIFIER '=' Expr {
     |$1: Type, $2: Token, $3: Token, $4: Expr| -> Simple;
-    $$ = Simple::VarDef(VarDef {
-      loc: $2.get_loc(),
-      name: $2.value,
-      type_: $1,
-      finish_loc: self.get_loc(),
-      src: Some($4),
-      scope: ptr::null(),
-      index: D::default(),
-      offset: -1,
-    });
+    $$ = Simple::VarDef(VarDef::new($2.get_loc(), $2.value, $1, Some($4), self.get_loc()));
   }
   | VAR IDENTIFIER '=' Expr {
     |$1: Token, $2: Token, $3: Token, $4: Expr| -> Simple;
-    $$ = Simple::VarDef(VarDef {
-      loc: $2.get_loc(),
-      name: $2.value,
-      type_: Type { loc: $1.get_loc(), sem: VAR },
-      finish_loc: self.get_loc(),
-      src: Some($4),
-      scope: ptr::null(),
-      index: D::default(),
-      offset: -1,
-    });
+    $$ = Simple::VarDef(VarDef::new($2.get_loc(), $2.value, Type { loc: $1.get_loc(), sem: VAR }, Some($4), self.get_loc()));
   }
   | VarDef {
     |$1: VarDef| -> Simple;
@@ -641,13 +1073,44 @@ Expr
       name: $2.value,
       arg: $4,
       is_arr_len: false,
+      string_method: None,
+      method: ptr::null(),
+    }));
+  }
+  | IDENTIFIER '(' ExprListOrEmpty ')' {
+    |$1: Token, $3: ExprList| -> Expr;
+    $$ = Expr::new($1.get_loc(), ExprData::Call(Call {
+      owner: None,
+      name: $1.value,
+      arg: $3,
+      is_arr_len: false,
+      string_method: None,
       method: ptr::null(),
     }));
   }
   | INT_CONST {
     |$1: Token| -> Expr;
     $$ = Expr::with_type($1.get_loc(), INT, ExprData::IntConst($1.value.parse::<i32>().unwrap_or_else(|_| {
-      self.errors.push(Error::new($1.get_loc(), IntTooLarge{ string: $1.value.to_string(), }));
+      self.errors.push(Error::new($1.get_loc(), IntTooLarge { string: $1.value.to_string() }));
+      0
+    })));
+  }
+  | LONG_CONST {
+    |$1: Token| -> Expr;
+    let digits = &$1.value[..$1.value.len() - 1];
+    $$ = Expr::with_type($1.get_loc(), LONG, ExprData::LongConst(digits.parse::<i64>().unwrap_or_else(|_| {
+      self.errors.push(Error::new($1.get_loc(), IntTooLarge { string: $1.value.to_string() }));
+      0
+    })));
+  }
+  | DOUBLE_CONST {
+    |$1: Token| -> Expr;
+    $$ = Expr::with_type($1.get_loc(), DOUBLE, ExprData::DoubleConst($1.value.parse::<f64>().unwrap()));
+  }
+  | CHAR_CONST {
+    |$1: Token| -> Expr;
+    $$ = Expr::with_type($1.get_loc(), CHAR, ExprData::CharConst(parse_char($1.value).unwrap_or_else(|| {
+      self.errors.push(Error::new($1.get_loc(), BadCharConst { string: $1.value.to_string() }));
       0
     })));
   }
@@ -662,15 +1125,15 @@ Expr
   | STRING_CONST {
     || -> Expr;
     $$ = Expr::with_type(Loc(self.tokenizer.string_builder.1, self.tokenizer.string_builder.2),
-        STRING, ExprData::StringConst(self.tokenizer.string_builder.0.clone()));
+                             STRING, ExprData::StringConst(self.tokenizer.string_builder.0.clone()));
   }
   | '[' ExprList ']' {
     |$2: ExprList| -> Expr;
-    $$ = Expr::new(self.get_loc(), ExprData::ArrayConst($1));
+    $$ = Expr::new(self.get_loc(), ExprData::ArrayConst($2));
   }
   | NULL {
     |$1: Token| -> Expr;
-    $$ = Expr::new($1.get_loc(), ExprData::Null);
+    $$ = Expr::with_type($1.get_loc(), NULL, ExprData::Null);
   }
   | Expr '+' Expr {
     |$1: Expr, $2: Token, $3: Expr| -> Expr;
@@ -751,12 +1214,20 @@ Expr
   | Expr '[' Expr ':' Expr ']' {
     |$1: Expr, $2: Token, $3: Expr, $5: Expr| -> Expr;
     $$ = Expr::new($2.get_loc(),
-      ExprData::Range(Range { arr: Box::new($1), lb: Box::new($3), ub: Box::new($5), }));
+                       ExprData::Range(Range { arr: Box::new($1), lb: Box::new($3), ub: Box::new($5) }));
+  }
+  | Expr '?' Expr ':' Expr {
+    |$1: Expr, $2: Token, $3: Expr, $5: Expr| -> Expr;
+    $$ = Expr::new($2.get_loc(), ExprData::Conditional(Conditional {
+      cond: Box::new($1),
+      on_true: Box::new($3),
+      on_false: Box::new($5),
+    }));
   }
   | Expr '[' Expr ']' DEFAULT Expr {
     |$1: Expr, $2: Token, $3: Expr, $6: Expr| -> Expr;
     $$ = Expr::new($2.get_loc(),
-      ExprData::Default(Default { arr: Box::new($1), idx: Box::new($3), dft: Box::new($6), }));
+                       ExprData::Default(Default { arr: Box::new($1), idx: Box::new($3), dft: Box::new($6) }));
   }
   | '[' Expr FOR IDENTIFIER IN Expr ']' {
     |$1: Token, $2: Expr, $4: Token, $6: Expr| -> Expr;
@@ -811,30 +1282,90 @@ Expr
     |$1: Token| -> Expr;
     $$ = Expr::with_type($1.get_loc(), STRING, ExprData::ReadLine);
   }
+  | CHAR_TO_INT '(' Expr ')' {
+    |$1: Token, $3: Expr| -> Expr;
+    $$ = gen_convert($1, $3, ConvertOp::CharToInt);
+  }
+  | INT_TO_CHAR '(' Expr ')' {
+    |$1: Token, $3: Expr| -> Expr;
+    $$ = gen_convert($1, $3, ConvertOp::IntToChar);
+  }
+  | STRING_TO_CHARS '(' Expr ')' {
+    |$1: Token, $3: Expr| -> Expr;
+    $$ = gen_convert($1, $3, ConvertOp::StringToChars);
+  }
+  | CHARS_TO_STRING '(' Expr ')' {
+    |$1: Token, $3: Expr| -> Expr;
+    $$ = gen_convert($1, $3, ConvertOp::CharsToString);
+  }
   | THIS {
     |$1: Token| -> Expr;
     $$ = Expr::new($1.get_loc(), ExprData::This);
   }
-  | NEW IDENTIFIER '(' ')' {
-    |$1: Token, $2: Token| -> Expr;
-    $$ = Expr::new($1.get_loc(), ExprData::NewClass { name: $2.value, });
+  | NEW IDENTIFIER '(' ExprListOrEmpty ')' {
+    |$1: Token, $2: Token, $4: ExprList| -> Expr;
+    $$ = Expr::new($1.get_loc(), ExprData::NewClass { name: $2.value, type_arg: Vec::new(), arg: $4 });
   }
-  | NEW Type '[' Expr ']' {
-    |$1: Token, $2: Type, $4: Expr| -> Expr;
-    $$ = Expr::new($1.get_loc(), ExprData::NewArray { elem_t: $2, len: Box::new($4), });
+  | NEW IDENTIFIER '<' TypeList '>' '(' ExprListOrEmpty ')' {
+    |$1: Token, $2: Token, $4: TypeList, $7: ExprList| -> Expr;
+    $$ = Expr::new($1.get_loc(), ExprData::NewClass { name: $2.value, type_arg: $4.into_iter().map(|t| t.sem).collect(), arg: $7 });
+  }
+  | NewArrayDims %prec NEW_ARRAY {
+    $$ = $1;
+  }
+  | NewArrayEmptyDims %prec NEW_ARRAY {
+    $$ = $1;
   }
   | INSTANCEOF '(' Expr ',' IDENTIFIER ')' {
     |$1: Token, $3: Expr, $5: Token| -> Expr;
-    $$ = Expr::new($1.get_loc(), ExprData::TypeTest { expr: Box::new($3), name: $5.value, target_class: ptr::null() });
+    $$ = Expr::with_type($1.get_loc(), BOOL, ExprData::TypeTest { expr: Box::new($3), name: $5.value, target_class: ptr::null() });
   }
-  | '(' CLASS IDENTIFIER ')' Expr {
-    |$3: Token, $5: Expr| -> Expr;
-    $$ = Expr::new($5.loc, ExprData::TypeCast { name: $3.value, expr: Box::new($5), });
+  | '(' Type ')' Expr {
+    |$2: Type, $4: Expr| -> Expr;
+    $$ = gen_cast($2, $4).unwrap_or_else(|(t, e)| {
+      self.errors.push(Error::new(t.loc, BadCastType { type_: t.sem.to_string() }));
+      e
+    });
+  }
+  | Expr '(' ExprListOrEmpty ')' {
+    |$1: Expr, $3: ExprList| -> Expr;
+    $$ = Expr::new($1.loc, ExprData::Apply(Apply { func: Box::new($1), arg: $3 }));
+  }
+  | LAMBDA '(' VarDefListOrEmpty ')' ARROW Expr {
+    |$1: Token, $3: VarDefList, $6: Expr| -> Expr;
+    let body = Block { loc: $6.loc, stmt: vec![Stmt::Simple(Simple::Expr($6))], ..D::default() };
+    $$ = gen_lambda($1.get_loc(), $3, body, true);
+  }
+  | LAMBDA '(' VarDefListOrEmpty ')' Block {
+    |$1: Token, $3: VarDefList, $5: Block| -> Expr;
+    $$ = gen_lambda($1.get_loc(), $3, $5, false);
+  }
+  ;
+
+NewArrayDims
+  : NEW Type '[' Expr ']' {
+    |$1: Token, $2: Type, $4: Expr| -> Expr;
+    $$ = Expr::new($1.get_loc(), ExprData::NewArray { elem_t: $2, len: Box::new($4), dims: Vec::new() });
+  }
+  | NewArrayDims '[' Expr ']' {
+    |$1: Expr, $3: Expr| -> Expr;
+    $$ = gen_new_array_dim($1, Some($3));
+  }
+  ;
+
+NewArrayEmptyDims
+  : NewArrayDims '[' ']' {
+    |$1: Expr| -> Expr;
+    $$ = gen_new_array_dim($1, None);
+  }
+  | NewArrayEmptyDims '[' ']' {
+    |$1: Expr| -> Expr;
+    $$ = gen_new_array_dim($1, None);
   }
   ;
 
 LValue
-  : MaybeReceiver IDENTIFIER {
+  : MaybeReceiver IDENTIFIER %prec NAME {
     |$1: Option<Expr>, $2: Token| -> Expr;
     $$ = Expr::new($2.get_loc(), ExprData::Id(Id {
       owner: $1.map(|e| Box::new(e)),
@@ -843,6 +1374,15 @@ LValue
       for_assign: D::default(),
     }));
   }
+  | IDENTIFIER %prec NAME {
+    |$1: Token| -> Expr;
+    $$ = Expr::new($1.get_loc(), ExprData::Id(Id {
+      owner: None,
+      name: $1.value,
+      symbol: ptr::null(),
+      for_assign: D::default(),
+    }));
+  }
   | Expr '[' Expr ']' {
     |$1: Expr, $3: Expr| -> Expr;
     $$ = Expr::new($1.loc, ExprData::Indexed(Indexed {
@@ -858,9 +1398,9 @@ MaybeReceiver
     |$1: Expr| -> Option<Expr>;
     $$ = Some($1);
   }
-  | /* empty */ {
-    || -> Option<Expr>;
-    $$ = None;
+  | SUPER '.' {
+    |$1: Token| -> Option<Expr>;
+    $$ = Some(Expr::new($1.get_loc(), ExprData::Super));
   }
   ;
 
@@ -878,19 +1418,22 @@ ExprListOrEmpty
 VarDef
   : Type IDENTIFIER {
     |$1: Type, $2: Token| -> VarDef;
-    $$ = VarDef {
-      loc: $2.get_loc(),
-      name: $2.value,
-      type_: $1,
-      finish_loc: self.get_loc(),
-      src: None,
-      scope: ptr::null(),
-      index: D::default(),
-      offset: -1,
-    };
+    $$ = VarDef::new($2.get_loc(), $2.value, $1, None, self.get_loc());
   }
   ;
         
+TypeList
+  : TypeList ',' Type {
+    |$1: TypeList, $3: Type| -> TypeList;
+    $1.push($3);
+    $$ = $1;
+  }
+  | Type {
+    |$1: Type| -> TypeList;
+    $$ = vec![$1];
+  }
+  ;
+
 Type
   : INT {
     |$1: Token| -> Type;
@@ -908,12 +1451,41 @@ Type
     |$1: Token| -> Type;
     $$ = Type { loc: $1.get_loc(), sem: STRING };
   }
+  | CHAR {
+    |$1: Token| -> Type;
+    $$ = Type { loc: $1.get_loc(), sem: CHAR };
+  }
+  | DOUBLE {
+    |$1: Token| -> Type;
+    $$ = Type { loc: $1.get_loc(), sem: DOUBLE };
+  }
+  | LONG {
+    |$1: Token| -> Type;
+    $$ = Type { loc: $1.get_loc(), sem: LONG };
+  }
   | CLASS IDENTIFIER  {
     |$1: Token, $2: Token| -> Type;
-    $$ = Type { loc: $2.get_loc(), sem: SemanticType::Named($2.value) };
+    $$ = Type { loc: $2.get_loc(), sem: SemanticType::Named($2.value, Vec::new()) };
+  }
+  | CLASS IDENTIFIER '<' TypeList '>' {
+    |$1: Token, $2: Token, $4: TypeList| -> Type;
+    $$ = Type { loc: $2.get_loc(), sem: SemanticType::Named($2.value, $4.into_iter().map(|t| t.sem).collect()) };
   }
   | Type '[' ']' {
     |$1: Type| -> Type;
     $$ = Type { loc: $1.loc, sem: SemanticType::Array(Box::new($1.sem)) };
   }
+  | '(' ')' ARROW Type {
+    |$1: Token, $4: Type| -> Type;
+    $$ = Type { loc: $1.get_loc(), sem: SemanticType::Func(Vec::new(), Box::new($4.sem)) };
+  }
+  | '(' Type ')' ARROW Type {
+    |$1: Token, $2: Type, $5: Type| -> Type;
+    $$ = Type { loc: $1.get_loc(), sem: SemanticType::Func(vec![$2.sem], Box::new($5.sem)) };
+  }
+  | '(' Type ',' TypeList ')' ARROW Type {
+    |$1: Token, $2: Type, $4: TypeList, $7: Type| -> Type;
+    let param = iter::once($2).chain($4.into_iter()).map(|t| t.sem).collect();
+    $$ = Type { loc: $1.get_loc(), sem: SemanticType::Func(param, Box::new($7.sem)) };
+  }
   ;
\ No newline at end of file
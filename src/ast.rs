@@ -3,10 +3,12 @@ use super::symbol::*;
 use super::types::*;
 use super::util::*;
 use super::tac::VTable;
+use super::errors::Warning;
 
 use std::default::Default as D;
 use std::ptr;
 use std::ops::Deref;
+use std::fmt;
 
 use llvm_sys::prelude::*;
 
@@ -15,6 +17,12 @@ pub struct Program {
   pub class: Vec<ClassDef>,
   pub scope: Scope,
   pub main: *const ClassDef,
+  // tac & llvm: without any try, an exception can only be uncaught, so it halts at once
+  pub has_try: bool,
+  // set by --no-asserts, code gen skips all asserts
+  pub no_asserts: bool,
+  // found by type checker, they don't stop the compilation
+  pub warnings: Vec<Warning>,
 }
 
 impl D for Program {
@@ -23,6 +31,9 @@ impl D for Program {
       class: D::default(),
       scope: Scope { symbols: D::default(), kind: ScopeKind::Global },
       main: ptr::null(),
+      has_try: false,
+      no_asserts: false,
+      warnings: Vec::new(),
     }
   }
 }
@@ -33,14 +44,28 @@ pub struct ClassDef {
   pub loc: Loc,
   pub name: &'static str,
   pub parent: Option<&'static str>,
+  // non-empty for a generic class, which can't be extended
+  pub type_param: Vec<TypeParam>,
   pub field: Vec<FieldDef>,
   pub sealed: bool,
+  pub abstract_: bool,
+  pub interface: bool,
+  pub implements: Vec<&'static str>,
+  // Some for an enum, a sealed class whose members are generated from its constants, see `SymbolBuilder::enum_members`
+  pub enum_: Option<Vec<(Loc, &'static str)>>,
+  // the built-in exception classes, see `BUILTIN_EXCEPTIONS`
+  pub builtin: bool,
   // semantic part
   // to calculate inheritance order and determine cyclic inheritance
   pub order: i32,
   // to avoid duplicate override check
   pub checked: bool,
   pub p_ptr: *mut ClassDef,
+  pub i_ptr: Vec<*mut ClassDef>,
+  // null if the class declares no constructor
+  pub ctor: *const MethodDef,
+  // only for interface: all concrete classes implementing it, directly or through parents
+  pub implementors: Vec<*const ClassDef>,
   pub scope: Scope,
   // default field_cnt is -1, for `not resolved`
   pub field_cnt: i32,
@@ -56,11 +81,20 @@ impl D for ClassDef {
       loc: D::default(),
       name: D::default(),
       parent: D::default(),
+      type_param: D::default(),
       field: D::default(),
       sealed: D::default(),
+      abstract_: D::default(),
+      interface: D::default(),
+      implements: D::default(),
+      enum_: D::default(),
+      builtin: D::default(),
       order: -1,
       checked: D::default(),
       p_ptr: ptr::null_mut(),
+      i_ptr: D::default(),
+      ctor: ptr::null(),
+      implementors: D::default(),
       scope: D::default(),
       field_cnt: -1,
       v_tbl: VTable { class: ptr::null(), methods: Vec::new() },
@@ -80,9 +114,57 @@ impl ClassDef {
       }
       class = class.get().p_ptr;
     }
+    // an abstract class can use the interface methods it doesn't implement
+    let mut class = self as *const ClassDef;
+    while !class.is_null() {
+      for &interface in &class.get().i_ptr {
+        if let Some(symbol) = interface.get().scope.get(name) {
+          return Some(*symbol);
+        }
+      }
+      class = class.get().p_ptr;
+    }
     None
   }
 
+  // all methods named `name` that can be called on this class, the ones overridden in a subclass excluded
+  pub fn lookup_overloads(&self, name: &'static str) -> Vec<*mut MethodDef> {
+    let mut ret = match self.scope.get(name) {
+      Some(Symbol::Method(method)) => {
+        let mut ret = vec![*method];
+        ret.extend(&method.get().overload);
+        ret
+      }
+      _ => Vec::new(),
+    };
+    // an inherited method is excluded only if a method of this class overrides it(or it is inherited twice)
+    let inherited = self.inherited_overloads(name);
+    let own = ret.clone();
+    for &method in &inherited {
+      let overridden = own.iter().any(|m| match_override(m.get(), &inherited) == Some(method));
+      if !overridden && !ret.iter().any(|m| m.get().same_param(method.get())) { ret.push(method); }
+    }
+    ret
+  }
+
+  // the methods named `name` inherited from the parent(private ones are not inherited)
+  // an abstract class can use the interface methods it doesn't implement
+  pub fn inherited_overloads(&self, name: &'static str) -> Vec<*mut MethodDef> {
+    let mut ret = Vec::new();
+    if !self.p_ptr.is_null() {
+      ret.extend(self.p_ptr.get().lookup_overloads(name).into_iter().filter(|method| method.get().access != Access::Private));
+    }
+    for &interface in &self.i_ptr { ret.extend(interface.get().lookup_overloads(name)); }
+    ret
+  }
+
+  // the method implementing interface method `method` in this class
+  pub fn lookup_impl(&self, method: &MethodDef) -> Option<*mut MethodDef> {
+    let candidates = self.lookup_overloads(method.name).into_iter()
+      .filter(|candidate| !candidate.get().class.get().interface).collect::<Vec<_>>();
+    match_override(method, &candidates)
+  }
+
   pub fn extends(&self, other: *const ClassDef) -> bool {
     let mut class = self as *const ClassDef;
     while !class.is_null() {
@@ -94,21 +176,101 @@ impl ClassDef {
     false
   }
 
+  pub fn implements(&self, interface: *const ClassDef) -> bool {
+    let mut class = self as *const ClassDef;
+    while !class.is_null() {
+      if class.get().i_ptr.iter().any(|&i| i as *const ClassDef == interface) {
+        return true;
+      }
+      class = class.get().p_ptr;
+    }
+    false
+  }
+
+  // the nearest user-defined constructor, which `new` and `super(...)` call
+  // a class without constructor has a default one, which does nothing but call its parent's
+  pub fn lookup_ctor(&self) -> Option<&MethodDef> {
+    let mut class = self as *const ClassDef;
+    while !class.is_null() {
+      if !class.get().ctor.is_null() {
+        return Some(class.get().ctor.get());
+      }
+      class = class.get().p_ptr;
+    }
+    None
+  }
+
+  // inside a generic class, `this` has its own type parameters as type arguments
   pub fn get_object_type(&self) -> SemanticType {
-    SemanticType::Object(self)
+    SemanticType::Object(self, self.type_param.iter().map(|p| SemanticType::Param(p)).collect())
+  }
+
+  pub fn lookup_type_param(&self, name: &'static str) -> Option<*const TypeParam> {
+    self.type_param.iter().find(|p| p.name == name).map(|p| p as *const TypeParam)
   }
 }
 
+// `T` or `T extends A` in `class C<T, U extends A>`
+// the members of its bound can be accessed through it, and code gen erases it to its bound, or to any object if it has no bound
+#[derive(Debug)]
+pub struct TypeParam {
+  pub loc: Loc,
+  pub name: &'static str,
+  pub bound: Option<Type>,
+  // the position in the type parameter list, to find the corresponding type argument
+  pub index: usize,
+}
+
 #[derive(Debug)]
 pub enum FieldDef {
   MethodDef(MethodDef),
   VarDef(VarDef),
+  // a constructor is named after its class and returns void
+  Ctor(MethodDef),
+}
+
+// ordered from the most restrictive
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Access {
+  Private,
+  Protected,
+  Public,
+}
+
+impl fmt::Display for Access {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match self {
+      Access::Private => write!(f, "private"),
+      Access::Protected => write!(f, "protected"),
+      Access::Public => write!(f, "public"),
+    }
+  }
+}
+
+impl Stmt {
+  // only called on loops by the parser
+  pub fn set_label(&mut self, label: &'static str) {
+    match self {
+      Stmt::While(while_) => while_.label = Some(label),
+      Stmt::DoWhile(do_while) => do_while.label = Some(label),
+      Stmt::For(for_) => for_.label = Some(label),
+      Stmt::Foreach(foreach) => foreach.label = Some(label),
+      _ => unreachable!(),
+    }
+  }
 }
 
 impl FieldDef {
+  pub fn set_access(&mut self, access: Access) {
+    match self {
+      FieldDef::MethodDef(method_def) | FieldDef::Ctor(method_def) => method_def.access = access,
+      FieldDef::VarDef(var_def) => var_def.access = access,
+    }
+  }
+
   pub fn get_loc(&self) -> Loc {
     match self {
-      FieldDef::MethodDef(method_def) => method_def.loc,
+      FieldDef::MethodDef(method_def) | FieldDef::Ctor(method_def) => method_def.loc,
       FieldDef::VarDef(var_def) => var_def.loc,
     }
   }
@@ -121,11 +283,19 @@ pub struct MethodDef {
   pub ret_t: Type,
   pub param: Vec<VarDef>,
   pub static_: bool,
+  // public if not specified
+  pub access: Access,
+  // abstract methods(including all interface methods) have an empty body
+  pub abstract_: bool,
   // body contains the scope of stack variables
   pub body: Block,
   // scope for parameters
   pub scope: Scope,
   pub class: *const ClassDef,
+  // the class scope only holds the 1st declared method of a name, the other overloads are kept here
+  pub overload: Vec<*mut MethodDef>,
+  // 0 for the 1st declared method of a name in its class, tac & llvm append it to the name of other overloads
+  pub overload_idx: u32,
   // tac & llvm: the offset in v-table
   pub offset: i32,
   pub llvm_t: LLVMTypeRef,
@@ -133,9 +303,47 @@ pub struct MethodDef {
 }
 
 impl MethodDef {
+  // for a constructor without explicit 'super(...)' as the first statement, the parent constructor it calls
+  pub fn implicit_super(&self) -> Option<&MethodDef> {
+    match self.body.stmt.first() {
+      Some(Stmt::SuperCall(_)) => None,
+      _ => {
+        let p = self.class.get().p_ptr;
+        if p.is_null() { None } else { p.get().lookup_ctor() }
+      }
+    }
+  }
+
   pub fn new(loc: Loc, name: &'static str, ret_t: Type, param: Vec<VarDef>, static_: bool, body: Block) -> MethodDef {
-    MethodDef { loc, name, ret_t, param, static_, body, scope: D::default(), class: ptr::null(), offset: -1, llvm_t: ptr::null_mut(), llvm_val: ptr::null_mut() }
+    MethodDef { loc, name, ret_t, param, static_, access: Access::Public, abstract_: false, body, scope: D::default(), class: ptr::null(), overload: Vec::new(), overload_idx: 0, offset: -1, llvm_t: ptr::null_mut(), llvm_val: ptr::null_mut() }
+  }
+
+  // parameters without 'this'
+  pub fn explicit_param(&self) -> &[VarDef] {
+    &self.param[if self.static_ { 0 } else { 1 }..]
   }
+
+  pub fn same_param(&self, other: &MethodDef) -> bool {
+    let (p1, p2) = (self.explicit_param(), other.explicit_param());
+    p1.len() == p2.len() && p1.iter().zip(p2.iter()).all(|(p1, p2)| p1.type_.sem == p2.type_.sem)
+  }
+
+  // the name used in tac & llvm, which must be unique in the class, so a method can't be named with '__'
+  pub fn mangled_name(&self) -> String {
+    if self.overload_idx == 0 { self.name.to_owned() } else { format!("{}__{}", self.name, self.overload_idx) }
+  }
+}
+
+// among `candidates`(all having the same name as `method`), the one `method` overrides(or is overridden by)
+// that is the one with exactly the same parameter types, or else the 1st one whose parameter types are all related(one assignable to the other),
+// so that a method with related but different parameter types is still checked as overriding, instead of silently overloading
+pub fn match_override(method: &MethodDef, candidates: &[*mut MethodDef]) -> Option<*mut MethodDef> {
+  let param = method.explicit_param();
+  candidates.iter().find(|c| c.get().same_param(method)).or_else(|| candidates.iter().find(|c| {
+    let c_param = c.get().explicit_param();
+    c_param.len() == param.len() && c_param.iter().zip(param.iter())
+      .all(|(p1, p2)| p1.type_.assignable_to(&p2.type_) || p2.type_.assignable_to(&p1.type_))
+  })).map(|c| *c)
 }
 
 // int x = 1;
@@ -148,18 +356,21 @@ pub struct VarDef {
   pub type_: Type,
   pub src: Option<Expr>,
   pub finish_loc: Loc,
+  pub static_: bool,
+  // only for fields, protected if not specified
+  pub access: Access,
   pub scope: *const Scope,
   // jvm: the index on stack, only valid for local & parameter variable
   pub jvm_index: u8,
-  // tac: the offset in object OR the virtual register id
-  // llvm: the offset in object
+  // tac: the offset in object OR the virtual register id OR the index in the static field block
+  // llvm: the offset in object, static fields use llvm_val as a global instead
   pub offset: i32,
   pub llvm_val: LLVMValueRef,
 }
 
 impl VarDef {
   pub fn new(loc: Loc, name: &'static str, type_: Type, src: Option<Expr>, finish_loc: Loc) -> VarDef {
-    VarDef { loc, name, type_, src, finish_loc, scope: ptr::null(), jvm_index: 255, offset: -1, llvm_val: ptr::null_mut() }
+    VarDef { loc, name, type_, src, finish_loc, static_: false, access: Access::Protected, scope: ptr::null(), jvm_index: 255, offset: -1, llvm_val: ptr::null_mut() }
   }
 }
 
@@ -182,14 +393,21 @@ pub enum Stmt {
   Simple(Simple),
   If(If),
   While(While),
+  DoWhile(DoWhile),
   For(For),
   Return(Return),
   Print(Print),
   Break(Break),
+  Continue(Continue),
   SCopy(SCopy),
   Foreach(Foreach),
   Guarded(Guarded),
+  Switch(Switch),
   Block(Block),
+  SuperCall(SuperCall),
+  Try(Try),
+  Throw(Throw),
+  Assert(Assert),
 }
 
 #[derive(Debug)]
@@ -221,13 +439,23 @@ pub struct If {
 #[derive(Debug)]
 pub struct While {
   pub loc: Loc,
+  pub label: Option<&'static str>,
   pub cond: Expr,
   pub body: Block,
 }
 
+#[derive(Debug)]
+pub struct DoWhile {
+  pub loc: Loc,
+  pub label: Option<&'static str>,
+  pub body: Block,
+  pub cond: Expr,
+}
+
 #[derive(Debug)]
 pub struct For {
   pub loc: Loc,
+  pub label: Option<&'static str>,
   // Skip for no init or update
   pub init: Simple,
   pub cond: Expr,
@@ -237,12 +465,61 @@ pub struct For {
 
 #[derive(Debug)]
 pub struct Foreach {
+  pub label: Option<&'static str>,
   pub def: VarDef,
   pub arr: Expr,
   pub cond: Option<Expr>,
   pub body: Block,
 }
 
+#[derive(Debug)]
+pub struct Switch {
+  pub loc: Loc,
+  pub cond: Expr,
+  pub case: Vec<Case>,
+}
+
+// control falls through to the next case, unless it breaks
+#[derive(Debug)]
+pub struct Case {
+  pub loc: Loc,
+  // None for default
+  pub value: Option<i32>,
+  // Some for an enum constant, whose ordinal is filled in value by type checker
+  pub name: Option<&'static str>,
+  pub body: Block,
+}
+
+#[derive(Debug)]
+pub struct Try {
+  pub loc: Loc,
+  pub body: Block,
+  pub catch: Vec<Catch>,
+  pub finally: Option<Block>,
+}
+
+// the exception variable is declared in the scope of body, like the variable of foreach
+#[derive(Debug)]
+pub struct Catch {
+  pub loc: Loc,
+  pub def: VarDef,
+  pub body: Block,
+}
+
+#[derive(Debug)]
+pub struct Throw {
+  pub loc: Loc,
+  pub expr: Expr,
+}
+
+// msg is a string printed after the location
+#[derive(Debug)]
+pub struct Assert {
+  pub loc: Loc,
+  pub cond: Expr,
+  pub msg: Option<Expr>,
+}
+
 #[derive(Debug)]
 pub struct Return {
   pub loc: Loc,
@@ -258,6 +535,13 @@ pub struct Print {
 #[derive(Debug)]
 pub struct Break {
   pub loc: Loc,
+  pub label: Option<&'static str>,
+}
+
+#[derive(Debug)]
+pub struct Continue {
+  pub loc: Loc,
+  pub label: Option<&'static str>,
 }
 
 #[derive(Debug)]
@@ -269,6 +553,15 @@ pub struct SCopy {
   pub src: Expr,
 }
 
+// only allowed as the first statement of a constructor
+#[derive(Debug)]
+pub struct SuperCall {
+  pub loc: Loc,
+  pub arg: Vec<Expr>,
+  // null if no ancestor declares a constructor
+  pub ctor: *const MethodDef,
+}
+
 #[derive(Debug)]
 pub struct Guarded {
   pub loc: Loc,
@@ -280,6 +573,8 @@ pub struct Assign {
   pub loc: Loc,
   pub dst: Expr,
   pub src: Expr,
+  // Some for compound assignment, like Add for 'a += b'
+  pub op: Option<Operator>,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -362,6 +657,9 @@ pub enum ExprData {
   Id(Id),
   Indexed(Indexed),
   IntConst(i32),
+  CharConst(u8),
+  LongConst(i64),
+  DoubleConst(f64),
   BoolConst(bool),
   StringConst(String),
   ArrayConst(Vec<Expr>),
@@ -370,12 +668,22 @@ pub enum ExprData {
   Unary(Unary),
   Binary(Binary),
   This,
+  // only as a receiver, like `super.f()` or `super.x`, resolved against the parent class
+  Super,
   ReadInt,
   ReadLine,
-  NewClass { name: &'static str },
+  Convert(Convert),
+  NewClass {
+    name: &'static str,
+    // only for a generic class
+    type_arg: Vec<SemanticType>,
+    arg: Vec<Expr>,
+  },
   NewArray {
     elem_t: Type,
     len: Box<Expr>,
+    // lengths of the inner dimensions of `new T[len][d1][d2]`, elem_t is already the type of a row
+    dims: Vec<Expr>,
   },
   TypeTest {
     expr: Box<Expr>,
@@ -388,7 +696,11 @@ pub enum ExprData {
   },
   Range(Range),
   Default(Default),
+  Conditional(Conditional),
   Comprehension(Comprehension),
+  Lambda(Box<Lambda>),
+  // call a function value, `f(x)` with a variable `f` is also turned into this by type checker
+  Apply(Apply),
 }
 
 impl Expr {
@@ -429,9 +741,53 @@ pub struct Call {
   pub name: &'static str,
   pub arg: Vec<Expr>,
   pub is_arr_len: bool,
+  // Some if this calls a builtin method of string, like 's.length()'
+  pub string_method: Option<StringMethod>,
   pub method: *const MethodDef,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StringMethod {
+  Length,
+  CharAt,
+  Substring,
+}
+
+// builtin conversions, like `CharToInt(c)`, and numeric casts, like `(int) 1.5`
+#[derive(Debug)]
+pub struct Convert {
+  pub op: ConvertOp,
+  pub expr: Box<Expr>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ConvertOp {
+  CharToInt,
+  IntToChar,
+  StringToChars,
+  CharsToString,
+  // from int, long or double, ToLong is also inserted by type checker to widen an int
+  ToInt,
+  ToDouble,
+  ToLong,
+}
+
+impl ConvertOp {
+  // (argument type, result type)
+  pub fn types(&self) -> (SemanticType, SemanticType) {
+    use self::ConvertOp::*;
+    match self {
+      CharToInt => (CHAR, INT),
+      IntToChar => (INT, CHAR),
+      StringToChars => (STRING, SemanticType::Array(Box::new(CHAR))),
+      CharsToString => (SemanticType::Array(Box::new(CHAR)), STRING),
+      ToInt => (DOUBLE, INT),
+      ToDouble => (INT, DOUBLE),
+      ToLong => (INT, LONG),
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct Binary {
   pub op: Operator,
@@ -459,10 +815,39 @@ pub struct Default {
   pub dft: Box<Expr>,
 }
 
+// cond ? on_true : on_false
+#[derive(Debug)]
+pub struct Conditional {
+  pub cond: Box<Expr>,
+  pub on_true: Box<Expr>,
+  pub on_false: Box<Expr>,
+}
+
 #[derive(Debug)]
 pub struct Comprehension {
   pub expr: Box<Expr>,
   pub name: &'static str,
   pub arr: Box<Expr>,
   pub cond: Option<Box<Expr>>,
+}
+
+// `lambda (int x) => x + 1` or `lambda (int x) { ... }`
+#[derive(Debug)]
+pub struct Lambda {
+  pub loc: Loc,
+  // its return type is inferred, and param[0] is the hidden env holding the captured values, like 'this' of a method
+  // type checker numbers the lambdas of a class from 1 as overload_idx, so the mangled name is unique
+  pub def: MethodDef,
+  // true for `=> expr`, whose body is a block of that expr
+  pub expr: bool,
+  // the local variables(including 'this') captured by value, and their copies declared in the lambda
+  pub capture: Vec<(*const VarDef, Box<VarDef>)>,
+  // llvm: the struct type of env, a function pointer followed by the captured values
+  pub llvm_env_t: LLVMTypeRef,
+}
+
+#[derive(Debug)]
+pub struct Apply {
+  pub func: Box<Expr>,
+  pub arg: Vec<Expr>,
 }
\ No newline at end of file
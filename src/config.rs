@@ -7,5 +7,35 @@ pub const BAD_CAST1: &'static str = "Decaf runtime error: ";
 pub const BAD_CAST2: &'static str = " cannot be cast to ";
 pub const BAD_CAST3: &'static str = "\n";
 pub const BAD_CAST: &'static str = "Decaf runtime error: %s cannot be cast to %s\n";
+pub const STRING_INDEX_OUT_OF_BOUND: &'static str = "Decaf runtime error: String index out of range\n";
 pub const DIV_0: &'static str = "Decaf runtime error: Division by zero error.\n";
-pub const REPEAT_NEG: &'static str = "Decaf runtime error: The length of the created array should not be less than 0.\n";
\ No newline at end of file
+pub const REPEAT_NEG: &'static str = "Decaf runtime error: The length of the created array should not be less than 0.\n";
+pub const UNCAUGHT1: &'static str = "Decaf runtime error: uncaught exception ";
+pub const UNCAUGHT2: &'static str = "\n";
+pub const UNCAUGHT: &'static str = "Decaf runtime error: uncaught exception %s\n";
+pub const ASSERT1: &'static str = "Decaf runtime error: Assertion failed at ";
+pub const ASSERT2: &'static str = ": ";
+pub const ASSERT3: &'static str = "\n";
+
+// built-in exception classes, and the java classes jvm code gen maps them to
+pub const EXCEPTION: &'static str = "Exception";
+pub const ARITHMETIC_EXCEPTION: &'static str = "ArithmeticException";
+pub const INDEX_OUT_OF_BOUNDS_EXCEPTION: &'static str = "IndexOutOfBoundsException";
+pub const NEGATIVE_ARRAY_SIZE_EXCEPTION: &'static str = "NegativeArraySizeException";
+pub const CLASS_CAST_EXCEPTION: &'static str = "ClassCastException";
+pub const BUILTIN_EXCEPTIONS: [(&'static str, &'static str); 5] = [
+  (EXCEPTION, "java/lang/RuntimeException"),
+  (ARITHMETIC_EXCEPTION, "java/lang/ArithmeticException"),
+  (INDEX_OUT_OF_BOUNDS_EXCEPTION, "java/lang/IndexOutOfBoundsException"),
+  (NEGATIVE_ARRAY_SIZE_EXCEPTION, "java/lang/NegativeArraySizeException"),
+  (CLASS_CAST_EXCEPTION, "java/lang/ClassCastException"),
+];
+// the hidden field of Exception holding the message of a runtime error, not a valid identifier
+pub const EXCEPTION_MESSAGE: &'static str = "_message";
+// the hidden fields of ClassCastException holding the class names of a bad cast, only used by tac
+pub const CAST_FROM: &'static str = "_from";
+pub const CAST_TO: &'static str = "_to";
+// the hidden fields of an enum, and the method returning the ordinal
+pub const ENUM_ORDINAL: &'static str = "_ordinal";
+pub const ENUM_NAME: &'static str = "_name";
+pub const ORDINAL: &'static str = "ordinal";
\ No newline at end of file
@@ -26,6 +26,21 @@ impl fmt::Display for Error {
   }
 }
 
+// a warning is printed in the same way as an error, but it doesn't stop the compilation
+pub struct Warning(pub Error);
+
+impl fmt::Display for Warning {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "*** Warning at {}: {}", self.0.loc, &self.0.error.get_msg())
+  }
+}
+
+impl fmt::Debug for Warning {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt::Display::fmt(self, f)
+  }
+}
+
 macro_rules! make_error {
   ($self_: ident, $($name: ident => $($field: ident : $type_: ty),* => $value: expr),*) => {
     $(pub struct $name {
@@ -43,22 +58,53 @@ make_error!(self,
   UnterminatedStr => string: String => format!("unterminated string constant {}", self.string),
   NewlineInStr => string: String => format!("illegal newline in string constant {}", self.string),
   IntTooLarge => string: String => format!("integer literal {} is too large", self.string),
+  BadCharConst => string: String => format!("illegal character constant {}", self.string),
   UnrecognizedChar => ch: char => format!("unrecognized character '{}'", self.ch),
+  BadCastType => type_: String => format!("can not cast to '{}'", self.type_),
   ConflictDeclaration => earlier: Loc, name: &'static str => format!("declaration of '{}' here conflicts with earlier declaration at {}", self.name, self.earlier),
+  BuiltinClass => name: &'static str => format!("'{}' is a built-in class", self.name),
   NoSuchClass => name: &'static str => format!("class '{}' not found", self.name),
   CyclicInheritance => => "illegal class inheritance (should be a cyclic)".to_owned(),
   SealedInheritance => => "illegal class inheritance from sealed class".to_owned(),
+  NotInterface => name: &'static str => format!("'{}' is not an interface", self.name),
+  ExtendInterface => name: &'static str => format!("class can not extend interface '{}'", self.name),
+  ExtendGeneric => name: &'static str => format!("class can not extend generic class '{}'", self.name),
+  WrongTypeArgc => name: &'static str, expect: i32, actual: i32 => format!("class '{}' expects {} type argument(s) but {} given", self.name, self.expect, self.actual),
+  BadTypeArg => arg_t: String => format!("type argument must be a class, string or array type, but is '{}'", self.arg_t),
+  BoundMismatch => arg_t: String, param: &'static str, bound_t: String => format!("type argument '{}' is not within the bound '{}' of type parameter '{}'", self.arg_t, self.bound_t, self.param),
+  GenericArray => name: &'static str => format!("can not create an array of type parameter '{}' by '%%', which copies objects of unknown classes", self.name),
+  GenericArrayMember => name: &'static str, owner_t: String => format!("'{}' of {} uses an array of type parameter, which can only be accessed inside the generic class", self.name, self.owner_t),
+  NoImplementation => class: &'static str, method: &'static str, interface: &'static str => format!("class '{}' does not implement method '{}' of interface '{}'", self.class, self.method, self.interface),
+  AbstractNotImplemented => class: &'static str, method: &'static str, owner: &'static str => format!("class '{}' must be abstract, since abstract method '{}' of '{}' is not implemented", self.class, self.method, self.owner),
+  ReservedMethodName => name: &'static str => format!("method name '{}' can not contain '__', which is reserved for overloads", self.name),
+  BadCtorName => name: &'static str => format!("invalid method declaration '{}', return type required", self.name),
   NoMainClass => => format!("no legal Main class named '{}' was found", MAIN_CLASS),
   VoidArrayElement => => "array element type must be non-void known type".to_owned(),
+  VoidFuncParam => => "function parameter type must be non-void known type".to_owned(),
+  GenericFunc => => "function type can not depend on type parameters".to_owned(),
   VoidVar => name: &'static str => format!("cannot declare identifier '{}' as void type", self.name),
   OverrideVar => name: &'static str => format!("overriding variable is not allowed for var '{}'", self.name),
+  WeakerAccess => method: &'static str, parent: &'static str, access: String => format!("overriding method '{}' can not have weaker access than {} in class '{}'", self.method, self.access, self.parent),
+  NoApplicableOverload => name: &'static str, arg_t: String => format!("no overload of function '{}' is applicable to argument(s) ({})", self.name, self.arg_t),
+  AmbiguousCall => name: &'static str => format!("call to function '{}' is ambiguous", self.name),
   BadOverride => method: &'static str, parent: &'static str => format!("overriding method '{}' doesn't match the type signature in class '{}'", self.method, self.parent),
   IncompatibleUnary => op: &'static str, r_t: String => format!("incompatible operand: {} {}", self.op, self.r_t),
   TestNotBool => => "test expression must have bool type".to_owned(),
   IncompatibleBinary => l_t: String, op: &'static str, r_t: String => format!("incompatible operands: {} {} {}", self.l_t, self.op, self.r_t),
   BreakOutOfLoop => => "'break' is only allowed inside a loop".to_owned(),
+  ContinueOutOfLoop => => "'continue' is only allowed inside a loop".to_owned(),
+  UndeclaredLabel => name: &'static str => format!("undeclared label '{}'", self.name),
+  DuplicateLabel => name: &'static str => format!("label '{}' is already in use", self.name),
+  SwitchNotInt => type_: String => format!("switch expression must have int type, but is '{}'", self.type_),
+  DuplicateCase => value: String => format!("duplicate case label '{}'", self.value),
+  CaseMismatch => value: String, type_: String => format!("case label '{}' doesn't match the switch expression type '{}'", self.value, self.type_),
+  ReservedEnumConst => name: &'static str => format!("enum constant can not be named '{}', which is a built-in method of enums", self.name),
+  AssignEnumConst => name: &'static str => format!("can not assign a value to enum constant '{}'", self.name),
+  NoSuchEnumConst => name: &'static str, enum_: &'static str => format!("'{}' is not a constant of enum '{}'", self.name, self.enum_),
+  MissingEnumCase => enum_: &'static str, names: String => format!("switch on enum '{}' doesn't handle {}", self.enum_, self.names),
   UndeclaredVar => name: &'static str => format!("undeclared variable '{}'", self.name),
   RefInStatic => field: &'static str, method: &'static str => format!("can not reference a non-static field '{}' from static method '{}'", self.field, self.method),
+  RefInStaticInit => field: &'static str, var: &'static str => format!("can not reference a non-static field '{}' from initializer of static field '{}'", self.field, self.var),
   BadFieldAccess => name: &'static str, owner_t: String => format!("cannot access field '{}' from '{}'", self.name, self.owner_t),
   PrivateFieldAccess => name: &'static str, owner_t: String => format!("field '{}' of '{}' not accessible here", self.name, self.owner_t),
   NoSuchField => name: &'static str, owner_t: String => format!("field '{}' not found in '{}'", self.name, self.owner_t),
@@ -67,8 +113,23 @@ make_error!(self,
   NotMethod => name: &'static str, owner_t: String => format!("'{}' is not a method in class '{}'", self.name, self.owner_t),
   WrongArgc => name: &'static str, expect: i32, actual: i32 => format!("function '{}' expects {} argument(s) but {} given", self.name, self.expect, self.actual),
   WrongArgType => loc: i32, arg_t: String, param_t: String => format!("incompatible argument {}: {} given, {} expected", self.loc, self.arg_t, self.param_t),
+  NotFunction => type_: String => format!("{} is not a function type", self.type_),
+  FuncArgc => type_: String, expect: i32, actual: i32 => format!("function of type '{}' expects {} argument(s) but {} given", self.type_, self.expect, self.actual),
+  AssignCaptured => name: &'static str => format!("can not assign to '{}', which is captured by lambda", self.name),
+  SuperInLambda => => "can not use super in lambda".to_owned(),
+  BadLambdaReturn => ret_t: String => format!("lambda can not return '{}'", self.ret_t),
   ThisInStatic => => "can not use this in static function".to_owned(),
+  SuperInStatic => => "can not use super in static function".to_owned(),
+  NoParent => class: &'static str => format!("class '{}' has no parent class", self.class),
+  AbstractSuperCall => name: &'static str => format!("abstract method '{}' cannot be accessed directly", self.name),
+  SuperCallNotFirst => => "call to 'super(...)' must be the first statement in constructor".to_owned(),
+  NoDefaultCtor => parent: &'static str => format!("there is no default constructor available in class '{}'", self.parent),
+  NewInterface => name: &'static str => format!("can not instantiate interface '{}'", self.name),
+  NewAbstract => name: &'static str => format!("can not instantiate abstract class '{}'", self.name),
+  NewEnum => name: &'static str => format!("can not instantiate enum '{}'", self.name),
   NotObject => type_: String => format!("{} is not a class type", self.type_),
+  NotException => type_: String => format!("{} is not an exception type", self.type_),
+  BadNumCast => type_: String, target: String => format!("cannot cast '{}' to '{}'", self.type_, self.target),
   BadPrintArg => loc: i32, type_: String => format!("incompatible argument {}: {} given, int/bool/string expected", self.loc, self.type_),
   WrongReturnType => ret_t: String, expect_t: String => format!("incompatible return: {} given, {} expected", self.ret_t, self.expect_t),
   BadNewArrayLen => => "new array length must be an integer".to_owned(),
@@ -76,10 +137,13 @@ make_error!(self,
   ArrayIndexNotInt => => "array subscript must be an integer".to_owned(),
   ArrayRepeatNotInt => => "array repeats time type must be int type".to_owned(),
   BadArrayOp => => "Array Operation on non-array type".to_owned(),
+  ConditionalMismatch => t1: String, t2: String => format!("incompatible types in conditional expression: {} and {}", self.t1, self.t2),
   DefaultMismatch => elem_t: String, dft_t: String => format!("Array has Element type {} but default has type {}", self.elem_t, self.dft_t),
   ForeachMismatch => elem_t: String, def_t: String => format!("Array has Element type {} but Foreach wants type {}", self.elem_t, self.def_t),
   ConcatMismatch => l_t: String, r_t: String => format!("concat {} with {}", self.l_t, self.r_t),
   SCopyNotClass => which: &'static str, type_: String => format!("incompatible argument {}: {} given, class expected", self.which, self.type_),
   SCopyMismatch => dst_t: String, src_t: String => format!("incompatible dst type: {} and src type: {}", self.dst_t, self.src_t),
-  NotLValue => op: &'static str => format!("operator {} can only be applied to lvalue", self.op)
+  NotLValue => op: &'static str => format!("operator {} can only be applied to lvalue", self.op),
+  TacBitOp => op: &'static str => format!("operator '{}' is not supported by tac codegen", self.op),
+  TacLong => => "type 'long' is not supported by tac codegen".to_owned()
 );
\ No newline at end of file
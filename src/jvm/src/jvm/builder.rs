@@ -5,11 +5,13 @@ use super::class::Instruction::*;
 
 use std::collections::HashMap;
 use std::string::ToString;
+use std::fmt;
 
 pub struct ClassBuilder {
   access_flags: u16,
   this_class_index: u16,
   super_class_index: u16,
+  interfaces: Vec<u16>,
   constants: Vec<Constant>,
   constant_cache: HashMap<Constant, u16>,
   fields: Vec<Field>,
@@ -22,6 +24,7 @@ impl ClassBuilder {
       access_flags,
       this_class_index: 0,
       super_class_index: 0,
+      interfaces: Vec::new(),
       constants: Vec::new(),
       constant_cache: HashMap::new(),
       fields: Vec::new(),
@@ -38,6 +41,10 @@ impl ClassBuilder {
     }
     self.constants.push(constant.clone());
     let ret = self.constants.len() as u16; // 1 indexed
+    match constant {
+      Constant::Long { .. } | Constant::Double { .. } => self.constants.push(Constant::Unusable),
+      _ => {}
+    }
     self.constant_cache.insert(constant, ret);
     ret
   }
@@ -69,6 +76,18 @@ impl ClassBuilder {
     self.push_constant(Constant::MethodRef { class_index, name_and_type_index })
   }
 
+  fn define_interface_method_ref(&mut self, class: &str, name: &str, argument_types: &[JavaType], return_type: &JavaType) -> u16 {
+    let class_index = self.define_class(class);
+    let descriptor = make_method_type(argument_types, return_type);
+    let name_and_type_index = self.define_name_and_type(name, &descriptor);
+    self.push_constant(Constant::InterfaceMethodRef { class_index, name_and_type_index })
+  }
+
+  // reference_kind is one of REF_*, reference_index points to the FieldRef/MethodRef/InterfaceMethodRef
+  pub fn define_method_handle(&mut self, reference_kind: u8, reference_index: u16) -> u16 {
+    self.push_constant(Constant::MethodHandle { reference_kind, reference_index })
+  }
+
   fn define_name_and_type(&mut self, name: &str, descriptor: &str) -> u16 {
     let name_index = self.define_utf8(name);
     let descriptor_index = self.define_utf8(&descriptor);
@@ -79,6 +98,20 @@ impl ClassBuilder {
     self.push_constant(Constant::Integer { bytes: value as u32 })
   }
 
+  fn define_float(&mut self, value: f32) -> u16 {
+    self.push_constant(Constant::Float { bytes: value.to_bits() })
+  }
+
+  fn define_long(&mut self, value: i64) -> u16 {
+    let value = value as u64;
+    self.push_constant(Constant::Long { high_bytes: (value >> 32) as u32, low_bytes: value as u32 })
+  }
+
+  fn define_double(&mut self, value: f64) -> u16 {
+    let value = value.to_bits();
+    self.push_constant(Constant::Double { high_bytes: (value >> 32) as u32, low_bytes: value as u32 })
+  }
+
   pub fn define_field(&mut self, access_flags: u16, name: &str, field_type: &JavaType) {
     let name_index = self.define_utf8(name);
     let descriptor = field_type.to_string();
@@ -86,12 +119,26 @@ impl ClassBuilder {
     self.fields.push(Field { access_flags, name_index, descriptor_index });
   }
 
+  pub fn add_interface(&mut self, interface: &str) {
+    let index = self.define_class(interface);
+    self.interfaces.push(index);
+  }
+
+  // ACC_ABSTRACT is added automatically
+  pub fn define_abstract_method(&mut self, access_flags: u16, name: &str, argument_types: &[JavaType], return_type: &JavaType) {
+    let name_index = self.define_utf8(name);
+    let descriptor = make_method_type(argument_types, return_type);
+    let descriptor_index = self.define_utf8(&descriptor);
+    self.methods.push(Method { access_flags: access_flags | ACC_ABSTRACT, name_index, descriptor_index, code: None });
+  }
+
   pub fn done(self) -> Class {
     Class {
       constant_pool: self.constants,
       access_flags: self.access_flags,
       this_class: self.this_class_index,
       super_class: self.super_class_index,
+      interfaces: self.interfaces,
       fields: self.fields,
       methods: self.methods,
     }
@@ -108,10 +155,58 @@ pub struct MethodBuilder {
   labels: HashMap<u16, u16>,
   // map index of code to label, index points to the high byte of code need to be filled with the label
   fills: Vec<(u16, u16)>,
-  cur_stack: u16,
-  max_stack: u16,
-  // only for debug
-  instructions: Vec<Instruction>,
+  // (index of the switch instruction, index of the 4 bytes offset, label) for tableswitch/lookupswitch
+  switch_fills: Vec<(u16, u16, u16)>,
+  // instructions with their index in code, used for computing max_stack
+  instructions: Vec<(u16, Instruction)>,
+  // (start label, end label, handler label, index of the caught Class or 0), in the order of lookup
+  exception_table: Vec<(u16, u16, u16, u16)>,
+}
+
+#[derive(Debug)]
+pub enum BuildError {
+  StackUnderflow { method: String, offset: u16 },
+  StackMismatch { method: String, offset: u16, expect: u16, actual: u16 },
+  UndefinedLabel { method: String, label: u16 },
+}
+
+impl fmt::Display for BuildError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    use self::BuildError::*;
+    match self {
+      StackUnderflow { method, offset } => write!(f, "{}: operand stack underflow at {}", method, offset),
+      StackMismatch { method, offset, expect, actual } =>
+        write!(f, "{}: inconsistent operand stack height at {}, {} vs {}", method, offset, expect, actual),
+      UndefinedLabel { method, label } => write!(f, "{}: label {} is used but not defined", method, label),
+    }
+  }
+}
+
+// number of stack slots the value of the descriptor takes
+fn slots_of(descriptor: &str) -> u16 {
+  match descriptor {
+    "V" => 0,
+    "J" | "D" => 2,
+    _ => 1,
+  }
+}
+
+// "(I[LA;J)V" => (4, 0)
+fn method_slots(descriptor: &str) -> (u16, u16) {
+  let (mut args, mut chars) = (0, descriptor[1..].chars());
+  loop {
+    match chars.next().unwrap() {
+      ')' => break,
+      c => {
+        let mut c = c;
+        let array = c == '[';
+        while c == '[' { c = chars.next().unwrap(); }
+        if c == 'L' { while chars.next().unwrap() != ';' {} }
+        args += if !array && (c == 'J' || c == 'D') { 2 } else { 1 };
+      }
+    }
+  }
+  (args, slots_of(chars.as_str()))
 }
 
 impl MethodBuilder {
@@ -130,9 +225,9 @@ impl MethodBuilder {
       code: Vec::new(),
       labels: HashMap::new(),
       fills: Vec::new(),
-      cur_stack: 0,
-      max_stack: 0,
+      switch_fills: Vec::new(),
       instructions: Vec::new(),
+      exception_table: Vec::new(),
     }
   }
 
@@ -157,24 +252,54 @@ impl MethodBuilder {
         self.ldc(index);
       }
     };
-    self.inc_stack();
   }
 
   pub fn bool_const(&mut self, value: bool) {
     self.push_code(if value { IConst1 } else { IConst0 });
-    self.inc_stack();
   }
 
   pub fn string_const(&mut self, value: &str) {
     let index = self.builder().define_string(value);
     self.ldc(index);
-    self.inc_stack();
+  }
+
+  pub fn float_const(&mut self, value: f32) {
+    let index = self.builder().define_float(value);
+    self.ldc(index);
+  }
+
+  pub fn long_const(&mut self, value: i64) {
+    let index = self.builder().define_long(value);
+    self.push_code(Ldc2W(index));
+  }
+
+  pub fn double_const(&mut self, value: f64) {
+    let index = self.builder().define_double(value);
+    self.push_code(Ldc2W(index));
+  }
+
+  // the MethodHandle constant itself needs class file version >= 51 to be loaded
+  pub fn method_handle_const(&mut self, reference_kind: u8, reference_index: u16) {
+    let index = self.builder().define_method_handle(reference_kind, reference_index);
+    self.ldc(index);
   }
 
   pub fn new_bool_array(&mut self) {
     self.new_array(4);
   }
 
+  pub fn new_char_array(&mut self) {
+    self.new_array(5);
+  }
+
+  pub fn new_double_array(&mut self) {
+    self.new_array(7);
+  }
+
+  pub fn new_long_array(&mut self) {
+    self.new_array(11);
+  }
+
   pub fn new_int_array(&mut self) {
     self.new_array(10);
   }
@@ -183,15 +308,23 @@ impl MethodBuilder {
     self.labels.insert(label, self.code.len() as u16);
   }
 
+  // exceptions of `class`(or any exception if None) thrown in [start, end) jump to handler
+  // entries added earlier take priority
+  pub fn try_catch(&mut self, start: u16, end: u16, handler: u16, class: Option<&str>) {
+    let catch_type = match class {
+      Some(class) => self.builder().define_class(class),
+      None => 0,
+    };
+    self.exception_table.push((start, end, handler, catch_type));
+  }
+
   // some instructions are not implemented(generate by wrapper function)
   // some instructions are merged
 
   pub fn a_const_null(&mut self) {
     self.push_code(AConstNull);
-    self.inc_stack();
   }
 
-  // stack is not inc-ed!!!
   fn ldc(&mut self, index: u16) {
     match index {
       0...255 => self.push_code(Ldc(index as u8)),
@@ -208,7 +341,24 @@ impl MethodBuilder {
       3 => ILoad3,
       _ => ILoad(index),
     });
-    self.inc_stack();
+  }
+
+  pub fn l_const_0(&mut self) {
+    self.push_code(LConst0);
+  }
+
+  // a long takes 2 local slots, like a double
+  pub fn l_load(&mut self, index: u8) {
+    self.push_code(LLoad(index));
+  }
+
+  pub fn d_const_0(&mut self) {
+    self.push_code(DConst0);
+  }
+
+  // a double takes 2 local slots: index and index + 1
+  pub fn d_load(&mut self, index: u8) {
+    self.push_code(DLoad(index));
   }
 
   pub fn a_load(&mut self, index: u8) {
@@ -219,22 +369,30 @@ impl MethodBuilder {
       3 => ALoad3,
       _ => ALoad(index),
     });
-    self.inc_stack();
   }
 
   pub fn i_a_load(&mut self) {
     self.push_code(IALoad);
-    self.dec_stack();
   }
 
   pub fn a_a_load(&mut self) {
     self.push_code(AALoad);
-    self.dec_stack();
   }
 
   pub fn b_a_load(&mut self) {
     self.push_code(BALoad);
-    self.dec_stack();
+  }
+
+  pub fn c_a_load(&mut self) {
+    self.push_code(CALoad);
+  }
+
+  pub fn l_a_load(&mut self) {
+    self.push_code(LALoad);
+  }
+
+  pub fn d_a_load(&mut self) {
+    self.push_code(DALoad);
   }
 
   pub fn i_store(&mut self, index: u8) {
@@ -245,7 +403,14 @@ impl MethodBuilder {
       3 => IStore3,
       _ => IStore(index),
     });
-    self.dec_stack();
+  }
+
+  pub fn l_store(&mut self, index: u8) {
+    self.push_code(LStore(index));
+  }
+
+  pub fn d_store(&mut self, index: u8) {
+    self.push_code(DStore(index));
   }
 
   pub fn a_store(&mut self, index: u8) {
@@ -256,50 +421,57 @@ impl MethodBuilder {
       3 => AStore3,
       _ => AStore(index),
     });
-    self.dec_stack();
   }
 
   pub fn i_a_store(&mut self) {
     self.push_code(IAStore);
-    self.dec_stack_n(3);
   }
 
   pub fn a_a_store(&mut self) {
     self.push_code(AAStore);
-    self.dec_stack_n(3);
   }
 
   pub fn b_a_store(&mut self) {
     self.push_code(BAStore);
-    self.dec_stack_n(3);
+  }
+
+  pub fn c_a_store(&mut self) {
+    self.push_code(CAStore);
+  }
+
+  pub fn l_a_store(&mut self) {
+    self.push_code(LAStore);
+  }
+
+  pub fn d_a_store(&mut self) {
+    self.push_code(DAStore);
   }
 
   pub fn pop(&mut self) {
     self.push_code(Pop);
-    self.dec_stack();
+  }
+
+  pub fn pop_2(&mut self) {
+    self.push_code(Pop2);
   }
 
   pub fn dup(&mut self) {
     self.push_code(Dup);
-    self.inc_stack();
   }
 
   // a, b -> b, a, b
   pub fn dup_x1(&mut self) {
     self.push_code(DupX1);
-    self.inc_stack();
   }
 
   // a, b, c -> c, a, b, c
   pub fn dup_x2(&mut self) {
     self.push_code(DupX2);
-    self.inc_stack();
   }
 
   // a, b -> a, b, a, b
   pub fn dup_2(&mut self) {
     self.push_code(Dup2);
-    self.inc_stack_n(2);
   }
 
   pub fn swap(&mut self) {
@@ -308,56 +480,153 @@ impl MethodBuilder {
 
   pub fn i_add(&mut self) {
     self.push_code(IAdd);
-    self.dec_stack();
   }
 
   pub fn i_sub(&mut self) {
     self.push_code(ISub);
-    self.dec_stack();
   }
 
   pub fn i_mul(&mut self) {
     self.push_code(IMul);
-    self.dec_stack();
   }
 
   pub fn i_div(&mut self) {
     self.push_code(IDiv);
-    self.dec_stack();
   }
 
   pub fn i_rem(&mut self) {
     self.push_code(IRem);
-    self.dec_stack();
   }
 
   pub fn i_neg(&mut self) {
     self.push_code(INeg);
   }
 
+  pub fn l_add(&mut self) {
+    self.push_code(LAdd);
+  }
+
+  pub fn l_sub(&mut self) {
+    self.push_code(LSub);
+  }
+
+  pub fn l_mul(&mut self) {
+    self.push_code(LMul);
+  }
+
+  pub fn l_div(&mut self) {
+    self.push_code(LDiv);
+  }
+
+  pub fn l_rem(&mut self) {
+    self.push_code(LRem);
+  }
+
+  pub fn l_neg(&mut self) {
+    self.push_code(LNeg);
+  }
+
+  // the shift amount is an int
+  pub fn l_shl(&mut self) {
+    self.push_code(LShl);
+  }
+
+  pub fn l_u_shr(&mut self) {
+    self.push_code(LUShr);
+  }
+
+  pub fn l_and(&mut self) {
+    self.push_code(LAnd);
+  }
+
+  pub fn l_or(&mut self) {
+    self.push_code(LOr);
+  }
+
+  pub fn l_xor(&mut self) {
+    self.push_code(LXor);
+  }
+
+  pub fn l_cmp(&mut self) {
+    self.push_code(LCmp);
+  }
+
+  pub fn i_2_l(&mut self) {
+    self.push_code(I2L);
+  }
+
+  pub fn l_2_i(&mut self) {
+    self.push_code(L2I);
+  }
+
+  pub fn l_2_d(&mut self) {
+    self.push_code(L2D);
+  }
+
+  pub fn d_2_l(&mut self) {
+    self.push_code(D2L);
+  }
+
+  pub fn d_add(&mut self) {
+    self.push_code(DAdd);
+  }
+
+  pub fn d_sub(&mut self) {
+    self.push_code(DSub);
+  }
+
+  pub fn d_mul(&mut self) {
+    self.push_code(DMul);
+  }
+
+  pub fn d_div(&mut self) {
+    self.push_code(DDiv);
+  }
+
+  pub fn d_rem(&mut self) {
+    self.push_code(DRem);
+  }
+
+  pub fn d_neg(&mut self) {
+    self.push_code(DNeg);
+  }
+
+  pub fn i_2_d(&mut self) {
+    self.push_code(I2D);
+  }
+
+  pub fn d_2_i(&mut self) {
+    self.push_code(D2I);
+  }
+
+  // push -1 if either is NaN
+  pub fn d_cmp_l(&mut self) {
+    self.push_code(DCmpL);
+  }
+
+  // push 1 if either is NaN
+  pub fn d_cmp_g(&mut self) {
+    self.push_code(DCmpG);
+  }
+
   pub fn i_shl(&mut self) {
     self.push_code(IShl);
-    self.dec_stack();
   }
 
   pub fn i_u_shr(&mut self) {
     self.push_code(IUShr);
-    self.dec_stack();
   }
 
   pub fn i_and(&mut self) {
     self.push_code(IAnd);
-    self.dec_stack();
   }
 
   pub fn i_or(&mut self) {
     self.push_code(IOr);
-    self.dec_stack();
   }
 
   pub fn i_xor(&mut self) {
     self.push_code(IXor);
-    self.dec_stack();
   }
 
   pub fn i_inc(&mut self, index: u8, value: u8) {
@@ -366,86 +635,102 @@ impl MethodBuilder {
 
   pub fn if_eq(&mut self, label: u16) {
     self.delay_code(label, IfEq(0));
-    self.dec_stack();
   }
 
   pub fn if_ne(&mut self, label: u16) {
     self.delay_code(label, IfNe(0));
-    self.dec_stack();
   }
 
   pub fn if_lt(&mut self, label: u16) {
     self.delay_code(label, IfLt(0));
-    self.dec_stack();
   }
 
   pub fn if_ge(&mut self, label: u16) {
     self.delay_code(label, IfGe(0));
-    self.dec_stack();
   }
 
   pub fn if_gt(&mut self, label: u16) {
     self.delay_code(label, IfGt(0));
-    self.dec_stack();
   }
 
   pub fn if_le(&mut self, label: u16) {
     self.delay_code(label, IfLe(0));
-    self.dec_stack();
   }
 
   pub fn if_i_cmp_eq(&mut self, label: u16) {
     self.delay_code(label, IfICmpEq(0));
-    self.dec_stack_n(2);
   }
 
   pub fn if_i_cmp_ne(&mut self, label: u16) {
     self.delay_code(label, IfICmpNe(0));
-    self.dec_stack_n(2);
   }
 
   pub fn if_i_cmp_lt(&mut self, label: u16) {
     self.delay_code(label, IfICmpLt(0));
-    self.dec_stack_n(2);
   }
 
   pub fn if_i_cmp_ge(&mut self, label: u16) {
     self.delay_code(label, IfICmpGe(0));
-    self.dec_stack_n(2);
   }
 
   pub fn if_i_cmp_gt(&mut self, label: u16) {
     self.delay_code(label, IfICmpGt(0));
-    self.dec_stack_n(2);
   }
 
   pub fn if_i_cmp_le(&mut self, label: u16) {
     self.delay_code(label, IfICmpLe(0));
-    self.dec_stack_n(2);
   }
 
   pub fn if_a_cmp_eq(&mut self, label: u16) {
     self.delay_code(label, IfACmpEq(0));
-    self.dec_stack_n(2);
   }
 
   pub fn if_a_cmp_ne(&mut self, label: u16) {
     self.delay_code(label, IfACmpNe(0));
-    self.dec_stack_n(2);
   }
 
   pub fn goto(&mut self, label: u16) {
     self.delay_code(label, Goto(0));
   }
 
+  // labels[i] is the target of value low + i
+  pub fn table_switch(&mut self, low: i32, default: u16, labels: &[u16]) {
+    let offset = self.code.len() as u16;
+    self.push_code(TableSwitch(low, low + labels.len() as i32 - 1));
+    let base = (offset + 4) & !3;
+    self.switch_fills.push((offset, base, default));
+    for (i, &label) in labels.iter().enumerate() {
+      self.switch_fills.push((offset, base + 12 + i as u16 * 4, label));
+    }
+  }
+
+  // pairs should be sorted by value
+  pub fn lookup_switch(&mut self, default: u16, pairs: &[(i32, u16)]) {
+    let offset = self.code.len() as u16;
+    self.push_code(LookupSwitch(pairs.len() as u32));
+    let base = (offset + 4) & !3;
+    self.switch_fills.push((offset, base, default));
+    for (i, &(value, label)) in pairs.iter().enumerate() {
+      let index = (base + 8 + i as u16 * 8) as usize;
+      self.code[index..index + 4].copy_from_slice(&[(value >> 24) as u8, (value >> 16) as u8, (value >> 8) as u8, value as u8]);
+      self.switch_fills.push((offset, index as u16 + 4, label));
+    }
+  }
+
   pub fn i_return(&mut self) {
     self.push_code(IReturn);
-    self.dec_stack();
   }
 
   pub fn a_return(&mut self) {
     self.push_code(AReturn);
-    self.dec_stack();
+  }
+
+  pub fn l_return(&mut self) {
+    self.push_code(LReturn);
+  }
+
+  pub fn d_return(&mut self) {
+    self.push_code(DReturn);
   }
 
   pub fn return_(&mut self) {
@@ -455,7 +740,11 @@ impl MethodBuilder {
   pub fn get_static(&mut self, class: &str, name: &str, field_type: &JavaType) {
     let index = self.builder().define_field_ref(class, name, field_type);
     self.push_code(GetStatic(index));
-    self.inc_stack();
+  }
+
+  pub fn put_static(&mut self, class: &str, name: &str, field_type: &JavaType) {
+    let index = self.builder().define_field_ref(class, name, field_type);
+    self.push_code(PutStatic(index));
   }
 
   pub fn get_field(&mut self, class: &str, name: &str, field_type: &JavaType) {
@@ -466,34 +755,32 @@ impl MethodBuilder {
   pub fn put_field(&mut self, class: &str, name: &str, field_type: &JavaType) {
     let index = self.builder().define_field_ref(class, name, field_type);
     self.push_code(PutField(index));
-    self.dec_stack();
   }
 
   pub fn invoke_virtual(&mut self, class: &str, name: &str, argument_types: &[JavaType], return_type: &JavaType) {
     let index = self.builder().define_method_ref(class, name, argument_types, return_type);
     self.push_code(InvokeVirtual(index));
-    self.dec_stack_n(argument_types.len() as u16 + 1);
-    if *return_type != JavaType::Void { self.inc_stack(); }
   }
 
   pub fn invoke_special(&mut self, class: &str, name: &str, argument_types: &[JavaType], return_type: &JavaType) {
     let index = self.builder().define_method_ref(class, name, argument_types, return_type);
     self.push_code(InvokeSpecial(index));
-    self.dec_stack_n(argument_types.len() as u16 + 1);
-    if *return_type != JavaType::Void { self.inc_stack(); }
   }
 
   pub fn invoke_static(&mut self, class: &str, name: &str, argument_types: &[JavaType], return_type: &JavaType) {
     let index = self.builder().define_method_ref(class, name, argument_types, return_type);
     self.push_code(InvokeStatic(index));
-    self.dec_stack_n(argument_types.len() as u16);
-    if *return_type != JavaType::Void { self.inc_stack(); }
+  }
+
+  pub fn invoke_interface(&mut self, class: &str, name: &str, argument_types: &[JavaType], return_type: &JavaType) {
+    let index = self.builder().define_interface_method_ref(class, name, argument_types, return_type);
+    let (args, _) = method_slots(&make_method_type(argument_types, return_type));
+    self.push_code(InvokeInterface(index, args as u8 + 1));
   }
 
   pub fn new_(&mut self, class: &str) {
     let index = self.builder().define_class(class);
     self.push_code(New(index));
-    self.inc_stack();
   }
 
   // a_type can only be int(10) / bool(4) in decaf
@@ -506,10 +793,20 @@ impl MethodBuilder {
     self.push_code(ANewArray(index));
   }
 
+  // array is the descriptor of the whole array type, the lengths of the first `dims` dimensions are on the stack
+  pub fn multi_a_new_array(&mut self, array: &str, dims: u8) {
+    let index = self.builder().define_class(array);
+    self.push_code(MultiANewArray(index, dims));
+  }
+
   pub fn array_length(&mut self) {
     self.push_code(ArrayLength);
   }
 
+  pub fn a_throw(&mut self) {
+    self.push_code(AThrow);
+  }
+
   pub fn check_cast(&mut self, class: &str) {
     let index = self.builder().define_class(class);
     self.push_code(CheckCast(index));
@@ -520,41 +817,153 @@ impl MethodBuilder {
     self.push_code(InstanceOf(index));
   }
 
-  fn inc_stack(&mut self) {
-    self.cur_stack += 1;
-    if self.cur_stack > self.max_stack {
-      self.max_stack = self.cur_stack
-    }
+  fn push_code(&mut self, instruction: Instruction) {
+    self.instructions.push((self.code.len() as u16, instruction));
+    instruction.write_to(&mut self.code);
   }
 
-  fn inc_stack_n(&mut self, n: u16) {
-    self.cur_stack += n;
-    if self.cur_stack > self.max_stack {
-      self.max_stack = self.cur_stack
-    }
+  fn delay_code(&mut self, label: u16, instruction: Instruction) {
+    self.instructions.push((self.code.len() as u16, instruction));
+    instruction.write_to(&mut self.code);
+    self.fills.push((self.code.len() as u16 - 2, label));
   }
 
-  fn dec_stack(&mut self) {
-    self.cur_stack -= 1;
+  fn utf8_of(&self, index: u16) -> &str {
+    match &self.builder().constants[index as usize - 1] {
+      Constant::Utf8(s) => s,
+      _ => unreachable!(),
+    }
   }
 
-  fn dec_stack_n(&mut self, n: u16) {
-    self.cur_stack -= n;
+  // descriptor of a FieldRef/MethodRef
+  fn descriptor_of(&self, index: u16) -> &str {
+    let constants = &self.builder().constants;
+    let name_and_type_index = match &constants[index as usize - 1] {
+      Constant::FieldRef { class_index: _, name_and_type_index } |
+      Constant::MethodRef { class_index: _, name_and_type_index } |
+      Constant::InterfaceMethodRef { class_index: _, name_and_type_index } => *name_and_type_index,
+      _ => unreachable!(),
+    };
+    match &constants[name_and_type_index as usize - 1] {
+      Constant::NameAndType { name_index: _, descriptor_index } => self.utf8_of(*descriptor_index),
+      _ => unreachable!(),
+    }
   }
 
-  fn push_code(&mut self, instruction: Instruction) {
-    instruction.write_to(&mut self.code);
-    self.instructions.push(instruction);
+  // (number of slots popped, number of slots pushed)
+  fn stack_effect(&self, instruction: &Instruction) -> (u16, u16) {
+    match *instruction {
+      AConstNull | IConstM1 | IConst0 | IConst1 | IConst2 | IConst3 | IConst4 | IConst5 | BIPush(_) | SIPush(_)
+      | Ldc(_) | LdcW(_) | ILoad(_) | ALoad(_) | ILoad0 | ILoad1 | ILoad2 | ILoad3
+      | ALoad0 | ALoad1 | ALoad2 | ALoad3 | New(_) => (0, 1),
+      Ldc2W(_) | LConst0 | DConst0 | LLoad(_) | DLoad(_) => (0, 2),
+      IALoad | AALoad | BALoad | CALoad => (2, 1),
+      LALoad | DALoad => (2, 2),
+      LStore(_) | DStore(_) | Pop2 => (2, 0),
+      LAStore | DAStore => (4, 0),
+      IStore(_) | AStore(_) | IStore0 | IStore1 | IStore2 | IStore3 | AStore0 | AStore1 | AStore2 | AStore3 => (1, 0),
+      IAStore | AAStore | BAStore | CAStore => (3, 0),
+      Pop => (1, 0),
+      Dup => (1, 2),
+      DupX1 => (2, 3),
+      DupX2 => (3, 4),
+      Dup2 => (2, 4),
+      Swap => (2, 2),
+      IAdd | ISub | IMul | IDiv | IRem | IShl | IUShr | IAnd | IOr | IXor => (2, 1),
+      INeg => (1, 1),
+      LAdd | LSub | LMul | LDiv | LRem | LAnd | LOr | LXor | DAdd | DSub | DMul | DDiv | DRem => (4, 2),
+      LShl | LUShr => (3, 2),
+      LNeg | DNeg | L2D | D2L => (2, 2),
+      I2L | I2D => (1, 2),
+      L2I | D2I => (2, 1),
+      LCmp | DCmpL | DCmpG => (4, 1),
+      IInc(_, _) | Goto(_) | Return => (0, 0),
+      TableSwitch(_, _) | LookupSwitch(_) => (1, 0),
+      IfEq(_) | IfNe(_) | IfLt(_) | IfGe(_) | IfGt(_) | IfLe(_) => (1, 0),
+      IfICmpEq(_) | IfICmpNe(_) | IfICmpLt(_) | IfICmpGe(_) | IfICmpGt(_) | IfICmpLe(_) | IfACmpEq(_) | IfACmpNe(_) => (2, 0),
+      IReturn | AReturn | AThrow => (1, 0),
+      LReturn | DReturn => (2, 0),
+      GetStatic(index) => (0, slots_of(self.descriptor_of(index))),
+      PutStatic(index) => (slots_of(self.descriptor_of(index)), 0),
+      GetField(index) => (1, slots_of(self.descriptor_of(index))),
+      PutField(index) => (1 + slots_of(self.descriptor_of(index)), 0),
+      InvokeVirtual(index) | InvokeSpecial(index) | InvokeInterface(index, _) => {
+        let (args, ret) = method_slots(self.descriptor_of(index));
+        (args + 1, ret)
+      }
+      InvokeStatic(index) => method_slots(self.descriptor_of(index)),
+      NewArray(_) | ANewArray(_) | ArrayLength | CheckCast(_) | InstanceOf(_) => (1, 1),
+      MultiANewArray(_, dims) => (dims as u16, 1),
+    }
   }
 
-  fn delay_code(&mut self, label: u16, instruction: Instruction) {
-    instruction.write_to(&mut self.code);
-    self.fills.push((self.code.len() as u16 - 2, label));
-    self.instructions.push(instruction);
+  // simulate the operand stack over the control flow graph
+  // every instruction must be reached with the same stack height from all its predecessors
+  fn compute_max_stack(&self, labels: &HashMap<u16, u16>) -> Result<u16, BuildError> {
+    let method = || self.utf8_of(self.name_index).to_owned();
+    let index_of: HashMap<u16, usize> = self.instructions.iter().enumerate().map(|(i, &(offset, _))| (offset, i)).collect();
+    // offset of a branch instruction => its target labels
+    let mut targets: HashMap<u16, Vec<u16>> = HashMap::new();
+    for &(index, label) in &self.fills { targets.entry(index - 1).or_default().push(label); }
+    for &(offset, _, label) in &self.switch_fills { targets.entry(offset).or_default().push(label); }
+    let mut heights: Vec<Option<u16>> = vec![None; self.instructions.len()];
+    let mut work = Vec::new();
+    let mut max_stack = 0;
+    if !self.instructions.is_empty() {
+      heights[0] = Some(0);
+      work.push(0);
+    }
+    // a handler starts with only the exception on the stack
+    for &(_, _, handler, _) in &self.exception_table {
+      let handler = match labels.get(&handler).and_then(|target| index_of.get(target)) {
+        Some(handler) => *handler,
+        None => return Err(BuildError::UndefinedLabel { method: method(), label: handler }),
+      };
+      if heights[handler].is_none() {
+        heights[handler] = Some(1);
+        work.push(handler);
+      }
+    }
+    while let Some(i) = work.pop() {
+      let (offset, instruction) = self.instructions[i];
+      let height = heights[i].unwrap();
+      let (pop, push) = self.stack_effect(&instruction);
+      if height < pop {
+        return Err(BuildError::StackUnderflow { method: method(), offset });
+      }
+      let height = height - pop + push;
+      if height > max_stack { max_stack = height; }
+      let mut successors = Vec::new();
+      match instruction {
+        IReturn | LReturn | DReturn | AReturn | Return | AThrow => {}
+        Goto(_) | TableSwitch(_, _) | LookupSwitch(_) => {}
+        _ => if i + 1 < self.instructions.len() { successors.push(i + 1); }
+      }
+      for label in targets.get(&offset).into_iter().flatten() {
+        let target = match labels.get(label).and_then(|target| index_of.get(target)) {
+          Some(target) => *target,
+          None => return Err(BuildError::UndefinedLabel { method: method(), label: *label }),
+        };
+        successors.push(target);
+      }
+      for successor in successors {
+        match heights[successor] {
+          Some(expect) => if expect != height {
+            return Err(BuildError::StackMismatch { method: method(), offset: self.instructions[successor].0, expect, actual: height });
+          }
+          None => {
+            heights[successor] = Some(height);
+            work.push(successor);
+          }
+        }
+      }
+    }
+    Ok(max_stack)
   }
 
-  pub fn done(self, max_locals: u16) {
-    let MethodBuilder { class_builder, access_flags, name_index, descriptor_index, mut code, labels, fills, cur_stack: _, max_stack, instructions: _ } = self;
+  pub fn done(self, max_locals: u16) -> Result<(), BuildError> {
+    let max_stack = self.compute_max_stack(&self.labels)?;
+    let MethodBuilder { class_builder, access_flags, name_index, descriptor_index, mut code, labels, fills, switch_fills, instructions: _, exception_table } = self;
 
     for (index, label) in fills {
       // rust thinks it inappropriate to have unsigned int overflow
@@ -562,6 +971,16 @@ impl MethodBuilder {
       code[index as usize] = (label >> 8) as u8;
       code[index as usize + 1] = label as u8;
     }
+    for (offset, index, label) in switch_fills {
+      let label = *labels.get(&label).unwrap() as i32 - offset as i32;
+      code[index as usize..index as usize + 4].copy_from_slice(&[(label >> 24) as u8, (label >> 16) as u8, (label >> 8) as u8, label as u8]);
+    }
+
+    let exception_table = exception_table.into_iter()
+      .map(|(start, end, handler, catch_type)| Exception { start_pc: labels[&start], end_pc: labels[&end], handler_pc: labels[&handler], catch_type })
+      // an empty range is not allowed
+      .filter(|exception| exception.start_pc < exception.end_pc)
+      .collect();
 
     let attribute_name_index = unsafe { (*class_builder).define_utf8("Code") };
     let code = Code {
@@ -569,8 +988,10 @@ impl MethodBuilder {
       max_stack,
       max_locals,
       code,
+      exception_table,
     };
 
-    unsafe { (*class_builder).methods.push(Method { access_flags, name_index, descriptor_index, code }) };
+    unsafe { (*class_builder).methods.push(Method { access_flags, name_index, descriptor_index, code: Some(code) }) };
+    Ok(())
   }
 }
\ No newline at end of file
@@ -4,8 +4,11 @@ pub const MAJOR_VERSION: u16 = 49;
 pub const MINOR_VERSION: u16 = 0;
 pub const ACC_PUBLIC: u16 = 0x1;
 pub const ACC_PRIVATE: u16 = 0x2;
+pub const ACC_PROTECTED: u16 = 0x4;
 pub const ACC_STATIC: u16 = 0x8;
 pub const ACC_FINAL: u16 = 0x10;
+pub const ACC_INTERFACE: u16 = 0x200;
+pub const ACC_ABSTRACT: u16 = 0x400;
 
 pub struct Class {
   // magic: u32 : doesn't need it here since it is const
@@ -15,7 +18,8 @@ pub struct Class {
   pub access_flags: u16,
   pub this_class: u16,
   pub super_class: u16,
-  // interfaces: Vec<Interface> : not implemented
+  // each is an index of Constant::Class
+  pub interfaces: Vec<u16>,
   pub fields: Vec<Field>,
   pub methods: Vec<Method>,
   // attributes: Vec<Attribute> : not implemented
@@ -25,13 +29,31 @@ pub struct Class {
 pub enum Constant {
   /* 1 */ Utf8(String),
   /* 3 */ Integer { bytes: u32 },
+  /* 4 */ Float { bytes: u32 },
+  /* 5 */ Long { high_bytes: u32, low_bytes: u32 },
+  /* 6 */ Double { high_bytes: u32, low_bytes: u32 },
   /* 7 */ Class { name_index: u16 },
   /* 8 */ String { string_index: u16 },
   /* 9 */ FieldRef { class_index: u16, name_and_type_index: u16 },
   /* 10 */ MethodRef { class_index: u16, name_and_type_index: u16 },
+  /* 11 */ InterfaceMethodRef { class_index: u16, name_and_type_index: u16 },
   /* 12 */ NameAndType { name_index: u16, descriptor_index: u16 },
+  /* 15 */ MethodHandle { reference_kind: u8, reference_index: u16 },
+  // Long & Double take two entries in the constant pool, the second one is unusable and not written
+  /* - */ Unusable,
 }
 
+// reference_kind of MethodHandle
+pub const REF_GET_FIELD: u8 = 1;
+pub const REF_GET_STATIC: u8 = 2;
+pub const REF_PUT_FIELD: u8 = 3;
+pub const REF_PUT_STATIC: u8 = 4;
+pub const REF_INVOKE_VIRTUAL: u8 = 5;
+pub const REF_INVOKE_STATIC: u8 = 6;
+pub const REF_INVOKE_SPECIAL: u8 = 7;
+pub const REF_NEW_INVOKE_SPECIAL: u8 = 8;
+pub const REF_INVOKE_INTERFACE: u8 = 9;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Field {
   pub access_flags: u16,
@@ -46,7 +68,8 @@ pub struct Method {
   pub name_index: u16,
   pub descriptor_index: u16,
   // attributes: Vec<Attribute> : not implemented, instead we have...
-  pub code: Code,
+  // abstract methods have no code
+  pub code: Option<Code>,
 }
 
 // actually Code is a kind of Attribute
@@ -59,10 +82,19 @@ pub struct Code {
   pub max_stack: u16,
   pub max_locals: u16,
   pub code: Vec<u8>,
-  // exception_table: Vec<Exception>: not implemented
+  pub exception_table: Vec<Exception>,
   // attributes: Vec<Attribute>: not implemented
 }
 
+// an entry of the exception table, catch_type 0 catches everything
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exception {
+  pub start_pc: u16,
+  pub end_pc: u16,
+  pub handler_pc: u16,
+  pub catch_type: u16,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Instruction {
   /* 0x01 */ AConstNull,
@@ -73,11 +105,16 @@ pub enum Instruction {
   /* 0x06 */ IConst3,
   /* 0x07 */ IConst4,
   /* 0x08 */ IConst5,
+  /* 0x09 */ LConst0,
+  /* 0x0E */ DConst0,
   /* 0x10 */ BIPush(u8),
   /* 0x11 */ SIPush(u16),
   /* 0x12 */ Ldc(u8),
   /* 0x13 */ LdcW(u16),
+  /* 0x14 */ Ldc2W(u16),
   /* 0x15 */ ILoad(u8),
+  /* 0x16 */ LLoad(u8),
+  /* 0x18 */ DLoad(u8),
   /* 0x19 */ ALoad(u8),
   /* 0x1A */ ILoad0,
   /* 0x1B */ ILoad1,
@@ -88,9 +125,14 @@ pub enum Instruction {
   /* 0x2C */ ALoad2,
   /* 0x2D */ ALoad3,
   /* 0x2E */ IALoad,
+  /* 0x2F */ LALoad,
+  /* 0x31 */ DALoad,
   /* 0x32 */ AALoad,
   /* 0x33 */ BALoad,
+  /* 0x34 */ CALoad,
   /* 0x36 */ IStore(u8),
+  /* 0x37 */ LStore(u8),
+  /* 0x39 */ DStore(u8),
   /* 0x3A */ AStore(u8),
   /* 0x3B */ IStore0,
   /* 0x3C */ IStore1,
@@ -101,26 +143,56 @@ pub enum Instruction {
   /* 0x4D */ AStore2,
   /* 0x4E */ AStore3,
   /* 0x4F */ IAStore,
+  /* 0x50 */ LAStore,
+  /* 0x52 */ DAStore,
   /* 0x53 */ AAStore,
   /* 0x54 */ BAStore,
+  /* 0x55 */ CAStore,
   /* 0x57 */ Pop,
+  /* 0x58 */ Pop2,
   /* 0x59 */ Dup,
   /* 0x5A */ DupX1,
   /* 0x5B */ DupX2,
   /* 0x5C */ Dup2,
   /* 0x5F */ Swap,
   /* 0x60 */ IAdd,
+  /* 0x61 */ LAdd,
+  /* 0x63 */ DAdd,
   /* 0x64 */ ISub,
+  /* 0x65 */ LSub,
+  /* 0x67 */ DSub,
   /* 0x68 */ IMul,
+  /* 0x69 */ LMul,
+  /* 0x6B */ DMul,
   /* 0x6C */ IDiv,
+  /* 0x6D */ LDiv,
+  /* 0x6F */ DDiv,
   /* 0x70 */ IRem,
+  /* 0x71 */ LRem,
+  /* 0x73 */ DRem,
   /* 0x74 */ INeg,
+  /* 0x75 */ LNeg,
+  /* 0x77 */ DNeg,
   /* 0x78 */ IShl,
+  /* 0x79 */ LShl,
   /* 0x7C */ IUShr,
+  /* 0x7D */ LUShr,
   /* 0x7E */ IAnd,
+  /* 0x7F */ LAnd,
   /* 0x80 */ IOr,
+  /* 0x81 */ LOr,
   /* 0x82 */ IXor,
+  /* 0x83 */ LXor,
   /* 0x84 */ IInc(u8, u8),
+  /* 0x85 */ I2L,
+  /* 0x87 */ I2D,
+  /* 0x88 */ L2I,
+  /* 0x8A */ L2D,
+  /* 0x8E */ D2I,
+  /* 0x8F */ D2L,
+  /* 0x94 */ LCmp,
+  /* 0x97 */ DCmpL,
+  /* 0x98 */ DCmpG,
   /* 0x99 */ IfEq(u16),
   /* 0x9A */ IfNe(u16),
   /* 0x9B */ IfLt(u16),
@@ -136,19 +208,28 @@ pub enum Instruction {
   /* 0xA5 */ IfACmpEq(u16),
   /* 0xA6 */ IfACmpNe(u16),
   /* 0xA7 */ Goto(u16),
+  // the jump offsets(and match keys) are filled by MethodBuilder
+  /* 0xAA */ TableSwitch(i32, i32),
+  /* 0xAB */ LookupSwitch(u32),
   /* 0xAC */ IReturn,
+  /* 0xAD */ LReturn,
+  /* 0xAF */ DReturn,
   /* 0xB0 */ AReturn,
   /* 0xB1 */ Return,
   /* 0xB2 */ GetStatic(u16),
+  /* 0xB3 */ PutStatic(u16),
   /* 0xB4 */ GetField(u16),
   /* 0xB5 */ PutField(u16),
   /* 0xB6 */ InvokeVirtual(u16),
   /* 0xB7 */ InvokeSpecial(u16),
   /* 0xB8 */ InvokeStatic(u16),
+  /* 0xB9 */ InvokeInterface(u16, u8),
   /* 0xBB */ New(u16),
   /* 0xBC */ NewArray(u8),
   /* 0xBD */ ANewArray(u16),
   /* 0xBE */ ArrayLength,
+  /* 0xBF */ AThrow,
   /* 0xC0 */ CheckCast(u16),
   /* 0xC1 */ InstanceOf(u16),
+  /* 0xC5 */ MultiANewArray(u16, u8),
 }
\ No newline at end of file
@@ -0,0 +1,116 @@
+use super::class::*;
+use super::writer::*;
+
+// a jar is a zip file with META-INF/MANIFEST.MF as its first entry
+// entries are stored without compression, so that no deflate implementation is needed
+pub struct Jar {
+  entries: Vec<(String, Vec<u8>)>,
+}
+
+pub struct JarBuilder {
+  entries: Vec<(String, Vec<u8>)>,
+}
+
+impl JarBuilder {
+  pub fn new(main_class: &str) -> JarBuilder {
+    let manifest = format!("Manifest-Version: 1.0\r\nMain-Class: {}\r\nCreated-By: decaf\r\n\r\n", main_class);
+    JarBuilder { entries: vec![("META-INF/MANIFEST.MF".to_owned(), manifest.into_bytes())] }
+  }
+
+  pub fn add_file(&mut self, name: &str, data: Vec<u8>) {
+    self.entries.push((name.to_owned(), data));
+  }
+
+  pub fn add_class(&mut self, name: &str, class: Class) {
+    let mut data = Vec::new();
+    class.write_to(&mut data);
+    self.add_file(&(name.to_owned() + ".class"), data);
+  }
+
+  pub fn done(self) -> Jar {
+    Jar { entries: self.entries }
+  }
+}
+
+// zip is little endian, while the class file writers are big endian
+fn le16(dst: &mut Vec<u8>, value: u16) {
+  dst.push(value as u8);
+  dst.push((value >> 8) as u8);
+}
+
+fn le32(dst: &mut Vec<u8>, value: u32) {
+  le16(dst, value as u16);
+  le16(dst, (value >> 16) as u16);
+}
+
+fn crc32(data: &[u8]) -> u32 {
+  let mut crc: u32 = 0xFFFFFFFF;
+  for &byte in data {
+    crc ^= byte as u32;
+    for _ in 0..8 {
+      crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };
+    }
+  }
+  !crc
+}
+
+const VERSION: u16 = 10; // 1.0, enough for stored entries
+const DOS_TIME: u16 = 0;
+const DOS_DATE: u16 = (1 << 5) | 1; // 1980-01-01
+
+impl Writer<Vec<u8>> for Jar {
+  fn write_to(self, dst: &mut Vec<u8>) {
+    let mut central = Vec::new();
+    let count = self.entries.len() as u16;
+    for (name, mut data) in self.entries {
+      let offset = dst.len() as u32;
+      let crc = crc32(&data);
+      let size = data.len() as u32;
+      // local file header
+      le32(dst, 0x04034B50);
+      le16(dst, VERSION);
+      le16(dst, 0); // flags
+      le16(dst, 0); // compression: stored
+      le16(dst, DOS_TIME);
+      le16(dst, DOS_DATE);
+      le32(dst, crc);
+      le32(dst, size); // compressed size
+      le32(dst, size); // uncompressed size
+      le16(dst, name.len() as u16);
+      le16(dst, 0); // extra field length
+      dst.extend_from_slice(name.as_bytes());
+      dst.append(&mut data);
+      // central directory header
+      le32(&mut central, 0x02014B50);
+      le16(&mut central, VERSION); // version made by
+      le16(&mut central, VERSION); // version needed
+      le16(&mut central, 0);
+      le16(&mut central, 0);
+      le16(&mut central, DOS_TIME);
+      le16(&mut central, DOS_DATE);
+      le32(&mut central, crc);
+      le32(&mut central, size);
+      le32(&mut central, size);
+      le16(&mut central, name.len() as u16);
+      le16(&mut central, 0); // extra field length
+      le16(&mut central, 0); // comment length
+      le16(&mut central, 0); // disk number start
+      le16(&mut central, 0); // internal attributes
+      le32(&mut central, 0); // external attributes
+      le32(&mut central, offset);
+      central.extend_from_slice(name.as_bytes());
+    }
+    let central_offset = dst.len() as u32;
+    let central_size = central.len() as u32;
+    dst.append(&mut central);
+    // end of central directory record
+    le32(dst, 0x06054B50);
+    le16(dst, 0); // number of this disk
+    le16(dst, 0); // disk where central directory starts
+    le16(dst, count);
+    le16(dst, count);
+    le32(dst, central_size);
+    le32(dst, central_offset);
+    le16(dst, 0); // comment length
+  }
+}
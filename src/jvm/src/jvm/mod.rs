@@ -1,4 +1,5 @@
 pub mod builder;
 pub mod class;
+pub mod jar;
 pub mod types;
 pub mod writer;
\ No newline at end of file
@@ -64,7 +64,7 @@ impl Writer<Vec<u8>> for Class {
       .write(self.constant_pool)
       .write(self.access_flags)
       .write(self.this_class).write(self.super_class)
-      .write(0 as u16) // interfaces_count
+      .write(self.interfaces)
       .write(self.fields)
       .write(self.methods)
       .write(0 as u16) // attributes_count
@@ -80,16 +80,29 @@ impl Writer<Vec<u8>> for Vec<Constant> {
       match constant {
         Utf8(s) => { dst.write(1 as u8).write(s.len() as u16).append(&mut s.into_bytes()); }
         Integer { bytes } => { dst.write(3 as u8).write(bytes); }
+        Float { bytes } => { dst.write(4 as u8).write(bytes); }
+        Long { high_bytes, low_bytes } => { dst.write(5 as u8).write(high_bytes).write(low_bytes); }
+        Double { high_bytes, low_bytes } => { dst.write(6 as u8).write(high_bytes).write(low_bytes); }
         Class { name_index } => { dst.write(7 as u8).write(name_index); }
         String { string_index } => { dst.write(8 as u8).write(string_index); }
         FieldRef { class_index, name_and_type_index } => { dst.write(9 as u8).write(class_index).write(name_and_type_index); }
         MethodRef { class_index, name_and_type_index } => { dst.write(10 as u8).write(class_index).write(name_and_type_index); }
+        InterfaceMethodRef { class_index, name_and_type_index } => { dst.write(11 as u8).write(class_index).write(name_and_type_index); }
         NameAndType { name_index, descriptor_index } => { dst.write(12 as u8).write(name_index).write(descriptor_index); }
+        MethodHandle { reference_kind, reference_index } => { dst.write(15 as u8).write(reference_kind).write(reference_index); }
+        Unusable => {}
       };
     }
   }
 }
 
+impl Writer<Vec<u8>> for Vec<u16> {
+  fn write_to(self, dst: &mut Vec<u8>) {
+    dst.write(self.len() as u16);
+    for index in self { dst.write(index); }
+  }
+}
+
 impl Writer<Vec<u8>> for Vec<Field> {
   fn write_to(self, dst: &mut Vec<u8>) {
     dst.write(self.len() as u16);
@@ -109,10 +122,11 @@ impl Writer<Vec<u8>> for Vec<Method> {
     for method in self {
       dst.write(method.access_flags)
         .write(method.name_index)
-        .write(method.descriptor_index)
-        .write(1 as u16) // attributes_count
-        .write(method.code) // the only attribute
-      ;
+        .write(method.descriptor_index);
+      match method.code {
+        Some(code) => { dst.write(1 as u16).write(code); } // the only attribute
+        None => { dst.write(0 as u16); } // attributes_count
+      }
     }
   }
 }
@@ -122,13 +136,16 @@ impl Writer<Vec<u8>> for Code {
     dst.write(self.attribute_name_index)
       .write(2 /* max_stack */ + 2 /* max_locals */
                + 4 /* code_length */ + self.code.len() as u32 /* code */
-               + 2 /* exception_table_length */ + 2 /* attributes_count */)
+               + 2 /* exception_table_length */ + 8 * self.exception_table.len() as u32 /* exception_table */
+               + 2 /* attributes_count */)
       .write(self.max_stack)
       .write(self.max_locals)
       .write(self.code)
-      .write(0 as u16) // exception_table_length
-      .write(0 as u16) // attributes_count
-    ;
+      .write(self.exception_table.len() as u16);
+    for exception in self.exception_table {
+      dst.write(exception.start_pc).write(exception.end_pc).write(exception.handler_pc).write(exception.catch_type);
+    }
+    dst.write(0 as u16); // attributes_count
   }
 }
 
@@ -144,11 +161,16 @@ impl Writer<Vec<u8>> for Instruction {
       IConst3 => dst.write(0x06 as u8),
       IConst4 => dst.write(0x07 as u8),
       IConst5 => dst.write(0x08 as u8),
+      LConst0 => dst.write(0x09 as u8),
+      DConst0 => dst.write(0x0E as u8),
       BIPush(byte) => dst.write(0x10 as u8).write(byte),
       SIPush(bytes) => dst.write(0x11 as u8).write(bytes),
       Ldc(index) => dst.write(0x12 as u8).write(index),
       LdcW(index) => dst.write(0x13 as u8).write(index),
+      Ldc2W(index) => dst.write(0x14 as u8).write(index),
       ILoad(stack_index) => dst.write(0x15 as u8).write(stack_index),
+      LLoad(stack_index) => dst.write(0x16 as u8).write(stack_index),
+      DLoad(stack_index) => dst.write(0x18 as u8).write(stack_index),
       ALoad(stack_index) => dst.write(0x19 as u8).write(stack_index),
       ILoad0 => dst.write(0x1A as u8),
       ILoad1 => dst.write(0x1B as u8),
@@ -159,9 +181,14 @@ impl Writer<Vec<u8>> for Instruction {
       ALoad2 => dst.write(0x2C as u8),
       ALoad3 => dst.write(0x2D as u8),
       IALoad => dst.write(0x2E as u8),
+      LALoad => dst.write(0x2F as u8),
+      DALoad => dst.write(0x31 as u8),
       AALoad => dst.write(0x32 as u8),
       BALoad => dst.write(0x33 as u8),
+      CALoad => dst.write(0x34 as u8),
       IStore(index) => dst.write(0x36 as u8).write(index),
+      LStore(index) => dst.write(0x37 as u8).write(index),
+      DStore(index) => dst.write(0x39 as u8).write(index),
       AStore(index) => dst.write(0x3A as u8).write(index),
       IStore0 => dst.write(0x3B as u8),
       IStore1 => dst.write(0x3C as u8),
@@ -172,26 +199,56 @@ impl Writer<Vec<u8>> for Instruction {
       AStore2 => dst.write(0x4D as u8),
       AStore3 => dst.write(0x4E as u8),
       IAStore => dst.write(0x4F as u8),
+      LAStore => dst.write(0x50 as u8),
+      DAStore => dst.write(0x52 as u8),
       AAStore => dst.write(0x53 as u8),
       BAStore => dst.write(0x54 as u8),
+      CAStore => dst.write(0x55 as u8),
       Pop => dst.write(0x57 as u8),
+      Pop2 => dst.write(0x58 as u8),
       Dup => dst.write(0x59 as u8),
       DupX1 => dst.write(0x5A as u8),
       DupX2 => dst.write(0x5B as u8),
       Dup2 => dst.write(0x5C as u8),
       Swap => dst.write(0x5F as u8),
       IAdd => dst.write(0x60 as u8),
+      LAdd => dst.write(0x61 as u8),
+      DAdd => dst.write(0x63 as u8),
       ISub => dst.write(0x64 as u8),
+      LSub => dst.write(0x65 as u8),
+      DSub => dst.write(0x67 as u8),
       IMul => dst.write(0x68 as u8),
+      LMul => dst.write(0x69 as u8),
+      DMul => dst.write(0x6B as u8),
       IDiv => dst.write(0x6C as u8),
+      LDiv => dst.write(0x6D as u8),
+      DDiv => dst.write(0x6F as u8),
       IRem => dst.write(0x70 as u8),
+      LRem => dst.write(0x71 as u8),
+      DRem => dst.write(0x73 as u8),
       INeg => dst.write(0x74 as u8),
+      LNeg => dst.write(0x75 as u8),
+      DNeg => dst.write(0x77 as u8),
       IShl => dst.write(0x78 as u8),
+      LShl => dst.write(0x79 as u8),
       IUShr => dst.write(0x7C as u8),
+      LUShr => dst.write(0x7D as u8),
       IAnd => dst.write(0x7E as u8),
+      LAnd => dst.write(0x7F as u8),
       IOr => dst.write(0x80 as u8),
+      LOr => dst.write(0x81 as u8),
       IXor => dst.write(0x82 as u8),
+      LXor => dst.write(0x83 as u8),
       IInc(index, value) => dst.write(0x84 as u8).write(index).write(value),
+      I2L => dst.write(0x85 as u8),
+      I2D => dst.write(0x87 as u8),
+      L2I => dst.write(0x88 as u8),
+      L2D => dst.write(0x8A as u8),
+      D2I => dst.write(0x8E as u8),
+      D2L => dst.write(0x8F as u8),
+      LCmp => dst.write(0x94 as u8),
+      DCmpL => dst.write(0x97 as u8),
+      DCmpG => dst.write(0x98 as u8),
       IfEq(offset) => dst.write(0x99 as u8).write(offset),
       IfNe(offset) => dst.write(0x9A as u8).write(offset),
       IfLt(offset) => dst.write(0x9B as u8).write(offset),
@@ -207,21 +264,42 @@ impl Writer<Vec<u8>> for Instruction {
       IfACmpEq(offset) => dst.write(0xA5 as u8).write(offset),
       IfACmpNe(offset) => dst.write(0xA6 as u8).write(offset),
       Goto(offset) => dst.write(0xA7 as u8).write(offset),
+      TableSwitch(low, high) => {
+        dst.write(0xAA as u8);
+        // the default offset starts at an index that is a multiple of 4
+        while dst.len() % 4 != 0 { dst.write(0 as u8); }
+        dst.write(0 as u32).write(low as u32).write(high as u32);
+        for _ in low..=high { dst.write(0 as u32); }
+        dst
+      }
+      LookupSwitch(npairs) => {
+        dst.write(0xAB as u8);
+        while dst.len() % 4 != 0 { dst.write(0 as u8); }
+        dst.write(0 as u32).write(npairs);
+        for _ in 0..npairs { dst.write(0 as u32).write(0 as u32); }
+        dst
+      }
       IReturn => dst.write(0xAC as u8),
+      LReturn => dst.write(0xAD as u8),
+      DReturn => dst.write(0xAF as u8),
       AReturn => dst.write(0xB0 as u8),
       Return => dst.write(0xB1 as u8),
       GetStatic(index) => dst.write(0xB2 as u8).write(index),
+      PutStatic(index) => dst.write(0xB3 as u8).write(index),
       GetField(index) => dst.write(0xB4 as u8).write(index),
       PutField(index) => dst.write(0xB5 as u8).write(index),
       InvokeVirtual(index) => dst.write(0xB6 as u8).write(index),
       InvokeSpecial(index) => dst.write(0xB7 as u8).write(index),
       InvokeStatic(index) => dst.write(0xB8 as u8).write(index),
+      InvokeInterface(index, count) => dst.write(0xB9 as u8).write(index).write(count).write(0 as u8),
       New(index) => dst.write(0xBB as u8).write(index),
       NewArray(a_type) => dst.write(0xBC as u8).write(a_type),
       ANewArray(index) => dst.write(0xBD as u8).write(index),
       ArrayLength => dst.write(0xBE as u8),
+      AThrow => dst.write(0xBF as u8),
       CheckCast(index) => dst.write(0xC0 as u8).write(index),
       InstanceOf(index) => dst.write(0xC1 as u8).write(index),
+      MultiANewArray(index, dims) => dst.write(0xC5 as u8).write(index).write(dims),
     };
   }
 }
@@ -1,29 +1,56 @@
 extern crate jvm;
 
 use jvm::*;
-use jvm::jvm::class::{ACC_PUBLIC, ACC_STATIC, ACC_FINAL};
+use jvm::jvm::class::{ACC_PUBLIC, ACC_PRIVATE, ACC_PROTECTED, ACC_STATIC, ACC_FINAL, ACC_INTERFACE, ACC_ABSTRACT};
 use jvm::jvm::writer::*;
+use jvm::jvm::jar::JarBuilder;
+use jvm::jvm::class::Class;
 
 use super::ast::*;
 use super::types::*;
 use super::symbol::*;
 use super::util::*;
+use super::config::*;
 
 use std::ptr;
+use std::fs;
+use std::path::Path;
 use std::ops::{DerefMut, Deref};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+// runtime helpers generated into the main class, '$' keeps them apart from decaf identifiers
+const STDIN: &'static str = "$stdin";
+const READ_INT: &'static str = "$readInteger";
+const READ_LINE: &'static str = "$readLine";
+const FORMAT_DOUBLE: &'static str = "$formatDouble";
+const STATIC_INIT: &'static str = "$init";
+
+fn access_flag(access: Access) -> u16 {
+  match access {
+    Access::Public => ACC_PUBLIC,
+    Access::Protected => ACC_PROTECTED,
+    Access::Private => ACC_PRIVATE,
+  }
+}
 
 // assume type can only be one of these
 macro_rules! handle {
-  ($t: expr, $int_bool: expr, $object: expr) => {
+  ($t: expr, $int_bool: expr, $long: expr, $double: expr, $object: expr) => {
     match $t {
-      SemanticType::Int | SemanticType::Bool => $int_bool,
+      SemanticType::Int | SemanticType::Bool | SemanticType::Char => $int_bool,
+      SemanticType::Long => $long,
+      SemanticType::Double => $double,
       _ => $object,
     }
   };
-  ($t: expr, $int: expr, $bool: expr, $object: expr) => {
+  ($t: expr, $int: expr, $bool: expr, $char: expr, $long: expr, $double: expr, $object: expr) => {
     match $t {
       SemanticType::Int => $int,
       SemanticType::Bool => $bool,
+      SemanticType::Char => $char,
+      SemanticType::Long => $long,
+      SemanticType::Double => $double,
       _ => $object,
     }
   };
@@ -42,13 +69,62 @@ macro_rules! cmp {
   } };
 }
 
+thread_local! {
+  // descriptor of `apply` => the interface generated for it, and the argument / return types of `apply`
+  static FUNC_INTERFACES: RefCell<HashMap<String, (&'static str, Vec<JavaType>, JavaType)>> = RefCell::new(HashMap::new());
+}
+
+// a function type is an interface with a single method `apply`, named after the descriptor of `apply`
+// the characters not allowed in a class name are escaped with '$', which decaf identifiers never contain
+fn func_interface(param: &[SemanticType], ret: &SemanticType) -> &'static str {
+  let (param, ret) = (param.iter().map(|t| t.to_java()).collect::<Vec<_>>(), ret.to_java());
+  let descriptor = make_method_type(&param, &ret);
+  FUNC_INTERFACES.with(|interfaces| interfaces.borrow_mut().entry(descriptor.clone()).or_insert_with(|| {
+    let mut name = "Func$".to_owned();
+    for c in descriptor[1..].chars() {
+      match c {
+        '/' => name += "$s",
+        ';' => name += "$e",
+        '[' => name += "$a",
+        ')' => name += "$r",
+        '$' => name += "$$",
+        c => name.push(c),
+      }
+    }
+    (Box::leak(name.into_boxed_str()), param, ret)
+  }).0)
+}
+
+// the built-in exceptions are java ones, so they are not generated
+fn java_name(class: &ClassDef) -> &'static str {
+  if class.builtin { BUILTIN_EXCEPTIONS.iter().find(|e| e.0 == class.name).unwrap().1 } else { class.name }
+}
+
+// a try being generated, its protected code is split into ranges around the inlined finally blocks of a return/break/continue
+struct TryState {
+  finally: *mut Block,
+  // length of loop_stack when entering it
+  depth: usize,
+  // start label of the open range
+  start: u16,
+  ranges: Vec<(u16, u16)>,
+}
+
 pub struct JvmCodeGen {
   class_builder: *mut ClassBuilder,
   method_builder: *mut MethodBuilder,
   main: *const ClassDef,
-  break_stack: Vec<u16>,
+  cur_class: *const ClassDef,
+  classes: Vec<(&'static str, Class)>,
+  loop_stack: Vec<(Option<&'static str>, u16, u16)>,
+  try_stack: Vec<TryState>,
+  no_asserts: bool,
   label: u16,
   stack_index: u8,
+  // lambdas created in the current class, their bodies are generated after its methods
+  lambdas: Vec<*mut Lambda>,
+  // classes with static field initializers, in the order their `$init` is called, see `clinit`
+  init_order: Vec<&'static str>,
 }
 
 trait ToJavaType {
@@ -63,37 +139,73 @@ impl ToJavaType for SemanticType {
     match self {
       Int => JavaType::Int,
       Bool => JavaType::Boolean,
+      Char => JavaType::Char,
+      Long => JavaType::Long,
+      Double => JavaType::Double,
       String => JavaType::Class("java/lang/String"),
       Void => JavaType::Void,
-      Object(class) => JavaType::Class(class.get().name),
+      Object(class, _) => JavaType::Class(java_name(class.get())),
+      // erased to its bound, or Object if unbounded
+      Param(_) => match self.bound() { Some(bound) => bound.to_java(), None => JavaType::Class("java/lang/Object") },
       Array(elem) => JavaType::Array(Box::new(elem.to_java())),
+      Func(param, ret) => JavaType::Class(func_interface(param, ret)),
       _ => unreachable!(),
     }
   }
 }
 
 impl JvmCodeGen {
-  pub fn gen(mut program: Program) {
+  // output is either a .jar file, or a directory to put the .class files in
+  pub fn gen(mut program: Program, output: &str) {
     let mut code_gen = JvmCodeGen {
       class_builder: ptr::null_mut(),
       method_builder: ptr::null_mut(),
       main: ptr::null(),
-      break_stack: Vec::new(),
+      cur_class: ptr::null(),
+      classes: Vec::new(),
+      loop_stack: Vec::new(),
+      try_stack: Vec::new(),
+      no_asserts: program.no_asserts,
       label: 0,
       stack_index: 0,
+      lambdas: Vec::new(),
+      init_order: Vec::new(),
     };
     code_gen.main = program.main;
+    // the same order as tac and llvm, enum constants go first since other initializers may use them
+    let mut classes = program.class.iter().collect::<Vec<_>>();
+    classes.sort_by_key(|class_def| class_def.enum_.is_none());
+    code_gen.init_order = classes.into_iter().filter(|class_def| JvmCodeGen::has_static_init(class_def)).map(|class_def| class_def.name).collect();
     for class_def in &mut program.class {
       code_gen.class_def(class_def);
     }
+    code_gen.func_interfaces();
+    if output.ends_with(".jar") {
+      let mut jar = JarBuilder::new(code_gen.main.get().name);
+      for (name, class) in code_gen.classes { jar.add_class(name, class); }
+      jar.done().write_to_file(output);
+    } else {
+      fs::create_dir_all(output).unwrap();
+      for (name, class) in code_gen.classes {
+        class.write_to_file(Path::new(output).join(name.to_owned() + ".class").to_str().unwrap());
+      }
+    }
   }
 
   fn store_to_stack(&mut self, t: &SemanticType, index: u8) {
-    handle!(t, self.i_store(index), self.a_store(index));
+    handle!(t, self.i_store(index), self.l_store(index), self.d_store(index), self.a_store(index));
   }
 
   fn load_from_stack(&mut self, t: &SemanticType, index: u8) {
-    handle!(t, self.i_load(index), self.a_load(index));
+    handle!(t, self.i_load(index), self.l_load(index), self.d_load(index), self.a_load(index));
+  }
+
+  fn array_load(&mut self, t: &SemanticType) {
+    handle!(t, self.i_a_load(), self.b_a_load(), self.c_a_load(), self.l_a_load(), self.d_a_load(), self.a_a_load());
+  }
+
+  fn array_store(&mut self, t: &SemanticType) {
+    handle!(t, self.i_a_store(), self.b_a_store(), self.c_a_store(), self.l_a_store(), self.d_a_store(), self.a_a_store());
   }
 
   fn new_local(&mut self) -> u8 {
@@ -102,24 +214,42 @@ impl JvmCodeGen {
     ret
   }
 
+  // a long or double takes 2 slots
+  fn new_local_of(&mut self, t: &SemanticType) -> u8 {
+    let ret = self.new_local();
+    if t == &LONG || t == &DOUBLE { self.stack_index += 1; }
+    ret
+  }
+
   fn new_label(&mut self) -> u16 {
     let ret = self.label;
     self.label += 1;
     ret
   }
 
+  fn print_str(&mut self, s: &str) {
+    self.get_static("java/lang/System", "out", &JavaType::Class("java/io/PrintStream"));
+    self.string_const(s);
+    self.invoke_virtual("java/io/PrintStream", "print", &[JavaType::Class("java/lang/String")], &JavaType::Void);
+  }
+
   // assume there is already a length on the top
   fn gen_new_array(&mut self, elem_t: &SemanticType) {
     use types::SemanticType::*;
     match elem_t {
       Int => self.new_int_array(),
       Bool => self.new_bool_array(),
+      Char => self.new_char_array(),
+      Long => self.new_long_array(),
+      Double => self.new_double_array(),
       String => self.a_new_array("java/lang/String"),
       // I don't quite understand the design
       // class A[] => A
       // class A[][] => [[LA;
-      SemanticType::Object(class) => self.a_new_array(class.get().name),
+      SemanticType::Object(class, _) => self.a_new_array(java_name(class.get())),
       SemanticType::Array(_) => self.a_new_array(&elem_t.to_java().to_string()),
+      // an array of the bound
+      Param(_) => if let JavaType::Class(class) = elem_t.to_java() { self.a_new_array(class) },
       _ => unreachable!(),
     }
   }
@@ -134,6 +264,13 @@ impl JvmCodeGen {
             self.i_inc(var_def.jvm_index, val as u8);
             self.i_load(var_def.jvm_index);
           }
+          ScopeKind::Class(class) if var_def.static_ => {
+            self.get_static(class.get().name, var_def.name, &JavaType::Int); // x
+            self.int_const(val); // x 1
+            self.i_add(); // x+1
+            self.dup(); // x+1 x+1
+            self.put_static(class.get().name, var_def.name, &JavaType::Int); // x+1
+          }
           ScopeKind::Class(class) => {
             self.expr(if let Some(owner) = &mut id.owner { owner } else { unreachable!() }); // ref
             self.dup(); // ref ref
@@ -170,6 +307,13 @@ impl JvmCodeGen {
             self.i_load(var_def.jvm_index);
             self.i_inc(var_def.jvm_index, val as u8);
           }
+          ScopeKind::Class(class) if var_def.static_ => {
+            self.get_static(class.get().name, var_def.name, &JavaType::Int); // x
+            self.dup(); // x x
+            self.int_const(val); // x x 1
+            self.i_add(); // x x+1
+            self.put_static(class.get().name, var_def.name, &JavaType::Int); // x
+          }
           ScopeKind::Class(class) => {
             self.expr(if let Some(owner) = &mut id.owner { owner } else { unreachable!() }); // ref
             self.dup(); // ref ref
@@ -212,39 +356,208 @@ impl DerefMut for JvmCodeGen {
 
 impl JvmCodeGen {
   fn class_def(&mut self, class_def: &mut ClassDef) {
-    let parent = if let Some(parent) = class_def.parent { parent } else { "java/lang/Object" };
+    if class_def.builtin { return; }
+    if class_def.interface {
+      self.interface_def(class_def);
+      return;
+    }
+    let parent = if class_def.p_ptr.is_null() { "java/lang/Object" } else { java_name(class_def.p_ptr.get()) };
     let mut class_builder =
-      ClassBuilder::new(ACC_PUBLIC | if class_def.sealed { ACC_FINAL } else { 0 }
+      ClassBuilder::new(ACC_PUBLIC | if class_def.sealed { ACC_FINAL } else { 0 } | if class_def.abstract_ { ACC_ABSTRACT } else { 0 }
                         , class_def.name, parent);
     self.class_builder = &mut class_builder;
+    self.cur_class = class_def;
+    for interface in &class_def.implements { class_builder.add_interface(interface); }
 
-    {
-      // generate constructor
+    if class_def.ctor.is_null() {
+      // generate default constructor
       let mut constructor = MethodBuilder::new(&mut class_builder, ACC_PUBLIC, "<init>", &[], &JavaType::Void);
       constructor.a_load(0);
       constructor.invoke_special(parent, "<init>", &[], &JavaType::Void);
       constructor.return_();
-      constructor.done(1);
+      constructor.done(1).unwrap();
     }
 
     for field_def in &mut class_def.field {
       match field_def {
-        FieldDef::MethodDef(method_def) => self.method_def(method_def),
+        FieldDef::MethodDef(method_def) | FieldDef::Ctor(method_def) => self.method_def(method_def),
         FieldDef::VarDef(var_def) => self.var_def(var_def),
       };
     }
-    class_builder.done().write_to_file(&(class_def.name.to_owned() + ".class"));
+    let is_main = class_def as *const ClassDef == self.main;
+    if is_main {
+      JvmCodeGen::read_helpers(&mut class_builder, class_def.name);
+      JvmCodeGen::format_double_helper(&mut class_builder);
+    }
+    if JvmCodeGen::has_static_init(class_def) {
+      self.static_init(&mut class_builder, class_def);
+    }
+    if is_main {
+      self.clinit(&mut class_builder, class_def);
+    }
+    while !self.lambdas.is_empty() {
+      let lambda = self.lambdas.remove(0);
+      self.lambda_body(lambda.get());
+    }
+    self.classes.push((class_def.name, class_builder.done()));
     self.class_builder = ptr::null_mut();
+    self.cur_class = ptr::null();
+  }
+
+  // argument types of the `<init>` of `class`, which is the default one if the class declares no constructor
+  fn ctor_types(class: &ClassDef) -> Vec<JavaType> {
+    if class.ctor.is_null() { Vec::new() } else {
+      class.ctor.get().param.iter().skip(1).map(|var_def| var_def.type_.to_java()).collect()
+    }
+  }
+
+  // the parent of a class without `extends` is java.lang.Object
+  fn super_ctor(&mut self, arg: &mut Vec<Expr>) {
+    let class = self.cur_class.get();
+    self.a_load(0);
+    for arg in arg { self.expr(arg); }
+    if class.p_ptr.is_null() {
+      self.invoke_special("java/lang/Object", "<init>", &[], &JavaType::Void);
+    } else {
+      let parent = class.p_ptr.get();
+      self.invoke_special(java_name(parent), "<init>", &JvmCodeGen::ctor_types(parent), &JavaType::Void);
+    }
+  }
+
+  fn interface_def(&mut self, class_def: &ClassDef) {
+    let mut class_builder = ClassBuilder::new(ACC_PUBLIC | ACC_INTERFACE | ACC_ABSTRACT, class_def.name, "java/lang/Object");
+    for field_def in &class_def.field {
+      if let FieldDef::MethodDef(method_def) = field_def {
+        let argument_types: Vec<JavaType> = method_def.param.iter().map(|var_def| var_def.type_.to_java()).collect();
+        class_builder.define_abstract_method(ACC_PUBLIC, method_def.name, &argument_types[1..], &method_def.ret_t.to_java());
+      }
+    }
+    self.classes.push((class_def.name, class_builder.done()));
+  }
+
+  fn func_interfaces(&mut self) {
+    let mut interfaces = FUNC_INTERFACES.with(|interfaces| interfaces.borrow().values().cloned().collect::<Vec<_>>());
+    interfaces.sort_by_key(|interface| interface.0);
+    for (name, param, ret) in interfaces {
+      let mut class_builder = ClassBuilder::new(ACC_PUBLIC | ACC_INTERFACE | ACC_ABSTRACT, name, "java/lang/Object");
+      class_builder.define_abstract_method(ACC_PUBLIC, "apply", &param, &ret);
+      self.classes.push((name, class_builder.done()));
+    }
+  }
+
+  fn has_static_init(class_def: &ClassDef) -> bool {
+    class_def.field.iter().any(|field| if let FieldDef::VarDef(var_def) = field { var_def.src.is_some() } else { false })
+  }
+
+  // static field initializers of a class, all of them are called by the <clinit> of main class
+  // so they run before main in a fixed order, rather than when each class is first used
+  fn static_init(&mut self, class_builder: &mut ClassBuilder, class_def: &mut ClassDef) {
+    let mut init = MethodBuilder::new(class_builder, ACC_STATIC, STATIC_INIT, &[], &JavaType::Void);
+    self.method_builder = &mut init;
+    self.label = 0;
+    self.stack_index = 0;
+    for field_def in &mut class_def.field {
+      if let FieldDef::VarDef(var_def) = field_def {
+        if let Some(src) = &mut var_def.src {
+          self.expr(src);
+          self.put_static(class_def.name, var_def.name, &var_def.type_.to_java());
+        }
+      }
+    }
+    init.return_();
+    init.done(self.stack_index as u16).unwrap();
+    self.method_builder = ptr::null_mut();
+  }
+
+  // the stdin scanner, and then the static field initializers of all classes
+  fn clinit(&mut self, class_builder: &mut ClassBuilder, class_def: &ClassDef) {
+    let mut clinit = MethodBuilder::new(class_builder, ACC_STATIC, "<clinit>", &[], &JavaType::Void);
+    self.method_builder = &mut clinit;
+    self.label = 0;
+    self.stack_index = 0;
+    self.new_("java/util/Scanner");
+    self.dup();
+    self.get_static("java/lang/System", "in", &JavaType::Class("java/io/InputStream"));
+    self.invoke_special("java/util/Scanner", "<init>", &[JavaType::Class("java/io/InputStream")], &JavaType::Void);
+    self.put_static(class_def.name, STDIN, &JavaType::Class("java/util/Scanner"));
+    for class in self.init_order.clone() {
+      self.invoke_static(class, STATIC_INIT, &[], &JavaType::Void);
+    }
+    clinit.return_();
+    clinit.done(self.stack_index as u16).unwrap();
+    self.method_builder = ptr::null_mut();
+  }
+
+  // ReadInteger & ReadLine share one Scanner on System.in, which is initialized in <clinit>
+  // ReadInteger reads the next whitespace separated token, like the tac intrinsic(Scanner.nextInt) and llvm's scanf("%d")
+  fn read_helpers(class_builder: &mut ClassBuilder, main: &str) {
+    let scanner = JavaType::Class("java/util/Scanner");
+    class_builder.define_field(ACC_PRIVATE | ACC_STATIC, STDIN, &scanner);
+    {
+      let mut read_line = MethodBuilder::new(class_builder, ACC_PUBLIC | ACC_STATIC, READ_LINE, &[], &JavaType::Class("java/lang/String"));
+      read_line.get_static(main, STDIN, &scanner);
+      read_line.invoke_virtual("java/util/Scanner", "nextLine", &[], &JavaType::Class("java/lang/String"));
+      read_line.a_return();
+      read_line.done(0).unwrap();
+    }
+    {
+      let mut read_int = MethodBuilder::new(class_builder, ACC_PUBLIC | ACC_STATIC, READ_INT, &[], &JavaType::Int);
+      read_int.get_static(main, STDIN, &scanner);
+      read_int.invoke_virtual("java/util/Scanner", "nextInt", &[], &JavaType::Int);
+      read_int.i_return();
+      read_int.done(0).unwrap();
+    }
+  }
+
+  // String.format(Locale.ROOT, "%f", x), the same format as printf in llvm, which prints "nan", "inf" and "-inf" for non-finite x
+  fn format_double_helper(class_builder: &mut ClassBuilder) {
+    let (string, object) = (JavaType::Class("java/lang/String"), JavaType::Class("java/lang/Object"));
+    let mut format = MethodBuilder::new(class_builder, ACC_PUBLIC | ACC_STATIC, FORMAT_DOUBLE, &[JavaType::Double], &string);
+    let (not_nan, positive, finite) = (0, 1, 2);
+    format.d_load(0);
+    format.invoke_static("java/lang/Double", "isNaN", &[JavaType::Double], &JavaType::Boolean);
+    format.if_eq(not_nan);
+    format.string_const("nan");
+    format.a_return();
+    format.label(not_nan);
+    format.d_load(0);
+    format.invoke_static("java/lang/Double", "isInfinite", &[JavaType::Double], &JavaType::Boolean);
+    format.if_eq(finite);
+    format.d_load(0);
+    format.d_const_0();
+    format.d_cmp_l();
+    format.if_gt(positive);
+    format.string_const("-inf");
+    format.a_return();
+    format.label(positive);
+    format.string_const("inf");
+    format.a_return();
+    format.label(finite);
+    format.get_static("java/util/Locale", "ROOT", &JavaType::Class("java/util/Locale"));
+    format.string_const("%f");
+    format.int_const(1);
+    format.a_new_array("java/lang/Object");
+    format.dup();
+    format.int_const(0);
+    format.d_load(0);
+    format.invoke_static("java/lang/Double", "valueOf", &[JavaType::Double], &JavaType::Class("java/lang/Double"));
+    format.a_a_store();
+    format.invoke_static("java/lang/String", "format", &[JavaType::Class("java/util/Locale"), string.clone(), JavaType::Array(Box::new(object))], &string);
+    format.a_return();
+    format.done(2).unwrap();
   }
 
   fn method_def(&mut self, method_def: &mut MethodDef) {
-    if method_def.class == self.main && method_def.name == "main" {
+    // main is the 1st declared one, other overloads of it are ordinary methods
+    if method_def.class == self.main && method_def.name == "main" && method_def.overload_idx == 0 {
       method_def.param.insert(0, VarDef {
         loc: method_def.loc,
         name: "args",
         type_: Type { loc: method_def.loc, sem: SemanticType::Array(Box::new(STRING)) },
         src: None,
         finish_loc: method_def.loc,
+        static_: false,
+        access: Access::Protected,
         scope: &method_def.scope,
         jvm_index: 0,
         offset: -1,
@@ -253,11 +566,17 @@ impl JvmCodeGen {
     }
     let argument_types: Vec<JavaType> = method_def.param.iter().map(|var_def| var_def.type_.to_java()).collect();
     let return_type = method_def.ret_t.to_java();
+    if method_def.abstract_ {
+      self.class_builder.get().define_abstract_method(access_flag(method_def.access), method_def.name, &argument_types[1..], &return_type);
+      return;
+    }
     // in type check, a virtual this is added to the param list
     // but jvm doesn't need it, so take the slice from 1 to end
+    let is_ctor = method_def.class.get().ctor == method_def as *const _;
+    let is_main = method_def.class == self.main && method_def.name == "main" && method_def.overload_idx == 0;
     let mut method_builder = MethodBuilder::new(self.class_builder.get(),
-                                                ACC_PUBLIC | if method_def.static_ { ACC_STATIC } else { 0 },
-                                                method_def.name,
+                                                access_flag(method_def.access) | if method_def.static_ { ACC_STATIC } else { 0 },
+                                                if is_ctor { "<init>" } else { method_def.name },
                                                 &argument_types[if method_def.static_ { 0 } else { 1 }..],
                                                 &return_type);
     self.method_builder = &mut method_builder;
@@ -265,21 +584,147 @@ impl JvmCodeGen {
     self.stack_index = 0;
     // this is counted here
     for var_def in &mut method_def.param { self.var_def(var_def); }
+    // jvm requires every constructor to call its parent's, even if it is the default one
+    let explicit_super = if let Some(Stmt::SuperCall(_)) = method_def.body.stmt.first() { true } else { false };
+    if is_ctor && !explicit_super { self.super_ctor(&mut Vec::new()); }
+    let start = self.new_label();
+    self.label(start);
     self.block(&mut method_def.body);
+    self.default_return(&method_def.ret_t.sem);
+    if is_main { self.uncaught_handler(start); }
+    if let Err(e) = method_builder.done(self.stack_index as u16) {
+      panic!("jvm codegen produced invalid bytecode: {}", e);
+    }
+    self.method_builder = ptr::null_mut();
+  }
 
-    // well, I don't know how to do control flow analysis, dirty hacks here
-    match &method_def.ret_t.sem {
-      SemanticType::Int | SemanticType::Bool => {
-        method_builder.int_const(0);
-        method_builder.i_return();
+  // an uncaught user exception prints its class name and exits like tac & llvm, built-in ones are rethrown
+  // it is added after the entries of the trys in main, so it is looked up last
+  fn uncaught_handler(&mut self, start: u16) {
+    let (handler, rethrow, print) = (self.new_label(), self.new_label(), self.new_label());
+    self.label(handler);
+    self.try_catch(start, handler, handler, Some(BUILTIN_EXCEPTIONS[0].1));
+    let e = self.new_local();
+    self.a_store(e);
+    for &(_, class) in &BUILTIN_EXCEPTIONS[1..] {
+      self.a_load(e);
+      self.instance_of(class);
+      self.if_ne(rethrow);
+    }
+    let string = JavaType::Class("java/lang/String");
+    self.print_str(UNCAUGHT1);
+    self.get_static("java/lang/System", "out", &JavaType::Class("java/io/PrintStream"));
+    self.a_load(e);
+    self.invoke_virtual("java/lang/Object", "getClass", &[], &JavaType::Class("java/lang/Class"));
+    self.invoke_virtual("java/lang/Class", "getName", &[], &string);
+    // the built-in Exception itself is java.lang.RuntimeException
+    self.dup();
+    self.string_const("java.lang.RuntimeException");
+    self.invoke_virtual("java/lang/String", "equals", &[JavaType::Class("java/lang/Object")], &JavaType::Boolean);
+    self.if_eq(print);
+    self.pop();
+    self.string_const(EXCEPTION);
+    self.label(print);
+    self.invoke_virtual("java/io/PrintStream", "print", &[string], &JavaType::Void);
+    self.print_str(UNCAUGHT2);
+    self.int_const(0);
+    self.invoke_static("java/lang/System", "exit", &[JavaType::Int], &JavaType::Void);
+    self.return_();
+    self.label(rethrow);
+    self.a_load(e);
+    self.a_throw();
+  }
+
+  // the default return is unreachable if the body always returns, which the verifier accepts
+  fn default_return(&mut self, ret_t: &SemanticType) {
+    match ret_t {
+      SemanticType::Int | SemanticType::Bool | SemanticType::Char => {
+        self.int_const(0);
+        self.i_return();
       }
-      SemanticType::Void => method_builder.return_(),
+      SemanticType::Long => {
+        self.l_const_0();
+        self.l_return();
+      }
+      SemanticType::Double => {
+        self.d_const_0();
+        self.d_return();
+      }
+      SemanticType::Void => self.method_builder.get().return_(),
       _ => {
-        method_builder.a_const_null();
-        method_builder.a_return();
+        self.a_const_null();
+        self.a_return();
       }
     };
-    method_builder.done(self.stack_index as u16);
+  }
+
+  // a lambda is an object of class `A$lambda__1`, which implements its function type and holds the captured values
+  // its `apply` calls the static method `A.lambda__1` with the captured values before the arguments
+  // so the body is still in class A, and can access the private members of A
+  fn lambda_class(&mut self, lambda: &Lambda) -> &'static str {
+    let def = &lambda.def;
+    let host = self.cur_class.get().name;
+    let name: &'static str = Box::leak(format!("{}${}", host, def.mangled_name()).into_boxed_str());
+    let mut class_builder = ClassBuilder::new(ACC_PUBLIC | ACC_FINAL, name, "java/lang/Object");
+    if let SemanticType::Func(param, ret) = &def.param[0].type_.sem { class_builder.add_interface(func_interface(param, ret)); }
+    let capture_t = lambda.capture.iter().map(|(_, copy)| copy.type_.to_java()).collect::<Vec<_>>();
+    for (index, t) in capture_t.iter().enumerate() {
+      class_builder.define_field(ACC_PRIVATE | ACC_FINAL, &format!("c{}", index), t);
+    }
+    {
+      let mut ctor = MethodBuilder::new(&mut class_builder, ACC_PUBLIC, "<init>", &capture_t, &JavaType::Void);
+      ctor.a_load(0);
+      ctor.invoke_special("java/lang/Object", "<init>", &[], &JavaType::Void);
+      let mut local = 1;
+      for (index, ((_, copy), t)) in lambda.capture.iter().zip(capture_t.iter()).enumerate() {
+        ctor.a_load(0);
+        handle!(&copy.type_.sem, ctor.i_load(local), ctor.l_load(local), ctor.d_load(local), ctor.a_load(local));
+        ctor.put_field(name, &format!("c{}", index), t);
+        local += if copy.type_.sem == LONG || copy.type_.sem == DOUBLE { 2 } else { 1 };
+      }
+      ctor.return_();
+      ctor.done(local as u16).unwrap();
+    }
+    {
+      let param_t = def.param.iter().skip(1).map(|var_def| var_def.type_.to_java()).collect::<Vec<_>>();
+      let ret_t = def.ret_t.to_java();
+      let mut apply = MethodBuilder::new(&mut class_builder, ACC_PUBLIC, "apply", &param_t, &ret_t);
+      for (index, t) in capture_t.iter().enumerate() {
+        apply.a_load(0);
+        apply.get_field(name, &format!("c{}", index), t);
+      }
+      let mut local = 1;
+      for var_def in def.param.iter().skip(1) {
+        handle!(&var_def.type_.sem, apply.i_load(local), apply.l_load(local), apply.d_load(local), apply.a_load(local));
+        local += if var_def.type_.sem == LONG || var_def.type_.sem == DOUBLE { 2 } else { 1 };
+      }
+      let argument_types = capture_t.iter().chain(param_t.iter()).cloned().collect::<Vec<_>>();
+      apply.invoke_static(host, &def.mangled_name(), &argument_types, &ret_t);
+      match &def.ret_t.sem {
+        SemanticType::Void => apply.return_(),
+        t => handle!(t, apply.i_return(), apply.l_return(), apply.d_return(), apply.a_return()),
+      }
+      apply.done(local as u16).unwrap();
+    }
+    self.classes.push((name, class_builder.done()));
+    name
+  }
+
+  fn lambda_body(&mut self, lambda: &mut Lambda) {
+    let def = &mut lambda.def;
+    let argument_types = lambda.capture.iter().map(|(_, copy)| &copy.type_).chain(def.param.iter().skip(1).map(|var_def| &var_def.type_))
+      .map(|t| t.to_java()).collect::<Vec<_>>();
+    let mut method_builder = MethodBuilder::new(self.class_builder.get(), ACC_STATIC, &def.mangled_name(), &argument_types, &def.ret_t.to_java());
+    self.method_builder = &mut method_builder;
+    self.label = 0;
+    self.stack_index = 0;
+    for (_, copy) in &mut lambda.capture { self.var_def(copy); }
+    for var_def in def.param.iter_mut().skip(1) { self.var_def(var_def); }
+    self.block(&mut def.body);
+    self.default_return(&def.ret_t.sem);
+    if let Err(e) = method_builder.done(self.stack_index as u16) {
+      panic!("jvm codegen produced invalid bytecode: {}", e);
+    }
     self.method_builder = ptr::null_mut();
   }
 
@@ -299,45 +744,86 @@ impl JvmCodeGen {
       }
       While(while_) => {
         let (before_cond, after_body) = (self.new_label(), self.new_label());
-        self.break_stack.push(after_body);
+        self.loop_stack.push((while_.label, after_body, before_cond));
         self.label(before_cond);
         self.expr(&mut while_.cond);
         self.if_eq(after_body);
         self.block(&mut while_.body);
         self.goto(before_cond);
         self.label(after_body);
-        self.break_stack.pop();
+        self.loop_stack.pop();
+      }
+      DoWhile(do_while) => {
+        let (before_body, before_cond, after_body) = (self.new_label(), self.new_label(), self.new_label());
+        self.loop_stack.push((do_while.label, after_body, before_cond));
+        self.label(before_body);
+        self.block(&mut do_while.body);
+        self.label(before_cond);
+        self.expr(&mut do_while.cond);
+        self.if_ne(before_body);
+        self.label(after_body);
+        self.loop_stack.pop();
       }
       For(for_) => {
-        let (before_cond, after_body) = (self.new_label(), self.new_label());
-        self.break_stack.push(after_body);
+        let (before_cond, before_update, after_body) = (self.new_label(), self.new_label(), self.new_label());
+        self.loop_stack.push((for_.label, after_body, before_update));
         self.simple(&mut for_.init);
         self.label(before_cond);
         self.expr(&mut for_.cond);
         self.if_eq(after_body);
         self.block(&mut for_.body);
+        self.label(before_update);
         self.simple(&mut for_.update);
         self.goto(before_cond);
         self.label(after_body);
-        self.break_stack.pop();
+        self.loop_stack.pop();
       }
       Return(return_) => if let Some(expr) = &mut return_.expr {
         self.expr(expr);
-        handle!(expr.type_, self.i_return(), self.a_return());
+        if self.try_stack.iter().any(|t| !t.finally.is_null()) {
+          let ret = self.new_local_of(&expr.type_);
+          self.store_to_stack(&expr.type_, ret);
+          self.leave_try(0);
+          self.load_from_stack(&expr.type_, ret);
+          handle!(expr.type_, self.i_return(), self.l_return(), self.d_return(), self.a_return());
+          self.reenter_try(0);
+        } else {
+          handle!(expr.type_, self.i_return(), self.l_return(), self.d_return(), self.a_return());
+        }
       } else {
+        self.leave_try(0);
         self.method_builder.get().return_();
+        self.reenter_try(0);
       },
       Print(print) => for print in &mut print.print {
         self.get_static("java/lang/System", "out", &JavaType::Class("java/io/PrintStream"));
-        self.expr(print);
-        self.invoke_virtual("java/io/PrintStream", "print", &[print.type_.to_java()], &JavaType::Void);
+        if print.type_ == DOUBLE {
+          let (main, string) = (self.main.get().name, JavaType::Class("java/lang/String"));
+          self.expr(print);
+          self.invoke_static(main, FORMAT_DOUBLE, &[JavaType::Double], &string);
+          self.invoke_virtual("java/io/PrintStream", "print", &[string], &JavaType::Void);
+        } else {
+          self.expr(print);
+          self.invoke_virtual("java/io/PrintStream", "print", &[print.type_.to_java()], &JavaType::Void);
+        }
       }
-      Break(_) => {
-        let out = *self.break_stack.last().unwrap();
+      Break(break_) => {
+        let (out, _) = loop_target(&self.loop_stack, break_.label);
+        let first = self.first_try_in(loop_index(&self.loop_stack, break_.label, false));
+        self.leave_try(first);
         self.goto(out);
+        self.reenter_try(first);
+      }
+      Continue(continue_) => {
+        let (_, next_iter) = loop_target(&self.loop_stack, continue_.label);
+        let first = self.first_try_in(loop_index(&self.loop_stack, continue_.label, true));
+        self.leave_try(first);
+        self.goto(next_iter);
+        self.reenter_try(first);
       }
       SCopy(s_copy) => self.s_copy(s_copy),
       Foreach(foreach) => self.foreach(foreach),
+      Switch(switch) => self.switch(switch),
       Guarded(guarded) => for (e, b) in &mut guarded.guarded {
         let after = self.new_label();
         self.expr(e);
@@ -346,9 +832,43 @@ impl JvmCodeGen {
         self.label(after);
       }
       Block(block) => self.block(block),
+      SuperCall(super_call) => self.super_ctor(&mut super_call.arg),
+      Try(try_) => self.try_(try_),
+      Throw(throw) => {
+        self.expr(&mut throw.expr);
+        self.a_throw();
+      }
+      Assert(assert) => if !self.no_asserts {
+        let ok = self.new_label();
+        let string = JavaType::Class("java/lang/String");
+        self.expr(&mut assert.cond);
+        self.if_ne(ok);
+        self.print_str(&format!("{}{}", ASSERT1, assert.loc));
+        if let Some(msg) = &mut assert.msg {
+          self.print_str(ASSERT2);
+          self.get_static("java/lang/System", "out", &JavaType::Class("java/io/PrintStream"));
+          self.expr(msg);
+          self.invoke_virtual("java/io/PrintStream", "print", &[string], &JavaType::Void);
+        }
+        self.print_str(ASSERT3);
+        self.int_const(0);
+        self.invoke_static("java/lang/System", "exit", &[JavaType::Int], &JavaType::Void);
+        self.label(ok);
+      }
     };
   }
 
+  // a member of a generic class has the erased type, which is casted back to the type argument
+  fn cast_erased(&mut self, declared: &SemanticType, actual: &SemanticType) {
+    if declared.has_param() && declared != actual {
+      match actual.to_java() {
+        JavaType::Class(name) => self.check_cast(name),
+        array @ JavaType::Array(_) => self.check_cast(&array.to_string()),
+        _ => {}
+      }
+    }
+  }
+
   fn expr(&mut self, expr: &mut Expr) {
     use ast::ExprData::*;
     match &mut expr.data {
@@ -356,15 +876,20 @@ impl JvmCodeGen {
         let var_def = id.symbol.get();
         match var_def.scope.get().kind {
           ScopeKind::Local(_) | ScopeKind::Parameter(_) => self.load_from_stack(&var_def.type_, var_def.jvm_index),
+          ScopeKind::Class(class) if var_def.static_ => self.get_static(class.get().name, var_def.name, &var_def.type_.to_java()),
           ScopeKind::Class(class) => {
             self.expr(if let Some(owner) = &mut id.owner { owner } else { unreachable!() });
-            self.get_field(class.get().name, var_def.name, &var_def.type_.to_java())
+            self.get_field(class.get().name, var_def.name, &var_def.type_.to_java());
+            self.cast_erased(&var_def.type_, &expr.type_);
           }
           _ => unreachable!(),
         }
       } else { if let Some(owner) = &mut id.owner { self.expr(owner); } }
       Indexed(indexed) => self.indexed(indexed, &expr.type_),
       IntConst(v) => self.int_const(*v),
+      CharConst(v) => self.int_const(*v as i32),
+      LongConst(v) => self.long_const(*v),
+      DoubleConst(v) => self.double_const(*v),
       BoolConst(v) => self.bool_const(*v),
       StringConst(v) => self.string_const(v),
       ArrayConst(_) => unimplemented!(),
@@ -372,6 +897,14 @@ impl JvmCodeGen {
       Call(call) => if call.is_arr_len {
         self.expr(if let Some(owner) = &mut call.owner { owner } else { unreachable!() });
         self.array_length();
+      } else if let Some(method) = call.string_method {
+        self.expr(if let Some(owner) = &mut call.owner { owner } else { unreachable!() });
+        for arg in &mut call.arg { self.expr(arg); }
+        match method {
+          StringMethod::Length => self.invoke_virtual("java/lang/String", "length", &[], &JavaType::Int),
+          StringMethod::CharAt => self.invoke_virtual("java/lang/String", "charAt", &[JavaType::Int], &JavaType::Char),
+          StringMethod::Substring => self.invoke_virtual("java/lang/String", "substring", &[JavaType::Int, JavaType::Int], &JavaType::Class("java/lang/String")),
+        }
       } else {
         let method = call.method.get();
         if let Some(owner) = &mut call.owner { self.expr(owner); }
@@ -380,31 +913,102 @@ impl JvmCodeGen {
         let return_type = method.ret_t.to_java();
         if method.static_ {
           self.invoke_static(method.class.get().name, method.name, &argument_types, &return_type);
+        } else if method.class.get().interface {
+          self.invoke_interface(method.class.get().name, method.name, &argument_types[1..], &return_type);
+        } else if let Some(Expr { data: Super, type_, .. }) = call.owner.as_ref().map(|owner| owner.as_ref()) {
+          self.invoke_special(type_.get_class().name, method.name, &argument_types[1..], &return_type);
+        } else if method.access == Access::Private {
+          // a private method is not virtual
+          self.invoke_special(method.class.get().name, method.name, &argument_types[1..], &return_type);
         } else {
           self.invoke_virtual(method.class.get().name, method.name, &argument_types[1..], &return_type);
         }
+        self.cast_erased(&method.ret_t, &expr.type_);
       }
       Unary(unary) => self.unary(unary),
       Binary(binary) => self.binary(binary),
-      This => self.a_load(0),
-      NewClass { name } => {
-        self.new_(name);
+      This | Super => self.a_load(0),
+      NewClass { name: _, type_arg: _, arg } => {
+        let class = expr.type_.get_class();
+        self.new_(java_name(class));
         self.dup();
-        self.invoke_special(name, "<init>", &[], &JavaType::Void);
+        for arg in arg.iter_mut() { self.expr(arg); }
+        self.invoke_special(java_name(class), "<init>", &JvmCodeGen::ctor_types(class), &JavaType::Void);
       }
-      NewArray { elem_t: _, len } => {
+      NewArray { elem_t: _, len, dims } => {
         self.expr(len);
-        self.gen_new_array(if let SemanticType::Array(elem_t) = &expr.type_ { elem_t } else { unreachable!() });
+        if dims.is_empty() {
+          self.gen_new_array(if let SemanticType::Array(elem_t) = &expr.type_ { elem_t } else { unreachable!() });
+        } else {
+          for dim in dims.iter_mut() { self.expr(dim); }
+          self.multi_a_new_array(&expr.type_.to_java().to_string(), dims.len() as u8 + 1);
+        }
       }
-      TypeTest { expr, name, target_class: _ } => {
+      TypeTest { expr, name: _, target_class } => {
         self.expr(expr);
-        self.instance_of(name);
+        self.instance_of(java_name(target_class.get()));
       }
-      TypeCast { name, expr } => {
-        self.expr(expr);
-        self.check_cast(name);
+      TypeCast { name: _, expr: src } => {
+        self.expr(src);
+        self.check_cast(java_name(expr.type_.get_class()));
       }
       Default(default) => self.default(default),
+      Conditional(conditional) => {
+        let (before_false, after) = (self.new_label(), self.new_label());
+        self.expr(&mut conditional.cond);
+        self.if_eq(before_false);
+        self.expr(&mut conditional.on_true);
+        self.goto(after);
+        self.label(before_false);
+        self.expr(&mut conditional.on_false);
+        self.label(after);
+      }
+      ReadInt => {
+        let main = self.main.get().name;
+        self.invoke_static(main, READ_INT, &[], &JavaType::Int);
+      }
+      ReadLine => {
+        let main = self.main.get().name;
+        self.invoke_static(main, READ_LINE, &[], &JavaType::Class("java/lang/String"));
+      }
+      Convert(convert) => {
+        let (string, chars) = (JavaType::Class("java/lang/String"), JavaType::Array(Box::new(JavaType::Char)));
+        self.expr(&mut convert.expr);
+        match convert.op {
+          ConvertOp::CharToInt => {}
+          // keep the low 8 bits, the same as other backends
+          ConvertOp::IntToChar => {
+            self.int_const(255);
+            self.i_and();
+          }
+          ConvertOp::ToInt | ConvertOp::ToDouble | ConvertOp::ToLong if convert.expr.type_ == expr.type_ => {}
+          ConvertOp::ToInt => if convert.expr.type_ == LONG { self.l_2_i() } else { self.d_2_i() },
+          ConvertOp::ToDouble => if convert.expr.type_ == LONG { self.l_2_d() } else { self.i_2_d() },
+          ConvertOp::ToLong => if convert.expr.type_ == INT { self.i_2_l() } else { self.d_2_l() },
+          ConvertOp::StringToChars => self.invoke_virtual("java/lang/String", "toCharArray", &[], &chars),
+          ConvertOp::CharsToString => self.invoke_static("java/lang/String", "valueOf", &[chars], &string),
+        }
+      }
+      Lambda(lambda) => {
+        let class = self.lambda_class(lambda);
+        self.new_(class);
+        self.dup();
+        for (src, _) in &lambda.capture {
+          let src = src.get();
+          self.load_from_stack(&src.type_, src.jvm_index);
+        }
+        let capture_t = lambda.capture.iter().map(|(_, copy)| copy.type_.to_java()).collect::<Vec<_>>();
+        self.invoke_special(class, "<init>", &capture_t, &JavaType::Void);
+        self.lambdas.push(lambda.as_mut());
+      }
+      Apply(apply) => {
+        self.expr(&mut apply.func);
+        for arg in &mut apply.arg { self.expr(arg); }
+        if let SemanticType::Func(param, ret) = &apply.func.type_ {
+          let argument_types = param.iter().map(|t| t.to_java()).collect::<Vec<_>>();
+          self.invoke_interface(func_interface(param, ret), "apply", &argument_types, &ret.to_java());
+        }
+      }
       _ => unimplemented!(),
     };
   }
@@ -414,21 +1018,23 @@ impl JvmCodeGen {
     match simple {
       Simple::Assign(assign) => self.assign(assign),
       Simple::VarDef(var_def) => {
-        let index = self.new_local();
+        let index = self.new_local_of(&var_def.type_);
         var_def.jvm_index = index;
         if let Some(src) = &mut var_def.src {
           self.expr(src);
           self.store_to_stack(&var_def.type_, index);
         } else {
-          // default init, int/bool => 0, string/class/object => null
-          handle!(&var_def.type_.sem, { self.int_const(0); self.i_store(index); }, { self.a_const_null(); self.a_store(index); });
+          // default init, int/bool/long => 0, double => 0.0, string/class/object => null
+          handle!(&var_def.type_.sem, { self.int_const(0); self.i_store(index); }, { self.l_const_0(); self.l_store(index); },
+                  { self.d_const_0(); self.d_store(index); }, { self.a_const_null(); self.a_store(index); });
         }
       }
       Simple::Expr(expr) => {
         self.expr(expr);
         match &expr.data {
-          ExprData::Call(call) => if call.method.get().ret_t.sem != VOID { self.pop(); }
-          _ => self.pop(),
+          ExprData::Call(call) if call.method.get().ret_t.sem == VOID => {}
+          ExprData::Apply(_) if expr.type_ == VOID => {}
+          _ => if expr.type_ == LONG || expr.type_ == DOUBLE { self.pop_2(); } else { self.pop(); },
         }
       }
       _ => {}
@@ -437,8 +1043,9 @@ impl JvmCodeGen {
 
   fn var_def(&mut self, var_def: &mut VarDef) {
     match var_def.scope.get().kind {
-      ScopeKind::Local(_) | ScopeKind::Parameter(_) => var_def.jvm_index = self.new_local(),
-      ScopeKind::Class(_) => self.class_builder.get().define_field(ACC_PUBLIC, var_def.name, &var_def.type_.to_java()),
+      ScopeKind::Local(_) | ScopeKind::Parameter(_) => var_def.jvm_index = self.new_local_of(&var_def.type_),
+      ScopeKind::Class(_) => self.class_builder.get().define_field(access_flag(var_def.access) | if var_def.static_ { ACC_STATIC } else { 0 },
+                                                                    var_def.name, &var_def.type_.to_java()),
       _ => unreachable!(),
     }
   }
@@ -449,17 +1056,19 @@ impl JvmCodeGen {
 
   fn s_copy(&mut self, s_copy: &mut SCopy) {
     let src = self.new_local();
-    let class = if let SemanticType::Object(class) = s_copy.src.type_ { class.get() } else { unreachable!() };
+    let class = if let SemanticType::Object(class, _) = s_copy.src.type_ { class.get() } else { unreachable!() };
     let dst = s_copy.dst_sym.get().jvm_index;
     let tmp = self.new_local();
     self.expr(&mut s_copy.src);
     self.a_store(src);
-    self.new_(class.name);
+    self.new_(java_name(class));
     self.dup();
-    self.invoke_special(class.name, "<init>", &[], &JavaType::Void);
+    self.invoke_special(java_name(class), "<init>", &[], &JavaType::Void);
     self.a_store(tmp);
     for field in &class.field {
       if let FieldDef::VarDef(var_def) = field {
+        // the hidden message of a built-in exception is not copied
+        if var_def.static_ || class.builtin { continue; }
         let field_type = &var_def.type_.to_java();
         self.a_load(tmp);
         self.a_load(src);
@@ -471,6 +1080,95 @@ impl JvmCodeGen {
     self.a_store(dst);
   }
 
+  // try {
+  //   <body>                 (ranges of the body are caught by each catch, and by finally)
+  // } catch (E e) {
+  //   <body>                 (ranges of the catch bodies are caught by finally)
+  // } finally { <F> }
+  // the finally block is inlined after the body and each catch, and before each return/break/continue out of it
+  // for other exceptions: tmp = exception; <F>; throw tmp
+  fn try_(&mut self, try_: &mut Try) {
+    let after = self.new_label();
+    let finally = try_.finally.as_mut().map_or(ptr::null_mut(), |f| f as *mut Block);
+    let start = self.new_label();
+    self.label(start);
+    self.try_stack.push(TryState { finally, depth: self.loop_stack.len(), start, ranges: Vec::new() });
+    self.block(&mut try_.body);
+    self.end_range(self.try_stack.len() - 1);
+    let body = self.try_stack.pop().unwrap().ranges;
+    self.inline_finally(finally);
+    self.goto(after);
+    let mut ranges = Vec::new();
+    let mut handlers = Vec::new();
+    for catch in &mut try_.catch {
+      let handler = self.new_label();
+      self.label(handler);
+      handlers.push((handler, java_name(catch.def.type_.get_class())));
+      self.try_stack.push(TryState { finally, depth: self.loop_stack.len(), start: handler, ranges: Vec::new() });
+      self.var_def(&mut catch.def);
+      self.a_store(catch.def.jvm_index);
+      self.block(&mut catch.body);
+      self.end_range(self.try_stack.len() - 1);
+      ranges.extend(self.try_stack.pop().unwrap().ranges);
+      self.inline_finally(finally);
+      self.goto(after);
+    }
+    // entries of inner trys are already added, so they are looked up first
+    for &(handler, class) in &handlers {
+      for &(start, end) in &body { self.try_catch(start, end, handler, Some(class)); }
+    }
+    if !finally.is_null() {
+      let handler = self.new_label();
+      for &(start, end) in body.iter().chain(ranges.iter()) { self.try_catch(start, end, handler, None); }
+      self.label(handler);
+      let tmp = self.new_local();
+      self.a_store(tmp);
+      self.inline_finally(finally);
+      self.a_load(tmp);
+      self.a_throw();
+    }
+    self.label(after);
+  }
+
+  fn inline_finally(&mut self, finally: *mut Block) {
+    if !finally.is_null() { self.block(finally.get()); }
+  }
+
+  // the index in try_stack of the outermost try inside the loop at `loop_index`
+  fn first_try_in(&self, loop_index: usize) -> usize {
+    self.try_stack.iter().position(|t| t.depth > loop_index).unwrap_or(self.try_stack.len())
+  }
+
+  fn end_range(&mut self, index: usize) {
+    let end = self.new_label();
+    self.label(end);
+    let t = &mut self.try_stack[index];
+    t.ranges.push((t.start, end));
+  }
+
+  // before jumping out of try_stack[first..], run their finally blocks from the innermost one
+  // the inlined finally of a try is only protected by the trys outside it
+  fn leave_try(&mut self, first: usize) {
+    if self.try_stack[first..].iter().all(|t| t.finally.is_null()) { return; }
+    for i in (first..self.try_stack.len()).rev() {
+      self.end_range(i);
+      let finally = self.try_stack[i].finally;
+      if !finally.is_null() {
+        let inner = self.try_stack.split_off(i);
+        self.inline_finally(finally);
+        self.try_stack.extend(inner);
+      }
+    }
+  }
+
+  // the code after the jump is protected again
+  fn reenter_try(&mut self, first: usize) {
+    if self.try_stack[first..].iter().all(|t| t.finally.is_null()) { return; }
+    let start = self.new_label();
+    self.label(start);
+    for t in &mut self.try_stack[first..] { t.start = start; }
+  }
+
   fn foreach(&mut self, foreach: &mut Foreach) {
     // for (it = 0, arr = foreach.arr; it < arr.length; ++it)
     //   x = array[it]
@@ -486,8 +1184,8 @@ impl JvmCodeGen {
     self.expr(&mut foreach.arr);
     self.a_store(arr);
 
-    let (before_cond, after_body) = (self.new_label(), self.new_label());
-    self.break_stack.push(after_body);
+    let (before_cond, before_inc, after_body) = (self.new_label(), self.new_label(), self.new_label());
+    self.loop_stack.push((foreach.label, after_body, before_inc));
     self.label(before_cond);
     // it < arr.length
     self.i_load(it);
@@ -497,8 +1195,8 @@ impl JvmCodeGen {
     // x = arr[i]
     self.a_load(arr);
     self.i_load(it);
-    handle!(&foreach.def.type_.sem, { self.i_a_load(); self.i_store(foreach.def.jvm_index); },
-            { self.b_a_load(); self.i_store(foreach.def.jvm_index); }, { self.a_a_load(); self.a_store(foreach.def.jvm_index); });
+    self.array_load(&foreach.def.type_);
+    self.store_to_stack(&foreach.def.type_, foreach.def.jvm_index);
     // if (!cond) break
     if let Some(cond) = &mut foreach.cond {
       self.expr(cond);
@@ -506,10 +1204,40 @@ impl JvmCodeGen {
     }
     self.block(&mut foreach.body);
     // ++it
+    self.label(before_inc);
     self.i_inc(it, 1);
     self.goto(before_cond);
     self.label(after_body);
-    self.break_stack.pop();
+    self.loop_stack.pop();
+  }
+
+  fn switch(&mut self, switch: &mut Switch) {
+    self.expr(&mut switch.cond);
+    let after_switch = self.new_label();
+    let label: Vec<u16> = switch.case.iter().map(|_| self.new_label()).collect();
+    let default = switch.case.iter().position(|case| case.value.is_none()).map_or(after_switch, |i| label[i]);
+    let mut table: Vec<(i32, u16)> = switch.case.iter().zip(label.iter())
+      .filter_map(|(case, &l)| case.value.map(|value| (value, l))).collect();
+    table.sort();
+    // the same cost estimation as javac
+    let n = table.len() as i64;
+    let (low, high) = (table.first().map_or(0, |t| t.0 as i64), table.last().map_or(0, |t| t.0 as i64));
+    if n > 0 && (4 + high - low + 1) + 3 * 3 <= (3 + 2 * n) + 3 * n {
+      let mut jump = vec![default; (high - low + 1) as usize];
+      for &(value, l) in &table { jump[(value as i64 - low) as usize] = l; }
+      self.table_switch(low as i32, default, &jump);
+    } else {
+      self.lookup_switch(default, &table);
+    }
+    // 'continue' in a switch goes to the enclosing loop
+    let next_iter = self.loop_stack.last().map_or(after_switch, |l| l.2);
+    self.loop_stack.push((None, after_switch, next_iter));
+    for (case, &l) in switch.case.iter_mut().zip(label.iter()) {
+      self.label(l);
+      self.block(&mut case.body);
+    }
+    self.loop_stack.pop();
+    self.label(after_switch);
   }
 
   fn assign(&mut self, assign: &mut Assign) {
@@ -518,18 +1246,57 @@ impl JvmCodeGen {
       ExprData::Id(id) => id.for_assign = true,
       _ => unreachable!(),
     }
-    self.expr(&mut assign.dst);
-    self.expr(&mut assign.src);
+    if let Some(op) = assign.op {
+      // 'x += c' for local x and small constant c can be done in place by iinc
+      if let (ExprData::Id(id), ExprData::IntConst(v)) = (&assign.dst.data, &assign.src.data) {
+        let var_def = id.symbol.get();
+        let v = if op == Operator::Sub { v.wrapping_neg() } else { *v };
+        match var_def.scope.get().kind {
+          ScopeKind::Local(_) | ScopeKind::Parameter(_) if (op == Operator::Add || op == Operator::Sub) && v as i8 as i32 == v => {
+            self.i_inc(var_def.jvm_index, v as u8);
+            return;
+          }
+          _ => {}
+        }
+      }
+      self.expr(&mut assign.dst);
+      // load the old value, reusing the ref / arr idx already on the stack
+      match &assign.dst.data {
+        ExprData::Id(id) => {
+          let var_def = id.symbol.get();
+          match var_def.scope.get().kind {
+            ScopeKind::Local(_) | ScopeKind::Parameter(_) => self.load_from_stack(&var_def.type_, var_def.jvm_index), // x
+            ScopeKind::Class(class) if var_def.static_ => self.get_static(class.get().name, var_def.name, &var_def.type_.to_java()), // x
+            ScopeKind::Class(class) => {
+              self.dup(); // ref ref
+              self.get_field(class.get().name, var_def.name, &var_def.type_.to_java()); // ref x
+            }
+            _ => unreachable!(),
+          }
+        }
+        ExprData::Indexed(_) => {
+          self.dup_2(); // arr idx arr idx
+          self.array_load(&assign.dst.type_); // arr idx x
+        }
+        _ => unreachable!(),
+      }
+      self.expr(&mut assign.src);
+      if assign.dst.type_ == STRING { self.string_concat(); } else { self.arith(op, &assign.dst.type_); }
+    } else {
+      self.expr(&mut assign.dst);
+      self.expr(&mut assign.src);
+    }
     match &assign.dst.data {
       ExprData::Id(id) => {
         let var_def = id.symbol.get();
         match var_def.scope.get().kind {
           ScopeKind::Local(_) | ScopeKind::Parameter(_) => self.store_to_stack(&var_def.type_, var_def.jvm_index),
+          ScopeKind::Class(class) if var_def.static_ => self.put_static(class.get().name, var_def.name, &var_def.type_.to_java()),
           ScopeKind::Class(class) => self.put_field(class.get().name, var_def.name, &var_def.type_.to_java()),
           _ => unreachable!(),
         }
       }
-      ExprData::Indexed(_) => handle!(assign.dst.type_, self.i_a_store(), self.b_a_store(), self.a_a_store()),
+      ExprData::Indexed(_) => self.array_store(&assign.dst.type_),
       _ => unreachable!(),
     }
   }
@@ -539,7 +1306,7 @@ impl JvmCodeGen {
     match unary.op {
       Neg => {
         self.expr(&mut unary.r);
-        self.i_neg();
+        handle!(unary.r.type_, self.i_neg(), self.l_neg(), self.d_neg(), unreachable!());
       }
       Not => {
         let (out, true_) = (self.new_label(), self.new_label());
@@ -564,7 +1331,7 @@ impl JvmCodeGen {
     match binary.op {
       Repeat => {
         let (before, after) = (self.new_label(), self.new_label());
-        let (val, it, arr) = (self.new_local(), self.new_local(), self.new_local());
+        let (val, it, arr) = (self.new_local_of(&binary.l.type_), self.new_local(), self.new_local());
         self.expr(&mut binary.l);
         let val_t = &binary.l.type_;
         self.store_to_stack(&binary.l.type_, val);
@@ -581,7 +1348,7 @@ impl JvmCodeGen {
         self.a_load(arr);
         self.i_load(it);
         self.load_from_stack(val_t, val);
-        handle!(val_t, self.i_a_store(), self.b_a_store(), self.a_a_store());
+        self.array_store(val_t);
         self.i_inc(it, 1);
         self.goto(before);
         self.label(after);
@@ -614,27 +1381,67 @@ impl JvmCodeGen {
       _ => {
         self.expr(&mut binary.l);
         self.expr(&mut binary.r);
+        if binary.l.type_ == STRING {
+          match binary.op {
+            Add => self.string_concat(),
+            Eq => self.string_equal(),
+            Ne => {
+              self.string_equal();
+              cmp!(self, if_eq);
+            }
+            _ => {
+              // compare the result of compareTo with 0 instead
+              self.invoke_virtual("java/lang/String", "compareTo", &[JavaType::Class("java/lang/String")], &JavaType::Int);
+              match binary.op {
+                Le => cmp!(self, if_le),
+                Lt => cmp!(self, if_lt),
+                Ge => cmp!(self, if_ge),
+                Gt => cmp!(self, if_gt),
+                _ => unreachable!(),
+              }
+            }
+          }
+          return;
+        }
+        if binary.l.type_ == LONG {
+          match binary.op {
+            Add | Sub | Mul | Div | Mod | BAnd | BOr | BXor | Shl | Shr => self.arith(binary.op, &LONG),
+            Le => { self.l_cmp(); cmp!(self, if_le); }
+            Lt => { self.l_cmp(); cmp!(self, if_lt); }
+            Ge => { self.l_cmp(); cmp!(self, if_ge); }
+            Gt => { self.l_cmp(); cmp!(self, if_gt); }
+            Eq => { self.l_cmp(); cmp!(self, if_eq); }
+            Ne => { self.l_cmp(); cmp!(self, if_ne); }
+            _ => unreachable!(),
+          }
+          return;
+        }
+        if binary.l.type_ == DOUBLE {
+          match binary.op {
+            Add | Sub | Mul | Div | Mod => self.arith(binary.op, &DOUBLE),
+            // dcmpg / dcmpl makes a comparison with NaN false
+            Le => { self.d_cmp_g(); cmp!(self, if_le); }
+            Lt => { self.d_cmp_g(); cmp!(self, if_lt); }
+            Ge => { self.d_cmp_l(); cmp!(self, if_ge); }
+            Gt => { self.d_cmp_l(); cmp!(self, if_gt); }
+            Eq => { self.d_cmp_l(); cmp!(self, if_eq); }
+            Ne => { self.d_cmp_l(); cmp!(self, if_ne); }
+            _ => unreachable!(),
+          }
+          return;
+        }
         match binary.op {
-          Add => self.i_add(),
-          Sub => self.i_sub(),
-          Mul => self.i_mul(),
-          Div => self.i_div(),
-          Mod => self.i_rem(),
-          BAnd => self.i_and(),
-          BOr => self.i_or(),
-          BXor => self.i_xor(),
-          Shl => self.i_shl(),
-          Shr => self.i_u_shr(),
+          Add | Sub | Mul | Div | Mod | BAnd | BOr | BXor | Shl | Shr => self.arith(binary.op, &binary.l.type_),
           Le => cmp!(self, if_i_cmp_le),
           Lt => cmp!(self, if_i_cmp_lt),
           Ge => cmp!(self, if_i_cmp_ge),
           Gt => cmp!(self, if_i_cmp_gt),
           Eq => match binary.l.type_ {
-            SemanticType::Int | SemanticType::Bool => cmp!(self, if_i_cmp_eq),
+            SemanticType::Int | SemanticType::Bool | SemanticType::Char => cmp!(self, if_i_cmp_eq),
             _ => cmp!(self, if_a_cmp_eq),
           }
           Ne => match binary.l.type_ {
-            SemanticType::Int | SemanticType::Bool => cmp!(self, if_i_cmp_ne),
+            SemanticType::Int | SemanticType::Bool | SemanticType::Char => cmp!(self, if_i_cmp_ne),
             _ => cmp!(self, if_a_cmp_ne),
           }
           _ => unreachable!(),
@@ -643,10 +1450,66 @@ impl JvmCodeGen {
     }
   }
 
+  fn string_concat(&mut self) {
+    self.invoke_virtual("java/lang/String", "concat", &[JavaType::Class("java/lang/String")], &JavaType::Class("java/lang/String"));
+  }
+
+  // null-safe, unlike String.equals
+  fn string_equal(&mut self) {
+    let object = JavaType::Class("java/lang/Object");
+    self.invoke_static("java/util/Objects", "equals", &[object.clone(), object], &JavaType::Boolean);
+  }
+
+  fn arith(&mut self, op: Operator, t: &SemanticType) {
+    use super::ast::Operator::*;
+    if t == &LONG {
+      // the shift amount is also widened to long, but lshl takes an int
+      if op == Shl || op == Shr { self.l_2_i(); }
+      match op {
+        Add => self.l_add(),
+        Sub => self.l_sub(),
+        Mul => self.l_mul(),
+        Div => self.l_div(),
+        Mod => self.l_rem(),
+        BAnd => self.l_and(),
+        BOr => self.l_or(),
+        BXor => self.l_xor(),
+        Shl => self.l_shl(),
+        Shr => self.l_u_shr(),
+        _ => unreachable!(),
+      }
+      return;
+    }
+    if t == &DOUBLE {
+      match op {
+        Add => self.d_add(),
+        Sub => self.d_sub(),
+        Mul => self.d_mul(),
+        Div => self.d_div(),
+        Mod => self.d_rem(),
+        _ => unreachable!(),
+      }
+      return;
+    }
+    match op {
+      Add => self.i_add(),
+      Sub => self.i_sub(),
+      Mul => self.i_mul(),
+      Div => self.i_div(),
+      Mod => self.i_rem(),
+      BAnd => self.i_and(),
+      BOr => self.i_or(),
+      BXor => self.i_xor(),
+      Shl => self.i_shl(),
+      Shr => self.i_u_shr(),
+      _ => unreachable!(),
+    }
+  }
+
   fn indexed(&mut self, indexed: &mut Indexed, expr_type: &SemanticType) {
     self.expr(&mut indexed.arr);
     self.expr(&mut indexed.idx);
-    if !indexed.for_assign { handle!(expr_type, self.i_a_load(), self.b_a_load(), self.a_a_load()); }
+    if !indexed.for_assign { self.array_load(expr_type); }
   }
 
   fn default(&mut self, default: &mut Default) {
@@ -664,7 +1527,7 @@ impl JvmCodeGen {
     self.dup();
     self.a_load(arr);
     self.swap();
-    handle!(&default.dft.type_, self.i_a_load(), self.b_a_load(), self.a_a_load());
+    self.array_load(&default.dft.type_);
     self.goto(after);
     self.label(dft);
     self.expr(&mut default.dft);
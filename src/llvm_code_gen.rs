@@ -3,6 +3,7 @@ use super::types::*;
 use super::symbol::*;
 use super::util::*;
 use super::config::*;
+use super::loc::*;
 use super::tac_code_gen::resolve_field_order;
 
 use llvm_sys::*;
@@ -12,7 +13,9 @@ use llvm_sys::transforms::pass_manager_builder::*;
 
 use std::ffi::CString;
 use std::ptr;
+use std::iter;
 use std::collections::HashMap;
+use std::default::Default as D;
 
 macro_rules! cstr {
   ($s: expr) => { concat!($s, "\0") as *const str as *const [libc::c_char] as *const libc::c_char }
@@ -34,18 +37,32 @@ pub struct LLVMCodeGen {
   void_t: LLVMTypeRef,
   str_t: LLVMTypeRef,
   i64_t: LLVMTypeRef,
+  double_t: LLVMTypeRef,
   i32_0: LLVMValueRef,
   // c std library functions
   malloc: LLVMValueRef,
   printf: LLVMValueRef,
   scanf: LLVMValueRef,
   strcmp: LLVMValueRef,
+  strlen: LLVMValueRef,
   memset: LLVMValueRef,
   memcpy: LLVMValueRef,
   exit: LLVMValueRef,
   string_pool: HashMap<String, LLVMValueRef>,
-  break_stack: Vec<LLVMBasicBlockRef>,
+  loop_stack: Vec<(Option<&'static str>, LLVMBasicBlockRef, LLVMBasicBlockRef)>,
   cur_method: *const MethodDef,
+  // see `Program::has_try`, if so this global holds the thrown exception, and is checked after each call
+  pending: LLVMValueRef,
+  builtin: HashMap<&'static str, *const ClassDef>,
+  // the bb an exception goes to in the current code, null for leaving the method
+  handler: LLVMBasicBlockRef,
+  // the bb where an exception leaves the method, null if not used yet
+  propagate: LLVMBasicBlockRef,
+  // trys being generated, (finally block, length of loop_stack when entering it, handler outside it)
+  try_stack: Vec<(*mut Block, usize, LLVMBasicBlockRef)>,
+  no_asserts: bool,
+  // lambdas created in the current method, their bodies are generated after it
+  lambdas: Vec<*mut Lambda>,
 }
 
 impl LLVMCodeGen {
@@ -60,15 +77,18 @@ impl LLVMCodeGen {
       let void_t = LLVMVoidTypeInContext(context);
       let str_t = ptr_of(i8_t);
       let i64_t = LLVMInt64TypeInContext(context);
+      let double_t = LLVMDoubleTypeInContext(context);
       let i32_0 = LLVMConstInt(i32_t, 0, 0);
       let malloc = LLVMAddFunction(module, cstr!("malloc"), LLVMFunctionType(str_t, [i64_t].as_mut_ptr(), 1, 0));
       let printf = LLVMAddFunction(module, cstr!("printf"), LLVMFunctionType(i32_t, [str_t].as_mut_ptr(), 1, 1));
       let scanf = LLVMAddFunction(module, cstr!("scanf"), LLVMFunctionType(i32_t, [str_t].as_mut_ptr(), 1, 1));
       let strcmp = LLVMAddFunction(module, cstr!("strcmp"), LLVMFunctionType(i32_t, [str_t, str_t].as_mut_ptr(), 2, 0));
+      let strlen = LLVMAddFunction(module, cstr!("strlen"), LLVMFunctionType(i64_t, [str_t].as_mut_ptr(), 1, 0));
       let memset = LLVMAddFunction(module, cstr!("memset"), LLVMFunctionType(str_t, [str_t, i32_t, i64_t].as_mut_ptr(), 3, 0));
       let memcpy = LLVMAddFunction(module, cstr!("memcpy"), LLVMFunctionType(str_t, [str_t, str_t, i64_t].as_mut_ptr(), 3, 0));
       let exit = LLVMAddFunction(module, cstr!("exit"), LLVMFunctionType(void_t, [i32_t].as_mut_ptr(), 1, 0));
-      let mut code_gen = LLVMCodeGen { context, module, builder, i1_t, i32_t, i8_t, void_t, str_t, i64_t, i32_0, malloc, printf, scanf, strcmp, memset, memcpy, exit, string_pool: HashMap::new(), break_stack: Vec::new(), cur_method: ptr::null_mut() };
+      let mut code_gen = LLVMCodeGen { context, module, builder, i1_t, i32_t, i8_t, void_t, str_t, i64_t, double_t, i32_0, malloc, printf, scanf, strcmp, strlen, memset, memcpy, exit, string_pool: HashMap::new(), loop_stack: Vec::new(), cur_method: ptr::null_mut(),
+        pending: ptr::null_mut(), builtin: HashMap::new(), handler: ptr::null_mut(), propagate: ptr::null_mut(), try_stack: Vec::new(), no_asserts: program.no_asserts, lambdas: Vec::new() };
       code_gen.program(&mut program);
       LLVMDisposeBuilder(builder);
       // optimize
@@ -133,6 +153,18 @@ impl LLVMCodeGen {
     LLVMBuildLoad(builder, ret, T)
   }
 
+  // an object implements the interface iff its exact class is one of the implementors
+  unsafe fn implements(&self, object: LLVMValueRef, interface: &ClassDef) -> LLVMValueRef {
+    let builder = self.builder;
+    let v_tbl = LLVMBuildLoad(builder, LLVMBuildBitCast(builder, object, ptr_of(self.str_t), T), T);
+    let mut ret = LLVMConstInt(self.i1_t, 0, 0);
+    for &class in &interface.implementors {
+      let eq = LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntEQ, v_tbl, self.to_i8_ptr(class.get().llvm_v_tbl), T);
+      ret = LLVMBuildOr(builder, ret, eq, T);
+    }
+    ret
+  }
+
   unsafe fn alloc_array(&self, len: LLVMValueRef, elem_t: LLVMTypeRef) -> LLVMValueRef {
     let builder = self.builder;
     let tot_len = LLVMBuildAdd(builder, LLVMBuildMul(builder, LLVMBuildIntCast(builder, len, self.i64_t, T), LLVMSizeOf(elem_t), T), LLVMSizeOf(self.i32_t), T);
@@ -142,6 +174,155 @@ impl LLVMCodeGen {
     LLVMBuildBitCast(builder, arr, ptr_of(elem_t), T)
   }
 
+  // lens[0] is the length of this array, its elements are arrays of lens[1..] if there are more
+  unsafe fn new_array(&mut self, elem_t: &SemanticType, lens: &[LLVMValueRef], counters: &[LLVMValueRef]) -> LLVMValueRef {
+    let builder = self.builder;
+    let (len, llvm_elem_t) = (lens[0], self.type_of(elem_t));
+    let arr = self.alloc_array(len, llvm_elem_t);
+    LLVMBuildCall(builder, self.memset, [LLVMBuildBitCast(builder, arr, self.str_t, T), self.i32_0, LLVMBuildMul(builder, LLVMBuildIntCast(builder, len, self.i64_t, T), LLVMSizeOf(llvm_elem_t), T)].as_mut_ptr(), 3, T);
+    if lens.len() > 1 {
+      let inner_t = if let SemanticType::Array(inner_t) = elem_t { inner_t.as_ref() } else { unreachable!() };
+      let i = counters[0];
+      LLVMBuildStore(builder, self.i32_0, i);
+      let (before_cond, before_body, after_body) = (self.new_bb(), self.new_bb(), self.new_bb());
+      LLVMBuildBr(builder, before_cond);
+      self.label(before_cond);
+      let i_load = LLVMBuildLoad(builder, i, T);
+      LLVMBuildCondBr(builder, LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSLT, i_load, len, T), before_body, after_body);
+      self.label(before_body);
+      let inner = self.new_array(inner_t, &lens[1..], &counters[1..]);
+      LLVMBuildStore(builder, inner, LLVMBuildGEP(builder, arr, [i_load].as_mut_ptr(), 1, T));
+      LLVMBuildStore(builder, LLVMBuildAdd(builder, i_load, LLVMConstInt(self.i32_t, 1, 0), T), i);
+      LLVMBuildBr(builder, before_cond);
+      self.label(after_body);
+    }
+    arr
+  }
+
+  // print `msg` and exit if `ok` is false
+  unsafe fn check(&mut self, ok: LLVMValueRef, class: &'static str, msg: &str) {
+    let (on_err, after) = (self.new_bb(), self.new_bb());
+    LLVMBuildCondBr(self.builder, ok, after, on_err);
+    self.label(on_err);
+    self.runtime_error(class, msg);
+    self.label(after);
+  }
+
+  // print `msg` and exit, or raise a built-in exception carrying it if the program has any try
+  unsafe fn runtime_error(&mut self, class: &'static str, msg: &str) {
+    if self.pending.is_null() {
+      LLVMBuildCall(self.builder, self.printf, [self.define_str(msg)].as_mut_ptr(), 1, T);
+      self.exit();
+    } else {
+      let msg = self.define_str(msg);
+      self.raise_builtin(class, msg);
+    }
+  }
+
+  unsafe fn raise_builtin(&mut self, class: &'static str, msg: LLVMValueRef) {
+    let e = self.new_object(self.builtin[class].get());
+    // `_message` is the only field of Exception, the root of all exceptions
+    LLVMBuildStore(self.builder, msg, LLVMBuildStructGEP(self.builder, e, 1, T));
+    self.raise(e);
+  }
+
+  unsafe fn raise(&mut self, e: LLVMValueRef) {
+    LLVMBuildStore(self.builder, self.to_i8_ptr(e), self.pending);
+    let handler = self.handler_bb();
+    LLVMBuildBr(self.builder, handler);
+  }
+
+  unsafe fn handler_bb(&mut self) -> LLVMBasicBlockRef {
+    if !self.handler.is_null() { return self.handler; }
+    // not appended, the current bb should still be the last one
+    if self.propagate.is_null() { self.propagate = LLVMInsertBasicBlockInContext(self.context, LLVMGetInsertBlock(self.builder), T); }
+    self.propagate
+  }
+
+  // the callee may have left an exception pending
+  unsafe fn check_exception(&mut self) {
+    if self.pending.is_null() { return; }
+    let (handler, after) = (self.handler_bb(), self.new_bb());
+    let e = LLVMBuildLoad(self.builder, self.pending, T);
+    LLVMBuildCondBr(self.builder, LLVMBuildIsNull(self.builder, e, T), after, handler);
+    self.label(after);
+  }
+
+  // print the message of a runtime error, or the class name of others, and exit
+  unsafe fn uncaught(&mut self, e: LLVMValueRef) {
+    let builder = self.builder;
+    let (on_msg, on_class) = (self.new_bb(), self.new_bb());
+    // both the v-table and the message are pointers, so view the object as an array of i8*
+    let e = LLVMBuildBitCast(builder, e, ptr_of(self.str_t), T);
+    let msg = LLVMBuildLoad(builder, LLVMBuildGEP(builder, e, [LLVMConstInt(self.i32_t, 1, 0)].as_mut_ptr(), 1, T), T);
+    LLVMBuildCondBr(builder, LLVMBuildIsNull(builder, msg, T), on_class, on_msg);
+    self.label(on_msg);
+    LLVMBuildCall(builder, self.printf, [self.define_str("%s"), msg].as_mut_ptr(), 2, T);
+    self.exit();
+    self.label(on_class);
+    let v_tbl = LLVMBuildLoad(builder, LLVMBuildBitCast(builder, e, ptr_of(ptr_of(self.str_t)), T), T);
+    let name = LLVMBuildLoad(builder, LLVMBuildGEP(builder, v_tbl, [LLVMConstInt(self.i32_t, 1, 0)].as_mut_ptr(), 1, T), T);
+    LLVMBuildCall(builder, self.printf, [self.define_str(UNCAUGHT), name].as_mut_ptr(), 2, T);
+    self.exit();
+  }
+
+  unsafe fn new_object(&self, class: &ClassDef) -> LLVMValueRef {
+    let builder = self.builder;
+    let obj = LLVMBuildMalloc(builder, class.llvm_t, T);
+    LLVMBuildCall(builder, self.memset, [self.to_i8_ptr(obj), self.i32_0, LLVMSizeOf(class.llvm_t)].as_mut_ptr(), 3, T);
+    LLVMBuildStore(builder, class.llvm_v_tbl, LLVMBuildStructGEP(builder, obj, 0, T));
+    obj
+  }
+
+  unsafe fn string_concat(&self, l: LLVMValueRef, r: LLVMValueRef) -> LLVMValueRef {
+    let builder = self.builder;
+    let l_len = LLVMBuildCall(builder, self.strlen, [l].as_mut_ptr(), 1, T);
+    let r_len = LLVMBuildCall(builder, self.strlen, [r].as_mut_ptr(), 1, T);
+    let r_len = LLVMBuildAdd(builder, r_len, LLVMConstInt(self.i64_t, 1, 0), T); // including '\0'
+    let s = LLVMBuildCall(builder, self.malloc, [LLVMBuildAdd(builder, l_len, r_len, T)].as_mut_ptr(), 1, T);
+    LLVMBuildCall(builder, self.memcpy, [s, l, l_len].as_mut_ptr(), 3, T);
+    LLVMBuildCall(builder, self.memcpy, [LLVMBuildGEP(builder, s, [l_len].as_mut_ptr(), 1, T), r, r_len].as_mut_ptr(), 3, T);
+    s
+  }
+
+  // fptosi is undefined out of range, so saturate like the jvm does, and NaN becomes 0
+  unsafe fn double_to_int(&self, src: LLVMValueRef, long: bool) -> LLVMValueRef {
+    let builder = self.builder;
+    let (int_t, min, max) = if long { (self.i64_t, i64::min_value(), i64::max_value()) } else { (self.i32_t, i32::min_value() as i64, i32::max_value() as i64) };
+    // max + 1 is a power of 2, which is exact as a double
+    let too_big = LLVMBuildFCmp(builder, LLVMRealPredicate::LLVMRealOGE, src, LLVMConstReal(self.double_t, -(min as f64)), T);
+    let too_small = LLVMBuildFCmp(builder, LLVMRealPredicate::LLVMRealOLT, src, LLVMConstReal(self.double_t, min as f64), T);
+    let nan = LLVMBuildFCmp(builder, LLVMRealPredicate::LLVMRealUNO, src, src, T);
+    let ret = LLVMBuildFPToSI(builder, src, int_t, T);
+    let ret = LLVMBuildSelect(builder, too_big, LLVMConstInt(int_t, max as u64, 1), ret, T);
+    let ret = LLVMBuildSelect(builder, too_small, LLVMConstInt(int_t, min as u64, 1), ret, T);
+    LLVMBuildSelect(builder, nan, LLVMConstInt(int_t, 0, 0), ret, T)
+  }
+
+  unsafe fn string_call(&mut self, method: StringMethod, s: LLVMValueRef, arg: &[LLVMValueRef]) -> LLVMValueRef {
+    let builder = self.builder;
+    let len = LLVMBuildIntCast(builder, LLVMBuildCall(builder, self.strlen, [s].as_mut_ptr(), 1, T), self.i32_t, T);
+    let le = |l, r| LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSLE, l, r, T);
+    match method {
+      StringMethod::Length => len,
+      StringMethod::CharAt => {
+        let ok = LLVMBuildAnd(builder, le(self.i32_0, arg[0]), LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSLT, arg[0], len, T), T);
+        self.check(ok, INDEX_OUT_OF_BOUNDS_EXCEPTION, STRING_INDEX_OUT_OF_BOUND);
+        LLVMBuildLoad(builder, LLVMBuildGEP(builder, s, [arg[0]].as_mut_ptr(), 1, T), T)
+      }
+      StringMethod::Substring => {
+        let (begin, end) = (arg[0], arg[1]);
+        let ok = LLVMBuildAnd(builder, LLVMBuildAnd(builder, le(self.i32_0, begin), le(begin, end), T), le(end, len), T);
+        self.check(ok, INDEX_OUT_OF_BOUNDS_EXCEPTION, STRING_INDEX_OUT_OF_BOUND);
+        let sub_len = LLVMBuildIntCast(builder, LLVMBuildSub(builder, end, begin, T), self.i64_t, T);
+        let sub = LLVMBuildCall(builder, self.malloc, [LLVMBuildAdd(builder, sub_len, LLVMConstInt(self.i64_t, 1, 0), T)].as_mut_ptr(), 1, T);
+        LLVMBuildCall(builder, self.memcpy, [sub, LLVMBuildGEP(builder, s, [begin].as_mut_ptr(), 1, T), sub_len].as_mut_ptr(), 3, T);
+        LLVMBuildStore(builder, LLVMConstInt(self.i8_t, 0, 0), LLVMBuildGEP(builder, sub, [sub_len].as_mut_ptr(), 1, T));
+        sub
+      }
+    }
+  }
+
   unsafe fn exit(&self) {
     LLVMBuildCall(self.builder, self.exit, [self.i32_0].as_mut_ptr(), 1, T);
     LLVMBuildUnreachable(self.builder);
@@ -186,20 +367,35 @@ impl LLVMCodeGen {
     match type_ {
       SemanticType::Int => self.i32_t,
       SemanticType::Bool => self.i1_t,
+      SemanticType::Char => self.i8_t,
+      SemanticType::Long => self.i64_t,
+      SemanticType::Double => self.double_t,
       SemanticType::Void => self.void_t,
       SemanticType::String => self.str_t,
-      SemanticType::Object(class) => ptr_of(class.get().llvm_t),
+      SemanticType::Object(class, _) => ptr_of(class.get().llvm_t),
+      // erased to its bound, or i8* if unbounded
+      SemanticType::Param(_) => match type_.bound() { Some(bound) => self.type_of(&bound), None => self.str_t },
       SemanticType::Array(elem) => ptr_of(self.type_of(elem)),
+      // the env of a lambda, see `Lambda::llvm_env_t`
+      SemanticType::Func(_, _) => self.str_t,
       _ => unreachable!(),
     }
   }
 
-  // declare & define struct type; declare v table type & val
+  // define struct type; declare v table type & val
+  // the struct type is already created, so that the fields and methods of a class before it can use it
   unsafe fn make_struct_type(&mut self, class: &mut ClassDef) {
-    if !class.llvm_t.is_null() { return; }
+    if LLVMIsOpaqueStruct(class.llvm_t) == 0 { return; }
     if !class.p_ptr.is_null() { self.make_struct_type(class.p_ptr.get()); }
     // determine class field
-    class.llvm_t = LLVMStructCreateNamed(self.context, cstring!(class.name));
+    if class.interface {
+      // all that is known about an interface object is that it starts with its v-table
+      LLVMStructSetBody(class.llvm_t, [self.str_t].as_mut_ptr(), 1, 0);
+      for field in &mut class.field {
+        if let FieldDef::MethodDef(method) = field { self.declare_method(method); }
+      }
+      return;
+    }
     class.llvm_v_tbl_t = LLVMStructCreateNamed(self.context, cstring!(format!("{}_VTableT", class.name)));
     let mut elem_t;
     if class.p_ptr.is_null() {
@@ -212,12 +408,17 @@ impl LLVMCodeGen {
     }
     for field in &mut class.field {
       match field {
-        FieldDef::VarDef(var) => elem_t.push(self.type_of(&var.type_)),
-        FieldDef::MethodDef(method) => {
-          let mut param_t = method.param.iter().map(|a| self.type_of(&a.type_)).collect::<Vec<_>>();
-          method.llvm_t = LLVMFunctionType(self.type_of(&method.ret_t), param_t.as_mut_ptr(), param_t.len() as u32, 0);
-          method.llvm_val = LLVMAddFunction(self.module, cstring!(format!("{}_{}", method.class.get().name, method.name)), method.llvm_t);
+        FieldDef::VarDef(var) => if var.static_ {
+          let var_t = self.type_of(&var.type_);
+          var.llvm_val = LLVMAddGlobal(self.module, var_t, cstring!(format!("{}_{}", class.name, var.name)));
+          LLVMSetInitializer(var.llvm_val, LLVMConstNull(var_t));
+        } else {
+          elem_t.push(self.type_of(&var.type_));
+          // keep the field offsets the same as tac, where a long takes 2 slots
+          if var.type_.sem == LONG { elem_t.push(self.i32_t); }
         }
+        FieldDef::MethodDef(method) => self.declare_method(method),
+        FieldDef::Ctor(ctor) => self.declare_method(ctor),
       }
     }
     LLVMStructSetBody(class.llvm_t, elem_t.as_mut_ptr(), elem_t.len() as u32, 0);
@@ -234,16 +435,43 @@ impl LLVMCodeGen {
     LLVMSetInitializer(class.llvm_v_tbl, LLVMConstStruct(v_tbl_elem.as_mut_ptr(), v_tbl_elem.len() as u32, 0));
   }
 
+  unsafe fn declare_method(&self, method: &mut MethodDef) {
+    let mut param_t = method.param.iter().map(|a| self.type_of(&a.type_)).collect::<Vec<_>>();
+    method.llvm_t = LLVMFunctionType(self.type_of(&method.ret_t), param_t.as_mut_ptr(), param_t.len() as u32, 0);
+    let class = method.class.get();
+    // a constructor's name is the class name, which a method may also use
+    let name = if class.ctor == method as *const _ { "<init>".to_owned() } else { method.mangled_name() };
+    method.llvm_val = LLVMAddFunction(self.module, cstring!(format!("{}_{}", class.name, name)), method.llvm_t);
+  }
+
   // return main function
   unsafe fn program(&mut self, program: &mut Program) {
+    for class in &mut program.class {
+      class.llvm_t = LLVMStructCreateNamed(self.context, cstring!(class.name));
+    }
     for class in &mut program.class {
       resolve_field_order(class);
       self.make_struct_type(class);
+      if class.builtin { self.builtin.insert(class.name, class); }
+    }
+    if program.has_try {
+      self.pending = LLVMAddGlobal(self.module, self.str_t, cstr!("pending"));
+      LLVMSetInitializer(self.pending, LLVMConstNull(self.str_t));
     }
     // must visit methods after all v tables are determined
     for class in &mut program.class {
       for field in &mut class.field {
-        if let FieldDef::MethodDef(method) = field { self.method(method); }
+        match field {
+          FieldDef::MethodDef(method) => if class.interface { self.dispatch(method); } else {
+            self.method(method);
+            self.lambda_bodies();
+          }
+          FieldDef::Ctor(ctor) => {
+            self.method(ctor);
+            self.lambda_bodies();
+          }
+          FieldDef::VarDef(_) => {}
+        }
       }
     }
     // add main function
@@ -251,32 +479,137 @@ impl LLVMCodeGen {
     let main = LLVMAddFunction(self.module, cstr!("main"), main_t);
     let bb = LLVMAppendBasicBlockInContext(self.context, main, cstr!("entry"));
     LLVMPositionBuilderAtEnd(self.builder, bb);
+    // static field initializers are evaluated before Main.main, in declaration order, except that enum constants go first
+    // they may need new basic blocks, so let a dummy method stand for main
+    let mut init = MethodDef::new(NO_LOC, "main", Type { loc: NO_LOC, sem: INT }, Vec::new(), true, D::default());
+    init.llvm_t = main_t;
+    init.llvm_val = main;
+    self.cur_method = &init;
+    self.propagate = ptr::null_mut();
+    let mut classes = program.class.iter_mut().collect::<Vec<_>>();
+    classes.sort_by_key(|class| class.enum_.is_none());
+    for class in classes {
+      for field in &mut class.field {
+        if let FieldDef::VarDef(var) = field {
+          if let Some(src) = &mut var.src {
+            self.expr(src);
+            LLVMBuildStore(self.builder, LLVMBuildBitCast(self.builder, src.llvm_val, self.type_of(&var.type_), T), var.llvm_val);
+          }
+        }
+      }
+    }
     LLVMBuildCall(self.builder, LLVMGetNamedFunction(self.module, cstr!("Main_main")), [].as_mut_ptr(), 0, T);
+    self.check_exception();
     LLVMBuildRet(self.builder, self.i32_0);
+    if !self.propagate.is_null() {
+      self.label(self.propagate);
+      let e = LLVMBuildLoad(self.builder, self.pending, T);
+      self.uncaught(e);
+    }
+    self.lambda_bodies();
   }
 
   unsafe fn method(&mut self, method: &mut MethodDef) {
     let (context, builder) = (self.context, self.builder);
     let bb = LLVMAppendBasicBlockInContext(context, method.llvm_val, cstr!("entry"));
     self.cur_method = method;
+    self.propagate = ptr::null_mut();
     self.label(bb);
     for (index, param) in method.param.iter_mut().enumerate() {
       param.llvm_val = LLVMBuildAlloca(builder, self.type_of(&param.type_), T);
       LLVMBuildStore(builder, LLVMGetParam(method.llvm_val, index as u32), param.llvm_val);
     }
+    if method.class.get().ctor == method as *const _ {
+      if let Some(p_ctor) = method.implicit_super() {
+        let this = LLVMGetParam(method.llvm_val, 0);
+        self.call_ctor(this, p_ctor, &mut []);
+      }
+    }
     self.block(&mut method.body);
     // user code forget to return, just add it for him
     if self.cur_bb_unterminated() {
-      match &method.ret_t.sem {
-        SemanticType::Int => LLVMBuildRet(builder, self.i32_0),
-        SemanticType::Bool => LLVMBuildRet(builder, LLVMConstInt(self.i1_t, 0, 0)),
-        SemanticType::Void => LLVMBuildRetVoid(builder),
-        SemanticType::String | SemanticType::Object(_) | SemanticType::Array(_) => LLVMBuildRet(builder, LLVMConstNull(self.type_of(&method.ret_t.sem))),
-        _ => unreachable!(),
-      };
+      self.ret_default(&method.ret_t.sem);
+    }
+    // the caller will find the exception pending, and the return value is not used
+    if !self.propagate.is_null() {
+      self.label(self.propagate);
+      self.ret_default(&method.ret_t.sem);
+    }
+  }
+
+  // a lambda is a function whose 1st parameter is its env, the captured values are copied from it before the body
+  unsafe fn lambda_bodies(&mut self) {
+    let builder = self.builder;
+    while !self.lambdas.is_empty() {
+      let lambda = self.lambdas.remove(0).get();
+      let def = &mut lambda.def;
+      let env_bb = LLVMAppendBasicBlockInContext(self.context, def.llvm_val, cstr!("env"));
+      LLVMPositionBuilderAtEnd(builder, env_bb);
+      let env = LLVMBuildBitCast(builder, LLVMGetParam(def.llvm_val, 0), ptr_of(lambda.llvm_env_t), T);
+      for (index, (_, copy)) in lambda.capture.iter_mut().enumerate() {
+        copy.llvm_val = LLVMBuildAlloca(builder, self.type_of(&copy.type_), T);
+        LLVMBuildStore(builder, LLVMBuildLoad(builder, LLVMBuildStructGEP(builder, env, index as u32 + 1, T), T), copy.llvm_val);
+      }
+      self.method(def);
+      LLVMPositionBuilderAtEnd(builder, env_bb);
+      LLVMBuildBr(builder, LLVMGetNextBasicBlock(env_bb));
     }
   }
 
+  unsafe fn ret_default(&self, ret_t: &SemanticType) {
+    let builder = self.builder;
+    match ret_t {
+      SemanticType::Int => LLVMBuildRet(builder, self.i32_0),
+      SemanticType::Bool => LLVMBuildRet(builder, LLVMConstInt(self.i1_t, 0, 0)),
+      SemanticType::Char => LLVMBuildRet(builder, LLVMConstInt(self.i8_t, 0, 0)),
+      SemanticType::Long => LLVMBuildRet(builder, LLVMConstInt(self.i64_t, 0, 0)),
+      SemanticType::Double => LLVMBuildRet(builder, LLVMConstReal(self.double_t, 0.0)),
+      SemanticType::Void => LLVMBuildRetVoid(builder),
+      SemanticType::String | SemanticType::Object(_, _) | SemanticType::Param(_) | SemanticType::Array(_) | SemanticType::Func(_, _) => LLVMBuildRet(builder, LLVMConstNull(self.type_of(ret_t))),
+      _ => unreachable!(),
+    };
+  }
+
+  // interface method `I_f` searches the implementors for the exact class of 'this'
+  // and calls the method that class uses for `f`
+  unsafe fn dispatch(&mut self, method: &MethodDef) {
+    let builder = self.builder;
+    let bb = LLVMAppendBasicBlockInContext(self.context, method.llvm_val, cstr!("entry"));
+    self.cur_method = method;
+    self.label(bb);
+    let this = LLVMGetParam(method.llvm_val, 0);
+    let v_tbl = LLVMBuildLoad(builder, LLVMBuildBitCast(builder, this, ptr_of(self.str_t), T), T);
+    for &class in &method.class.get().implementors {
+      let class = class.get();
+      let target = class.lookup_impl(method).unwrap().get();
+      let (on_eq, next) = (self.new_bb(), self.new_bb());
+      LLVMBuildCondBr(builder, LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntEQ, v_tbl, self.to_i8_ptr(class.llvm_v_tbl), T), on_eq, next);
+      self.label(on_eq);
+      let mut arg = target.param.iter().enumerate()
+        .map(|(index, p)| LLVMBuildBitCast(builder, LLVMGetParam(method.llvm_val, index as u32), self.type_of(&p.type_), T)).collect::<Vec<_>>();
+      let ret = LLVMBuildCall(builder, target.llvm_val, arg.as_mut_ptr(), arg.len() as u32, T);
+      if method.ret_t.sem == VOID {
+        LLVMBuildRetVoid(builder);
+      } else {
+        LLVMBuildRet(builder, LLVMBuildBitCast(builder, ret, self.type_of(&method.ret_t), T));
+      }
+      self.label(next);
+    }
+    LLVMBuildUnreachable(builder);
+  }
+
+  // `this` is already evaluated, only `arg` need evaluating
+  unsafe fn call_ctor(&mut self, this: LLVMValueRef, ctor: &MethodDef, arg: &mut [Expr]) {
+    let builder = self.builder;
+    let mut arg_val = vec![LLVMBuildBitCast(builder, this, self.type_of(&ctor.param[0].type_), T)];
+    arg_val.extend(arg.iter_mut().zip(ctor.param.iter().skip(1)).map(|(a, p)| {
+      self.expr(a);
+      LLVMBuildBitCast(builder, a.llvm_val, self.type_of(&p.type_), T)
+    }));
+    LLVMBuildCall(builder, ctor.llvm_val, arg_val.as_mut_ptr(), arg_val.len() as u32, T);
+    self.check_exception();
+  }
+
   unsafe fn stmt(&mut self, stmt: &mut Stmt) {
     let builder = self.builder;
     match stmt {
@@ -304,40 +637,68 @@ impl LLVMCodeGen {
         self.expr(&mut while_.cond);
         LLVMBuildCondBr(builder, while_.cond.llvm_val, before_body, after_body);
         self.label(before_body);
-        self.break_stack.push(after_body);
+        self.loop_stack.push((while_.label, after_body, before_cond));
         self.block(&mut while_.body);
-        self.break_stack.pop();
+        self.loop_stack.pop();
+        if self.cur_bb_unterminated() {
+          LLVMBuildBr(builder, before_cond);
+        }
+        self.label(after_body);
+      }
+      Stmt::DoWhile(do_while) => {
+        let (before_body, before_cond, after_body) = (self.new_bb(), self.new_bb(), self.new_bb());
+        LLVMBuildBr(builder, before_body);
+        self.label(before_body);
+        self.loop_stack.push((do_while.label, after_body, before_cond));
+        self.block(&mut do_while.body);
+        self.loop_stack.pop();
         if self.cur_bb_unterminated() {
           LLVMBuildBr(builder, before_cond);
         }
+        self.label(before_cond);
+        self.expr(&mut do_while.cond);
+        LLVMBuildCondBr(builder, do_while.cond.llvm_val, before_body, after_body);
         self.label(after_body);
       }
       Stmt::For(for_) => {
-        let (before_cond, before_body, after_body) = (self.new_bb(), self.new_bb(), self.new_bb());
+        let (before_cond, before_body, before_update, after_body) = (self.new_bb(), self.new_bb(), self.new_bb(), self.new_bb());
         self.simple(&mut for_.init);
         LLVMBuildBr(builder, before_cond);
         self.label(before_cond);
         self.expr(&mut for_.cond);
         LLVMBuildCondBr(builder, for_.cond.llvm_val, before_body, after_body);
         self.label(before_body);
-        self.break_stack.push(after_body);
+        self.loop_stack.push((for_.label, after_body, before_update));
         self.block(&mut for_.body);
-        self.break_stack.pop();
+        self.loop_stack.pop();
         if self.cur_bb_unterminated() {
-          self.simple(&mut for_.update);
-          LLVMBuildBr(builder, before_cond);
+          LLVMBuildBr(builder, before_update);
         }
+        self.label(before_update);
+        self.simple(&mut for_.update);
+        LLVMBuildBr(builder, before_cond);
         self.label(after_body);
       }
       Stmt::Return(return_) => if let Some(expr) = &mut return_.expr {
         self.expr(expr);
-        LLVMBuildRet(builder, LLVMBuildBitCast(builder, expr.llvm_val, LLVMGetReturnType(self.cur_method.get().llvm_t), T));
-      } else { LLVMBuildRetVoid(builder); }
+        // the value is already loaded, so the finally blocks can't change it
+        if self.leave_try(0) {
+          LLVMBuildRet(builder, LLVMBuildBitCast(builder, expr.llvm_val, LLVMGetReturnType(self.cur_method.get().llvm_t), T));
+        }
+      } else if self.leave_try(0) { LLVMBuildRetVoid(builder); }
       Stmt::Print(print) => for print in &mut print.print {
         self.expr(print);
         match &print.type_ {
           SemanticType::Int => LLVMBuildCall(builder, self.printf, [self.define_str("%d"), print.llvm_val].as_mut_ptr(), 2, T),
+          SemanticType::Char => LLVMBuildCall(builder, self.printf, [self.define_str("%c"), LLVMBuildZExt(builder, print.llvm_val, self.i32_t, T)].as_mut_ptr(), 2, T),
           SemanticType::String => LLVMBuildCall(builder, self.printf, [self.define_str("%s"), print.llvm_val].as_mut_ptr(), 2, T),
+          SemanticType::Long => LLVMBuildCall(builder, self.printf, [self.define_str("%lld"), print.llvm_val].as_mut_ptr(), 2, T),
+          SemanticType::Double => {
+            // the sign of a nan is not printed, to be the same as jvm
+            let nan = LLVMBuildFCmp(builder, LLVMRealPredicate::LLVMRealUNO, print.llvm_val, print.llvm_val, T);
+            let fmt = LLVMBuildSelect(builder, nan, self.define_str("nan"), self.define_str("%f"), T);
+            LLVMBuildCall(builder, self.printf, [fmt, print.llvm_val].as_mut_ptr(), 2, T)
+          }
           SemanticType::Bool => {
             let tf = LLVMBuildSelect(builder, print.llvm_val, self.define_str("true"), self.define_str("false"), T);
             LLVMBuildCall(builder, self.printf, [tf].as_mut_ptr(), 1, T)
@@ -345,7 +706,14 @@ impl LLVMCodeGen {
           _ => unreachable!(),
         };
       }
-      Stmt::Break(_) => { LLVMBuildBr(builder, *self.break_stack.last().unwrap()); }
+      Stmt::Break(break_) => {
+        let first = self.first_try_in(loop_index(&self.loop_stack, break_.label, false));
+        if self.leave_try(first) { LLVMBuildBr(builder, loop_target(&self.loop_stack, break_.label).0); }
+      }
+      Stmt::Continue(continue_) => {
+        let first = self.first_try_in(loop_index(&self.loop_stack, continue_.label, true));
+        if self.leave_try(first) { LLVMBuildBr(builder, loop_target(&self.loop_stack, continue_.label).1); }
+      }
       Stmt::SCopy(s_copy) => {
         self.expr(&mut s_copy.src);
         let obj_t = s_copy.src.type_.get_class().llvm_t;
@@ -359,7 +727,7 @@ impl LLVMCodeGen {
         LLVMBuildStore(builder, self.i32_0, i); // REMEMBER TO INITIALIZE
         let one = LLVMConstInt(self.i32_t, 1, 0);
         let len = self.array_length(foreach.arr.llvm_val);
-        let (before_i, before_cond, before_body, after_body) = (self.new_bb(), self.new_bb(), self.new_bb(), self.new_bb());
+        let (before_i, before_cond, before_body, before_inc, after_body) = (self.new_bb(), self.new_bb(), self.new_bb(), self.new_bb(), self.new_bb());
         LLVMBuildBr(builder, before_i);
         self.label(before_i);
         let load_i = LLVMBuildLoad(builder, i, T);
@@ -373,13 +741,15 @@ impl LLVMCodeGen {
           LLVMBuildBr(builder, before_body);
         }
         self.label(before_body);
-        self.break_stack.push(after_body);
+        self.loop_stack.push((foreach.label, after_body, before_inc));
         self.block(&mut foreach.body);
-        self.break_stack.pop();
+        self.loop_stack.pop();
         if self.cur_bb_unterminated() {
-          LLVMBuildStore(builder, LLVMBuildAdd(builder, load_i, one, T), i);
-          LLVMBuildBr(builder, before_i);
+          LLVMBuildBr(builder, before_inc);
         }
+        self.label(before_inc);
+        LLVMBuildStore(builder, LLVMBuildAdd(builder, load_i, one, T), i);
+        LLVMBuildBr(builder, before_i);
         self.label(after_body);
       }
       Stmt::Guarded(guarded) => for (e, b) in &mut guarded.guarded {
@@ -393,10 +763,142 @@ impl LLVMCodeGen {
         }
         self.label(on_false);
       }
+      Stmt::Switch(switch) => {
+        self.expr(&mut switch.cond);
+        let bb: Vec<LLVMBasicBlockRef> = switch.case.iter().map(|_| self.new_bb()).collect();
+        let after_switch = self.new_bb();
+        let default = switch.case.iter().position(|case| case.value.is_none()).map_or(after_switch, |i| bb[i]);
+        let switch_ = LLVMBuildSwitch(builder, switch.cond.llvm_val, default, switch.case.len() as u32);
+        for (case, &bb) in switch.case.iter().zip(bb.iter()) {
+          if let Some(value) = case.value { LLVMAddCase(switch_, LLVMConstInt(self.i32_t, value as u64, 0), bb); }
+        }
+        // 'continue' in a switch goes to the enclosing loop
+        let next_iter = self.loop_stack.last().map_or(after_switch, |l| l.2);
+        self.loop_stack.push((None, after_switch, next_iter));
+        for (i, case) in switch.case.iter_mut().enumerate() {
+          self.label(bb[i]);
+          self.block(&mut case.body);
+          // fall through to the next case
+          if self.cur_bb_unterminated() {
+            LLVMBuildBr(builder, *bb.get(i + 1).unwrap_or(&after_switch));
+          }
+        }
+        self.loop_stack.pop();
+        self.label(after_switch);
+      }
       Stmt::Block(block) => self.block(block),
+      Stmt::SuperCall(super_call) => if !super_call.ctor.is_null() {
+        let this = LLVMGetParam(self.cur_method.get().llvm_val, 0);
+        self.call_ctor(this, super_call.ctor.get(), &mut super_call.arg);
+      }
+      Stmt::Try(try_) => self.try_(try_),
+      Stmt::Throw(throw) => {
+        self.expr(&mut throw.expr);
+        if self.pending.is_null() { self.uncaught(throw.expr.llvm_val); } else { self.raise(throw.expr.llvm_val); }
+      }
+      Stmt::Assert(assert) => if !self.no_asserts {
+        let (fail, ok) = (self.new_bb(), self.new_bb());
+        self.expr(&mut assert.cond);
+        LLVMBuildCondBr(builder, assert.cond.llvm_val, ok, fail);
+        self.label(fail);
+        let prefix = format!("{}{}", ASSERT1, assert.loc);
+        if let Some(msg) = &mut assert.msg {
+          self.expr(msg);
+          let fmt = self.define_str(&format!("{}{}%s{}", prefix, ASSERT2, ASSERT3));
+          LLVMBuildCall(builder, self.printf, [fmt, msg.llvm_val].as_mut_ptr(), 2, T);
+        } else {
+          let fmt = self.define_str(&format!("{}{}", prefix, ASSERT3));
+          LLVMBuildCall(builder, self.printf, [fmt].as_mut_ptr(), 1, T);
+        }
+        self.exit();
+        self.label(ok);
+      }
     }
   }
 
+  // an exception in the body goes to `dispatch`, which tests the catches in order
+  // the finally block is inlined after the body and each catch, and before each return/break/continue out of it
+  // an exception in a catch, or matching no catch goes to `rethrow`: e = pending; pending = null; <finally>; pending = e
+  unsafe fn try_(&mut self, try_: &mut Try) {
+    let builder = self.builder;
+    let outer = self.handler;
+    let finally = try_.finally.as_mut().map_or(ptr::null_mut(), |f| f as *mut Block);
+    let (body, dispatch, after) = (self.new_bb(), self.new_bb(), self.new_bb());
+    let rethrow = if finally.is_null() { outer } else { self.new_bb() };
+    LLVMBuildBr(builder, body);
+    self.label(body);
+    self.handler = dispatch;
+    self.try_stack.push((finally, self.loop_stack.len(), outer));
+    self.block(&mut try_.body);
+    self.try_stack.pop();
+    self.handler = outer;
+    if self.cur_bb_unterminated() && self.inline_finally(finally) { LLVMBuildBr(builder, after); }
+    self.label(dispatch);
+    let e = LLVMBuildLoad(builder, self.pending, T);
+    for catch in &mut try_.catch {
+      let (on_match, next) = (self.new_bb(), self.new_bb());
+      let class = catch.def.type_.get_class();
+      let e = LLVMBuildBitCast(builder, e, ptr_of(class.llvm_t), T);
+      LLVMBuildCondBr(builder, self.instance_of(e, class.llvm_v_tbl), on_match, next);
+      self.label(on_match);
+      LLVMBuildStore(builder, LLVMConstNull(self.str_t), self.pending);
+      catch.def.llvm_val = LLVMBuildAlloca(builder, ptr_of(class.llvm_t), T);
+      LLVMBuildStore(builder, e, catch.def.llvm_val);
+      self.handler = rethrow;
+      self.try_stack.push((finally, self.loop_stack.len(), outer));
+      self.block(&mut catch.body);
+      self.try_stack.pop();
+      self.handler = outer;
+      if self.cur_bb_unterminated() && self.inline_finally(finally) { LLVMBuildBr(builder, after); }
+      self.label(next);
+    }
+    if !finally.is_null() {
+      LLVMBuildBr(builder, rethrow);
+      self.label(rethrow);
+      let e = LLVMBuildLoad(builder, self.pending, T);
+      LLVMBuildStore(builder, LLVMConstNull(self.str_t), self.pending);
+      if self.inline_finally(finally) {
+        LLVMBuildStore(builder, e, self.pending);
+        let handler = self.handler_bb();
+        LLVMBuildBr(builder, handler);
+      }
+    } else {
+      let handler = self.handler_bb();
+      LLVMBuildBr(builder, handler);
+    }
+    self.label(after);
+  }
+
+  // return false if the finally block doesn't complete normally, and no more code should follow
+  unsafe fn inline_finally(&mut self, finally: *mut Block) -> bool {
+    if !finally.is_null() { self.block(finally.get()); }
+    self.cur_bb_unterminated()
+  }
+
+  // the index in try_stack of the outermost try inside the loop at `loop_index`
+  fn first_try_in(&self, loop_index: usize) -> usize {
+    self.try_stack.iter().position(|t| t.1 > loop_index).unwrap_or(self.try_stack.len())
+  }
+
+  // before jumping out of try_stack[first..], run their finally blocks from the innermost one
+  // the inlined finally of a try is only protected by the trys outside it
+  unsafe fn leave_try(&mut self, first: usize) -> bool {
+    let handler = self.handler;
+    let mut complete = true;
+    for i in (first..self.try_stack.len()).rev() {
+      let (finally, _, outer) = self.try_stack[i];
+      if !finally.is_null() {
+        let inner = self.try_stack.split_off(i);
+        self.handler = outer;
+        complete = self.inline_finally(finally);
+        self.try_stack.extend(inner);
+        if !complete { break; }
+      }
+    }
+    self.handler = handler;
+    complete
+  }
+
   // block belongs to bb
   unsafe fn block(&mut self, block: &mut Block) {
     for stmt in &mut block.stmt {
@@ -417,20 +919,31 @@ impl LLVMCodeGen {
         if let ExprData::Id(id) = &mut assign.dst.data { id.for_assign = true; }
         if let ExprData::Indexed(indexed) = &mut assign.dst.data { indexed.for_assign = true; }
         self.expr(&mut assign.dst);
+        // load the old value before evaluating src, the pointer itself is only evaluated once
+        let cur = if assign.op.is_some() { LLVMBuildLoad(builder, assign.dst.llvm_val, T) } else { ptr::null_mut() };
         self.expr(&mut assign.src);
+        let src = match assign.op {
+          Some(_) if assign.dst.type_ == STRING => self.string_concat(cur, assign.src.llvm_val),
+          Some(op) => self.arith(op, &assign.dst.type_, cur, assign.src.llvm_val),
+          None => assign.src.llvm_val,
+        };
         // this is the advantage of pointer, I don't need to care about with form of assign it is, just store value to pointer
-        LLVMBuildStore(builder, LLVMBuildBitCast(builder, assign.src.llvm_val, self.type_of(&assign.dst.type_), T), assign.dst.llvm_val);
+        // the pointee type may be erased, so it is used instead of the type of dst
+        LLVMBuildStore(builder, LLVMBuildBitCast(builder, src, LLVMGetElementType(LLVMTypeOf(assign.dst.llvm_val)), T), assign.dst.llvm_val);
       }
       Simple::VarDef(var_def) => {
         var_def.llvm_val = LLVMBuildAlloca(builder, self.type_of(&var_def.type_), T);
         LLVMBuildStore(builder, if let Some(src) = &mut var_def.src {
           self.expr(src);
-          src.llvm_val
+          LLVMBuildBitCast(builder, src.llvm_val, self.type_of(&var_def.type_), T)
         } else {
           match &var_def.type_.sem {
             SemanticType::Int => self.i32_0,
             SemanticType::Bool => LLVMConstInt(self.i1_t, 0, 0),
-            SemanticType::String | SemanticType::Object(_) | SemanticType::Array(_) => LLVMConstNull(self.type_of(&var_def.type_)),
+            SemanticType::Char => LLVMConstInt(self.i8_t, 0, 0),
+            SemanticType::Long => LLVMConstInt(self.i64_t, 0, 0),
+            SemanticType::Double => LLVMConstReal(self.double_t, 0.0),
+            SemanticType::String | SemanticType::Object(_, _) | SemanticType::Param(_) | SemanticType::Array(_) | SemanticType::Func(_, _) => LLVMConstNull(self.type_of(&var_def.type_)),
             _ => unreachable!(),
           }
         }, var_def.llvm_val);
@@ -440,6 +953,45 @@ impl LLVMCodeGen {
     }
   }
 
+  unsafe fn arith(&mut self, op: Operator, t: &SemanticType, l: LLVMValueRef, r: LLVMValueRef) -> LLVMValueRef {
+    use ast::Operator::*;
+    let builder = self.builder;
+    // floating point division by 0 is not an error
+    if t == &DOUBLE {
+      return match op {
+        Add => LLVMBuildFAdd(builder, l, r, T),
+        Sub => LLVMBuildFSub(builder, l, r, T),
+        Mul => LLVMBuildFMul(builder, l, r, T),
+        Div => LLVMBuildFDiv(builder, l, r, T),
+        Mod => LLVMBuildFRem(builder, l, r, T),
+        _ => unreachable!(),
+      };
+    }
+    match op {
+      Add => LLVMBuildAdd(builder, l, r, T),
+      Sub => LLVMBuildSub(builder, l, r, T),
+      Mul => LLVMBuildMul(builder, l, r, T),
+      Div | Mod => {
+        let (on_err, after) = (self.new_bb(), self.new_bb());
+        LLVMBuildCondBr(builder, LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntEQ, r, LLVMConstNull(LLVMTypeOf(r)), T), on_err, after);
+        self.label(on_err);
+        self.runtime_error(ARITHMETIC_EXCEPTION, DIV_0);
+        self.label(after);
+        if op == Div {
+          LLVMBuildSDiv(builder, l, r, T)
+        } else {
+          LLVMBuildSRem(builder, l, r, T)
+        }
+      }
+      BAnd => LLVMBuildAnd(builder, l, r, T),
+      BOr => LLVMBuildOr(builder, l, r, T),
+      BXor => LLVMBuildXor(builder, l, r, T),
+      Shl => LLVMBuildShl(builder, l, r, T),
+      Shr => LLVMBuildLShr(builder, l, r, T),
+      _ => unreachable!(),
+    }
+  }
+
   unsafe fn expr(&mut self, expr: &mut Expr) {
     use ast::ExprData::*;
     let builder = self.builder;
@@ -448,6 +1000,7 @@ impl LLVMCodeGen {
         let var_def = id.symbol.get();
         let ptr = match var_def.scope.get().kind {
           ScopeKind::Local(_) | ScopeKind::Parameter(_) => var_def.llvm_val,
+          ScopeKind::Class(_) if var_def.static_ => var_def.llvm_val,
           ScopeKind::Class(_) => {
             let owner = id.owner.as_mut().unwrap();
             self.expr(owner);
@@ -466,13 +1019,15 @@ impl LLVMCodeGen {
                                               LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSLT, indexed.idx.llvm_val, self.array_length(indexed.arr.llvm_val), T), T)
                         , after, on_err);
         self.label(on_err);
-        LLVMBuildCall(builder, self.printf, [self.define_str(INDEX_OUT_OF_BOUND)].as_mut_ptr(), 1, T);
-        self.exit();
+        self.runtime_error(INDEX_OUT_OF_BOUNDS_EXCEPTION, INDEX_OUT_OF_BOUND);
         self.label(after);
         let ptr = LLVMBuildGEP(builder, indexed.arr.llvm_val, [indexed.idx.llvm_val].as_mut_ptr(), 1, T);
         if indexed.for_assign { ptr } else { LLVMBuildLoad(builder, ptr, T) }
       }
       IntConst(v) => LLVMConstInt(self.i32_t, *v as u64, 0),
+      CharConst(v) => LLVMConstInt(self.i8_t, *v as u64, 0),
+      LongConst(v) => LLVMConstInt(self.i64_t, *v as u64, 1),
+      DoubleConst(v) => LLVMConstReal(self.double_t, *v),
       BoolConst(v) => LLVMConstInt(self.i1_t, if *v { 1 } else { 0 }, 0),
       StringConst(v) => self.define_str(v),
       ArrayConst(_) => unimplemented!(),
@@ -481,9 +1036,17 @@ impl LLVMCodeGen {
         let owner = call.owner.as_mut().unwrap();
         self.expr(owner);
         self.array_length(owner.llvm_val)
+      } else if let Some(method) = call.string_method {
+        let owner = call.owner.as_mut().unwrap();
+        self.expr(owner);
+        let arg = call.arg.iter_mut().map(|a| {
+          self.expr(a);
+          a.llvm_val
+        }).collect::<Vec<_>>();
+        self.string_call(method, owner.llvm_val, &arg)
       } else {
         let method = call.method.get();
-        if method.static_ {
+        let ret = if method.static_ {
           let mut arg = call.arg.iter_mut().zip(method.param.iter()).map(|(a, p)| {
             self.expr(a);
             LLVMBuildBitCast(builder, a.llvm_val, self.type_of(&p.type_), T)
@@ -492,12 +1055,21 @@ impl LLVMCodeGen {
         } else {
           let owner = call.owner.as_mut().unwrap();
           self.expr(owner);
-          let mut arg = vec![owner.llvm_val];
-          arg.extend(call.arg.iter_mut().map(|e| { (self.expr(e), e.llvm_val).1 }));
-          let v_tbl = LLVMBuildLoad(builder, LLVMBuildStructGEP(builder, owner.llvm_val, 0, T), T);
-          let v_fn = LLVMBuildLoad(builder, LLVMBuildStructGEP(builder, v_tbl, method.offset as u32 + 2, T), T);
+          let mut arg = vec![LLVMBuildBitCast(builder, owner.llvm_val, self.type_of(&method.param[0].type_), T)];
+          arg.extend(call.arg.iter_mut().zip(method.param.iter().skip(1)).map(|(a, p)| {
+            self.expr(a);
+            LLVMBuildBitCast(builder, a.llvm_val, self.type_of(&p.type_), T)
+          }));
+          // an interface method is a dispatcher, and a super call is bound statically, both called directly
+          let is_super = if let Super = owner.data { true } else { false };
+          let v_fn = if method.class.get().interface || is_super { method.llvm_val } else {
+            let v_tbl = LLVMBuildLoad(builder, LLVMBuildStructGEP(builder, owner.llvm_val, 0, T), T);
+            LLVMBuildLoad(builder, LLVMBuildStructGEP(builder, v_tbl, method.offset as u32 + 2, T), T)
+          };
           LLVMBuildCall(builder, v_fn, arg.as_mut_ptr(), arg.len() as u32, T)
-        }
+        };
+        self.check_exception();
+        ret
       }
       Unary(unary) => {
         use ast::Operator::*;
@@ -509,6 +1081,7 @@ impl LLVMCodeGen {
         let r = unary.r.llvm_val;
         let one = LLVMConstInt(self.i32_t, 1, 0);
         match unary.op {
+          Neg if unary.r.type_ == DOUBLE => LLVMBuildFNeg(builder, r, T),
           Neg => LLVMBuildNeg(builder, r, T),
           Not => LLVMBuildNot(builder, r, T),
           PreInc => {
@@ -538,29 +1111,28 @@ impl LLVMCodeGen {
         if binary.op != And && binary.op != Or { // they will handle the short-circuit eval
           self.expr(&mut binary.r);
         }
-        let (l, r) = (binary.l.llvm_val, binary.r.llvm_val);
+        let (mut l, mut r) = (binary.l.llvm_val, binary.r.llvm_val);
+        if binary.l.type_ == STRING && (binary.op == Lt || binary.op == Le || binary.op == Gt || binary.op == Ge) {
+          // compare the result of strcmp with 0 instead
+          l = LLVMBuildCall(builder, self.strcmp, [l, r].as_mut_ptr(), 2, T);
+          r = self.i32_0;
+        }
         match binary.op {
-          Add => LLVMBuildAdd(builder, l, r, T),
-          Sub => LLVMBuildSub(builder, l, r, T),
-          Mul => LLVMBuildMul(builder, l, r, T),
-          Div | Mod => {
-            let (on_err, after) = (self.new_bb(), self.new_bb());
-            LLVMBuildCondBr(builder, LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntEQ, r, self.i32_0, T), on_err, after);
-            self.label(on_err);
-            LLVMBuildCall(builder, self.printf, [self.define_str(DIV_0)].as_mut_ptr(), 1, T);
-            self.exit();
-            self.label(after);
-            if binary.op == Div {
-              LLVMBuildSDiv(builder, l, r, T)
-            } else {
-              LLVMBuildSRem(builder, l, r, T)
-            }
-          }
-          BAnd => LLVMBuildAnd(builder, l, r, T),
-          BOr => LLVMBuildOr(builder, l, r, T),
-          BXor => LLVMBuildXor(builder, l, r, T),
-          Shl => LLVMBuildShl(builder, l, r, T),
-          Shr => LLVMBuildLShr(builder, l, r, T),
+          Add if binary.l.type_ == STRING => self.string_concat(l, r),
+          Add | Sub | Mul | Div | Mod | BAnd | BOr | BXor | Shl | Shr => self.arith(binary.op, &binary.l.type_, l, r),
+          Lt | Le | Gt | Ge | Eq | Ne if binary.l.type_ == DOUBLE => LLVMBuildFCmp(builder, match binary.op {
+            Lt => LLVMRealPredicate::LLVMRealOLT,
+            Le => LLVMRealPredicate::LLVMRealOLE,
+            Gt => LLVMRealPredicate::LLVMRealOGT,
+            Ge => LLVMRealPredicate::LLVMRealOGE,
+            Eq => LLVMRealPredicate::LLVMRealOEQ,
+            _ => LLVMRealPredicate::LLVMRealUNE,
+          }, l, r, T),
+          // chars are unsigned
+          Lt if binary.l.type_ == CHAR => LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntULT, l, r, T),
+          Le if binary.l.type_ == CHAR => LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntULE, l, r, T),
+          Gt if binary.l.type_ == CHAR => LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntUGT, l, r, T),
+          Ge if binary.l.type_ == CHAR => LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntUGE, l, r, T),
           Lt => LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSLT, l, r, T),
           Le => LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSLE, l, r, T),
           Gt => LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSGT, l, r, T),
@@ -600,8 +1172,7 @@ impl LLVMCodeGen {
             let (on_err, after) = (self.new_bb(), self.new_bb());
             LLVMBuildCondBr(builder, LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSLT, len, self.i32_0, T), on_err, after);
             self.label(on_err);
-            LLVMBuildCall(builder, self.printf, [self.define_str(REPEAT_NEG)].as_mut_ptr(), 1, T);
-            self.exit();
+            self.runtime_error(NEGATIVE_ARRAY_SIZE_EXCEPTION, REPEAT_NEG);
             self.label(after);
             let arr = self.alloc_array(len, elem_t);
             let i = LLVMBuildAlloca(builder, self.i32_t, T);
@@ -613,8 +1184,8 @@ impl LLVMCodeGen {
             LLVMBuildCondBr(builder, LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSLT, i_load, len, T), before_body, after_body);
             self.label(before_body);
             LLVMBuildStore(builder, match &binary.l.type_ {
-              SemanticType::Int | SemanticType::Bool | SemanticType::String => l,
-              SemanticType::Object(class) => {
+              SemanticType::Int | SemanticType::Bool | SemanticType::Char | SemanticType::Long | SemanticType::Double | SemanticType::String => l,
+              SemanticType::Object(class, _) => {
                 let obj_t = class.get().llvm_t;
                 let obj = LLVMBuildMalloc(builder, obj_t, T);
                 LLVMBuildCall(builder, self.memcpy, [self.to_i8_ptr(obj), self.to_i8_ptr(l), LLVMSizeOf(obj_t)].as_mut_ptr(), 3, T);
@@ -630,7 +1201,7 @@ impl LLVMCodeGen {
           _ => unimplemented!(),
         }
       }
-      This => LLVMGetParam(self.cur_method.get().llvm_val, 0),
+      This | Super => LLVMGetParam(self.cur_method.get().llvm_val, 0),
       ReadInt => {
         let tmp = LLVMBuildAlloca(builder, self.i32_t, T);
         LLVMBuildCall(builder, self.scanf, [self.define_str("%d"), tmp].as_mut_ptr(), 2, T);
@@ -639,44 +1210,74 @@ impl LLVMCodeGen {
       ReadLine => {
         unimplemented!()
       }
-      NewClass { name: _ } => {
-        let obj_t = expr.type_.get_class().llvm_t;
-        let obj = LLVMBuildMalloc(builder, obj_t, T);
-        LLVMBuildCall(builder, self.memset, [self.to_i8_ptr(obj), self.i32_0, LLVMSizeOf(obj_t)].as_mut_ptr(), 3, T);
-        let v_tbl = LLVMBuildStructGEP(builder, obj, 0, T);
-        LLVMBuildStore(builder, expr.type_.get_class().llvm_v_tbl, v_tbl);
+      NewClass { name: _, type_arg: _, arg } => {
+        let obj = self.new_object(expr.type_.get_class());
+        if let Some(ctor) = expr.type_.get_class().lookup_ctor() {
+          self.call_ctor(obj, ctor, arg);
+        }
         obj
       }
-      NewArray { elem_t, len } => {
+      Convert(convert) => {
+        self.expr(&mut convert.expr);
+        let src = convert.expr.llvm_val;
+        match convert.op {
+          // chars are unsigned
+          ConvertOp::CharToInt => LLVMBuildZExt(builder, src, self.i32_t, T),
+          ConvertOp::IntToChar => LLVMBuildTrunc(builder, src, self.i8_t, T),
+          ConvertOp::ToInt | ConvertOp::ToDouble | ConvertOp::ToLong if convert.expr.type_ == expr.type_ => src,
+          ConvertOp::ToInt | ConvertOp::ToLong if convert.expr.type_ == DOUBLE => self.double_to_int(src, convert.op == ConvertOp::ToLong),
+          ConvertOp::ToInt => LLVMBuildTrunc(builder, src, self.i32_t, T),
+          ConvertOp::ToLong => LLVMBuildSExt(builder, src, self.i64_t, T),
+          ConvertOp::ToDouble => LLVMBuildSIToFP(builder, src, self.double_t, T),
+          ConvertOp::StringToChars => {
+            let len = LLVMBuildCall(builder, self.strlen, [src].as_mut_ptr(), 1, T);
+            let arr = self.alloc_array(LLVMBuildIntCast(builder, len, self.i32_t, T), self.i8_t);
+            LLVMBuildCall(builder, self.memcpy, [arr, src, len].as_mut_ptr(), 3, T);
+            arr
+          }
+          ConvertOp::CharsToString => {
+            let len = LLVMBuildIntCast(builder, self.array_length(src), self.i64_t, T);
+            let s = LLVMBuildCall(builder, self.malloc, [LLVMBuildAdd(builder, len, LLVMConstInt(self.i64_t, 1, 0), T)].as_mut_ptr(), 1, T);
+            LLVMBuildCall(builder, self.memcpy, [s, src, len].as_mut_ptr(), 3, T);
+            LLVMBuildStore(builder, LLVMConstInt(self.i8_t, 0, 0), LLVMBuildGEP(builder, s, [len].as_mut_ptr(), 1, T));
+            s
+          }
+        }
+      }
+      NewArray { elem_t, len, dims } => {
         self.expr(len);
-        let len = len.llvm_val;
-        let elem_t = self.type_of(elem_t);
-        let (on_err, after) = (self.new_bb(), self.new_bb());
-        let cmp = LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSLT, len, self.i32_0, T);
-        LLVMBuildCondBr(builder, cmp, on_err, after);
-        self.label(on_err);
-        LLVMBuildCall(builder, self.printf, [self.define_str(NEW_ARR_NEG)].as_mut_ptr(), 1, T);
-        self.exit();
-        self.label(after);
-        let arr = self.alloc_array(len, elem_t);
-        LLVMBuildCall(builder, self.memset, [LLVMBuildBitCast(builder, arr, self.str_t, T), self.i32_0, LLVMBuildMul(builder, LLVMBuildIntCast(builder, len, self.i64_t, T), LLVMSizeOf(elem_t), T)].as_mut_ptr(), 3, T);
-        arr
+        for dim in dims.iter_mut() { self.expr(dim); }
+        let lens = iter::once(len.llvm_val).chain(dims.iter().map(|dim| dim.llvm_val)).collect::<Vec<_>>();
+        for &len in &lens {
+          self.check(LLVMBuildICmp(builder, LLVMIntPredicate::LLVMIntSGE, len, self.i32_0, T), NEGATIVE_ARRAY_SIZE_EXCEPTION, NEW_ARR_NEG);
+        }
+        // the loop variables are allocated here, not inside the loops
+        let counters = dims.iter().map(|_| LLVMBuildAlloca(builder, self.i32_t, T)).collect::<Vec<_>>();
+        self.new_array(&elem_t.sem, &lens, &counters)
       }
       TypeTest { expr: src, name: _, target_class } => {
         self.expr(src);
-        self.instance_of(src.llvm_val, target_class.get().llvm_v_tbl)
+        let target_class = target_class.get();
+        if target_class.interface { self.implements(src.llvm_val, target_class) } else { self.instance_of(src.llvm_val, target_class.llvm_v_tbl) }
       }
       TypeCast { name, expr: src } => {
         self.expr(src);
         let target_t = expr.type_.get_class();
-        let check = self.instance_of(src.llvm_val, target_t.llvm_v_tbl);
+        let check = if target_t.interface { self.implements(src.llvm_val, target_t) } else { self.instance_of(src.llvm_val, target_t.llvm_v_tbl) };
         let (on_false, after) = (self.new_bb(), self.new_bb());
         LLVMBuildCondBr(builder, check, after, on_false);
         self.label(on_false);
-        let v_tbl = LLVMBuildLoad(builder, LLVMBuildStructGEP(builder, src.llvm_val, 0, T), T);
-        let obj_name = LLVMBuildLoad(builder, LLVMBuildStructGEP(builder, v_tbl, 1, T), T);
-        LLVMBuildCall(builder, self.printf, [self.define_str(BAD_CAST), obj_name, self.define_str(name)].as_mut_ptr(), 3, T);
-        self.exit();
+        // src may be an interface object, so view the v-table as an array of i8*
+        let v_tbl = LLVMBuildLoad(builder, LLVMBuildBitCast(builder, src.llvm_val, ptr_of(ptr_of(self.str_t)), T), T);
+        let obj_name = LLVMBuildLoad(builder, LLVMBuildGEP(builder, v_tbl, [LLVMConstInt(self.i32_t, 1, 0)].as_mut_ptr(), 1, T), T);
+        if self.pending.is_null() {
+          LLVMBuildCall(builder, self.printf, [self.define_str(BAD_CAST), obj_name, self.define_str(name)].as_mut_ptr(), 3, T);
+          self.exit();
+        } else {
+          let (bad_cast1, bad_cast2, name, bad_cast3) = (self.define_str(BAD_CAST1), self.define_str(BAD_CAST2), self.define_str(name), self.define_str(BAD_CAST3));
+          let msg = [obj_name, bad_cast2, name, bad_cast3].iter().fold(bad_cast1, |l, &r| self.string_concat(l, r));
+          self.raise_builtin(CLASS_CAST_EXCEPTION, msg);
+        }
         self.label(after);
         LLVMBuildBitCast(builder, src.llvm_val, ptr_of(target_t.llvm_t), T)
       }
@@ -701,7 +1302,60 @@ impl LLVMCodeGen {
         self.label(after);
         LLVMBuildLoad(builder, res, T)
       }
+      Conditional(conditional) => {
+        self.expr(&mut conditional.cond);
+        let res_t = self.type_of(&expr.type_);
+        let res = LLVMBuildAlloca(builder, res_t, T);
+        let (on_true, on_false, after) = (self.new_bb(), self.new_bb(), self.new_bb());
+        LLVMBuildCondBr(builder, conditional.cond.llvm_val, on_true, on_false);
+        self.label(on_true);
+        self.expr(&mut conditional.on_true);
+        LLVMBuildStore(builder, LLVMBuildBitCast(builder, conditional.on_true.llvm_val, res_t, T), res);
+        LLVMBuildBr(builder, after);
+        self.label(on_false);
+        self.expr(&mut conditional.on_false);
+        LLVMBuildStore(builder, LLVMBuildBitCast(builder, conditional.on_false.llvm_val, res_t, T), res);
+        LLVMBuildBr(builder, after);
+        self.label(after);
+        LLVMBuildLoad(builder, res, T)
+      }
       Comprehension(_) => unimplemented!(),
+      Lambda(lambda) => {
+        self.declare_method(&mut lambda.def);
+        let mut env_t = vec![self.str_t];
+        env_t.extend(lambda.capture.iter().map(|(_, copy)| self.type_of(&copy.type_)));
+        lambda.llvm_env_t = LLVMStructTypeInContext(self.context, env_t.as_mut_ptr(), env_t.len() as u32, 0);
+        let env = LLVMBuildMalloc(builder, lambda.llvm_env_t, T);
+        LLVMBuildStore(builder, self.to_i8_ptr(lambda.def.llvm_val), LLVMBuildStructGEP(builder, env, 0, T));
+        for (index, (src, _)) in lambda.capture.iter().enumerate() {
+          LLVMBuildStore(builder, LLVMBuildLoad(builder, src.get().llvm_val, T), LLVMBuildStructGEP(builder, env, index as u32 + 1, T));
+        }
+        self.lambdas.push(lambda.as_mut());
+        self.to_i8_ptr(env)
+      }
+      Apply(apply) => {
+        self.expr(&mut apply.func);
+        let (param_t, ret_t) = if let SemanticType::Func(param_t, ret_t) = &apply.func.type_ { (param_t, ret_t) } else { unreachable!() };
+        let mut llvm_param_t = vec![self.str_t];
+        llvm_param_t.extend(param_t.iter().map(|p| self.type_of(p)));
+        let fn_t = LLVMFunctionType(self.type_of(ret_t), llvm_param_t.as_mut_ptr(), llvm_param_t.len() as u32, 0);
+        let mut arg = vec![apply.func.llvm_val];
+        arg.extend(apply.arg.iter_mut().zip(param_t.iter()).map(|(a, p)| {
+          self.expr(a);
+          LLVMBuildBitCast(builder, a.llvm_val, self.type_of(p), T)
+        }));
+        // the function pointer is the 1st field of env
+        let f = LLVMBuildLoad(builder, LLVMBuildBitCast(builder, apply.func.llvm_val, ptr_of(ptr_of(fn_t)), T), T);
+        let ret = LLVMBuildCall(builder, f, arg.as_mut_ptr(), arg.len() as u32, T);
+        self.check_exception();
+        ret
+      }
     };
+    // a member of a generic class has the erased type, which is casted back to the type argument
+    let erased = match &expr.data { Id(id) => !id.for_assign, Call(_) => true, _ => false };
+    if let (true, SemanticType::Object(_, _)) | (true, SemanticType::Array(_)) = (erased, &expr.type_) {
+      let type_ = self.type_of(&expr.type_);
+      if LLVMTypeOf(expr.llvm_val) != type_ { expr.llvm_val = LLVMBuildBitCast(builder, expr.llvm_val, type_, T); }
+    }
   }
 }
\ No newline at end of file
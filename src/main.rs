@@ -51,10 +51,11 @@ fn read_input(filename: &str) -> &'static str {
 
 fn compile(input: &'static str, cmd: &ArgMatches) -> Result<(), Vec<Error>> {
   let mut printer = print::IndentPrinter::new();
-  let mut output: Box<io::Write> = if let Some(output) = cmd.value_of("OUTPUT") {
-    let out = File::create(output).unwrap();
-    Box::new(out)
-  } else { Box::new(io::stdout()) };
+  // jvm codegen handles OUTPUT itself, since it may produce multiple files
+  let mut output: Box<io::Write> = match cmd.value_of("OUTPUT") {
+    Some(output) if !cmd.is_present("JVM") => Box::new(File::create(output).unwrap()),
+    _ => Box::new(io::stdout()),
+  };
   let mut program = parser::Parser::new().parse(input)?;
 
   if cmd.is_present("LEX") {
@@ -64,16 +65,19 @@ fn compile(input: &'static str, cmd: &ArgMatches) -> Result<(), Vec<Error>> {
   }
   program = symbol_builder::SymbolBuilder::build(program)?;
   program = type_checker::TypeChecker::check(program)?;
+  // stdout may be the generated code
+  for warning in &program.warnings { eprintln!("{}", warning); }
+  program.no_asserts = cmd.is_present("NO_ASSERTS");
   if cmd.is_present("SCOPE") {
     program.print_scope(&mut printer);
     printer.flush(&mut output);
     return Ok(());
   }
   if cmd.is_present("JVM") {
-    jvm_code_gen::JvmCodeGen::gen(program);
+    jvm_code_gen::JvmCodeGen::gen(program, cmd.value_of("OUTPUT").unwrap_or("."));
     Ok(())
   } else if cmd.is_present("TAC") {
-    let tac_program = tac_code_gen::TacCodeGen::gen(&mut program);
+    let tac_program = tac_code_gen::TacCodeGen::gen(&mut program)?;
     let mut printer = print::IndentPrinter::new();
     tac_program.print_to(&mut printer);
     printer.flush(&mut output);
@@ -91,10 +95,11 @@ fn main() {
     .arg(Arg::with_name("LEX").short("l").long("lex").help("Dump lexical & syntactical analysis result."))
     .arg(Arg::with_name("SCOPE").short("s").long("scope").help("Dump scope & type check analysis result."))
     .arg(Arg::with_name("TAC").short("t").long("tac").help("Dump tac code."))
-    .arg(Arg::with_name("JVM").short("j").long("jvm").help("Dump jvm bytecode to .class file."))
+    .arg(Arg::with_name("JVM").short("j").long("jvm").help("Dump jvm bytecode to .class files in OUTPUT directory, or to a runnable jar if OUTPUT ends with .jar."))
     .arg(Arg::with_name("LLVM").short("L").long("llvm").help("Dump llvm ir."))
     .group(ArgGroup::with_name("USAGE").required(true).args(&["LEX", "SCOPE", "TAC", "JVM", "LLVM"]))
     .arg(Arg::with_name("INPUT").required(true))
+    .arg(Arg::with_name("NO_ASSERTS").long("no-asserts").help("Strip all assert statements from the generated code."))
     .arg(Arg::with_name("OUTPUT").short("o").long("output").value_name("FILE").takes_value(true))
     .get_matches()
   ;
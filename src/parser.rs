@@ -12,54 +12,92 @@ enum SV {
   _2(Result<Program, Vec<Error>>),
   _3(ClassDef),
   _4(Flag),
-  _5(Option<Str>),
-  _6(FieldList),
-  _7(VarDef),
-  _8(MethodDef),
-  _9(Type),
-  _10(VarDefList),
-  _11(Block),
-  _12(StmtList),
-  _13(Stmt),
-  _14(Simple),
-  _15(Expr),
-  _16(Option<Expr>),
-  _17(Option<Block>),
-  _18(GuardedList),
-  _19(ExprList),
+  _5(TypeParamList),
+  _6(Option<Str>),
+  _7(IdentList),
+  _8(FieldList),
+  _9(ConstList),
+  _10(TypeParam),
+  _11(Type),
+  _12(VarDefList),
+  _13(FieldDef),
+  _14(Access),
+  _15(VarDef),
+  _16(Expr),
+  _17(MethodDef),
+  _18(Block),
+  _19(StmtList),
+  _20(Stmt),
+  _21(Simple),
+  _22(ExprList),
+  _23(Option<Expr>),
+  _24(Option<Block>),
+  _25(GuardedList),
+  _26(CaseList),
+  _27(Case),
+  _28(i32),
+  _29(CatchList),
+  _30(Catch),
+  _31(TypeList),
 }
 
 // Lex rules.
-static LEX_RULES: [&'static str; 98] = [
+static LEX_RULES: [&'static str; 139] = [
   r##########"^void"##########,
   r##########"^int"##########,
   r##########"^bool"##########,
   r##########"^string"##########,
+  r##########"^char"##########,
+  r##########"^double"##########,
+  r##########"^long"##########,
   r##########"^new"##########,
   r##########"^null"##########,
   r##########"^true"##########,
   r##########"^false"##########,
   r##########"^class"##########,
   r##########"^extends"##########,
+  r##########"^interface"##########,
+  r##########"^implements"##########,
+  r##########"^abstract"##########,
+  r##########"^super"##########,
   r##########"^this"##########,
   r##########"^while"##########,
+  r##########"^do"##########,
   r##########"^foreach"##########,
   r##########"^for"##########,
   r##########"^if"##########,
   r##########"^else"##########,
   r##########"^return"##########,
   r##########"^break"##########,
+  r##########"^continue"##########,
+  r##########"^switch"##########,
+  r##########"^case"##########,
   r##########"^Print"##########,
   r##########"^ReadInteger"##########,
   r##########"^ReadLine"##########,
+  r##########"^CharToInt"##########,
+  r##########"^IntToChar"##########,
+  r##########"^StringToChars"##########,
+  r##########"^CharsToString"##########,
   r##########"^static"##########,
+  r##########"^public"##########,
+  r##########"^private"##########,
+  r##########"^protected"##########,
   r##########"^instanceof"##########,
   r##########"^scopy"##########,
   r##########"^sealed"##########,
   r##########"^var"##########,
   r##########"^default"##########,
   r##########"^in"##########,
+  r##########"^try"##########,
+  r##########"^catch"##########,
+  r##########"^finally"##########,
+  r##########"^throw"##########,
+  r##########"^assert"##########,
+  r##########"^enum"##########,
+  r##########"^lambda"##########,
   r##########"^\|\|\|"##########,
+  r##########"^=>"##########,
   r##########"^<="##########,
   r##########"^>="##########,
   r##########"^=="##########,
@@ -71,6 +109,16 @@ static LEX_RULES: [&'static str; 98] = [
   r##########"^--"##########,
   r##########"^<<"##########,
   r##########"^>>"##########,
+  r##########"^\+="##########,
+  r##########"^-="##########,
+  r##########"^\*="##########,
+  r##########"^/="##########,
+  r##########"^%="##########,
+  r##########"^&="##########,
+  r##########"^\|="##########,
+  r##########"^\^="##########,
+  r##########"^<<="##########,
+  r##########"^>>="##########,
   r##########"^\+"##########,
   r##########"^-"##########,
   r##########"^\*"##########,
@@ -93,6 +141,7 @@ static LEX_RULES: [&'static str; 98] = [
   r##########"^\{"##########,
   r##########"^\}"##########,
   r##########"^:"##########,
+  r##########"^\?"##########,
   r##########"^""##########,
   r##########"^\n"##########,
   r##########"^\r"##########,
@@ -105,28 +154,32 @@ static LEX_RULES: [&'static str; 98] = [
   r##########"^."##########,
   r##########"^\u002f\u002f[^\n]*"##########,
   r##########"^\s+"##########,
+  r##########"^\d+(\.\d*([eE][+-]?\d+)?|[eE][+-]?\d+)"##########,
+  r##########"^\d+[lL]"##########,
   r##########"^\d+"##########,
+  r##########"^\u0027([^\u0027\\\n]|\\.)\u0027"##########,
   r##########"^[A-Za-z][_0-9A-Za-z]*"##########,
   r##########"^\{"##########,
   r##########"^\}"##########,
-  r##########"^;"##########,
+  r##########"^,"##########,
+  r##########"^<"##########,
+  r##########"^>"##########,
   r##########"^\("##########,
   r##########"^\)"##########,
-  r##########"^,"##########,
-  r##########"^:"##########,
+  r##########"^;"##########,
   r##########"^="##########,
+  r##########"^:"##########,
+  r##########"^\-"##########,
   r##########"^\["##########,
   r##########"^\]"##########,
   r##########"^\+"##########,
-  r##########"^\-"##########,
   r##########"^\*"##########,
   r##########"^/"##########,
   r##########"^%"##########,
-  r##########"^<"##########,
-  r##########"^>"##########,
   r##########"^&"##########,
   r##########"^\|"##########,
   r##########"^\^"##########,
+  r##########"^\?"##########,
   r##########"^!"##########,
   r##########"^\."##########
 ];
@@ -160,7 +213,7 @@ macro_rules! pop {
 
 // Productions data.
 // 0 - encoded non-terminal, 1 - length of RHS to pop from the stack
-static PRODUCTIONS: [[i32; 2]; 119] = [[-1, 1], [0, 1], [1, 2], [1, 1], [2, 7], [3, 1], [3, 0], [4, 2], [4, 0], [5, 3], [5, 2], [5, 0], [6, 7], [6, 6], [7, 1], [7, 0], [8, 3], [8, 1], [9, 3], [10, 2], [10, 0], [11, 2], [11, 1], [11, 1], [11, 1], [11, 2], [11, 2], [11, 2], [11, 2], [11, 1], [11, 1], [11, 1], [12, 1], [13, 5], [14, 9], [15, 9], [16, 1], [17, 6], [18, 2], [18, 0], [19, 6], [20, 1], [20, 1], [21, 2], [21, 0], [22, 4], [23, 1], [23, 0], [24, 5], [24, 3], [25, 2], [25, 1], [26, 4], [27, 3], [27, 1], [28, 3], [28, 4], [28, 4], [28, 1], [28, 1], [28, 0], [29, 1], [29, 5], [29, 1], [29, 1], [29, 1], [29, 1], [29, 3], [29, 1], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 3], [29, 6], [29, 6], [29, 7], [29, 9], [29, 3], [29, 2], [29, 2], [29, 2], [29, 2], [29, 2], [29, 2], [29, 3], [29, 3], [29, 1], [29, 4], [29, 5], [29, 6], [29, 5], [30, 2], [30, 4], [31, 2], [31, 0], [32, 1], [32, 0], [33, 2], [34, 1], [34, 1], [34, 1], [34, 1], [34, 2], [34, 3]];
+static PRODUCTIONS: [[i32; 2]; 210] = [[-1, 1], [0, 1], [1, 2], [1, 1], [2, 9], [2, 9], [2, 5], [2, 5], [3, 3], [3, 1], [4, 3], [4, 0], [5, 3], [5, 1], [6, 2], [7, 1], [7, 0], [8, 2], [8, 0], [9, 2], [9, 0], [10, 3], [10, 1], [11, 7], [11, 0], [12, 2], [12, 3], [12, 0], [13, 1], [13, 1], [13, 1], [14, 2], [14, 3], [14, 6], [14, 1], [14, 5], [15, 7], [15, 6], [15, 7], [16, 1], [16, 0], [17, 3], [17, 1], [18, 3], [19, 2], [19, 0], [20, 2], [20, 1], [20, 1], [20, 2], [20, 2], [20, 2], [20, 2], [20, 3], [20, 2], [20, 1], [20, 1], [20, 1], [20, 1], [20, 3], [20, 5], [20, 7], [20, 5], [21, 1], [22, 1], [22, 2], [22, 1], [22, 1], [23, 5], [24, 6], [25, 9], [26, 9], [27, 1], [27, 2], [28, 1], [28, 2], [29, 6], [30, 2], [30, 0], [31, 6], [32, 1], [32, 1], [33, 2], [33, 0], [34, 4], [35, 7], [36, 2], [36, 0], [37, 4], [37, 4], [37, 3], [38, 1], [38, 2], [39, 1], [39, 0], [40, 5], [40, 3], [41, 3], [41, 5], [41, 4], [42, 2], [42, 1], [43, 6], [44, 2], [44, 1], [45, 4], [46, 3], [46, 1], [47, 3], [47, 3], [47, 3], [47, 3], [47, 3], [47, 3], [47, 3], [47, 3], [47, 3], [47, 3], [47, 3], [47, 4], [47, 4], [47, 1], [47, 1], [47, 0], [48, 1], [48, 5], [48, 4], [48, 1], [48, 1], [48, 1], [48, 1], [48, 1], [48, 1], [48, 1], [48, 3], [48, 1], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 3], [48, 6], [48, 5], [48, 6], [48, 7], [48, 9], [48, 3], [48, 2], [48, 2], [48, 2], [48, 2], [48, 2], [48, 2], [48, 3], [48, 3], [48, 4], [48, 4], [48, 4], [48, 4], [48, 1], [48, 5], [48, 8], [48, 1], [48, 1], [48, 6], [48, 4], [48, 4], [48, 6], [48, 5], [49, 5], [49, 4], [50, 3], [50, 3], [51, 2], [51, 1], [51, 4], [52, 2], [52, 2], [53, 1], [53, 0], [54, 2], [55, 3], [55, 1], [56, 1], [56, 1], [56, 1], [56, 1], [56, 1], [56, 1], [56, 1], [56, 2], [56, 5], [56, 3], [56, 4], [56, 5], [56, 7]];
 
 // Table entry.
 enum TE {
@@ -178,266 +231,502 @@ enum TE {
 
 lazy_static! {
     // Lexical rules grouped by lexer state (by start condition).
-    static ref LEX_RULES_BY_START_CONDITIONS: HashMap<&'static str, Vec<i32>> = hashmap! { "INITIAL" => vec! [ 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97 ], "S" => vec! [ 63, 64, 65, 66, 67, 68, 69, 70, 71 ] };
+    static ref LEX_RULES_BY_START_CONDITIONS: HashMap<&'static str, Vec<i32>> = hashmap! { "INITIAL" => vec! [ 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 109, 110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138 ], "S" => vec! [ 100, 101, 102, 103, 104, 105, 106, 107, 108 ] };
 
     // Maps a string name of a token type to its encoded number (the first
     // token number starts after all numbers for non-terminal).
-    static ref TOKENS_MAP: HashMap<&'static str, i32> = hashmap! { "CLASS" => 35, "IDENTIFIER" => 36, "SEALED" => 37, "EXTENDS" => 38, "STATIC" => 39, "WHILE" => 40, "FOR" => 41, "FOREACH" => 42, "IN" => 43, "BREAK" => 44, "IF" => 45, "ELSE" => 46, "SCOPY" => 47, "VAR" => 48, "GUARD_SPLIT" => 49, "RETURN" => 50, "PRINT" => 51, "INT_CONST" => 52, "TRUE" => 53, "FALSE" => 54, "STRING_CONST" => 55, "NULL" => 56, "EQUAL" => 57, "NOT_EQUAL" => 58, "LESS_EQUAL" => 59, "GREATER_EQUAL" => 60, "AND" => 61, "OR" => 62, "REPEAT" => 63, "SHL" => 64, "SHR" => 65, "DEFAULT" => 66, "INC" => 67, "DEC" => 68, "READ_INTEGER" => 69, "READ_LINE" => 70, "THIS" => 71, "NEW" => 72, "INSTANCEOF" => 73, "INT" => 74, "VOID" => 75, "BOOL" => 76, "STRING" => 77, "'{'" => 78, "'}'" => 79, "';'" => 80, "'('" => 81, "')'" => 82, "','" => 83, "':'" => 84, "'='" => 85, "'['" => 86, "']'" => 87, "'+'" => 88, "'-'" => 89, "'*'" => 90, "'/'" => 91, "'%'" => 92, "'<'" => 93, "'>'" => 94, "'&'" => 95, "'|'" => 96, "'^'" => 97, "'!'" => 98, "'.'" => 99, "$" => 100 };
+    static ref TOKENS_MAP: HashMap<&'static str, i32> = hashmap! { "CLASS" => 57, "IDENTIFIER" => 58, "ABSTRACT" => 59, "INTERFACE" => 60, "ENUM" => 61, "SEALED" => 62, "EXTENDS" => 63, "IMPLEMENTS" => 64, "PUBLIC" => 65, "PROTECTED" => 66, "PRIVATE" => 67, "STATIC" => 68, "THROW" => 69, "ASSERT" => 70, "SUPER" => 71, "WHILE" => 72, "DO" => 73, "FOR" => 74, "FOREACH" => 75, "IN" => 76, "BREAK" => 77, "CONTINUE" => 78, "IF" => 79, "ELSE" => 80, "SCOPY" => 81, "VAR" => 82, "SWITCH" => 83, "CASE" => 84, "DEFAULT" => 85, "INT_CONST" => 86, "GUARD_SPLIT" => 87, "TRY" => 88, "FINALLY" => 89, "CATCH" => 90, "RETURN" => 91, "PRINT" => 92, "ADD_ASSIGN" => 93, "SUB_ASSIGN" => 94, "MUL_ASSIGN" => 95, "DIV_ASSIGN" => 96, "MOD_ASSIGN" => 97, "AND_ASSIGN" => 98, "OR_ASSIGN" => 99, "XOR_ASSIGN" => 100, "SHL_ASSIGN" => 101, "SHR_ASSIGN" => 102, "LONG_CONST" => 103, "DOUBLE_CONST" => 104, "CHAR_CONST" => 105, "TRUE" => 106, "FALSE" => 107, "STRING_CONST" => 108, "NULL" => 109, "EQUAL" => 110, "NOT_EQUAL" => 111, "LESS_EQUAL" => 112, "GREATER_EQUAL" => 113, "AND" => 114, "OR" => 115, "REPEAT" => 116, "SHL" => 117, "SHR" => 118, "INC" => 119, "DEC" => 120, "READ_INTEGER" => 121, "READ_LINE" => 122, "CHAR_TO_INT" => 123, "INT_TO_CHAR" => 124, "STRING_TO_CHARS" => 125, "CHARS_TO_STRING" => 126, "THIS" => 127, "NEW" => 128, "INSTANCEOF" => 129, "LAMBDA" => 130, "ARROW" => 131, "INT" => 132, "VOID" => 133, "BOOL" => 134, "STRING" => 135, "CHAR" => 136, "DOUBLE" => 137, "LONG" => 138, "'{'" => 139, "'}'" => 140, "','" => 141, "'<'" => 142, "'>'" => 143, "'('" => 144, "')'" => 145, "';'" => 146, "'='" => 147, "':'" => 148, "'-'" => 149, "'['" => 150, "']'" => 151, "'+'" => 152, "'*'" => 153, "'/'" => 154, "'%'" => 155, "'&'" => 156, "'|'" => 157, "'^'" => 158, "'?'" => 159, "'!'" => 160, "'.'" => 161, "$" => 162 };
 
     // Parsing table.
     // Vector index is the state number, value is a map
     // from an encoded symbol to table entry (TE).
     static ref TABLE: Vec<HashMap<i32, TE>>= vec![
-    hashmap! { 0 => TE::Transit(1), 1 => TE::Transit(2), 2 => TE::Transit(3), 3 => TE::Transit(4), 35 => TE::Reduce(6), 37 => TE::Shift(5) },
-    hashmap! { 100 => TE::Accept },
-    hashmap! { 2 => TE::Transit(6), 3 => TE::Transit(4), 35 => TE::Reduce(6), 37 => TE::Shift(5), 100 => TE::Reduce(1) },
-    hashmap! { 35 => TE::Reduce(3), 37 => TE::Reduce(3), 100 => TE::Reduce(3) },
-    hashmap! { 35 => TE::Shift(7) },
-    hashmap! { 35 => TE::Reduce(5) },
-    hashmap! { 35 => TE::Reduce(2), 37 => TE::Reduce(2), 100 => TE::Reduce(2) },
-    hashmap! { 36 => TE::Shift(8) },
-    hashmap! { 4 => TE::Transit(9), 38 => TE::Shift(10), 78 => TE::Reduce(8) },
-    hashmap! { 78 => TE::Shift(11) },
-    hashmap! { 36 => TE::Shift(249) },
-    hashmap! { 5 => TE::Transit(12), 35 => TE::Reduce(11), 39 => TE::Reduce(11), 74 => TE::Reduce(11), 75 => TE::Reduce(11), 76 => TE::Reduce(11), 77 => TE::Reduce(11), 79 => TE::Reduce(11) },
-    hashmap! { 6 => TE::Transit(15), 33 => TE::Transit(14), 34 => TE::Transit(16), 35 => TE::Shift(21), 39 => TE::Shift(22), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 79 => TE::Shift(13) },
-    hashmap! { 35 => TE::Reduce(4), 37 => TE::Reduce(4), 100 => TE::Reduce(4) },
-    hashmap! { 80 => TE::Shift(23) },
-    hashmap! { 35 => TE::Reduce(10), 39 => TE::Reduce(10), 74 => TE::Reduce(10), 75 => TE::Reduce(10), 76 => TE::Reduce(10), 77 => TE::Reduce(10), 79 => TE::Reduce(10) },
-    hashmap! { 36 => TE::Shift(24), 86 => TE::Shift(25) },
-    hashmap! { 36 => TE::Reduce(113), 86 => TE::Reduce(113) },
-    hashmap! { 36 => TE::Reduce(114), 86 => TE::Reduce(114) },
-    hashmap! { 36 => TE::Reduce(115), 86 => TE::Reduce(115) },
-    hashmap! { 36 => TE::Reduce(116), 86 => TE::Reduce(116) },
-    hashmap! { 36 => TE::Shift(179) },
-    hashmap! { 34 => TE::Transit(243), 35 => TE::Shift(21), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20) },
-    hashmap! { 35 => TE::Reduce(9), 39 => TE::Reduce(9), 74 => TE::Reduce(9), 75 => TE::Reduce(9), 76 => TE::Reduce(9), 77 => TE::Reduce(9), 79 => TE::Reduce(9) },
-    hashmap! { 80 => TE::Reduce(112), 81 => TE::Shift(26) },
-    hashmap! { 87 => TE::Shift(172) },
-    hashmap! { 7 => TE::Transit(27), 8 => TE::Transit(28), 33 => TE::Transit(29), 34 => TE::Transit(30), 35 => TE::Shift(21), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 82 => TE::Reduce(15) },
-    hashmap! { 82 => TE::Shift(31) },
-    hashmap! { 82 => TE::Reduce(14), 83 => TE::Shift(240) },
-    hashmap! { 82 => TE::Reduce(17), 83 => TE::Reduce(17) },
-    hashmap! { 36 => TE::Shift(242), 86 => TE::Shift(25) },
-    hashmap! { 9 => TE::Transit(32), 78 => TE::Shift(33) },
-    hashmap! { 35 => TE::Reduce(13), 39 => TE::Reduce(13), 74 => TE::Reduce(13), 75 => TE::Reduce(13), 76 => TE::Reduce(13), 77 => TE::Reduce(13), 79 => TE::Reduce(13) },
-    hashmap! { 10 => TE::Transit(34), 35 => TE::Reduce(20), 36 => TE::Reduce(20), 40 => TE::Reduce(20), 41 => TE::Reduce(20), 42 => TE::Reduce(20), 44 => TE::Reduce(20), 45 => TE::Reduce(20), 47 => TE::Reduce(20), 48 => TE::Reduce(20), 50 => TE::Reduce(20), 51 => TE::Reduce(20), 52 => TE::Reduce(20), 53 => TE::Reduce(20), 54 => TE::Reduce(20), 55 => TE::Reduce(20), 56 => TE::Reduce(20), 67 => TE::Reduce(20), 68 => TE::Reduce(20), 69 => TE::Reduce(20), 70 => TE::Reduce(20), 71 => TE::Reduce(20), 72 => TE::Reduce(20), 73 => TE::Reduce(20), 74 => TE::Reduce(20), 75 => TE::Reduce(20), 76 => TE::Reduce(20), 77 => TE::Reduce(20), 78 => TE::Reduce(20), 79 => TE::Reduce(20), 80 => TE::Reduce(20), 81 => TE::Reduce(20), 86 => TE::Reduce(20), 89 => TE::Reduce(20), 98 => TE::Reduce(20) },
-    hashmap! { 9 => TE::Transit(47), 11 => TE::Transit(36), 13 => TE::Transit(39), 14 => TE::Transit(40), 15 => TE::Transit(45), 16 => TE::Transit(43), 17 => TE::Transit(38), 19 => TE::Transit(44), 22 => TE::Transit(46), 25 => TE::Transit(41), 26 => TE::Transit(42), 28 => TE::Transit(37), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 40 => TE::Shift(71), 41 => TE::Shift(72), 42 => TE::Shift(77), 44 => TE::Shift(75), 45 => TE::Shift(70), 47 => TE::Shift(76), 48 => TE::Shift(50), 50 => TE::Shift(73), 51 => TE::Shift(74), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 78 => TE::Shift(33), 79 => TE::Shift(35), 80 => TE::Reduce(60), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 35 => TE::Reduce(18), 36 => TE::Reduce(18), 39 => TE::Reduce(18), 40 => TE::Reduce(18), 41 => TE::Reduce(18), 42 => TE::Reduce(18), 44 => TE::Reduce(18), 45 => TE::Reduce(18), 46 => TE::Reduce(18), 47 => TE::Reduce(18), 48 => TE::Reduce(18), 49 => TE::Reduce(18), 50 => TE::Reduce(18), 51 => TE::Reduce(18), 52 => TE::Reduce(18), 53 => TE::Reduce(18), 54 => TE::Reduce(18), 55 => TE::Reduce(18), 56 => TE::Reduce(18), 67 => TE::Reduce(18), 68 => TE::Reduce(18), 69 => TE::Reduce(18), 70 => TE::Reduce(18), 71 => TE::Reduce(18), 72 => TE::Reduce(18), 73 => TE::Reduce(18), 74 => TE::Reduce(18), 75 => TE::Reduce(18), 76 => TE::Reduce(18), 77 => TE::Reduce(18), 78 => TE::Reduce(18), 79 => TE::Reduce(18), 80 => TE::Reduce(18), 81 => TE::Reduce(18), 86 => TE::Reduce(18), 89 => TE::Reduce(18), 98 => TE::Reduce(18) },
-    hashmap! { 35 => TE::Reduce(19), 36 => TE::Reduce(19), 40 => TE::Reduce(19), 41 => TE::Reduce(19), 42 => TE::Reduce(19), 44 => TE::Reduce(19), 45 => TE::Reduce(19), 47 => TE::Reduce(19), 48 => TE::Reduce(19), 50 => TE::Reduce(19), 51 => TE::Reduce(19), 52 => TE::Reduce(19), 53 => TE::Reduce(19), 54 => TE::Reduce(19), 55 => TE::Reduce(19), 56 => TE::Reduce(19), 67 => TE::Reduce(19), 68 => TE::Reduce(19), 69 => TE::Reduce(19), 70 => TE::Reduce(19), 71 => TE::Reduce(19), 72 => TE::Reduce(19), 73 => TE::Reduce(19), 74 => TE::Reduce(19), 75 => TE::Reduce(19), 76 => TE::Reduce(19), 77 => TE::Reduce(19), 78 => TE::Reduce(19), 79 => TE::Reduce(19), 80 => TE::Reduce(19), 81 => TE::Reduce(19), 86 => TE::Reduce(19), 89 => TE::Reduce(19), 98 => TE::Reduce(19) },
-    hashmap! { 80 => TE::Shift(78) },
-    hashmap! { 35 => TE::Reduce(22), 36 => TE::Reduce(22), 40 => TE::Reduce(22), 41 => TE::Reduce(22), 42 => TE::Reduce(22), 44 => TE::Reduce(22), 45 => TE::Reduce(22), 46 => TE::Reduce(22), 47 => TE::Reduce(22), 48 => TE::Reduce(22), 49 => TE::Reduce(22), 50 => TE::Reduce(22), 51 => TE::Reduce(22), 52 => TE::Reduce(22), 53 => TE::Reduce(22), 54 => TE::Reduce(22), 55 => TE::Reduce(22), 56 => TE::Reduce(22), 67 => TE::Reduce(22), 68 => TE::Reduce(22), 69 => TE::Reduce(22), 70 => TE::Reduce(22), 71 => TE::Reduce(22), 72 => TE::Reduce(22), 73 => TE::Reduce(22), 74 => TE::Reduce(22), 75 => TE::Reduce(22), 76 => TE::Reduce(22), 77 => TE::Reduce(22), 78 => TE::Reduce(22), 79 => TE::Reduce(22), 80 => TE::Reduce(22), 81 => TE::Reduce(22), 86 => TE::Reduce(22), 89 => TE::Reduce(22), 98 => TE::Reduce(22) },
-    hashmap! { 35 => TE::Reduce(23), 36 => TE::Reduce(23), 40 => TE::Reduce(23), 41 => TE::Reduce(23), 42 => TE::Reduce(23), 44 => TE::Reduce(23), 45 => TE::Reduce(23), 46 => TE::Reduce(23), 47 => TE::Reduce(23), 48 => TE::Reduce(23), 49 => TE::Reduce(23), 50 => TE::Reduce(23), 51 => TE::Reduce(23), 52 => TE::Reduce(23), 53 => TE::Reduce(23), 54 => TE::Reduce(23), 55 => TE::Reduce(23), 56 => TE::Reduce(23), 67 => TE::Reduce(23), 68 => TE::Reduce(23), 69 => TE::Reduce(23), 70 => TE::Reduce(23), 71 => TE::Reduce(23), 72 => TE::Reduce(23), 73 => TE::Reduce(23), 74 => TE::Reduce(23), 75 => TE::Reduce(23), 76 => TE::Reduce(23), 77 => TE::Reduce(23), 78 => TE::Reduce(23), 79 => TE::Reduce(23), 80 => TE::Reduce(23), 81 => TE::Reduce(23), 86 => TE::Reduce(23), 89 => TE::Reduce(23), 98 => TE::Reduce(23) },
-    hashmap! { 35 => TE::Reduce(24), 36 => TE::Reduce(24), 40 => TE::Reduce(24), 41 => TE::Reduce(24), 42 => TE::Reduce(24), 44 => TE::Reduce(24), 45 => TE::Reduce(24), 46 => TE::Reduce(24), 47 => TE::Reduce(24), 48 => TE::Reduce(24), 49 => TE::Reduce(24), 50 => TE::Reduce(24), 51 => TE::Reduce(24), 52 => TE::Reduce(24), 53 => TE::Reduce(24), 54 => TE::Reduce(24), 55 => TE::Reduce(24), 56 => TE::Reduce(24), 67 => TE::Reduce(24), 68 => TE::Reduce(24), 69 => TE::Reduce(24), 70 => TE::Reduce(24), 71 => TE::Reduce(24), 72 => TE::Reduce(24), 73 => TE::Reduce(24), 74 => TE::Reduce(24), 75 => TE::Reduce(24), 76 => TE::Reduce(24), 77 => TE::Reduce(24), 78 => TE::Reduce(24), 79 => TE::Reduce(24), 80 => TE::Reduce(24), 81 => TE::Reduce(24), 86 => TE::Reduce(24), 89 => TE::Reduce(24), 98 => TE::Reduce(24) },
-    hashmap! { 80 => TE::Shift(79) },
-    hashmap! { 80 => TE::Shift(80) },
-    hashmap! { 80 => TE::Shift(81) },
-    hashmap! { 80 => TE::Shift(82) },
-    hashmap! { 35 => TE::Reduce(29), 36 => TE::Reduce(29), 40 => TE::Reduce(29), 41 => TE::Reduce(29), 42 => TE::Reduce(29), 44 => TE::Reduce(29), 45 => TE::Reduce(29), 46 => TE::Reduce(29), 47 => TE::Reduce(29), 48 => TE::Reduce(29), 49 => TE::Reduce(29), 50 => TE::Reduce(29), 51 => TE::Reduce(29), 52 => TE::Reduce(29), 53 => TE::Reduce(29), 54 => TE::Reduce(29), 55 => TE::Reduce(29), 56 => TE::Reduce(29), 67 => TE::Reduce(29), 68 => TE::Reduce(29), 69 => TE::Reduce(29), 70 => TE::Reduce(29), 71 => TE::Reduce(29), 72 => TE::Reduce(29), 73 => TE::Reduce(29), 74 => TE::Reduce(29), 75 => TE::Reduce(29), 76 => TE::Reduce(29), 77 => TE::Reduce(29), 78 => TE::Reduce(29), 79 => TE::Reduce(29), 80 => TE::Reduce(29), 81 => TE::Reduce(29), 86 => TE::Reduce(29), 89 => TE::Reduce(29), 98 => TE::Reduce(29) },
-    hashmap! { 35 => TE::Reduce(30), 36 => TE::Reduce(30), 40 => TE::Reduce(30), 41 => TE::Reduce(30), 42 => TE::Reduce(30), 44 => TE::Reduce(30), 45 => TE::Reduce(30), 46 => TE::Reduce(30), 47 => TE::Reduce(30), 48 => TE::Reduce(30), 49 => TE::Reduce(30), 50 => TE::Reduce(30), 51 => TE::Reduce(30), 52 => TE::Reduce(30), 53 => TE::Reduce(30), 54 => TE::Reduce(30), 55 => TE::Reduce(30), 56 => TE::Reduce(30), 67 => TE::Reduce(30), 68 => TE::Reduce(30), 69 => TE::Reduce(30), 70 => TE::Reduce(30), 71 => TE::Reduce(30), 72 => TE::Reduce(30), 73 => TE::Reduce(30), 74 => TE::Reduce(30), 75 => TE::Reduce(30), 76 => TE::Reduce(30), 77 => TE::Reduce(30), 78 => TE::Reduce(30), 79 => TE::Reduce(30), 80 => TE::Reduce(30), 81 => TE::Reduce(30), 86 => TE::Reduce(30), 89 => TE::Reduce(30), 98 => TE::Reduce(30) },
-    hashmap! { 35 => TE::Reduce(31), 36 => TE::Reduce(31), 40 => TE::Reduce(31), 41 => TE::Reduce(31), 42 => TE::Reduce(31), 44 => TE::Reduce(31), 45 => TE::Reduce(31), 46 => TE::Reduce(31), 47 => TE::Reduce(31), 48 => TE::Reduce(31), 49 => TE::Reduce(31), 50 => TE::Reduce(31), 51 => TE::Reduce(31), 52 => TE::Reduce(31), 53 => TE::Reduce(31), 54 => TE::Reduce(31), 55 => TE::Reduce(31), 56 => TE::Reduce(31), 67 => TE::Reduce(31), 68 => TE::Reduce(31), 69 => TE::Reduce(31), 70 => TE::Reduce(31), 71 => TE::Reduce(31), 72 => TE::Reduce(31), 73 => TE::Reduce(31), 74 => TE::Reduce(31), 75 => TE::Reduce(31), 76 => TE::Reduce(31), 77 => TE::Reduce(31), 78 => TE::Reduce(31), 79 => TE::Reduce(31), 80 => TE::Reduce(31), 81 => TE::Reduce(31), 86 => TE::Reduce(31), 89 => TE::Reduce(31), 98 => TE::Reduce(31) },
-    hashmap! { 57 => TE::Reduce(61), 58 => TE::Reduce(61), 59 => TE::Reduce(61), 60 => TE::Reduce(61), 61 => TE::Reduce(61), 62 => TE::Reduce(61), 63 => TE::Reduce(61), 64 => TE::Reduce(61), 65 => TE::Reduce(61), 67 => TE::Reduce(61), 68 => TE::Reduce(61), 80 => TE::Reduce(61), 82 => TE::Reduce(61), 85 => TE::Shift(83), 86 => TE::Reduce(61), 88 => TE::Reduce(61), 89 => TE::Reduce(61), 90 => TE::Reduce(61), 91 => TE::Reduce(61), 92 => TE::Reduce(61), 93 => TE::Reduce(61), 94 => TE::Reduce(61), 95 => TE::Reduce(61), 96 => TE::Reduce(61), 97 => TE::Reduce(61), 99 => TE::Reduce(61) },
-    hashmap! { 36 => TE::Shift(182), 86 => TE::Shift(25) },
-    hashmap! { 36 => TE::Shift(185) },
-    hashmap! { 80 => TE::Reduce(58), 82 => TE::Reduce(58) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(59), 82 => TE::Reduce(59), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 36 => TE::Shift(133) },
-    hashmap! { 40 => TE::Reduce(63), 41 => TE::Reduce(63), 45 => TE::Reduce(63), 57 => TE::Reduce(63), 58 => TE::Reduce(63), 59 => TE::Reduce(63), 60 => TE::Reduce(63), 61 => TE::Reduce(63), 62 => TE::Reduce(63), 63 => TE::Reduce(63), 64 => TE::Reduce(63), 65 => TE::Reduce(63), 67 => TE::Reduce(63), 68 => TE::Reduce(63), 80 => TE::Reduce(63), 82 => TE::Reduce(63), 83 => TE::Reduce(63), 84 => TE::Reduce(63), 86 => TE::Reduce(63), 87 => TE::Reduce(63), 88 => TE::Reduce(63), 89 => TE::Reduce(63), 90 => TE::Reduce(63), 91 => TE::Reduce(63), 92 => TE::Reduce(63), 93 => TE::Reduce(63), 94 => TE::Reduce(63), 95 => TE::Reduce(63), 96 => TE::Reduce(63), 97 => TE::Reduce(63), 99 => TE::Reduce(63) },
-    hashmap! { 40 => TE::Reduce(64), 41 => TE::Reduce(64), 45 => TE::Reduce(64), 57 => TE::Reduce(64), 58 => TE::Reduce(64), 59 => TE::Reduce(64), 60 => TE::Reduce(64), 61 => TE::Reduce(64), 62 => TE::Reduce(64), 63 => TE::Reduce(64), 64 => TE::Reduce(64), 65 => TE::Reduce(64), 67 => TE::Reduce(64), 68 => TE::Reduce(64), 80 => TE::Reduce(64), 82 => TE::Reduce(64), 83 => TE::Reduce(64), 84 => TE::Reduce(64), 86 => TE::Reduce(64), 87 => TE::Reduce(64), 88 => TE::Reduce(64), 89 => TE::Reduce(64), 90 => TE::Reduce(64), 91 => TE::Reduce(64), 92 => TE::Reduce(64), 93 => TE::Reduce(64), 94 => TE::Reduce(64), 95 => TE::Reduce(64), 96 => TE::Reduce(64), 97 => TE::Reduce(64), 99 => TE::Reduce(64) },
-    hashmap! { 40 => TE::Reduce(65), 41 => TE::Reduce(65), 45 => TE::Reduce(65), 57 => TE::Reduce(65), 58 => TE::Reduce(65), 59 => TE::Reduce(65), 60 => TE::Reduce(65), 61 => TE::Reduce(65), 62 => TE::Reduce(65), 63 => TE::Reduce(65), 64 => TE::Reduce(65), 65 => TE::Reduce(65), 67 => TE::Reduce(65), 68 => TE::Reduce(65), 80 => TE::Reduce(65), 82 => TE::Reduce(65), 83 => TE::Reduce(65), 84 => TE::Reduce(65), 86 => TE::Reduce(65), 87 => TE::Reduce(65), 88 => TE::Reduce(65), 89 => TE::Reduce(65), 90 => TE::Reduce(65), 91 => TE::Reduce(65), 92 => TE::Reduce(65), 93 => TE::Reduce(65), 94 => TE::Reduce(65), 95 => TE::Reduce(65), 96 => TE::Reduce(65), 97 => TE::Reduce(65), 99 => TE::Reduce(65) },
-    hashmap! { 40 => TE::Reduce(66), 41 => TE::Reduce(66), 45 => TE::Reduce(66), 57 => TE::Reduce(66), 58 => TE::Reduce(66), 59 => TE::Reduce(66), 60 => TE::Reduce(66), 61 => TE::Reduce(66), 62 => TE::Reduce(66), 63 => TE::Reduce(66), 64 => TE::Reduce(66), 65 => TE::Reduce(66), 67 => TE::Reduce(66), 68 => TE::Reduce(66), 80 => TE::Reduce(66), 82 => TE::Reduce(66), 83 => TE::Reduce(66), 84 => TE::Reduce(66), 86 => TE::Reduce(66), 87 => TE::Reduce(66), 88 => TE::Reduce(66), 89 => TE::Reduce(66), 90 => TE::Reduce(66), 91 => TE::Reduce(66), 92 => TE::Reduce(66), 93 => TE::Reduce(66), 94 => TE::Reduce(66), 95 => TE::Reduce(66), 96 => TE::Reduce(66), 97 => TE::Reduce(66), 99 => TE::Reduce(66) },
-    hashmap! { 27 => TE::Transit(141), 29 => TE::Transit(142), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 40 => TE::Reduce(68), 41 => TE::Reduce(68), 45 => TE::Reduce(68), 57 => TE::Reduce(68), 58 => TE::Reduce(68), 59 => TE::Reduce(68), 60 => TE::Reduce(68), 61 => TE::Reduce(68), 62 => TE::Reduce(68), 63 => TE::Reduce(68), 64 => TE::Reduce(68), 65 => TE::Reduce(68), 67 => TE::Reduce(68), 68 => TE::Reduce(68), 80 => TE::Reduce(68), 82 => TE::Reduce(68), 83 => TE::Reduce(68), 84 => TE::Reduce(68), 86 => TE::Reduce(68), 87 => TE::Reduce(68), 88 => TE::Reduce(68), 89 => TE::Reduce(68), 90 => TE::Reduce(68), 91 => TE::Reduce(68), 92 => TE::Reduce(68), 93 => TE::Reduce(68), 94 => TE::Reduce(68), 95 => TE::Reduce(68), 96 => TE::Reduce(68), 97 => TE::Reduce(68), 99 => TE::Reduce(68) },
-    hashmap! { 29 => TE::Transit(152), 30 => TE::Transit(85), 31 => TE::Transit(53), 35 => TE::Shift(153), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(158), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(159), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(160), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(161), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 81 => TE::Shift(162) },
-    hashmap! { 81 => TE::Shift(164) },
-    hashmap! { 40 => TE::Reduce(101), 41 => TE::Reduce(101), 45 => TE::Reduce(101), 57 => TE::Reduce(101), 58 => TE::Reduce(101), 59 => TE::Reduce(101), 60 => TE::Reduce(101), 61 => TE::Reduce(101), 62 => TE::Reduce(101), 63 => TE::Reduce(101), 64 => TE::Reduce(101), 65 => TE::Reduce(101), 67 => TE::Reduce(101), 68 => TE::Reduce(101), 80 => TE::Reduce(101), 82 => TE::Reduce(101), 83 => TE::Reduce(101), 84 => TE::Reduce(101), 86 => TE::Reduce(101), 87 => TE::Reduce(101), 88 => TE::Reduce(101), 89 => TE::Reduce(101), 90 => TE::Reduce(101), 91 => TE::Reduce(101), 92 => TE::Reduce(101), 93 => TE::Reduce(101), 94 => TE::Reduce(101), 95 => TE::Reduce(101), 96 => TE::Reduce(101), 97 => TE::Reduce(101), 99 => TE::Reduce(101) },
-    hashmap! { 34 => TE::Transit(167), 35 => TE::Shift(21), 36 => TE::Shift(166), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20) },
-    hashmap! { 81 => TE::Shift(174) },
-    hashmap! { 78 => TE::Shift(189), 81 => TE::Shift(188) },
-    hashmap! { 81 => TE::Shift(197) },
-    hashmap! { 81 => TE::Shift(201) },
-    hashmap! { 29 => TE::Transit(209), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 80 => TE::Reduce(51), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 81 => TE::Shift(210) },
-    hashmap! { 80 => TE::Reduce(36) },
-    hashmap! { 81 => TE::Shift(213) },
-    hashmap! { 81 => TE::Shift(218) },
-    hashmap! { 35 => TE::Reduce(21), 36 => TE::Reduce(21), 40 => TE::Reduce(21), 41 => TE::Reduce(21), 42 => TE::Reduce(21), 44 => TE::Reduce(21), 45 => TE::Reduce(21), 46 => TE::Reduce(21), 47 => TE::Reduce(21), 48 => TE::Reduce(21), 49 => TE::Reduce(21), 50 => TE::Reduce(21), 51 => TE::Reduce(21), 52 => TE::Reduce(21), 53 => TE::Reduce(21), 54 => TE::Reduce(21), 55 => TE::Reduce(21), 56 => TE::Reduce(21), 67 => TE::Reduce(21), 68 => TE::Reduce(21), 69 => TE::Reduce(21), 70 => TE::Reduce(21), 71 => TE::Reduce(21), 72 => TE::Reduce(21), 73 => TE::Reduce(21), 74 => TE::Reduce(21), 75 => TE::Reduce(21), 76 => TE::Reduce(21), 77 => TE::Reduce(21), 78 => TE::Reduce(21), 79 => TE::Reduce(21), 80 => TE::Reduce(21), 81 => TE::Reduce(21), 86 => TE::Reduce(21), 89 => TE::Reduce(21), 98 => TE::Reduce(21) },
-    hashmap! { 35 => TE::Reduce(25), 36 => TE::Reduce(25), 40 => TE::Reduce(25), 41 => TE::Reduce(25), 42 => TE::Reduce(25), 44 => TE::Reduce(25), 45 => TE::Reduce(25), 46 => TE::Reduce(25), 47 => TE::Reduce(25), 48 => TE::Reduce(25), 49 => TE::Reduce(25), 50 => TE::Reduce(25), 51 => TE::Reduce(25), 52 => TE::Reduce(25), 53 => TE::Reduce(25), 54 => TE::Reduce(25), 55 => TE::Reduce(25), 56 => TE::Reduce(25), 67 => TE::Reduce(25), 68 => TE::Reduce(25), 69 => TE::Reduce(25), 70 => TE::Reduce(25), 71 => TE::Reduce(25), 72 => TE::Reduce(25), 73 => TE::Reduce(25), 74 => TE::Reduce(25), 75 => TE::Reduce(25), 76 => TE::Reduce(25), 77 => TE::Reduce(25), 78 => TE::Reduce(25), 79 => TE::Reduce(25), 80 => TE::Reduce(25), 81 => TE::Reduce(25), 86 => TE::Reduce(25), 89 => TE::Reduce(25), 98 => TE::Reduce(25) },
-    hashmap! { 35 => TE::Reduce(26), 36 => TE::Reduce(26), 40 => TE::Reduce(26), 41 => TE::Reduce(26), 42 => TE::Reduce(26), 44 => TE::Reduce(26), 45 => TE::Reduce(26), 46 => TE::Reduce(26), 47 => TE::Reduce(26), 48 => TE::Reduce(26), 49 => TE::Reduce(26), 50 => TE::Reduce(26), 51 => TE::Reduce(26), 52 => TE::Reduce(26), 53 => TE::Reduce(26), 54 => TE::Reduce(26), 55 => TE::Reduce(26), 56 => TE::Reduce(26), 67 => TE::Reduce(26), 68 => TE::Reduce(26), 69 => TE::Reduce(26), 70 => TE::Reduce(26), 71 => TE::Reduce(26), 72 => TE::Reduce(26), 73 => TE::Reduce(26), 74 => TE::Reduce(26), 75 => TE::Reduce(26), 76 => TE::Reduce(26), 77 => TE::Reduce(26), 78 => TE::Reduce(26), 79 => TE::Reduce(26), 80 => TE::Reduce(26), 81 => TE::Reduce(26), 86 => TE::Reduce(26), 89 => TE::Reduce(26), 98 => TE::Reduce(26) },
-    hashmap! { 35 => TE::Reduce(27), 36 => TE::Reduce(27), 40 => TE::Reduce(27), 41 => TE::Reduce(27), 42 => TE::Reduce(27), 44 => TE::Reduce(27), 45 => TE::Reduce(27), 46 => TE::Reduce(27), 47 => TE::Reduce(27), 48 => TE::Reduce(27), 49 => TE::Reduce(27), 50 => TE::Reduce(27), 51 => TE::Reduce(27), 52 => TE::Reduce(27), 53 => TE::Reduce(27), 54 => TE::Reduce(27), 55 => TE::Reduce(27), 56 => TE::Reduce(27), 67 => TE::Reduce(27), 68 => TE::Reduce(27), 69 => TE::Reduce(27), 70 => TE::Reduce(27), 71 => TE::Reduce(27), 72 => TE::Reduce(27), 73 => TE::Reduce(27), 74 => TE::Reduce(27), 75 => TE::Reduce(27), 76 => TE::Reduce(27), 77 => TE::Reduce(27), 78 => TE::Reduce(27), 79 => TE::Reduce(27), 80 => TE::Reduce(27), 81 => TE::Reduce(27), 86 => TE::Reduce(27), 89 => TE::Reduce(27), 98 => TE::Reduce(27) },
-    hashmap! { 35 => TE::Reduce(28), 36 => TE::Reduce(28), 40 => TE::Reduce(28), 41 => TE::Reduce(28), 42 => TE::Reduce(28), 44 => TE::Reduce(28), 45 => TE::Reduce(28), 46 => TE::Reduce(28), 47 => TE::Reduce(28), 48 => TE::Reduce(28), 49 => TE::Reduce(28), 50 => TE::Reduce(28), 51 => TE::Reduce(28), 52 => TE::Reduce(28), 53 => TE::Reduce(28), 54 => TE::Reduce(28), 55 => TE::Reduce(28), 56 => TE::Reduce(28), 67 => TE::Reduce(28), 68 => TE::Reduce(28), 69 => TE::Reduce(28), 70 => TE::Reduce(28), 71 => TE::Reduce(28), 72 => TE::Reduce(28), 73 => TE::Reduce(28), 74 => TE::Reduce(28), 75 => TE::Reduce(28), 76 => TE::Reduce(28), 77 => TE::Reduce(28), 78 => TE::Reduce(28), 79 => TE::Reduce(28), 80 => TE::Reduce(28), 81 => TE::Reduce(28), 86 => TE::Reduce(28), 89 => TE::Reduce(28), 98 => TE::Reduce(28) },
-    hashmap! { 29 => TE::Transit(84), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(55), 82 => TE::Reduce(55), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(61), 41 => TE::Reduce(61), 45 => TE::Reduce(61), 57 => TE::Reduce(61), 58 => TE::Reduce(61), 59 => TE::Reduce(61), 60 => TE::Reduce(61), 61 => TE::Reduce(61), 62 => TE::Reduce(61), 63 => TE::Reduce(61), 64 => TE::Reduce(61), 65 => TE::Reduce(61), 67 => TE::Reduce(61), 68 => TE::Reduce(61), 80 => TE::Reduce(61), 82 => TE::Reduce(61), 83 => TE::Reduce(61), 84 => TE::Reduce(61), 86 => TE::Reduce(61), 87 => TE::Reduce(61), 88 => TE::Reduce(61), 89 => TE::Reduce(61), 90 => TE::Reduce(61), 91 => TE::Reduce(61), 92 => TE::Reduce(61), 93 => TE::Reduce(61), 94 => TE::Reduce(61), 95 => TE::Reduce(61), 96 => TE::Reduce(61), 97 => TE::Reduce(61), 99 => TE::Reduce(61) },
-    hashmap! { 29 => TE::Transit(109), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(110), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(111), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(112), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(113), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(114), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(115), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(116), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(117), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(118), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(119), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(120), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(121), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(122), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(123), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(124), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(125), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(126), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(127), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 29 => TE::Transit(128), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 40 => TE::Reduce(97), 41 => TE::Reduce(97), 45 => TE::Reduce(97), 57 => TE::Reduce(97), 58 => TE::Reduce(97), 59 => TE::Reduce(97), 60 => TE::Reduce(97), 61 => TE::Reduce(97), 62 => TE::Reduce(97), 63 => TE::Reduce(97), 64 => TE::Reduce(97), 65 => TE::Reduce(97), 67 => TE::Reduce(97), 68 => TE::Reduce(97), 80 => TE::Reduce(97), 82 => TE::Reduce(97), 83 => TE::Reduce(97), 84 => TE::Reduce(97), 86 => TE::Reduce(97), 87 => TE::Reduce(97), 88 => TE::Reduce(97), 89 => TE::Reduce(97), 90 => TE::Reduce(97), 91 => TE::Reduce(97), 92 => TE::Reduce(97), 93 => TE::Reduce(97), 94 => TE::Reduce(97), 95 => TE::Reduce(97), 96 => TE::Reduce(97), 97 => TE::Reduce(97), 99 => TE::Reduce(97) },
-    hashmap! { 40 => TE::Reduce(98), 41 => TE::Reduce(98), 45 => TE::Reduce(98), 57 => TE::Reduce(98), 58 => TE::Reduce(98), 59 => TE::Reduce(98), 60 => TE::Reduce(98), 61 => TE::Reduce(98), 62 => TE::Reduce(98), 63 => TE::Reduce(98), 64 => TE::Reduce(98), 65 => TE::Reduce(98), 67 => TE::Reduce(98), 68 => TE::Reduce(98), 80 => TE::Reduce(98), 82 => TE::Reduce(98), 83 => TE::Reduce(98), 84 => TE::Reduce(98), 86 => TE::Reduce(98), 87 => TE::Reduce(98), 88 => TE::Reduce(98), 89 => TE::Reduce(98), 90 => TE::Reduce(98), 91 => TE::Reduce(98), 92 => TE::Reduce(98), 93 => TE::Reduce(98), 94 => TE::Reduce(98), 95 => TE::Reduce(98), 96 => TE::Reduce(98), 97 => TE::Reduce(98), 99 => TE::Reduce(98) },
-    hashmap! { 36 => TE::Reduce(108) },
-    hashmap! { 40 => TE::Reduce(69), 41 => TE::Reduce(69), 45 => TE::Reduce(69), 57 => TE::Reduce(69), 58 => TE::Reduce(69), 59 => TE::Reduce(69), 60 => TE::Reduce(69), 61 => TE::Reduce(69), 62 => TE::Reduce(69), 63 => TE::Reduce(69), 64 => TE::Reduce(69), 65 => TE::Reduce(69), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(69), 82 => TE::Reduce(69), 83 => TE::Reduce(69), 84 => TE::Reduce(69), 86 => TE::Shift(105), 87 => TE::Reduce(69), 88 => TE::Reduce(69), 89 => TE::Reduce(69), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Reduce(69), 94 => TE::Reduce(69), 95 => TE::Reduce(69), 96 => TE::Reduce(69), 97 => TE::Reduce(69), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(70), 41 => TE::Reduce(70), 45 => TE::Reduce(70), 57 => TE::Reduce(70), 58 => TE::Reduce(70), 59 => TE::Reduce(70), 60 => TE::Reduce(70), 61 => TE::Reduce(70), 62 => TE::Reduce(70), 63 => TE::Reduce(70), 64 => TE::Reduce(70), 65 => TE::Reduce(70), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(70), 82 => TE::Reduce(70), 83 => TE::Reduce(70), 84 => TE::Reduce(70), 86 => TE::Shift(105), 87 => TE::Reduce(70), 88 => TE::Reduce(70), 89 => TE::Reduce(70), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Reduce(70), 94 => TE::Reduce(70), 95 => TE::Reduce(70), 96 => TE::Reduce(70), 97 => TE::Reduce(70), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(71), 41 => TE::Reduce(71), 45 => TE::Reduce(71), 57 => TE::Reduce(71), 58 => TE::Reduce(71), 59 => TE::Reduce(71), 60 => TE::Reduce(71), 61 => TE::Reduce(71), 62 => TE::Reduce(71), 63 => TE::Reduce(71), 64 => TE::Reduce(71), 65 => TE::Reduce(71), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(71), 82 => TE::Reduce(71), 83 => TE::Reduce(71), 84 => TE::Reduce(71), 86 => TE::Shift(105), 87 => TE::Reduce(71), 88 => TE::Reduce(71), 89 => TE::Reduce(71), 90 => TE::Reduce(71), 91 => TE::Reduce(71), 92 => TE::Reduce(71), 93 => TE::Reduce(71), 94 => TE::Reduce(71), 95 => TE::Reduce(71), 96 => TE::Reduce(71), 97 => TE::Reduce(71), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(72), 41 => TE::Reduce(72), 45 => TE::Reduce(72), 57 => TE::Reduce(72), 58 => TE::Reduce(72), 59 => TE::Reduce(72), 60 => TE::Reduce(72), 61 => TE::Reduce(72), 62 => TE::Reduce(72), 63 => TE::Reduce(72), 64 => TE::Reduce(72), 65 => TE::Reduce(72), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(72), 82 => TE::Reduce(72), 83 => TE::Reduce(72), 84 => TE::Reduce(72), 86 => TE::Shift(105), 87 => TE::Reduce(72), 88 => TE::Reduce(72), 89 => TE::Reduce(72), 90 => TE::Reduce(72), 91 => TE::Reduce(72), 92 => TE::Reduce(72), 93 => TE::Reduce(72), 94 => TE::Reduce(72), 95 => TE::Reduce(72), 96 => TE::Reduce(72), 97 => TE::Reduce(72), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(73), 41 => TE::Reduce(73), 45 => TE::Reduce(73), 57 => TE::Reduce(73), 58 => TE::Reduce(73), 59 => TE::Reduce(73), 60 => TE::Reduce(73), 61 => TE::Reduce(73), 62 => TE::Reduce(73), 63 => TE::Reduce(73), 64 => TE::Reduce(73), 65 => TE::Reduce(73), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(73), 82 => TE::Reduce(73), 83 => TE::Reduce(73), 84 => TE::Reduce(73), 86 => TE::Shift(105), 87 => TE::Reduce(73), 88 => TE::Reduce(73), 89 => TE::Reduce(73), 90 => TE::Reduce(73), 91 => TE::Reduce(73), 92 => TE::Reduce(73), 93 => TE::Reduce(73), 94 => TE::Reduce(73), 95 => TE::Reduce(73), 96 => TE::Reduce(73), 97 => TE::Reduce(73), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(74), 41 => TE::Reduce(74), 45 => TE::Reduce(74), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Reduce(74), 62 => TE::Reduce(74), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(74), 82 => TE::Reduce(74), 83 => TE::Reduce(74), 84 => TE::Reduce(74), 86 => TE::Shift(105), 87 => TE::Reduce(74), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Reduce(74), 96 => TE::Reduce(74), 97 => TE::Reduce(74), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(75), 41 => TE::Reduce(75), 45 => TE::Reduce(75), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Reduce(75), 62 => TE::Reduce(75), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(75), 82 => TE::Reduce(75), 83 => TE::Reduce(75), 84 => TE::Reduce(75), 86 => TE::Shift(105), 87 => TE::Reduce(75), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Reduce(75), 96 => TE::Reduce(75), 97 => TE::Reduce(75), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(76), 41 => TE::Reduce(76), 45 => TE::Reduce(76), 57 => TE::Reduce(76), 58 => TE::Reduce(76), 61 => TE::Reduce(76), 62 => TE::Reduce(76), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(76), 82 => TE::Reduce(76), 83 => TE::Reduce(76), 84 => TE::Reduce(76), 86 => TE::Shift(105), 87 => TE::Reduce(76), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 95 => TE::Reduce(76), 96 => TE::Reduce(76), 97 => TE::Reduce(76), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(77), 41 => TE::Reduce(77), 45 => TE::Reduce(77), 57 => TE::Reduce(77), 58 => TE::Reduce(77), 61 => TE::Reduce(77), 62 => TE::Reduce(77), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(77), 82 => TE::Reduce(77), 83 => TE::Reduce(77), 84 => TE::Reduce(77), 86 => TE::Shift(105), 87 => TE::Reduce(77), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 95 => TE::Reduce(77), 96 => TE::Reduce(77), 97 => TE::Reduce(77), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(78), 41 => TE::Reduce(78), 45 => TE::Reduce(78), 57 => TE::Reduce(78), 58 => TE::Reduce(78), 61 => TE::Reduce(78), 62 => TE::Reduce(78), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(78), 82 => TE::Reduce(78), 83 => TE::Reduce(78), 84 => TE::Reduce(78), 86 => TE::Shift(105), 87 => TE::Reduce(78), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 95 => TE::Reduce(78), 96 => TE::Reduce(78), 97 => TE::Reduce(78), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(79), 41 => TE::Reduce(79), 45 => TE::Reduce(79), 57 => TE::Reduce(79), 58 => TE::Reduce(79), 61 => TE::Reduce(79), 62 => TE::Reduce(79), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(79), 82 => TE::Reduce(79), 83 => TE::Reduce(79), 84 => TE::Reduce(79), 86 => TE::Shift(105), 87 => TE::Reduce(79), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 95 => TE::Reduce(79), 96 => TE::Reduce(79), 97 => TE::Reduce(79), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(80), 41 => TE::Reduce(80), 45 => TE::Reduce(80), 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Reduce(80), 62 => TE::Reduce(80), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(80), 82 => TE::Reduce(80), 83 => TE::Reduce(80), 84 => TE::Reduce(80), 86 => TE::Shift(105), 87 => TE::Reduce(80), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(81), 41 => TE::Reduce(81), 45 => TE::Reduce(81), 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Reduce(81), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(81), 82 => TE::Reduce(81), 83 => TE::Reduce(81), 84 => TE::Reduce(81), 86 => TE::Shift(105), 87 => TE::Reduce(81), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(82), 41 => TE::Reduce(82), 45 => TE::Reduce(82), 57 => TE::Reduce(82), 58 => TE::Reduce(82), 59 => TE::Reduce(82), 60 => TE::Reduce(82), 61 => TE::Reduce(82), 62 => TE::Reduce(82), 63 => TE::Reduce(82), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(82), 82 => TE::Reduce(82), 83 => TE::Reduce(82), 84 => TE::Reduce(82), 86 => TE::Shift(105), 87 => TE::Reduce(82), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Reduce(82), 94 => TE::Reduce(82), 95 => TE::Reduce(82), 96 => TE::Reduce(82), 97 => TE::Reduce(82), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(83), 41 => TE::Reduce(83), 45 => TE::Reduce(83), 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Reduce(83), 62 => TE::Reduce(83), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(83), 82 => TE::Reduce(83), 83 => TE::Reduce(83), 84 => TE::Reduce(83), 86 => TE::Shift(105), 87 => TE::Reduce(83), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Reduce(83), 96 => TE::Reduce(83), 97 => TE::Reduce(83), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(84), 41 => TE::Reduce(84), 45 => TE::Reduce(84), 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Reduce(84), 62 => TE::Reduce(84), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(84), 82 => TE::Reduce(84), 83 => TE::Reduce(84), 84 => TE::Reduce(84), 86 => TE::Shift(105), 87 => TE::Reduce(84), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Reduce(84), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(85), 41 => TE::Reduce(85), 45 => TE::Reduce(85), 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Reduce(85), 62 => TE::Reduce(85), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(85), 82 => TE::Reduce(85), 83 => TE::Reduce(85), 84 => TE::Reduce(85), 86 => TE::Shift(105), 87 => TE::Reduce(85), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Reduce(85), 97 => TE::Reduce(85), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(86), 41 => TE::Reduce(86), 45 => TE::Reduce(86), 57 => TE::Reduce(86), 58 => TE::Reduce(86), 59 => TE::Reduce(86), 60 => TE::Reduce(86), 61 => TE::Reduce(86), 62 => TE::Reduce(86), 63 => TE::Reduce(86), 64 => TE::Reduce(86), 65 => TE::Reduce(86), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(86), 82 => TE::Reduce(86), 83 => TE::Reduce(86), 84 => TE::Reduce(86), 86 => TE::Shift(105), 87 => TE::Reduce(86), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Reduce(86), 94 => TE::Reduce(86), 95 => TE::Reduce(86), 96 => TE::Reduce(86), 97 => TE::Reduce(86), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(87), 41 => TE::Reduce(87), 45 => TE::Reduce(87), 57 => TE::Reduce(87), 58 => TE::Reduce(87), 59 => TE::Reduce(87), 60 => TE::Reduce(87), 61 => TE::Reduce(87), 62 => TE::Reduce(87), 63 => TE::Reduce(87), 64 => TE::Reduce(87), 65 => TE::Reduce(87), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(87), 82 => TE::Reduce(87), 83 => TE::Reduce(87), 84 => TE::Reduce(87), 86 => TE::Shift(105), 87 => TE::Reduce(87), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Reduce(87), 94 => TE::Reduce(87), 95 => TE::Reduce(87), 96 => TE::Reduce(87), 97 => TE::Reduce(87), 99 => TE::Shift(108) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 84 => TE::Shift(129), 86 => TE::Shift(105), 87 => TE::Shift(130), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 29 => TE::Transit(131), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 40 => TE::Reduce(107), 41 => TE::Reduce(107), 45 => TE::Reduce(107), 57 => TE::Reduce(107), 58 => TE::Reduce(107), 59 => TE::Reduce(107), 60 => TE::Reduce(107), 61 => TE::Reduce(107), 62 => TE::Reduce(107), 63 => TE::Reduce(107), 64 => TE::Reduce(107), 65 => TE::Reduce(107), 66 => TE::Shift(180), 67 => TE::Reduce(107), 68 => TE::Reduce(107), 80 => TE::Reduce(107), 82 => TE::Reduce(107), 83 => TE::Reduce(107), 84 => TE::Reduce(107), 85 => TE::Reduce(107), 86 => TE::Reduce(107), 87 => TE::Reduce(107), 88 => TE::Reduce(107), 89 => TE::Reduce(107), 90 => TE::Reduce(107), 91 => TE::Reduce(107), 92 => TE::Reduce(107), 93 => TE::Reduce(107), 94 => TE::Reduce(107), 95 => TE::Reduce(107), 96 => TE::Reduce(107), 97 => TE::Reduce(107), 99 => TE::Reduce(107) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 86 => TE::Shift(105), 87 => TE::Shift(132), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(88), 41 => TE::Reduce(88), 45 => TE::Reduce(88), 57 => TE::Reduce(88), 58 => TE::Reduce(88), 59 => TE::Reduce(88), 60 => TE::Reduce(88), 61 => TE::Reduce(88), 62 => TE::Reduce(88), 63 => TE::Reduce(88), 64 => TE::Reduce(88), 65 => TE::Reduce(88), 67 => TE::Reduce(88), 68 => TE::Reduce(88), 80 => TE::Reduce(88), 82 => TE::Reduce(88), 83 => TE::Reduce(88), 84 => TE::Reduce(88), 86 => TE::Reduce(88), 87 => TE::Reduce(88), 88 => TE::Reduce(88), 89 => TE::Reduce(88), 90 => TE::Reduce(88), 91 => TE::Reduce(88), 92 => TE::Reduce(88), 93 => TE::Reduce(88), 94 => TE::Reduce(88), 95 => TE::Reduce(88), 96 => TE::Reduce(88), 97 => TE::Reduce(88), 99 => TE::Reduce(88) },
-    hashmap! { 40 => TE::Reduce(106), 41 => TE::Reduce(106), 45 => TE::Reduce(106), 57 => TE::Reduce(106), 58 => TE::Reduce(106), 59 => TE::Reduce(106), 60 => TE::Reduce(106), 61 => TE::Reduce(106), 62 => TE::Reduce(106), 63 => TE::Reduce(106), 64 => TE::Reduce(106), 65 => TE::Reduce(106), 67 => TE::Reduce(106), 68 => TE::Reduce(106), 80 => TE::Reduce(106), 81 => TE::Shift(134), 82 => TE::Reduce(106), 83 => TE::Reduce(106), 84 => TE::Reduce(106), 85 => TE::Reduce(106), 86 => TE::Reduce(106), 87 => TE::Reduce(106), 88 => TE::Reduce(106), 89 => TE::Reduce(106), 90 => TE::Reduce(106), 91 => TE::Reduce(106), 92 => TE::Reduce(106), 93 => TE::Reduce(106), 94 => TE::Reduce(106), 95 => TE::Reduce(106), 96 => TE::Reduce(106), 97 => TE::Reduce(106), 99 => TE::Reduce(106) },
-    hashmap! { 27 => TE::Transit(136), 29 => TE::Transit(137), 30 => TE::Transit(85), 31 => TE::Transit(53), 32 => TE::Transit(135), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 82 => TE::Reduce(111), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 82 => TE::Shift(138) },
-    hashmap! { 82 => TE::Reduce(110), 83 => TE::Shift(139) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 82 => TE::Reduce(54), 83 => TE::Reduce(54), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(62), 41 => TE::Reduce(62), 45 => TE::Reduce(62), 57 => TE::Reduce(62), 58 => TE::Reduce(62), 59 => TE::Reduce(62), 60 => TE::Reduce(62), 61 => TE::Reduce(62), 62 => TE::Reduce(62), 63 => TE::Reduce(62), 64 => TE::Reduce(62), 65 => TE::Reduce(62), 67 => TE::Reduce(62), 68 => TE::Reduce(62), 80 => TE::Reduce(62), 82 => TE::Reduce(62), 83 => TE::Reduce(62), 84 => TE::Reduce(62), 86 => TE::Reduce(62), 87 => TE::Reduce(62), 88 => TE::Reduce(62), 89 => TE::Reduce(62), 90 => TE::Reduce(62), 91 => TE::Reduce(62), 92 => TE::Reduce(62), 93 => TE::Reduce(62), 94 => TE::Reduce(62), 95 => TE::Reduce(62), 96 => TE::Reduce(62), 97 => TE::Reduce(62), 99 => TE::Reduce(62) },
-    hashmap! { 29 => TE::Transit(140), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 82 => TE::Reduce(53), 83 => TE::Reduce(53), 86 => TE::Shift(105), 87 => TE::Reduce(53), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 83 => TE::Shift(139), 87 => TE::Shift(143) },
-    hashmap! { 41 => TE::Shift(144), 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 83 => TE::Reduce(54), 86 => TE::Shift(105), 87 => TE::Reduce(54), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(67), 41 => TE::Reduce(67), 45 => TE::Reduce(67), 57 => TE::Reduce(67), 58 => TE::Reduce(67), 59 => TE::Reduce(67), 60 => TE::Reduce(67), 61 => TE::Reduce(67), 62 => TE::Reduce(67), 63 => TE::Reduce(67), 64 => TE::Reduce(67), 65 => TE::Reduce(67), 67 => TE::Reduce(67), 68 => TE::Reduce(67), 80 => TE::Reduce(67), 82 => TE::Reduce(67), 83 => TE::Reduce(67), 84 => TE::Reduce(67), 86 => TE::Reduce(67), 87 => TE::Reduce(67), 88 => TE::Reduce(67), 89 => TE::Reduce(67), 90 => TE::Reduce(67), 91 => TE::Reduce(67), 92 => TE::Reduce(67), 93 => TE::Reduce(67), 94 => TE::Reduce(67), 95 => TE::Reduce(67), 96 => TE::Reduce(67), 97 => TE::Reduce(67), 99 => TE::Reduce(67) },
-    hashmap! { 36 => TE::Shift(145) },
-    hashmap! { 43 => TE::Shift(146) },
-    hashmap! { 29 => TE::Transit(147), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 45 => TE::Shift(149), 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 86 => TE::Shift(105), 87 => TE::Shift(148), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(90), 41 => TE::Reduce(90), 45 => TE::Reduce(90), 57 => TE::Reduce(90), 58 => TE::Reduce(90), 59 => TE::Reduce(90), 60 => TE::Reduce(90), 61 => TE::Reduce(90), 62 => TE::Reduce(90), 63 => TE::Reduce(90), 64 => TE::Reduce(90), 65 => TE::Reduce(90), 67 => TE::Reduce(90), 68 => TE::Reduce(90), 80 => TE::Reduce(90), 82 => TE::Reduce(90), 83 => TE::Reduce(90), 84 => TE::Reduce(90), 86 => TE::Reduce(90), 87 => TE::Reduce(90), 88 => TE::Reduce(90), 89 => TE::Reduce(90), 90 => TE::Reduce(90), 91 => TE::Reduce(90), 92 => TE::Reduce(90), 93 => TE::Reduce(90), 94 => TE::Reduce(90), 95 => TE::Reduce(90), 96 => TE::Reduce(90), 97 => TE::Reduce(90), 99 => TE::Reduce(90) },
-    hashmap! { 29 => TE::Transit(150), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 86 => TE::Shift(105), 87 => TE::Shift(151), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(91), 41 => TE::Reduce(91), 45 => TE::Reduce(91), 57 => TE::Reduce(91), 58 => TE::Reduce(91), 59 => TE::Reduce(91), 60 => TE::Reduce(91), 61 => TE::Reduce(91), 62 => TE::Reduce(91), 63 => TE::Reduce(91), 64 => TE::Reduce(91), 65 => TE::Reduce(91), 67 => TE::Reduce(91), 68 => TE::Reduce(91), 80 => TE::Reduce(91), 82 => TE::Reduce(91), 83 => TE::Reduce(91), 84 => TE::Reduce(91), 86 => TE::Reduce(91), 87 => TE::Reduce(91), 88 => TE::Reduce(91), 89 => TE::Reduce(91), 90 => TE::Reduce(91), 91 => TE::Reduce(91), 92 => TE::Reduce(91), 93 => TE::Reduce(91), 94 => TE::Reduce(91), 95 => TE::Reduce(91), 96 => TE::Reduce(91), 97 => TE::Reduce(91), 99 => TE::Reduce(91) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 82 => TE::Shift(154), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 36 => TE::Shift(155) },
-    hashmap! { 40 => TE::Reduce(92), 41 => TE::Reduce(92), 45 => TE::Reduce(92), 57 => TE::Reduce(92), 58 => TE::Reduce(92), 59 => TE::Reduce(92), 60 => TE::Reduce(92), 61 => TE::Reduce(92), 62 => TE::Reduce(92), 63 => TE::Reduce(92), 64 => TE::Reduce(92), 65 => TE::Reduce(92), 67 => TE::Reduce(92), 68 => TE::Reduce(92), 80 => TE::Reduce(92), 82 => TE::Reduce(92), 83 => TE::Reduce(92), 84 => TE::Reduce(92), 86 => TE::Reduce(92), 87 => TE::Reduce(92), 88 => TE::Reduce(92), 89 => TE::Reduce(92), 90 => TE::Reduce(92), 91 => TE::Reduce(92), 92 => TE::Reduce(92), 93 => TE::Reduce(92), 94 => TE::Reduce(92), 95 => TE::Reduce(92), 96 => TE::Reduce(92), 97 => TE::Reduce(92), 99 => TE::Reduce(92) },
-    hashmap! { 82 => TE::Shift(156) },
-    hashmap! { 29 => TE::Transit(157), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 40 => TE::Reduce(105), 41 => TE::Reduce(105), 45 => TE::Reduce(105), 57 => TE::Reduce(105), 58 => TE::Reduce(105), 59 => TE::Reduce(105), 60 => TE::Reduce(105), 61 => TE::Reduce(105), 62 => TE::Reduce(105), 63 => TE::Reduce(105), 64 => TE::Reduce(105), 65 => TE::Reduce(105), 67 => TE::Reduce(105), 68 => TE::Reduce(105), 80 => TE::Reduce(105), 82 => TE::Reduce(105), 83 => TE::Reduce(105), 84 => TE::Reduce(105), 86 => TE::Reduce(105), 87 => TE::Reduce(105), 88 => TE::Reduce(105), 89 => TE::Reduce(105), 90 => TE::Reduce(105), 91 => TE::Reduce(105), 92 => TE::Reduce(105), 93 => TE::Reduce(105), 94 => TE::Reduce(105), 95 => TE::Reduce(105), 96 => TE::Reduce(105), 97 => TE::Reduce(105), 99 => TE::Reduce(105) },
-    hashmap! { 40 => TE::Reduce(93), 41 => TE::Reduce(93), 45 => TE::Reduce(93), 57 => TE::Reduce(93), 58 => TE::Reduce(93), 59 => TE::Reduce(93), 60 => TE::Reduce(93), 61 => TE::Reduce(93), 62 => TE::Reduce(93), 63 => TE::Reduce(93), 64 => TE::Reduce(93), 65 => TE::Reduce(93), 80 => TE::Reduce(93), 82 => TE::Reduce(93), 83 => TE::Reduce(93), 84 => TE::Reduce(93), 86 => TE::Shift(105), 87 => TE::Reduce(93), 88 => TE::Reduce(93), 89 => TE::Reduce(93), 90 => TE::Reduce(93), 91 => TE::Reduce(93), 92 => TE::Reduce(93), 93 => TE::Reduce(93), 94 => TE::Reduce(93), 95 => TE::Reduce(93), 96 => TE::Reduce(93), 97 => TE::Reduce(93), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(94), 41 => TE::Reduce(94), 45 => TE::Reduce(94), 57 => TE::Reduce(94), 58 => TE::Reduce(94), 59 => TE::Reduce(94), 60 => TE::Reduce(94), 61 => TE::Reduce(94), 62 => TE::Reduce(94), 63 => TE::Reduce(94), 64 => TE::Reduce(94), 65 => TE::Reduce(94), 80 => TE::Reduce(94), 82 => TE::Reduce(94), 83 => TE::Reduce(94), 84 => TE::Reduce(94), 86 => TE::Shift(105), 87 => TE::Reduce(94), 88 => TE::Reduce(94), 89 => TE::Reduce(94), 90 => TE::Reduce(94), 91 => TE::Reduce(94), 92 => TE::Reduce(94), 93 => TE::Reduce(94), 94 => TE::Reduce(94), 95 => TE::Reduce(94), 96 => TE::Reduce(94), 97 => TE::Reduce(94), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(95), 41 => TE::Reduce(95), 45 => TE::Reduce(95), 57 => TE::Reduce(95), 58 => TE::Reduce(95), 59 => TE::Reduce(95), 60 => TE::Reduce(95), 61 => TE::Reduce(95), 62 => TE::Reduce(95), 63 => TE::Reduce(95), 64 => TE::Reduce(95), 65 => TE::Reduce(95), 80 => TE::Reduce(95), 82 => TE::Reduce(95), 83 => TE::Reduce(95), 84 => TE::Reduce(95), 86 => TE::Shift(105), 87 => TE::Reduce(95), 88 => TE::Reduce(95), 89 => TE::Reduce(95), 90 => TE::Reduce(95), 91 => TE::Reduce(95), 92 => TE::Reduce(95), 93 => TE::Reduce(95), 94 => TE::Reduce(95), 95 => TE::Reduce(95), 96 => TE::Reduce(95), 97 => TE::Reduce(95), 99 => TE::Shift(108) },
-    hashmap! { 40 => TE::Reduce(96), 41 => TE::Reduce(96), 45 => TE::Reduce(96), 57 => TE::Reduce(96), 58 => TE::Reduce(96), 59 => TE::Reduce(96), 60 => TE::Reduce(96), 61 => TE::Reduce(96), 62 => TE::Reduce(96), 63 => TE::Reduce(96), 64 => TE::Reduce(96), 65 => TE::Reduce(96), 80 => TE::Reduce(96), 82 => TE::Reduce(96), 83 => TE::Reduce(96), 84 => TE::Reduce(96), 86 => TE::Shift(105), 87 => TE::Reduce(96), 88 => TE::Reduce(96), 89 => TE::Reduce(96), 90 => TE::Reduce(96), 91 => TE::Reduce(96), 92 => TE::Reduce(96), 93 => TE::Reduce(96), 94 => TE::Reduce(96), 95 => TE::Reduce(96), 96 => TE::Reduce(96), 97 => TE::Reduce(96), 99 => TE::Shift(108) },
-    hashmap! { 82 => TE::Shift(163) },
-    hashmap! { 40 => TE::Reduce(99), 41 => TE::Reduce(99), 45 => TE::Reduce(99), 57 => TE::Reduce(99), 58 => TE::Reduce(99), 59 => TE::Reduce(99), 60 => TE::Reduce(99), 61 => TE::Reduce(99), 62 => TE::Reduce(99), 63 => TE::Reduce(99), 64 => TE::Reduce(99), 65 => TE::Reduce(99), 67 => TE::Reduce(99), 68 => TE::Reduce(99), 80 => TE::Reduce(99), 82 => TE::Reduce(99), 83 => TE::Reduce(99), 84 => TE::Reduce(99), 86 => TE::Reduce(99), 87 => TE::Reduce(99), 88 => TE::Reduce(99), 89 => TE::Reduce(99), 90 => TE::Reduce(99), 91 => TE::Reduce(99), 92 => TE::Reduce(99), 93 => TE::Reduce(99), 94 => TE::Reduce(99), 95 => TE::Reduce(99), 96 => TE::Reduce(99), 97 => TE::Reduce(99), 99 => TE::Reduce(99) },
-    hashmap! { 82 => TE::Shift(165) },
-    hashmap! { 40 => TE::Reduce(100), 41 => TE::Reduce(100), 45 => TE::Reduce(100), 57 => TE::Reduce(100), 58 => TE::Reduce(100), 59 => TE::Reduce(100), 60 => TE::Reduce(100), 61 => TE::Reduce(100), 62 => TE::Reduce(100), 63 => TE::Reduce(100), 64 => TE::Reduce(100), 65 => TE::Reduce(100), 67 => TE::Reduce(100), 68 => TE::Reduce(100), 80 => TE::Reduce(100), 82 => TE::Reduce(100), 83 => TE::Reduce(100), 84 => TE::Reduce(100), 86 => TE::Reduce(100), 87 => TE::Reduce(100), 88 => TE::Reduce(100), 89 => TE::Reduce(100), 90 => TE::Reduce(100), 91 => TE::Reduce(100), 92 => TE::Reduce(100), 93 => TE::Reduce(100), 94 => TE::Reduce(100), 95 => TE::Reduce(100), 96 => TE::Reduce(100), 97 => TE::Reduce(100), 99 => TE::Reduce(100) },
-    hashmap! { 81 => TE::Shift(168) },
-    hashmap! { 86 => TE::Shift(170) },
-    hashmap! { 82 => TE::Shift(169) },
-    hashmap! { 40 => TE::Reduce(102), 41 => TE::Reduce(102), 45 => TE::Reduce(102), 57 => TE::Reduce(102), 58 => TE::Reduce(102), 59 => TE::Reduce(102), 60 => TE::Reduce(102), 61 => TE::Reduce(102), 62 => TE::Reduce(102), 63 => TE::Reduce(102), 64 => TE::Reduce(102), 65 => TE::Reduce(102), 67 => TE::Reduce(102), 68 => TE::Reduce(102), 80 => TE::Reduce(102), 82 => TE::Reduce(102), 83 => TE::Reduce(102), 84 => TE::Reduce(102), 86 => TE::Reduce(102), 87 => TE::Reduce(102), 88 => TE::Reduce(102), 89 => TE::Reduce(102), 90 => TE::Reduce(102), 91 => TE::Reduce(102), 92 => TE::Reduce(102), 93 => TE::Reduce(102), 94 => TE::Reduce(102), 95 => TE::Reduce(102), 96 => TE::Reduce(102), 97 => TE::Reduce(102), 99 => TE::Reduce(102) },
-    hashmap! { 29 => TE::Transit(171), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 87 => TE::Shift(172), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 86 => TE::Shift(105), 87 => TE::Shift(173), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 36 => TE::Reduce(118), 86 => TE::Reduce(118) },
-    hashmap! { 40 => TE::Reduce(103), 41 => TE::Reduce(103), 45 => TE::Reduce(103), 57 => TE::Reduce(103), 58 => TE::Reduce(103), 59 => TE::Reduce(103), 60 => TE::Reduce(103), 61 => TE::Reduce(103), 62 => TE::Reduce(103), 63 => TE::Reduce(103), 64 => TE::Reduce(103), 65 => TE::Reduce(103), 67 => TE::Reduce(103), 68 => TE::Reduce(103), 80 => TE::Reduce(103), 82 => TE::Reduce(103), 83 => TE::Reduce(103), 84 => TE::Reduce(103), 86 => TE::Reduce(103), 87 => TE::Reduce(103), 88 => TE::Reduce(103), 89 => TE::Reduce(103), 90 => TE::Reduce(103), 91 => TE::Reduce(103), 92 => TE::Reduce(103), 93 => TE::Reduce(103), 94 => TE::Reduce(103), 95 => TE::Reduce(103), 96 => TE::Reduce(103), 97 => TE::Reduce(103), 99 => TE::Reduce(103) },
-    hashmap! { 29 => TE::Transit(175), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 83 => TE::Shift(176), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 36 => TE::Shift(177) },
-    hashmap! { 82 => TE::Shift(178) },
-    hashmap! { 40 => TE::Reduce(104), 41 => TE::Reduce(104), 45 => TE::Reduce(104), 57 => TE::Reduce(104), 58 => TE::Reduce(104), 59 => TE::Reduce(104), 60 => TE::Reduce(104), 61 => TE::Reduce(104), 62 => TE::Reduce(104), 63 => TE::Reduce(104), 64 => TE::Reduce(104), 65 => TE::Reduce(104), 67 => TE::Reduce(104), 68 => TE::Reduce(104), 80 => TE::Reduce(104), 82 => TE::Reduce(104), 83 => TE::Reduce(104), 84 => TE::Reduce(104), 86 => TE::Reduce(104), 87 => TE::Reduce(104), 88 => TE::Reduce(104), 89 => TE::Reduce(104), 90 => TE::Reduce(104), 91 => TE::Reduce(104), 92 => TE::Reduce(104), 93 => TE::Reduce(104), 94 => TE::Reduce(104), 95 => TE::Reduce(104), 96 => TE::Reduce(104), 97 => TE::Reduce(104), 99 => TE::Reduce(104) },
-    hashmap! { 36 => TE::Reduce(117), 86 => TE::Reduce(117) },
-    hashmap! { 29 => TE::Transit(181), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 40 => TE::Reduce(89), 41 => TE::Reduce(89), 45 => TE::Reduce(89), 57 => TE::Reduce(89), 58 => TE::Reduce(89), 59 => TE::Reduce(89), 60 => TE::Reduce(89), 61 => TE::Reduce(89), 62 => TE::Reduce(89), 63 => TE::Reduce(89), 64 => TE::Reduce(89), 65 => TE::Reduce(89), 67 => TE::Reduce(89), 68 => TE::Reduce(89), 80 => TE::Reduce(89), 82 => TE::Reduce(89), 83 => TE::Reduce(89), 84 => TE::Reduce(89), 87 => TE::Reduce(89), 88 => TE::Reduce(89), 89 => TE::Reduce(89), 90 => TE::Reduce(89), 91 => TE::Reduce(89), 92 => TE::Reduce(89), 93 => TE::Reduce(89), 94 => TE::Reduce(89), 95 => TE::Reduce(89), 96 => TE::Reduce(89), 97 => TE::Reduce(89) },
-    hashmap! { 80 => TE::Reduce(112), 82 => TE::Reduce(112), 85 => TE::Shift(183) },
-    hashmap! { 29 => TE::Transit(184), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(56), 82 => TE::Reduce(56), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 85 => TE::Shift(186) },
-    hashmap! { 29 => TE::Transit(187), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(57), 82 => TE::Reduce(57), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 29 => TE::Transit(190), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 23 => TE::Transit(230), 24 => TE::Transit(231), 29 => TE::Transit(232), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 79 => TE::Reduce(47), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 82 => TE::Shift(191), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 9 => TE::Transit(47), 11 => TE::Transit(193), 12 => TE::Transit(192), 13 => TE::Transit(39), 14 => TE::Transit(40), 15 => TE::Transit(45), 16 => TE::Transit(43), 17 => TE::Transit(38), 19 => TE::Transit(44), 22 => TE::Transit(46), 25 => TE::Transit(41), 26 => TE::Transit(42), 28 => TE::Transit(37), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 40 => TE::Shift(71), 41 => TE::Shift(72), 42 => TE::Shift(77), 44 => TE::Shift(75), 45 => TE::Shift(70), 47 => TE::Shift(76), 48 => TE::Shift(50), 50 => TE::Shift(73), 51 => TE::Shift(74), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 78 => TE::Shift(33), 80 => TE::Reduce(60), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 18 => TE::Transit(194), 35 => TE::Reduce(39), 36 => TE::Reduce(39), 40 => TE::Reduce(39), 41 => TE::Reduce(39), 42 => TE::Reduce(39), 44 => TE::Reduce(39), 45 => TE::Reduce(39), 46 => TE::Shift(195), 47 => TE::Reduce(39), 48 => TE::Reduce(39), 49 => TE::Reduce(39), 50 => TE::Reduce(39), 51 => TE::Reduce(39), 52 => TE::Reduce(39), 53 => TE::Reduce(39), 54 => TE::Reduce(39), 55 => TE::Reduce(39), 56 => TE::Reduce(39), 67 => TE::Reduce(39), 68 => TE::Reduce(39), 69 => TE::Reduce(39), 70 => TE::Reduce(39), 71 => TE::Reduce(39), 72 => TE::Reduce(39), 73 => TE::Reduce(39), 74 => TE::Reduce(39), 75 => TE::Reduce(39), 76 => TE::Reduce(39), 77 => TE::Reduce(39), 78 => TE::Reduce(39), 79 => TE::Reduce(39), 80 => TE::Reduce(39), 81 => TE::Reduce(39), 86 => TE::Reduce(39), 89 => TE::Reduce(39), 98 => TE::Reduce(39) },
-    hashmap! { 35 => TE::Reduce(32), 36 => TE::Reduce(32), 40 => TE::Reduce(32), 41 => TE::Reduce(32), 42 => TE::Reduce(32), 44 => TE::Reduce(32), 45 => TE::Reduce(32), 46 => TE::Reduce(32), 47 => TE::Reduce(32), 48 => TE::Reduce(32), 49 => TE::Reduce(32), 50 => TE::Reduce(32), 51 => TE::Reduce(32), 52 => TE::Reduce(32), 53 => TE::Reduce(32), 54 => TE::Reduce(32), 55 => TE::Reduce(32), 56 => TE::Reduce(32), 67 => TE::Reduce(32), 68 => TE::Reduce(32), 69 => TE::Reduce(32), 70 => TE::Reduce(32), 71 => TE::Reduce(32), 72 => TE::Reduce(32), 73 => TE::Reduce(32), 74 => TE::Reduce(32), 75 => TE::Reduce(32), 76 => TE::Reduce(32), 77 => TE::Reduce(32), 78 => TE::Reduce(32), 79 => TE::Reduce(32), 80 => TE::Reduce(32), 81 => TE::Reduce(32), 86 => TE::Reduce(32), 89 => TE::Reduce(32), 98 => TE::Reduce(32) },
-    hashmap! { 35 => TE::Reduce(37), 36 => TE::Reduce(37), 40 => TE::Reduce(37), 41 => TE::Reduce(37), 42 => TE::Reduce(37), 44 => TE::Reduce(37), 45 => TE::Reduce(37), 46 => TE::Reduce(37), 47 => TE::Reduce(37), 48 => TE::Reduce(37), 49 => TE::Reduce(37), 50 => TE::Reduce(37), 51 => TE::Reduce(37), 52 => TE::Reduce(37), 53 => TE::Reduce(37), 54 => TE::Reduce(37), 55 => TE::Reduce(37), 56 => TE::Reduce(37), 67 => TE::Reduce(37), 68 => TE::Reduce(37), 69 => TE::Reduce(37), 70 => TE::Reduce(37), 71 => TE::Reduce(37), 72 => TE::Reduce(37), 73 => TE::Reduce(37), 74 => TE::Reduce(37), 75 => TE::Reduce(37), 76 => TE::Reduce(37), 77 => TE::Reduce(37), 78 => TE::Reduce(37), 79 => TE::Reduce(37), 80 => TE::Reduce(37), 81 => TE::Reduce(37), 86 => TE::Reduce(37), 89 => TE::Reduce(37), 98 => TE::Reduce(37) },
-    hashmap! { 9 => TE::Transit(47), 11 => TE::Transit(193), 12 => TE::Transit(196), 13 => TE::Transit(39), 14 => TE::Transit(40), 15 => TE::Transit(45), 16 => TE::Transit(43), 17 => TE::Transit(38), 19 => TE::Transit(44), 22 => TE::Transit(46), 25 => TE::Transit(41), 26 => TE::Transit(42), 28 => TE::Transit(37), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 40 => TE::Shift(71), 41 => TE::Shift(72), 42 => TE::Shift(77), 44 => TE::Shift(75), 45 => TE::Shift(70), 47 => TE::Shift(76), 48 => TE::Shift(50), 50 => TE::Shift(73), 51 => TE::Shift(74), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 78 => TE::Shift(33), 80 => TE::Reduce(60), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 35 => TE::Reduce(38), 36 => TE::Reduce(38), 40 => TE::Reduce(38), 41 => TE::Reduce(38), 42 => TE::Reduce(38), 44 => TE::Reduce(38), 45 => TE::Reduce(38), 46 => TE::Reduce(38), 47 => TE::Reduce(38), 48 => TE::Reduce(38), 49 => TE::Reduce(38), 50 => TE::Reduce(38), 51 => TE::Reduce(38), 52 => TE::Reduce(38), 53 => TE::Reduce(38), 54 => TE::Reduce(38), 55 => TE::Reduce(38), 56 => TE::Reduce(38), 67 => TE::Reduce(38), 68 => TE::Reduce(38), 69 => TE::Reduce(38), 70 => TE::Reduce(38), 71 => TE::Reduce(38), 72 => TE::Reduce(38), 73 => TE::Reduce(38), 74 => TE::Reduce(38), 75 => TE::Reduce(38), 76 => TE::Reduce(38), 77 => TE::Reduce(38), 78 => TE::Reduce(38), 79 => TE::Reduce(38), 80 => TE::Reduce(38), 81 => TE::Reduce(38), 86 => TE::Reduce(38), 89 => TE::Reduce(38), 98 => TE::Reduce(38) },
-    hashmap! { 29 => TE::Transit(198), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 82 => TE::Shift(199), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 9 => TE::Transit(47), 11 => TE::Transit(193), 12 => TE::Transit(200), 13 => TE::Transit(39), 14 => TE::Transit(40), 15 => TE::Transit(45), 16 => TE::Transit(43), 17 => TE::Transit(38), 19 => TE::Transit(44), 22 => TE::Transit(46), 25 => TE::Transit(41), 26 => TE::Transit(42), 28 => TE::Transit(37), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 40 => TE::Shift(71), 41 => TE::Shift(72), 42 => TE::Shift(77), 44 => TE::Shift(75), 45 => TE::Shift(70), 47 => TE::Shift(76), 48 => TE::Shift(50), 50 => TE::Shift(73), 51 => TE::Shift(74), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 78 => TE::Shift(33), 80 => TE::Reduce(60), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 35 => TE::Reduce(33), 36 => TE::Reduce(33), 40 => TE::Reduce(33), 41 => TE::Reduce(33), 42 => TE::Reduce(33), 44 => TE::Reduce(33), 45 => TE::Reduce(33), 46 => TE::Reduce(33), 47 => TE::Reduce(33), 48 => TE::Reduce(33), 49 => TE::Reduce(33), 50 => TE::Reduce(33), 51 => TE::Reduce(33), 52 => TE::Reduce(33), 53 => TE::Reduce(33), 54 => TE::Reduce(33), 55 => TE::Reduce(33), 56 => TE::Reduce(33), 67 => TE::Reduce(33), 68 => TE::Reduce(33), 69 => TE::Reduce(33), 70 => TE::Reduce(33), 71 => TE::Reduce(33), 72 => TE::Reduce(33), 73 => TE::Reduce(33), 74 => TE::Reduce(33), 75 => TE::Reduce(33), 76 => TE::Reduce(33), 77 => TE::Reduce(33), 78 => TE::Reduce(33), 79 => TE::Reduce(33), 80 => TE::Reduce(33), 81 => TE::Reduce(33), 86 => TE::Reduce(33), 89 => TE::Reduce(33), 98 => TE::Reduce(33) },
-    hashmap! { 28 => TE::Transit(202), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 48 => TE::Shift(50), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 80 => TE::Reduce(60), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 80 => TE::Shift(203) },
-    hashmap! { 29 => TE::Transit(204), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Shift(205), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 28 => TE::Transit(206), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 48 => TE::Shift(50), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 81 => TE::Shift(60), 82 => TE::Reduce(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 82 => TE::Shift(207) },
-    hashmap! { 9 => TE::Transit(47), 11 => TE::Transit(193), 12 => TE::Transit(208), 13 => TE::Transit(39), 14 => TE::Transit(40), 15 => TE::Transit(45), 16 => TE::Transit(43), 17 => TE::Transit(38), 19 => TE::Transit(44), 22 => TE::Transit(46), 25 => TE::Transit(41), 26 => TE::Transit(42), 28 => TE::Transit(37), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 40 => TE::Shift(71), 41 => TE::Shift(72), 42 => TE::Shift(77), 44 => TE::Shift(75), 45 => TE::Shift(70), 47 => TE::Shift(76), 48 => TE::Shift(50), 50 => TE::Shift(73), 51 => TE::Shift(74), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 78 => TE::Shift(33), 80 => TE::Reduce(60), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 35 => TE::Reduce(34), 36 => TE::Reduce(34), 40 => TE::Reduce(34), 41 => TE::Reduce(34), 42 => TE::Reduce(34), 44 => TE::Reduce(34), 45 => TE::Reduce(34), 46 => TE::Reduce(34), 47 => TE::Reduce(34), 48 => TE::Reduce(34), 49 => TE::Reduce(34), 50 => TE::Reduce(34), 51 => TE::Reduce(34), 52 => TE::Reduce(34), 53 => TE::Reduce(34), 54 => TE::Reduce(34), 55 => TE::Reduce(34), 56 => TE::Reduce(34), 67 => TE::Reduce(34), 68 => TE::Reduce(34), 69 => TE::Reduce(34), 70 => TE::Reduce(34), 71 => TE::Reduce(34), 72 => TE::Reduce(34), 73 => TE::Reduce(34), 74 => TE::Reduce(34), 75 => TE::Reduce(34), 76 => TE::Reduce(34), 77 => TE::Reduce(34), 78 => TE::Reduce(34), 79 => TE::Reduce(34), 80 => TE::Reduce(34), 81 => TE::Reduce(34), 86 => TE::Reduce(34), 89 => TE::Reduce(34), 98 => TE::Reduce(34) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 80 => TE::Reduce(50), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 27 => TE::Transit(211), 29 => TE::Transit(137), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 82 => TE::Shift(212), 83 => TE::Shift(139) },
-    hashmap! { 80 => TE::Reduce(52) },
-    hashmap! { 36 => TE::Shift(214) },
-    hashmap! { 83 => TE::Shift(215) },
-    hashmap! { 29 => TE::Transit(216), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 82 => TE::Shift(217), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 80 => TE::Reduce(40) },
-    hashmap! { 20 => TE::Transit(219), 34 => TE::Transit(221), 35 => TE::Shift(21), 48 => TE::Shift(220), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20) },
-    hashmap! { 36 => TE::Shift(222) },
-    hashmap! { 36 => TE::Reduce(41) },
-    hashmap! { 36 => TE::Reduce(42), 86 => TE::Shift(25) },
-    hashmap! { 43 => TE::Shift(223) },
-    hashmap! { 29 => TE::Transit(224), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 21 => TE::Transit(225), 40 => TE::Shift(226), 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 82 => TE::Reduce(44), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 82 => TE::Shift(227) },
-    hashmap! { 29 => TE::Transit(229), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 9 => TE::Transit(47), 11 => TE::Transit(193), 12 => TE::Transit(228), 13 => TE::Transit(39), 14 => TE::Transit(40), 15 => TE::Transit(45), 16 => TE::Transit(43), 17 => TE::Transit(38), 19 => TE::Transit(44), 22 => TE::Transit(46), 25 => TE::Transit(41), 26 => TE::Transit(42), 28 => TE::Transit(37), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 40 => TE::Shift(71), 41 => TE::Shift(72), 42 => TE::Shift(77), 44 => TE::Shift(75), 45 => TE::Shift(70), 47 => TE::Shift(76), 48 => TE::Shift(50), 50 => TE::Shift(73), 51 => TE::Shift(74), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 78 => TE::Shift(33), 80 => TE::Reduce(60), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 35 => TE::Reduce(35), 36 => TE::Reduce(35), 40 => TE::Reduce(35), 41 => TE::Reduce(35), 42 => TE::Reduce(35), 44 => TE::Reduce(35), 45 => TE::Reduce(35), 46 => TE::Reduce(35), 47 => TE::Reduce(35), 48 => TE::Reduce(35), 49 => TE::Reduce(35), 50 => TE::Reduce(35), 51 => TE::Reduce(35), 52 => TE::Reduce(35), 53 => TE::Reduce(35), 54 => TE::Reduce(35), 55 => TE::Reduce(35), 56 => TE::Reduce(35), 67 => TE::Reduce(35), 68 => TE::Reduce(35), 69 => TE::Reduce(35), 70 => TE::Reduce(35), 71 => TE::Reduce(35), 72 => TE::Reduce(35), 73 => TE::Reduce(35), 74 => TE::Reduce(35), 75 => TE::Reduce(35), 76 => TE::Reduce(35), 77 => TE::Reduce(35), 78 => TE::Reduce(35), 79 => TE::Reduce(35), 80 => TE::Reduce(35), 81 => TE::Reduce(35), 86 => TE::Reduce(35), 89 => TE::Reduce(35), 98 => TE::Reduce(35) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 82 => TE::Reduce(43), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 79 => TE::Shift(233) },
-    hashmap! { 49 => TE::Shift(234), 79 => TE::Reduce(46) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 84 => TE::Shift(238), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 35 => TE::Reduce(45), 36 => TE::Reduce(45), 40 => TE::Reduce(45), 41 => TE::Reduce(45), 42 => TE::Reduce(45), 44 => TE::Reduce(45), 45 => TE::Reduce(45), 46 => TE::Reduce(45), 47 => TE::Reduce(45), 48 => TE::Reduce(45), 49 => TE::Reduce(45), 50 => TE::Reduce(45), 51 => TE::Reduce(45), 52 => TE::Reduce(45), 53 => TE::Reduce(45), 54 => TE::Reduce(45), 55 => TE::Reduce(45), 56 => TE::Reduce(45), 67 => TE::Reduce(45), 68 => TE::Reduce(45), 69 => TE::Reduce(45), 70 => TE::Reduce(45), 71 => TE::Reduce(45), 72 => TE::Reduce(45), 73 => TE::Reduce(45), 74 => TE::Reduce(45), 75 => TE::Reduce(45), 76 => TE::Reduce(45), 77 => TE::Reduce(45), 78 => TE::Reduce(45), 79 => TE::Reduce(45), 80 => TE::Reduce(45), 81 => TE::Reduce(45), 86 => TE::Reduce(45), 89 => TE::Reduce(45), 98 => TE::Reduce(45) },
-    hashmap! { 29 => TE::Transit(235), 30 => TE::Transit(85), 31 => TE::Transit(53), 36 => TE::Reduce(109), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 57 => TE::Shift(91), 58 => TE::Shift(92), 59 => TE::Shift(95), 60 => TE::Shift(96), 61 => TE::Shift(97), 62 => TE::Shift(98), 63 => TE::Shift(99), 64 => TE::Shift(103), 65 => TE::Shift(104), 67 => TE::Shift(106), 68 => TE::Shift(107), 84 => TE::Shift(236), 86 => TE::Shift(105), 88 => TE::Shift(86), 89 => TE::Shift(87), 90 => TE::Shift(88), 91 => TE::Shift(89), 92 => TE::Shift(90), 93 => TE::Shift(93), 94 => TE::Shift(94), 95 => TE::Shift(100), 96 => TE::Shift(101), 97 => TE::Shift(102), 99 => TE::Shift(108) },
-    hashmap! { 9 => TE::Transit(47), 11 => TE::Transit(193), 12 => TE::Transit(237), 13 => TE::Transit(39), 14 => TE::Transit(40), 15 => TE::Transit(45), 16 => TE::Transit(43), 17 => TE::Transit(38), 19 => TE::Transit(44), 22 => TE::Transit(46), 25 => TE::Transit(41), 26 => TE::Transit(42), 28 => TE::Transit(37), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 40 => TE::Shift(71), 41 => TE::Shift(72), 42 => TE::Shift(77), 44 => TE::Shift(75), 45 => TE::Shift(70), 47 => TE::Shift(76), 48 => TE::Shift(50), 50 => TE::Shift(73), 51 => TE::Shift(74), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 78 => TE::Shift(33), 80 => TE::Reduce(60), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 49 => TE::Reduce(48), 79 => TE::Reduce(48) },
-    hashmap! { 9 => TE::Transit(47), 11 => TE::Transit(193), 12 => TE::Transit(239), 13 => TE::Transit(39), 14 => TE::Transit(40), 15 => TE::Transit(45), 16 => TE::Transit(43), 17 => TE::Transit(38), 19 => TE::Transit(44), 22 => TE::Transit(46), 25 => TE::Transit(41), 26 => TE::Transit(42), 28 => TE::Transit(37), 29 => TE::Transit(52), 30 => TE::Transit(48), 31 => TE::Transit(53), 33 => TE::Transit(51), 34 => TE::Transit(49), 35 => TE::Shift(21), 36 => TE::Reduce(109), 40 => TE::Shift(71), 41 => TE::Shift(72), 42 => TE::Shift(77), 44 => TE::Shift(75), 45 => TE::Shift(70), 47 => TE::Shift(76), 48 => TE::Shift(50), 50 => TE::Shift(73), 51 => TE::Shift(74), 52 => TE::Shift(54), 53 => TE::Shift(55), 54 => TE::Shift(56), 55 => TE::Shift(57), 56 => TE::Shift(59), 67 => TE::Shift(63), 68 => TE::Shift(64), 69 => TE::Shift(65), 70 => TE::Shift(66), 71 => TE::Shift(67), 72 => TE::Shift(68), 73 => TE::Shift(69), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 78 => TE::Shift(33), 80 => TE::Reduce(60), 81 => TE::Shift(60), 86 => TE::Shift(58), 89 => TE::Shift(61), 98 => TE::Shift(62) },
-    hashmap! { 49 => TE::Reduce(49), 79 => TE::Reduce(49) },
-    hashmap! { 33 => TE::Transit(241), 34 => TE::Transit(30), 35 => TE::Shift(21), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20) },
-    hashmap! { 82 => TE::Reduce(16), 83 => TE::Reduce(16) },
-    hashmap! { 82 => TE::Reduce(112), 83 => TE::Reduce(112) },
-    hashmap! { 36 => TE::Shift(244), 86 => TE::Shift(25) },
-    hashmap! { 81 => TE::Shift(245) },
-    hashmap! { 7 => TE::Transit(246), 8 => TE::Transit(28), 33 => TE::Transit(29), 34 => TE::Transit(30), 35 => TE::Shift(21), 74 => TE::Shift(17), 75 => TE::Shift(18), 76 => TE::Shift(19), 77 => TE::Shift(20), 82 => TE::Reduce(15) },
-    hashmap! { 82 => TE::Shift(247) },
-    hashmap! { 9 => TE::Transit(248), 78 => TE::Shift(33) },
-    hashmap! { 35 => TE::Reduce(12), 39 => TE::Reduce(12), 74 => TE::Reduce(12), 75 => TE::Reduce(12), 76 => TE::Reduce(12), 77 => TE::Reduce(12), 79 => TE::Reduce(12) },
-    hashmap! { 78 => TE::Reduce(7) }
+    hashmap! { 0 => TE::Transit(1), 1 => TE::Transit(2), 2 => TE::Transit(3), 7 => TE::Transit(4), 57 => TE::Reduce(16), 59 => TE::Shift(5), 60 => TE::Shift(6), 61 => TE::Shift(7), 62 => TE::Shift(8) },
+    hashmap! { 162 => TE::Accept },
+    hashmap! { 2 => TE::Transit(9), 7 => TE::Transit(4), 57 => TE::Reduce(16), 59 => TE::Shift(5), 60 => TE::Shift(6), 61 => TE::Shift(7), 62 => TE::Shift(8), 162 => TE::Reduce(1) },
+    hashmap! { 57 => TE::Reduce(3), 59 => TE::Reduce(3), 60 => TE::Reduce(3), 61 => TE::Reduce(3), 62 => TE::Reduce(3), 162 => TE::Reduce(3) },
+    hashmap! { 57 => TE::Shift(10) },
+    hashmap! { 57 => TE::Shift(11) },
+    hashmap! { 58 => TE::Shift(12) },
+    hashmap! { 58 => TE::Shift(13) },
+    hashmap! { 57 => TE::Reduce(15) },
+    hashmap! { 57 => TE::Reduce(2), 59 => TE::Reduce(2), 60 => TE::Reduce(2), 61 => TE::Reduce(2), 62 => TE::Reduce(2), 162 => TE::Reduce(2) },
+    hashmap! { 58 => TE::Shift(14) },
+    hashmap! { 58 => TE::Shift(15) },
+    hashmap! { 139 => TE::Shift(16) },
+    hashmap! { 139 => TE::Shift(17) },
+    hashmap! { 4 => TE::Transit(18), 63 => TE::Reduce(11), 64 => TE::Reduce(11), 139 => TE::Reduce(11), 142 => TE::Shift(19) },
+    hashmap! { 4 => TE::Transit(20), 63 => TE::Reduce(11), 64 => TE::Reduce(11), 139 => TE::Reduce(11), 142 => TE::Shift(19) },
+    hashmap! { 11 => TE::Transit(21), 57 => TE::Reduce(24), 132 => TE::Reduce(24), 133 => TE::Reduce(24), 134 => TE::Reduce(24), 135 => TE::Reduce(24), 136 => TE::Reduce(24), 137 => TE::Reduce(24), 138 => TE::Reduce(24), 140 => TE::Reduce(24), 144 => TE::Reduce(24) },
+    hashmap! { 3 => TE::Transit(22), 58 => TE::Shift(23) },
+    hashmap! { 8 => TE::Transit(24), 63 => TE::Shift(25), 64 => TE::Reduce(18), 139 => TE::Reduce(18) },
+    hashmap! { 5 => TE::Transit(26), 6 => TE::Transit(27), 58 => TE::Shift(28) },
+    hashmap! { 8 => TE::Transit(29), 63 => TE::Shift(25), 64 => TE::Reduce(18), 139 => TE::Reduce(18) },
+    hashmap! { 56 => TE::Transit(31), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 140 => TE::Shift(30), 144 => TE::Shift(40) },
+    hashmap! { 140 => TE::Shift(41), 141 => TE::Shift(42) },
+    hashmap! { 140 => TE::Reduce(9), 141 => TE::Reduce(9) },
+    hashmap! { 9 => TE::Transit(43), 64 => TE::Shift(44), 139 => TE::Reduce(20) },
+    hashmap! { 58 => TE::Shift(45) },
+    hashmap! { 141 => TE::Shift(47), 143 => TE::Shift(46) },
+    hashmap! { 141 => TE::Reduce(13), 143 => TE::Reduce(13) },
+    hashmap! { 8 => TE::Transit(48), 63 => TE::Shift(25), 141 => TE::Reduce(18), 143 => TE::Reduce(18) },
+    hashmap! { 9 => TE::Transit(49), 64 => TE::Shift(44), 139 => TE::Reduce(20) },
+    hashmap! { 57 => TE::Reduce(6), 59 => TE::Reduce(6), 60 => TE::Reduce(6), 61 => TE::Reduce(6), 62 => TE::Reduce(6), 162 => TE::Reduce(6) },
+    hashmap! { 58 => TE::Shift(50), 150 => TE::Shift(51) },
+    hashmap! { 58 => TE::Reduce(197), 141 => TE::Reduce(197), 143 => TE::Reduce(197), 145 => TE::Reduce(197), 150 => TE::Reduce(197) },
+    hashmap! { 58 => TE::Reduce(198), 141 => TE::Reduce(198), 143 => TE::Reduce(198), 145 => TE::Reduce(198), 150 => TE::Reduce(198) },
+    hashmap! { 58 => TE::Reduce(199), 141 => TE::Reduce(199), 143 => TE::Reduce(199), 145 => TE::Reduce(199), 150 => TE::Reduce(199) },
+    hashmap! { 58 => TE::Reduce(200), 141 => TE::Reduce(200), 143 => TE::Reduce(200), 145 => TE::Reduce(200), 150 => TE::Reduce(200) },
+    hashmap! { 58 => TE::Reduce(201), 141 => TE::Reduce(201), 143 => TE::Reduce(201), 145 => TE::Reduce(201), 150 => TE::Reduce(201) },
+    hashmap! { 58 => TE::Reduce(202), 141 => TE::Reduce(202), 143 => TE::Reduce(202), 145 => TE::Reduce(202), 150 => TE::Reduce(202) },
+    hashmap! { 58 => TE::Reduce(203), 141 => TE::Reduce(203), 143 => TE::Reduce(203), 145 => TE::Reduce(203), 150 => TE::Reduce(203) },
+    hashmap! { 58 => TE::Shift(52) },
+    hashmap! { 56 => TE::Transit(54), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40), 145 => TE::Shift(53) },
+    hashmap! { 57 => TE::Reduce(7), 59 => TE::Reduce(7), 60 => TE::Reduce(7), 61 => TE::Reduce(7), 62 => TE::Reduce(7), 162 => TE::Reduce(7) },
+    hashmap! { 58 => TE::Shift(55) },
+    hashmap! { 139 => TE::Shift(56) },
+    hashmap! { 10 => TE::Transit(57), 58 => TE::Shift(58) },
+    hashmap! { 64 => TE::Reduce(17), 139 => TE::Reduce(17), 141 => TE::Reduce(17), 143 => TE::Reduce(17) },
+    hashmap! { 63 => TE::Reduce(10), 64 => TE::Reduce(10), 139 => TE::Reduce(10) },
+    hashmap! { 6 => TE::Transit(59), 58 => TE::Shift(28) },
+    hashmap! { 141 => TE::Reduce(14), 143 => TE::Reduce(14) },
+    hashmap! { 139 => TE::Shift(60) },
+    hashmap! { 144 => TE::Shift(61) },
+    hashmap! { 151 => TE::Shift(62) },
+    hashmap! { 58 => TE::Reduce(204), 141 => TE::Reduce(204), 142 => TE::Shift(63), 143 => TE::Reduce(204), 145 => TE::Reduce(204), 150 => TE::Reduce(204) },
+    hashmap! { 131 => TE::Shift(64) },
+    hashmap! { 141 => TE::Shift(66), 145 => TE::Shift(65), 150 => TE::Shift(51) },
+    hashmap! { 140 => TE::Reduce(8), 141 => TE::Reduce(8) },
+    hashmap! { 12 => TE::Transit(67), 57 => TE::Reduce(27), 58 => TE::Reduce(27), 59 => TE::Reduce(27), 65 => TE::Reduce(27), 66 => TE::Reduce(27), 67 => TE::Reduce(27), 68 => TE::Reduce(27), 132 => TE::Reduce(27), 133 => TE::Reduce(27), 134 => TE::Reduce(27), 135 => TE::Reduce(27), 136 => TE::Reduce(27), 137 => TE::Reduce(27), 138 => TE::Reduce(27), 140 => TE::Reduce(27), 144 => TE::Reduce(27) },
+    hashmap! { 139 => TE::Reduce(19), 141 => TE::Shift(68) },
+    hashmap! { 139 => TE::Reduce(22), 141 => TE::Reduce(22) },
+    hashmap! { 141 => TE::Reduce(12), 143 => TE::Reduce(12) },
+    hashmap! { 12 => TE::Transit(69), 57 => TE::Reduce(27), 58 => TE::Reduce(27), 59 => TE::Reduce(27), 65 => TE::Reduce(27), 66 => TE::Reduce(27), 67 => TE::Reduce(27), 68 => TE::Reduce(27), 132 => TE::Reduce(27), 133 => TE::Reduce(27), 134 => TE::Reduce(27), 135 => TE::Reduce(27), 136 => TE::Reduce(27), 137 => TE::Reduce(27), 138 => TE::Reduce(27), 140 => TE::Reduce(27), 144 => TE::Reduce(27) },
+    hashmap! { 16 => TE::Transit(70), 17 => TE::Transit(71), 54 => TE::Transit(72), 56 => TE::Transit(73), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40), 145 => TE::Reduce(40) },
+    hashmap! { 58 => TE::Reduce(206), 141 => TE::Reduce(206), 143 => TE::Reduce(206), 145 => TE::Reduce(206), 150 => TE::Reduce(206) },
+    hashmap! { 55 => TE::Transit(74), 56 => TE::Transit(75), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 56 => TE::Transit(76), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 131 => TE::Shift(77) },
+    hashmap! { 55 => TE::Transit(78), 56 => TE::Transit(75), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 13 => TE::Transit(81), 14 => TE::Transit(80), 15 => TE::Transit(84), 54 => TE::Transit(82), 56 => TE::Transit(89), 57 => TE::Shift(39), 58 => TE::Shift(85), 59 => TE::Shift(90), 65 => TE::Shift(86), 66 => TE::Shift(87), 67 => TE::Shift(88), 68 => TE::Shift(83), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 140 => TE::Shift(79), 144 => TE::Shift(40) },
+    hashmap! { 58 => TE::Shift(91) },
+    hashmap! { 13 => TE::Transit(81), 14 => TE::Transit(80), 15 => TE::Transit(84), 54 => TE::Transit(82), 56 => TE::Transit(89), 57 => TE::Shift(39), 58 => TE::Shift(85), 59 => TE::Shift(90), 65 => TE::Shift(86), 66 => TE::Shift(87), 67 => TE::Shift(88), 68 => TE::Shift(83), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 140 => TE::Shift(92), 144 => TE::Shift(40) },
+    hashmap! { 145 => TE::Shift(93) },
+    hashmap! { 141 => TE::Shift(94), 145 => TE::Reduce(39) },
+    hashmap! { 141 => TE::Reduce(42), 145 => TE::Reduce(42) },
+    hashmap! { 58 => TE::Shift(95), 150 => TE::Shift(51) },
+    hashmap! { 141 => TE::Shift(97), 143 => TE::Shift(96) },
+    hashmap! { 141 => TE::Reduce(196), 143 => TE::Reduce(196), 145 => TE::Reduce(196), 150 => TE::Shift(51) },
+    hashmap! { 58 => TE::Reduce(207), 141 => TE::Reduce(207), 143 => TE::Reduce(207), 145 => TE::Reduce(207), 150 => TE::Shift(51) },
+    hashmap! { 56 => TE::Transit(98), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 141 => TE::Shift(97), 145 => TE::Shift(99) },
+    hashmap! { 57 => TE::Reduce(4), 59 => TE::Reduce(4), 60 => TE::Reduce(4), 61 => TE::Reduce(4), 62 => TE::Reduce(4), 162 => TE::Reduce(4) },
+    hashmap! { 57 => TE::Reduce(25), 58 => TE::Reduce(25), 59 => TE::Reduce(25), 65 => TE::Reduce(25), 66 => TE::Reduce(25), 67 => TE::Reduce(25), 68 => TE::Reduce(25), 132 => TE::Reduce(25), 133 => TE::Reduce(25), 134 => TE::Reduce(25), 135 => TE::Reduce(25), 136 => TE::Reduce(25), 137 => TE::Reduce(25), 138 => TE::Reduce(25), 140 => TE::Reduce(25), 144 => TE::Reduce(25) },
+    hashmap! { 14 => TE::Transit(100), 15 => TE::Transit(84), 54 => TE::Transit(82), 56 => TE::Transit(89), 57 => TE::Shift(39), 58 => TE::Shift(85), 59 => TE::Shift(90), 68 => TE::Shift(83), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 146 => TE::Shift(101) },
+    hashmap! { 54 => TE::Transit(102), 56 => TE::Transit(103), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 57 => TE::Reduce(34), 58 => TE::Reduce(34), 59 => TE::Reduce(34), 65 => TE::Reduce(34), 66 => TE::Reduce(34), 67 => TE::Reduce(34), 68 => TE::Reduce(34), 132 => TE::Reduce(34), 133 => TE::Reduce(34), 134 => TE::Reduce(34), 135 => TE::Reduce(34), 136 => TE::Reduce(34), 137 => TE::Reduce(34), 138 => TE::Reduce(34), 140 => TE::Reduce(34), 144 => TE::Reduce(34) },
+    hashmap! { 144 => TE::Shift(104) },
+    hashmap! { 57 => TE::Reduce(28), 58 => TE::Reduce(28), 59 => TE::Reduce(28), 68 => TE::Reduce(28), 132 => TE::Reduce(28), 133 => TE::Reduce(28), 134 => TE::Reduce(28), 135 => TE::Reduce(28), 136 => TE::Reduce(28), 137 => TE::Reduce(28), 138 => TE::Reduce(28), 144 => TE::Reduce(28) },
+    hashmap! { 57 => TE::Reduce(29), 58 => TE::Reduce(29), 59 => TE::Reduce(29), 68 => TE::Reduce(29), 132 => TE::Reduce(29), 133 => TE::Reduce(29), 134 => TE::Reduce(29), 135 => TE::Reduce(29), 136 => TE::Reduce(29), 137 => TE::Reduce(29), 138 => TE::Reduce(29), 144 => TE::Reduce(29) },
+    hashmap! { 57 => TE::Reduce(30), 58 => TE::Reduce(30), 59 => TE::Reduce(30), 68 => TE::Reduce(30), 132 => TE::Reduce(30), 133 => TE::Reduce(30), 134 => TE::Reduce(30), 135 => TE::Reduce(30), 136 => TE::Reduce(30), 137 => TE::Reduce(30), 138 => TE::Reduce(30), 144 => TE::Reduce(30) },
+    hashmap! { 58 => TE::Shift(105), 150 => TE::Shift(51) },
+    hashmap! { 56 => TE::Transit(106), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 139 => TE::Reduce(21), 141 => TE::Reduce(21) },
+    hashmap! { 57 => TE::Reduce(5), 59 => TE::Reduce(5), 60 => TE::Reduce(5), 61 => TE::Reduce(5), 62 => TE::Reduce(5), 162 => TE::Reduce(5) },
+    hashmap! { 146 => TE::Shift(107) },
+    hashmap! { 54 => TE::Transit(108), 56 => TE::Transit(73), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 141 => TE::Reduce(194), 145 => TE::Reduce(194) },
+    hashmap! { 58 => TE::Reduce(205), 141 => TE::Reduce(205), 143 => TE::Reduce(205), 145 => TE::Reduce(205), 150 => TE::Reduce(205) },
+    hashmap! { 56 => TE::Transit(109), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 58 => TE::Reduce(208), 141 => TE::Reduce(208), 143 => TE::Reduce(208), 145 => TE::Reduce(208), 150 => TE::Shift(51) },
+    hashmap! { 131 => TE::Shift(110) },
+    hashmap! { 57 => TE::Reduce(26), 58 => TE::Reduce(26), 59 => TE::Reduce(26), 65 => TE::Reduce(26), 66 => TE::Reduce(26), 67 => TE::Reduce(26), 68 => TE::Reduce(26), 132 => TE::Reduce(26), 133 => TE::Reduce(26), 134 => TE::Reduce(26), 135 => TE::Reduce(26), 136 => TE::Reduce(26), 137 => TE::Reduce(26), 138 => TE::Reduce(26), 140 => TE::Reduce(26), 144 => TE::Reduce(26) },
+    hashmap! { 57 => TE::Reduce(31), 58 => TE::Reduce(31), 59 => TE::Reduce(31), 65 => TE::Reduce(31), 66 => TE::Reduce(31), 67 => TE::Reduce(31), 68 => TE::Reduce(31), 132 => TE::Reduce(31), 133 => TE::Reduce(31), 134 => TE::Reduce(31), 135 => TE::Reduce(31), 136 => TE::Reduce(31), 137 => TE::Reduce(31), 138 => TE::Reduce(31), 140 => TE::Reduce(31), 144 => TE::Reduce(31) },
+    hashmap! { 146 => TE::Shift(111) },
+    hashmap! { 58 => TE::Shift(112), 150 => TE::Shift(51) },
+    hashmap! { 16 => TE::Transit(113), 17 => TE::Transit(71), 54 => TE::Transit(72), 56 => TE::Transit(73), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40), 145 => TE::Reduce(40) },
+    hashmap! { 144 => TE::Shift(114), 146 => TE::Reduce(194) },
+    hashmap! { 58 => TE::Shift(115), 150 => TE::Shift(51) },
+    hashmap! { 57 => TE::Reduce(23), 132 => TE::Reduce(23), 133 => TE::Reduce(23), 134 => TE::Reduce(23), 135 => TE::Reduce(23), 136 => TE::Reduce(23), 137 => TE::Reduce(23), 138 => TE::Reduce(23), 140 => TE::Reduce(23), 144 => TE::Reduce(23) },
+    hashmap! { 141 => TE::Reduce(41), 145 => TE::Reduce(41) },
+    hashmap! { 141 => TE::Reduce(195), 143 => TE::Reduce(195), 145 => TE::Reduce(195), 150 => TE::Shift(51) },
+    hashmap! { 56 => TE::Transit(116), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 57 => TE::Reduce(32), 58 => TE::Reduce(32), 59 => TE::Reduce(32), 65 => TE::Reduce(32), 66 => TE::Reduce(32), 67 => TE::Reduce(32), 68 => TE::Reduce(32), 132 => TE::Reduce(32), 133 => TE::Reduce(32), 134 => TE::Reduce(32), 135 => TE::Reduce(32), 136 => TE::Reduce(32), 137 => TE::Reduce(32), 138 => TE::Reduce(32), 140 => TE::Reduce(32), 144 => TE::Reduce(32) },
+    hashmap! { 144 => TE::Shift(118), 146 => TE::Reduce(194), 147 => TE::Shift(117) },
+    hashmap! { 145 => TE::Shift(119) },
+    hashmap! { 16 => TE::Transit(120), 17 => TE::Transit(71), 54 => TE::Transit(72), 56 => TE::Transit(73), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40), 145 => TE::Reduce(40) },
+    hashmap! { 144 => TE::Shift(121) },
+    hashmap! { 58 => TE::Reduce(209), 141 => TE::Reduce(209), 143 => TE::Reduce(209), 145 => TE::Reduce(209), 150 => TE::Shift(51) },
+    hashmap! { 48 => TE::Transit(122), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 16 => TE::Transit(153), 17 => TE::Transit(71), 54 => TE::Transit(72), 56 => TE::Transit(73), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40), 145 => TE::Reduce(40) },
+    hashmap! { 18 => TE::Transit(154), 139 => TE::Shift(155) },
+    hashmap! { 145 => TE::Shift(156) },
+    hashmap! { 16 => TE::Transit(157), 17 => TE::Transit(71), 54 => TE::Transit(72), 56 => TE::Transit(73), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40), 145 => TE::Reduce(40) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 146 => TE::Shift(158), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(124), 74 => TE::Reduce(124), 79 => TE::Reduce(124), 110 => TE::Reduce(124), 111 => TE::Reduce(124), 112 => TE::Reduce(124), 113 => TE::Reduce(124), 114 => TE::Reduce(124), 115 => TE::Reduce(124), 116 => TE::Reduce(124), 117 => TE::Reduce(124), 118 => TE::Reduce(124), 119 => TE::Reduce(124), 120 => TE::Reduce(124), 141 => TE::Reduce(124), 142 => TE::Reduce(124), 143 => TE::Reduce(124), 144 => TE::Reduce(124), 145 => TE::Reduce(124), 146 => TE::Reduce(124), 148 => TE::Reduce(124), 149 => TE::Reduce(124), 150 => TE::Reduce(124), 151 => TE::Reduce(124), 152 => TE::Reduce(124), 153 => TE::Reduce(124), 154 => TE::Reduce(124), 155 => TE::Reduce(124), 156 => TE::Reduce(124), 157 => TE::Reduce(124), 158 => TE::Reduce(124), 159 => TE::Reduce(124), 161 => TE::Reduce(124) },
+    hashmap! { 58 => TE::Shift(184) },
+    hashmap! { 72 => TE::Reduce(188), 74 => TE::Reduce(188), 79 => TE::Reduce(188), 93 => TE::Reduce(188), 94 => TE::Reduce(188), 95 => TE::Reduce(188), 96 => TE::Reduce(188), 97 => TE::Reduce(188), 98 => TE::Reduce(188), 99 => TE::Reduce(188), 100 => TE::Reduce(188), 101 => TE::Reduce(188), 102 => TE::Reduce(188), 110 => TE::Reduce(188), 111 => TE::Reduce(188), 112 => TE::Reduce(188), 113 => TE::Reduce(188), 114 => TE::Reduce(188), 115 => TE::Reduce(188), 116 => TE::Reduce(188), 117 => TE::Reduce(188), 118 => TE::Reduce(188), 119 => TE::Reduce(188), 120 => TE::Reduce(188), 141 => TE::Reduce(188), 142 => TE::Reduce(188), 143 => TE::Reduce(188), 144 => TE::Shift(185), 145 => TE::Reduce(188), 146 => TE::Reduce(188), 147 => TE::Reduce(188), 148 => TE::Reduce(188), 149 => TE::Reduce(188), 150 => TE::Reduce(188), 151 => TE::Reduce(188), 152 => TE::Reduce(188), 153 => TE::Reduce(188), 154 => TE::Reduce(188), 155 => TE::Reduce(188), 156 => TE::Reduce(188), 157 => TE::Reduce(188), 158 => TE::Reduce(188), 159 => TE::Reduce(188), 161 => TE::Reduce(188) },
+    hashmap! { 72 => TE::Reduce(127), 74 => TE::Reduce(127), 79 => TE::Reduce(127), 110 => TE::Reduce(127), 111 => TE::Reduce(127), 112 => TE::Reduce(127), 113 => TE::Reduce(127), 114 => TE::Reduce(127), 115 => TE::Reduce(127), 116 => TE::Reduce(127), 117 => TE::Reduce(127), 118 => TE::Reduce(127), 119 => TE::Reduce(127), 120 => TE::Reduce(127), 141 => TE::Reduce(127), 142 => TE::Reduce(127), 143 => TE::Reduce(127), 144 => TE::Reduce(127), 145 => TE::Reduce(127), 146 => TE::Reduce(127), 148 => TE::Reduce(127), 149 => TE::Reduce(127), 150 => TE::Reduce(127), 151 => TE::Reduce(127), 152 => TE::Reduce(127), 153 => TE::Reduce(127), 154 => TE::Reduce(127), 155 => TE::Reduce(127), 156 => TE::Reduce(127), 157 => TE::Reduce(127), 158 => TE::Reduce(127), 159 => TE::Reduce(127), 161 => TE::Reduce(127) },
+    hashmap! { 72 => TE::Reduce(128), 74 => TE::Reduce(128), 79 => TE::Reduce(128), 110 => TE::Reduce(128), 111 => TE::Reduce(128), 112 => TE::Reduce(128), 113 => TE::Reduce(128), 114 => TE::Reduce(128), 115 => TE::Reduce(128), 116 => TE::Reduce(128), 117 => TE::Reduce(128), 118 => TE::Reduce(128), 119 => TE::Reduce(128), 120 => TE::Reduce(128), 141 => TE::Reduce(128), 142 => TE::Reduce(128), 143 => TE::Reduce(128), 144 => TE::Reduce(128), 145 => TE::Reduce(128), 146 => TE::Reduce(128), 148 => TE::Reduce(128), 149 => TE::Reduce(128), 150 => TE::Reduce(128), 151 => TE::Reduce(128), 152 => TE::Reduce(128), 153 => TE::Reduce(128), 154 => TE::Reduce(128), 155 => TE::Reduce(128), 156 => TE::Reduce(128), 157 => TE::Reduce(128), 158 => TE::Reduce(128), 159 => TE::Reduce(128), 161 => TE::Reduce(128) },
+    hashmap! { 72 => TE::Reduce(129), 74 => TE::Reduce(129), 79 => TE::Reduce(129), 110 => TE::Reduce(129), 111 => TE::Reduce(129), 112 => TE::Reduce(129), 113 => TE::Reduce(129), 114 => TE::Reduce(129), 115 => TE::Reduce(129), 116 => TE::Reduce(129), 117 => TE::Reduce(129), 118 => TE::Reduce(129), 119 => TE::Reduce(129), 120 => TE::Reduce(129), 141 => TE::Reduce(129), 142 => TE::Reduce(129), 143 => TE::Reduce(129), 144 => TE::Reduce(129), 145 => TE::Reduce(129), 146 => TE::Reduce(129), 148 => TE::Reduce(129), 149 => TE::Reduce(129), 150 => TE::Reduce(129), 151 => TE::Reduce(129), 152 => TE::Reduce(129), 153 => TE::Reduce(129), 154 => TE::Reduce(129), 155 => TE::Reduce(129), 156 => TE::Reduce(129), 157 => TE::Reduce(129), 158 => TE::Reduce(129), 159 => TE::Reduce(129), 161 => TE::Reduce(129) },
+    hashmap! { 72 => TE::Reduce(130), 74 => TE::Reduce(130), 79 => TE::Reduce(130), 110 => TE::Reduce(130), 111 => TE::Reduce(130), 112 => TE::Reduce(130), 113 => TE::Reduce(130), 114 => TE::Reduce(130), 115 => TE::Reduce(130), 116 => TE::Reduce(130), 117 => TE::Reduce(130), 118 => TE::Reduce(130), 119 => TE::Reduce(130), 120 => TE::Reduce(130), 141 => TE::Reduce(130), 142 => TE::Reduce(130), 143 => TE::Reduce(130), 144 => TE::Reduce(130), 145 => TE::Reduce(130), 146 => TE::Reduce(130), 148 => TE::Reduce(130), 149 => TE::Reduce(130), 150 => TE::Reduce(130), 151 => TE::Reduce(130), 152 => TE::Reduce(130), 153 => TE::Reduce(130), 154 => TE::Reduce(130), 155 => TE::Reduce(130), 156 => TE::Reduce(130), 157 => TE::Reduce(130), 158 => TE::Reduce(130), 159 => TE::Reduce(130), 161 => TE::Reduce(130) },
+    hashmap! { 72 => TE::Reduce(131), 74 => TE::Reduce(131), 79 => TE::Reduce(131), 110 => TE::Reduce(131), 111 => TE::Reduce(131), 112 => TE::Reduce(131), 113 => TE::Reduce(131), 114 => TE::Reduce(131), 115 => TE::Reduce(131), 116 => TE::Reduce(131), 117 => TE::Reduce(131), 118 => TE::Reduce(131), 119 => TE::Reduce(131), 120 => TE::Reduce(131), 141 => TE::Reduce(131), 142 => TE::Reduce(131), 143 => TE::Reduce(131), 144 => TE::Reduce(131), 145 => TE::Reduce(131), 146 => TE::Reduce(131), 148 => TE::Reduce(131), 149 => TE::Reduce(131), 150 => TE::Reduce(131), 151 => TE::Reduce(131), 152 => TE::Reduce(131), 153 => TE::Reduce(131), 154 => TE::Reduce(131), 155 => TE::Reduce(131), 156 => TE::Reduce(131), 157 => TE::Reduce(131), 158 => TE::Reduce(131), 159 => TE::Reduce(131), 161 => TE::Reduce(131) },
+    hashmap! { 72 => TE::Reduce(132), 74 => TE::Reduce(132), 79 => TE::Reduce(132), 110 => TE::Reduce(132), 111 => TE::Reduce(132), 112 => TE::Reduce(132), 113 => TE::Reduce(132), 114 => TE::Reduce(132), 115 => TE::Reduce(132), 116 => TE::Reduce(132), 117 => TE::Reduce(132), 118 => TE::Reduce(132), 119 => TE::Reduce(132), 120 => TE::Reduce(132), 141 => TE::Reduce(132), 142 => TE::Reduce(132), 143 => TE::Reduce(132), 144 => TE::Reduce(132), 145 => TE::Reduce(132), 146 => TE::Reduce(132), 148 => TE::Reduce(132), 149 => TE::Reduce(132), 150 => TE::Reduce(132), 151 => TE::Reduce(132), 152 => TE::Reduce(132), 153 => TE::Reduce(132), 154 => TE::Reduce(132), 155 => TE::Reduce(132), 156 => TE::Reduce(132), 157 => TE::Reduce(132), 158 => TE::Reduce(132), 159 => TE::Reduce(132), 161 => TE::Reduce(132) },
+    hashmap! { 72 => TE::Reduce(133), 74 => TE::Reduce(133), 79 => TE::Reduce(133), 110 => TE::Reduce(133), 111 => TE::Reduce(133), 112 => TE::Reduce(133), 113 => TE::Reduce(133), 114 => TE::Reduce(133), 115 => TE::Reduce(133), 116 => TE::Reduce(133), 117 => TE::Reduce(133), 118 => TE::Reduce(133), 119 => TE::Reduce(133), 120 => TE::Reduce(133), 141 => TE::Reduce(133), 142 => TE::Reduce(133), 143 => TE::Reduce(133), 144 => TE::Reduce(133), 145 => TE::Reduce(133), 146 => TE::Reduce(133), 148 => TE::Reduce(133), 149 => TE::Reduce(133), 150 => TE::Reduce(133), 151 => TE::Reduce(133), 152 => TE::Reduce(133), 153 => TE::Reduce(133), 154 => TE::Reduce(133), 155 => TE::Reduce(133), 156 => TE::Reduce(133), 157 => TE::Reduce(133), 158 => TE::Reduce(133), 159 => TE::Reduce(133), 161 => TE::Reduce(133) },
+    hashmap! { 46 => TE::Transit(186), 48 => TE::Transit(187), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(135), 74 => TE::Reduce(135), 79 => TE::Reduce(135), 110 => TE::Reduce(135), 111 => TE::Reduce(135), 112 => TE::Reduce(135), 113 => TE::Reduce(135), 114 => TE::Reduce(135), 115 => TE::Reduce(135), 116 => TE::Reduce(135), 117 => TE::Reduce(135), 118 => TE::Reduce(135), 119 => TE::Reduce(135), 120 => TE::Reduce(135), 141 => TE::Reduce(135), 142 => TE::Reduce(135), 143 => TE::Reduce(135), 144 => TE::Reduce(135), 145 => TE::Reduce(135), 146 => TE::Reduce(135), 148 => TE::Reduce(135), 149 => TE::Reduce(135), 150 => TE::Reduce(135), 151 => TE::Reduce(135), 152 => TE::Reduce(135), 153 => TE::Reduce(135), 154 => TE::Reduce(135), 155 => TE::Reduce(135), 156 => TE::Reduce(135), 157 => TE::Reduce(135), 158 => TE::Reduce(135), 159 => TE::Reduce(135), 161 => TE::Reduce(135) },
+    hashmap! { 48 => TE::Transit(188), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 56 => TE::Transit(189), 57 => TE::Shift(39), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(190), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(191), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(192), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(193), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(194), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 144 => TE::Shift(195) },
+    hashmap! { 144 => TE::Shift(196) },
+    hashmap! { 144 => TE::Shift(197) },
+    hashmap! { 144 => TE::Shift(198) },
+    hashmap! { 144 => TE::Shift(199) },
+    hashmap! { 144 => TE::Shift(200) },
+    hashmap! { 72 => TE::Reduce(173), 74 => TE::Reduce(173), 79 => TE::Reduce(173), 110 => TE::Reduce(173), 111 => TE::Reduce(173), 112 => TE::Reduce(173), 113 => TE::Reduce(173), 114 => TE::Reduce(173), 115 => TE::Reduce(173), 116 => TE::Reduce(173), 117 => TE::Reduce(173), 118 => TE::Reduce(173), 119 => TE::Reduce(173), 120 => TE::Reduce(173), 141 => TE::Reduce(173), 142 => TE::Reduce(173), 143 => TE::Reduce(173), 144 => TE::Reduce(173), 145 => TE::Reduce(173), 146 => TE::Reduce(173), 148 => TE::Reduce(173), 149 => TE::Reduce(173), 150 => TE::Reduce(173), 151 => TE::Reduce(173), 152 => TE::Reduce(173), 153 => TE::Reduce(173), 154 => TE::Reduce(173), 155 => TE::Reduce(173), 156 => TE::Reduce(173), 157 => TE::Reduce(173), 158 => TE::Reduce(173), 159 => TE::Reduce(173), 161 => TE::Reduce(173) },
+    hashmap! { 56 => TE::Transit(202), 57 => TE::Shift(39), 58 => TE::Shift(201), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 72 => TE::Reduce(176), 74 => TE::Reduce(176), 79 => TE::Reduce(176), 110 => TE::Reduce(176), 111 => TE::Reduce(176), 112 => TE::Reduce(176), 113 => TE::Reduce(176), 114 => TE::Reduce(176), 115 => TE::Reduce(176), 116 => TE::Reduce(176), 117 => TE::Reduce(176), 118 => TE::Reduce(176), 119 => TE::Reduce(176), 120 => TE::Reduce(176), 141 => TE::Reduce(176), 142 => TE::Reduce(176), 143 => TE::Reduce(176), 144 => TE::Reduce(176), 145 => TE::Reduce(176), 146 => TE::Reduce(176), 148 => TE::Reduce(176), 149 => TE::Reduce(176), 150 => TE::Shift(203), 151 => TE::Reduce(176), 152 => TE::Reduce(176), 153 => TE::Reduce(176), 154 => TE::Reduce(176), 155 => TE::Reduce(176), 156 => TE::Reduce(176), 157 => TE::Reduce(176), 158 => TE::Reduce(176), 159 => TE::Reduce(176), 161 => TE::Reduce(176) },
+    hashmap! { 72 => TE::Reduce(177), 74 => TE::Reduce(177), 79 => TE::Reduce(177), 110 => TE::Reduce(177), 111 => TE::Reduce(177), 112 => TE::Reduce(177), 113 => TE::Reduce(177), 114 => TE::Reduce(177), 115 => TE::Reduce(177), 116 => TE::Reduce(177), 117 => TE::Reduce(177), 118 => TE::Reduce(177), 119 => TE::Reduce(177), 120 => TE::Reduce(177), 141 => TE::Reduce(177), 142 => TE::Reduce(177), 143 => TE::Reduce(177), 144 => TE::Reduce(177), 145 => TE::Reduce(177), 146 => TE::Reduce(177), 148 => TE::Reduce(177), 149 => TE::Reduce(177), 150 => TE::Shift(204), 151 => TE::Reduce(177), 152 => TE::Reduce(177), 153 => TE::Reduce(177), 154 => TE::Reduce(177), 155 => TE::Reduce(177), 156 => TE::Reduce(177), 157 => TE::Reduce(177), 158 => TE::Reduce(177), 159 => TE::Reduce(177), 161 => TE::Reduce(177) },
+    hashmap! { 144 => TE::Shift(205) },
+    hashmap! { 144 => TE::Shift(206) },
+    hashmap! { 161 => TE::Shift(207) },
+    hashmap! { 145 => TE::Shift(208) },
+    hashmap! { 57 => TE::Reduce(35), 58 => TE::Reduce(35), 59 => TE::Reduce(35), 65 => TE::Reduce(35), 66 => TE::Reduce(35), 67 => TE::Reduce(35), 68 => TE::Reduce(35), 132 => TE::Reduce(35), 133 => TE::Reduce(35), 134 => TE::Reduce(35), 135 => TE::Reduce(35), 136 => TE::Reduce(35), 137 => TE::Reduce(35), 138 => TE::Reduce(35), 140 => TE::Reduce(35), 144 => TE::Reduce(35) },
+    hashmap! { 19 => TE::Transit(209), 57 => TE::Reduce(45), 58 => TE::Reduce(45), 69 => TE::Reduce(45), 70 => TE::Reduce(45), 71 => TE::Reduce(45), 72 => TE::Reduce(45), 73 => TE::Reduce(45), 74 => TE::Reduce(45), 75 => TE::Reduce(45), 77 => TE::Reduce(45), 78 => TE::Reduce(45), 79 => TE::Reduce(45), 81 => TE::Reduce(45), 82 => TE::Reduce(45), 83 => TE::Reduce(45), 86 => TE::Reduce(45), 88 => TE::Reduce(45), 91 => TE::Reduce(45), 92 => TE::Reduce(45), 103 => TE::Reduce(45), 104 => TE::Reduce(45), 105 => TE::Reduce(45), 106 => TE::Reduce(45), 107 => TE::Reduce(45), 108 => TE::Reduce(45), 109 => TE::Reduce(45), 119 => TE::Reduce(45), 120 => TE::Reduce(45), 121 => TE::Reduce(45), 122 => TE::Reduce(45), 123 => TE::Reduce(45), 124 => TE::Reduce(45), 125 => TE::Reduce(45), 126 => TE::Reduce(45), 127 => TE::Reduce(45), 128 => TE::Reduce(45), 129 => TE::Reduce(45), 130 => TE::Reduce(45), 132 => TE::Reduce(45), 133 => TE::Reduce(45), 134 => TE::Reduce(45), 135 => TE::Reduce(45), 136 => TE::Reduce(45), 137 => TE::Reduce(45), 138 => TE::Reduce(45), 139 => TE::Reduce(45), 140 => TE::Reduce(45), 144 => TE::Reduce(45), 146 => TE::Reduce(45), 149 => TE::Reduce(45), 150 => TE::Reduce(45), 160 => TE::Reduce(45) },
+    hashmap! { 18 => TE::Transit(210), 139 => TE::Shift(155) },
+    hashmap! { 145 => TE::Shift(211) },
+    hashmap! { 57 => TE::Reduce(33), 58 => TE::Reduce(33), 59 => TE::Reduce(33), 65 => TE::Reduce(33), 66 => TE::Reduce(33), 67 => TE::Reduce(33), 68 => TE::Reduce(33), 132 => TE::Reduce(33), 133 => TE::Reduce(33), 134 => TE::Reduce(33), 135 => TE::Reduce(33), 136 => TE::Reduce(33), 137 => TE::Reduce(33), 138 => TE::Reduce(33), 140 => TE::Reduce(33), 144 => TE::Reduce(33) },
+    hashmap! { 48 => TE::Transit(212), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(213), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(214), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(215), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(216), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(217), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(218), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(219), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(220), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(221), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(222), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(223), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(224), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(225), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(226), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(227), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(228), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(229), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(230), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(231), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(232), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(165), 74 => TE::Reduce(165), 79 => TE::Reduce(165), 110 => TE::Reduce(165), 111 => TE::Reduce(165), 112 => TE::Reduce(165), 113 => TE::Reduce(165), 114 => TE::Reduce(165), 115 => TE::Reduce(165), 116 => TE::Reduce(165), 117 => TE::Reduce(165), 118 => TE::Reduce(165), 119 => TE::Reduce(165), 120 => TE::Reduce(165), 141 => TE::Reduce(165), 142 => TE::Reduce(165), 143 => TE::Reduce(165), 144 => TE::Reduce(165), 145 => TE::Reduce(165), 146 => TE::Reduce(165), 148 => TE::Reduce(165), 149 => TE::Reduce(165), 150 => TE::Reduce(165), 151 => TE::Reduce(165), 152 => TE::Reduce(165), 153 => TE::Reduce(165), 154 => TE::Reduce(165), 155 => TE::Reduce(165), 156 => TE::Reduce(165), 157 => TE::Reduce(165), 158 => TE::Reduce(165), 159 => TE::Reduce(165), 161 => TE::Reduce(165) },
+    hashmap! { 72 => TE::Reduce(166), 74 => TE::Reduce(166), 79 => TE::Reduce(166), 110 => TE::Reduce(166), 111 => TE::Reduce(166), 112 => TE::Reduce(166), 113 => TE::Reduce(166), 114 => TE::Reduce(166), 115 => TE::Reduce(166), 116 => TE::Reduce(166), 117 => TE::Reduce(166), 118 => TE::Reduce(166), 119 => TE::Reduce(166), 120 => TE::Reduce(166), 141 => TE::Reduce(166), 142 => TE::Reduce(166), 143 => TE::Reduce(166), 144 => TE::Reduce(166), 145 => TE::Reduce(166), 146 => TE::Reduce(166), 148 => TE::Reduce(166), 149 => TE::Reduce(166), 150 => TE::Reduce(166), 151 => TE::Reduce(166), 152 => TE::Reduce(166), 153 => TE::Reduce(166), 154 => TE::Reduce(166), 155 => TE::Reduce(166), 156 => TE::Reduce(166), 157 => TE::Reduce(166), 158 => TE::Reduce(166), 159 => TE::Reduce(166), 161 => TE::Reduce(166) },
+    hashmap! { 46 => TE::Transit(234), 48 => TE::Transit(235), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 53 => TE::Transit(233), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 145 => TE::Reduce(193), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 58 => TE::Reduce(190) },
+    hashmap! { 72 => TE::Reduce(187), 74 => TE::Reduce(187), 79 => TE::Reduce(187), 93 => TE::Reduce(187), 94 => TE::Reduce(187), 95 => TE::Reduce(187), 96 => TE::Reduce(187), 97 => TE::Reduce(187), 98 => TE::Reduce(187), 99 => TE::Reduce(187), 100 => TE::Reduce(187), 101 => TE::Reduce(187), 102 => TE::Reduce(187), 110 => TE::Reduce(187), 111 => TE::Reduce(187), 112 => TE::Reduce(187), 113 => TE::Reduce(187), 114 => TE::Reduce(187), 115 => TE::Reduce(187), 116 => TE::Reduce(187), 117 => TE::Reduce(187), 118 => TE::Reduce(187), 119 => TE::Reduce(187), 120 => TE::Reduce(187), 141 => TE::Reduce(187), 142 => TE::Reduce(187), 143 => TE::Reduce(187), 144 => TE::Shift(236), 145 => TE::Reduce(187), 146 => TE::Reduce(187), 147 => TE::Reduce(187), 148 => TE::Reduce(187), 149 => TE::Reduce(187), 150 => TE::Reduce(187), 151 => TE::Reduce(187), 152 => TE::Reduce(187), 153 => TE::Reduce(187), 154 => TE::Reduce(187), 155 => TE::Reduce(187), 156 => TE::Reduce(187), 157 => TE::Reduce(187), 158 => TE::Reduce(187), 159 => TE::Reduce(187), 161 => TE::Reduce(187) },
+    hashmap! { 46 => TE::Transit(234), 48 => TE::Transit(235), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 53 => TE::Transit(237), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 145 => TE::Reduce(193), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 141 => TE::Shift(239), 151 => TE::Shift(238) },
+    hashmap! { 74 => TE::Shift(240), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(107), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(107), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(241), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 145 => TE::Shift(242), 150 => TE::Shift(51) },
+    hashmap! { 48 => TE::Transit(188), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 56 => TE::Transit(243), 57 => TE::Shift(39), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(190), 145 => TE::Shift(53), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(161), 74 => TE::Reduce(161), 79 => TE::Reduce(161), 110 => TE::Reduce(161), 111 => TE::Reduce(161), 112 => TE::Reduce(161), 113 => TE::Reduce(161), 114 => TE::Reduce(161), 115 => TE::Reduce(161), 116 => TE::Reduce(161), 117 => TE::Reduce(161), 118 => TE::Reduce(161), 141 => TE::Reduce(161), 142 => TE::Reduce(161), 143 => TE::Reduce(161), 144 => TE::Shift(182), 145 => TE::Reduce(161), 146 => TE::Reduce(161), 148 => TE::Reduce(161), 149 => TE::Reduce(161), 150 => TE::Shift(178), 151 => TE::Reduce(161), 152 => TE::Reduce(161), 153 => TE::Reduce(161), 154 => TE::Reduce(161), 155 => TE::Reduce(161), 156 => TE::Reduce(161), 157 => TE::Reduce(161), 158 => TE::Reduce(161), 159 => TE::Reduce(161), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(162), 74 => TE::Reduce(162), 79 => TE::Reduce(162), 110 => TE::Reduce(162), 111 => TE::Reduce(162), 112 => TE::Reduce(162), 113 => TE::Reduce(162), 114 => TE::Reduce(162), 115 => TE::Reduce(162), 116 => TE::Reduce(162), 117 => TE::Reduce(162), 118 => TE::Reduce(162), 141 => TE::Reduce(162), 142 => TE::Reduce(162), 143 => TE::Reduce(162), 144 => TE::Shift(182), 145 => TE::Reduce(162), 146 => TE::Reduce(162), 148 => TE::Reduce(162), 149 => TE::Reduce(162), 150 => TE::Shift(178), 151 => TE::Reduce(162), 152 => TE::Reduce(162), 153 => TE::Reduce(162), 154 => TE::Reduce(162), 155 => TE::Reduce(162), 156 => TE::Reduce(162), 157 => TE::Reduce(162), 158 => TE::Reduce(162), 159 => TE::Reduce(162), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(163), 74 => TE::Reduce(163), 79 => TE::Reduce(163), 110 => TE::Reduce(163), 111 => TE::Reduce(163), 112 => TE::Reduce(163), 113 => TE::Reduce(163), 114 => TE::Reduce(163), 115 => TE::Reduce(163), 116 => TE::Reduce(163), 117 => TE::Reduce(163), 118 => TE::Reduce(163), 141 => TE::Reduce(163), 142 => TE::Reduce(163), 143 => TE::Reduce(163), 144 => TE::Shift(182), 145 => TE::Reduce(163), 146 => TE::Reduce(163), 148 => TE::Reduce(163), 149 => TE::Reduce(163), 150 => TE::Shift(178), 151 => TE::Reduce(163), 152 => TE::Reduce(163), 153 => TE::Reduce(163), 154 => TE::Reduce(163), 155 => TE::Reduce(163), 156 => TE::Reduce(163), 157 => TE::Reduce(163), 158 => TE::Reduce(163), 159 => TE::Reduce(163), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(164), 74 => TE::Reduce(164), 79 => TE::Reduce(164), 110 => TE::Reduce(164), 111 => TE::Reduce(164), 112 => TE::Reduce(164), 113 => TE::Reduce(164), 114 => TE::Reduce(164), 115 => TE::Reduce(164), 116 => TE::Reduce(164), 117 => TE::Reduce(164), 118 => TE::Reduce(164), 141 => TE::Reduce(164), 142 => TE::Reduce(164), 143 => TE::Reduce(164), 144 => TE::Shift(182), 145 => TE::Reduce(164), 146 => TE::Reduce(164), 148 => TE::Reduce(164), 149 => TE::Reduce(164), 150 => TE::Shift(178), 151 => TE::Reduce(164), 152 => TE::Reduce(164), 153 => TE::Reduce(164), 154 => TE::Reduce(164), 155 => TE::Reduce(164), 156 => TE::Reduce(164), 157 => TE::Reduce(164), 158 => TE::Reduce(164), 159 => TE::Reduce(164), 161 => TE::Shift(183) },
+    hashmap! { 145 => TE::Shift(244) },
+    hashmap! { 145 => TE::Shift(245) },
+    hashmap! { 48 => TE::Transit(246), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(247), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(248), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(249), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 142 => TE::Shift(251), 144 => TE::Shift(250) },
+    hashmap! { 150 => TE::Shift(252) },
+    hashmap! { 48 => TE::Transit(253), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 151 => TE::Shift(254), 160 => TE::Shift(137) },
+    hashmap! { 151 => TE::Shift(255) },
+    hashmap! { 48 => TE::Transit(256), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 16 => TE::Transit(257), 17 => TE::Transit(71), 54 => TE::Transit(72), 56 => TE::Transit(73), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40), 145 => TE::Reduce(40) },
+    hashmap! { 58 => TE::Reduce(191) },
+    hashmap! { 18 => TE::Transit(258), 139 => TE::Shift(155) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(260), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 140 => TE::Shift(259), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 57 => TE::Reduce(37), 58 => TE::Reduce(37), 59 => TE::Reduce(37), 65 => TE::Reduce(37), 66 => TE::Reduce(37), 67 => TE::Reduce(37), 68 => TE::Reduce(37), 132 => TE::Reduce(37), 133 => TE::Reduce(37), 134 => TE::Reduce(37), 135 => TE::Reduce(37), 136 => TE::Reduce(37), 137 => TE::Reduce(37), 138 => TE::Reduce(37), 140 => TE::Reduce(37), 144 => TE::Reduce(37) },
+    hashmap! { 146 => TE::Shift(298) },
+    hashmap! { 72 => TE::Reduce(136), 74 => TE::Reduce(136), 79 => TE::Reduce(136), 110 => TE::Reduce(136), 111 => TE::Reduce(136), 112 => TE::Reduce(136), 113 => TE::Reduce(136), 114 => TE::Reduce(136), 115 => TE::Reduce(136), 116 => TE::Reduce(136), 117 => TE::Reduce(136), 118 => TE::Reduce(136), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(136), 142 => TE::Reduce(136), 143 => TE::Reduce(136), 144 => TE::Shift(182), 145 => TE::Reduce(136), 146 => TE::Reduce(136), 148 => TE::Reduce(136), 149 => TE::Reduce(136), 150 => TE::Shift(178), 151 => TE::Reduce(136), 152 => TE::Reduce(136), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(136), 157 => TE::Reduce(136), 158 => TE::Reduce(136), 159 => TE::Reduce(136), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(137), 74 => TE::Reduce(137), 79 => TE::Reduce(137), 110 => TE::Reduce(137), 111 => TE::Reduce(137), 112 => TE::Reduce(137), 113 => TE::Reduce(137), 114 => TE::Reduce(137), 115 => TE::Reduce(137), 116 => TE::Reduce(137), 117 => TE::Reduce(137), 118 => TE::Reduce(137), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(137), 142 => TE::Reduce(137), 143 => TE::Reduce(137), 144 => TE::Shift(182), 145 => TE::Reduce(137), 146 => TE::Reduce(137), 148 => TE::Reduce(137), 149 => TE::Reduce(137), 150 => TE::Shift(178), 151 => TE::Reduce(137), 152 => TE::Reduce(137), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(137), 157 => TE::Reduce(137), 158 => TE::Reduce(137), 159 => TE::Reduce(137), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(138), 74 => TE::Reduce(138), 79 => TE::Reduce(138), 110 => TE::Reduce(138), 111 => TE::Reduce(138), 112 => TE::Reduce(138), 113 => TE::Reduce(138), 114 => TE::Reduce(138), 115 => TE::Reduce(138), 116 => TE::Reduce(138), 117 => TE::Reduce(138), 118 => TE::Reduce(138), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(138), 142 => TE::Reduce(138), 143 => TE::Reduce(138), 144 => TE::Shift(182), 145 => TE::Reduce(138), 146 => TE::Reduce(138), 148 => TE::Reduce(138), 149 => TE::Reduce(138), 150 => TE::Shift(178), 151 => TE::Reduce(138), 152 => TE::Reduce(138), 153 => TE::Reduce(138), 154 => TE::Reduce(138), 155 => TE::Reduce(138), 156 => TE::Reduce(138), 157 => TE::Reduce(138), 158 => TE::Reduce(138), 159 => TE::Reduce(138), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(139), 74 => TE::Reduce(139), 79 => TE::Reduce(139), 110 => TE::Reduce(139), 111 => TE::Reduce(139), 112 => TE::Reduce(139), 113 => TE::Reduce(139), 114 => TE::Reduce(139), 115 => TE::Reduce(139), 116 => TE::Reduce(139), 117 => TE::Reduce(139), 118 => TE::Reduce(139), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(139), 142 => TE::Reduce(139), 143 => TE::Reduce(139), 144 => TE::Shift(182), 145 => TE::Reduce(139), 146 => TE::Reduce(139), 148 => TE::Reduce(139), 149 => TE::Reduce(139), 150 => TE::Shift(178), 151 => TE::Reduce(139), 152 => TE::Reduce(139), 153 => TE::Reduce(139), 154 => TE::Reduce(139), 155 => TE::Reduce(139), 156 => TE::Reduce(139), 157 => TE::Reduce(139), 158 => TE::Reduce(139), 159 => TE::Reduce(139), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(140), 74 => TE::Reduce(140), 79 => TE::Reduce(140), 110 => TE::Reduce(140), 111 => TE::Reduce(140), 112 => TE::Reduce(140), 113 => TE::Reduce(140), 114 => TE::Reduce(140), 115 => TE::Reduce(140), 116 => TE::Reduce(140), 117 => TE::Reduce(140), 118 => TE::Reduce(140), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(140), 142 => TE::Reduce(140), 143 => TE::Reduce(140), 144 => TE::Shift(182), 145 => TE::Reduce(140), 146 => TE::Reduce(140), 148 => TE::Reduce(140), 149 => TE::Reduce(140), 150 => TE::Shift(178), 151 => TE::Reduce(140), 152 => TE::Reduce(140), 153 => TE::Reduce(140), 154 => TE::Reduce(140), 155 => TE::Reduce(140), 156 => TE::Reduce(140), 157 => TE::Reduce(140), 158 => TE::Reduce(140), 159 => TE::Reduce(140), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(141), 74 => TE::Reduce(141), 79 => TE::Reduce(141), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Reduce(141), 115 => TE::Reduce(141), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(141), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(141), 146 => TE::Reduce(141), 148 => TE::Reduce(141), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(141), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(141), 157 => TE::Reduce(141), 158 => TE::Reduce(141), 159 => TE::Reduce(141), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(142), 74 => TE::Reduce(142), 79 => TE::Reduce(142), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Reduce(142), 115 => TE::Reduce(142), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(142), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(142), 146 => TE::Reduce(142), 148 => TE::Reduce(142), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(142), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(142), 157 => TE::Reduce(142), 158 => TE::Reduce(142), 159 => TE::Reduce(142), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(143), 74 => TE::Reduce(143), 79 => TE::Reduce(143), 110 => TE::Reduce(143), 111 => TE::Reduce(143), 114 => TE::Reduce(143), 115 => TE::Reduce(143), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(143), 144 => TE::Shift(182), 145 => TE::Reduce(143), 146 => TE::Reduce(143), 148 => TE::Reduce(143), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(143), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(143), 157 => TE::Reduce(143), 158 => TE::Reduce(143), 159 => TE::Reduce(143), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(144), 74 => TE::Reduce(144), 79 => TE::Reduce(144), 110 => TE::Reduce(144), 111 => TE::Reduce(144), 114 => TE::Reduce(144), 115 => TE::Reduce(144), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(144), 144 => TE::Shift(182), 145 => TE::Reduce(144), 146 => TE::Reduce(144), 148 => TE::Reduce(144), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(144), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(144), 157 => TE::Reduce(144), 158 => TE::Reduce(144), 159 => TE::Reduce(144), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(145), 74 => TE::Reduce(145), 79 => TE::Reduce(145), 110 => TE::Reduce(145), 111 => TE::Reduce(145), 114 => TE::Reduce(145), 115 => TE::Reduce(145), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(145), 144 => TE::Shift(182), 145 => TE::Reduce(145), 146 => TE::Reduce(145), 148 => TE::Reduce(145), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(145), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(145), 157 => TE::Reduce(145), 158 => TE::Reduce(145), 159 => TE::Reduce(145), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(146), 74 => TE::Reduce(146), 79 => TE::Reduce(146), 110 => TE::Reduce(146), 111 => TE::Reduce(146), 114 => TE::Reduce(146), 115 => TE::Reduce(146), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(146), 144 => TE::Shift(182), 145 => TE::Reduce(146), 146 => TE::Reduce(146), 148 => TE::Reduce(146), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(146), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(146), 157 => TE::Reduce(146), 158 => TE::Reduce(146), 159 => TE::Reduce(146), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(147), 74 => TE::Reduce(147), 79 => TE::Reduce(147), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Reduce(147), 115 => TE::Reduce(147), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(147), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(147), 146 => TE::Reduce(147), 148 => TE::Reduce(147), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(147), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Reduce(147), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(148), 74 => TE::Reduce(148), 79 => TE::Reduce(148), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Reduce(148), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(148), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(148), 146 => TE::Reduce(148), 148 => TE::Reduce(148), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(148), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Reduce(148), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(149), 74 => TE::Reduce(149), 79 => TE::Reduce(149), 110 => TE::Reduce(149), 111 => TE::Reduce(149), 112 => TE::Reduce(149), 113 => TE::Reduce(149), 114 => TE::Reduce(149), 115 => TE::Reduce(149), 116 => TE::Reduce(149), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(149), 142 => TE::Reduce(149), 143 => TE::Reduce(149), 144 => TE::Shift(182), 145 => TE::Reduce(149), 146 => TE::Reduce(149), 148 => TE::Reduce(149), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(149), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(149), 157 => TE::Reduce(149), 158 => TE::Reduce(149), 159 => TE::Reduce(149), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(150), 74 => TE::Reduce(150), 79 => TE::Reduce(150), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Reduce(150), 115 => TE::Reduce(150), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(150), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(150), 146 => TE::Reduce(150), 148 => TE::Reduce(150), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(150), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(150), 157 => TE::Reduce(150), 158 => TE::Reduce(150), 159 => TE::Reduce(150), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(151), 74 => TE::Reduce(151), 79 => TE::Reduce(151), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Reduce(151), 115 => TE::Reduce(151), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(151), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(151), 146 => TE::Reduce(151), 148 => TE::Reduce(151), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(151), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Reduce(151), 158 => TE::Shift(175), 159 => TE::Reduce(151), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(152), 74 => TE::Reduce(152), 79 => TE::Reduce(152), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Reduce(152), 115 => TE::Reduce(152), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(152), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(152), 146 => TE::Reduce(152), 148 => TE::Reduce(152), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(152), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Reduce(152), 158 => TE::Reduce(152), 159 => TE::Reduce(152), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(153), 74 => TE::Reduce(153), 79 => TE::Reduce(153), 110 => TE::Reduce(153), 111 => TE::Reduce(153), 112 => TE::Reduce(153), 113 => TE::Reduce(153), 114 => TE::Reduce(153), 115 => TE::Reduce(153), 116 => TE::Reduce(153), 117 => TE::Reduce(153), 118 => TE::Reduce(153), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(153), 142 => TE::Reduce(153), 143 => TE::Reduce(153), 144 => TE::Shift(182), 145 => TE::Reduce(153), 146 => TE::Reduce(153), 148 => TE::Reduce(153), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(153), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(153), 157 => TE::Reduce(153), 158 => TE::Reduce(153), 159 => TE::Reduce(153), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(154), 74 => TE::Reduce(154), 79 => TE::Reduce(154), 110 => TE::Reduce(154), 111 => TE::Reduce(154), 112 => TE::Reduce(154), 113 => TE::Reduce(154), 114 => TE::Reduce(154), 115 => TE::Reduce(154), 116 => TE::Reduce(154), 117 => TE::Reduce(154), 118 => TE::Reduce(154), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(154), 142 => TE::Reduce(154), 143 => TE::Reduce(154), 144 => TE::Shift(182), 145 => TE::Reduce(154), 146 => TE::Reduce(154), 148 => TE::Reduce(154), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(154), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Reduce(154), 157 => TE::Reduce(154), 158 => TE::Reduce(154), 159 => TE::Reduce(154), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 148 => TE::Shift(299), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Shift(300), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 148 => TE::Shift(301), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 145 => TE::Shift(302) },
+    hashmap! { 141 => TE::Shift(239), 145 => TE::Reduce(192) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(107), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(107), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 46 => TE::Transit(234), 48 => TE::Transit(235), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 53 => TE::Transit(303), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 145 => TE::Reduce(193), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 145 => TE::Shift(304) },
+    hashmap! { 72 => TE::Reduce(134), 74 => TE::Reduce(134), 79 => TE::Reduce(134), 110 => TE::Reduce(134), 111 => TE::Reduce(134), 112 => TE::Reduce(134), 113 => TE::Reduce(134), 114 => TE::Reduce(134), 115 => TE::Reduce(134), 116 => TE::Reduce(134), 117 => TE::Reduce(134), 118 => TE::Reduce(134), 119 => TE::Reduce(134), 120 => TE::Reduce(134), 141 => TE::Reduce(134), 142 => TE::Reduce(134), 143 => TE::Reduce(134), 144 => TE::Reduce(134), 145 => TE::Reduce(134), 146 => TE::Reduce(134), 148 => TE::Reduce(134), 149 => TE::Reduce(134), 150 => TE::Reduce(134), 151 => TE::Reduce(134), 152 => TE::Reduce(134), 153 => TE::Reduce(134), 154 => TE::Reduce(134), 155 => TE::Reduce(134), 156 => TE::Reduce(134), 157 => TE::Reduce(134), 158 => TE::Reduce(134), 159 => TE::Reduce(134), 161 => TE::Reduce(134) },
+    hashmap! { 48 => TE::Transit(305), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 58 => TE::Shift(306) },
+    hashmap! { 72 => TE::Reduce(160), 74 => TE::Reduce(160), 79 => TE::Reduce(160), 110 => TE::Reduce(160), 111 => TE::Reduce(160), 112 => TE::Reduce(160), 113 => TE::Reduce(160), 114 => TE::Reduce(160), 115 => TE::Reduce(160), 116 => TE::Reduce(160), 117 => TE::Reduce(160), 118 => TE::Reduce(160), 119 => TE::Reduce(160), 120 => TE::Reduce(160), 141 => TE::Reduce(160), 142 => TE::Reduce(160), 143 => TE::Reduce(160), 144 => TE::Reduce(160), 145 => TE::Reduce(160), 146 => TE::Reduce(160), 148 => TE::Reduce(160), 149 => TE::Reduce(160), 150 => TE::Reduce(160), 151 => TE::Reduce(160), 152 => TE::Reduce(160), 153 => TE::Reduce(160), 154 => TE::Reduce(160), 155 => TE::Reduce(160), 156 => TE::Reduce(160), 157 => TE::Reduce(160), 158 => TE::Reduce(160), 159 => TE::Reduce(160), 161 => TE::Reduce(160) },
+    hashmap! { 48 => TE::Transit(307), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 141 => TE::Shift(66), 145 => TE::Shift(308), 150 => TE::Shift(51) },
+    hashmap! { 72 => TE::Reduce(167), 74 => TE::Reduce(167), 79 => TE::Reduce(167), 110 => TE::Reduce(167), 111 => TE::Reduce(167), 112 => TE::Reduce(167), 113 => TE::Reduce(167), 114 => TE::Reduce(167), 115 => TE::Reduce(167), 116 => TE::Reduce(167), 117 => TE::Reduce(167), 118 => TE::Reduce(167), 119 => TE::Reduce(167), 120 => TE::Reduce(167), 141 => TE::Reduce(167), 142 => TE::Reduce(167), 143 => TE::Reduce(167), 144 => TE::Reduce(167), 145 => TE::Reduce(167), 146 => TE::Reduce(167), 148 => TE::Reduce(167), 149 => TE::Reduce(167), 150 => TE::Reduce(167), 151 => TE::Reduce(167), 152 => TE::Reduce(167), 153 => TE::Reduce(167), 154 => TE::Reduce(167), 155 => TE::Reduce(167), 156 => TE::Reduce(167), 157 => TE::Reduce(167), 158 => TE::Reduce(167), 159 => TE::Reduce(167), 161 => TE::Reduce(167) },
+    hashmap! { 72 => TE::Reduce(168), 74 => TE::Reduce(168), 79 => TE::Reduce(168), 110 => TE::Reduce(168), 111 => TE::Reduce(168), 112 => TE::Reduce(168), 113 => TE::Reduce(168), 114 => TE::Reduce(168), 115 => TE::Reduce(168), 116 => TE::Reduce(168), 117 => TE::Reduce(168), 118 => TE::Reduce(168), 119 => TE::Reduce(168), 120 => TE::Reduce(168), 141 => TE::Reduce(168), 142 => TE::Reduce(168), 143 => TE::Reduce(168), 144 => TE::Reduce(168), 145 => TE::Reduce(168), 146 => TE::Reduce(168), 148 => TE::Reduce(168), 149 => TE::Reduce(168), 150 => TE::Reduce(168), 151 => TE::Reduce(168), 152 => TE::Reduce(168), 153 => TE::Reduce(168), 154 => TE::Reduce(168), 155 => TE::Reduce(168), 156 => TE::Reduce(168), 157 => TE::Reduce(168), 158 => TE::Reduce(168), 159 => TE::Reduce(168), 161 => TE::Reduce(168) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(309), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(310), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(311), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(312), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 46 => TE::Transit(234), 48 => TE::Transit(235), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 53 => TE::Transit(313), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 145 => TE::Reduce(193), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 55 => TE::Transit(314), 56 => TE::Transit(75), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 48 => TE::Transit(315), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 151 => TE::Shift(62), 160 => TE::Shift(137) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Shift(316), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(185), 74 => TE::Reduce(185), 79 => TE::Reduce(185), 110 => TE::Reduce(185), 111 => TE::Reduce(185), 112 => TE::Reduce(185), 113 => TE::Reduce(185), 114 => TE::Reduce(185), 115 => TE::Reduce(185), 116 => TE::Reduce(185), 117 => TE::Reduce(185), 118 => TE::Reduce(185), 119 => TE::Reduce(185), 120 => TE::Reduce(185), 141 => TE::Reduce(185), 142 => TE::Reduce(185), 143 => TE::Reduce(185), 144 => TE::Reduce(185), 145 => TE::Reduce(185), 146 => TE::Reduce(185), 148 => TE::Reduce(185), 149 => TE::Reduce(185), 150 => TE::Reduce(185), 151 => TE::Reduce(185), 152 => TE::Reduce(185), 153 => TE::Reduce(185), 154 => TE::Reduce(185), 155 => TE::Reduce(185), 156 => TE::Reduce(185), 157 => TE::Reduce(185), 158 => TE::Reduce(185), 159 => TE::Reduce(185), 161 => TE::Reduce(185) },
+    hashmap! { 72 => TE::Reduce(186), 74 => TE::Reduce(186), 79 => TE::Reduce(186), 110 => TE::Reduce(186), 111 => TE::Reduce(186), 112 => TE::Reduce(186), 113 => TE::Reduce(186), 114 => TE::Reduce(186), 115 => TE::Reduce(186), 116 => TE::Reduce(186), 117 => TE::Reduce(186), 118 => TE::Reduce(186), 119 => TE::Reduce(186), 120 => TE::Reduce(186), 141 => TE::Reduce(186), 142 => TE::Reduce(186), 143 => TE::Reduce(186), 144 => TE::Reduce(186), 145 => TE::Reduce(186), 146 => TE::Reduce(186), 148 => TE::Reduce(186), 149 => TE::Reduce(186), 150 => TE::Reduce(186), 151 => TE::Reduce(186), 152 => TE::Reduce(186), 153 => TE::Reduce(186), 154 => TE::Reduce(186), 155 => TE::Reduce(186), 156 => TE::Reduce(186), 157 => TE::Reduce(186), 158 => TE::Reduce(186), 159 => TE::Reduce(186), 161 => TE::Reduce(186) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Shift(317), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 145 => TE::Shift(318) },
+    hashmap! { 57 => TE::Reduce(36), 58 => TE::Reduce(36), 59 => TE::Reduce(36), 65 => TE::Reduce(36), 66 => TE::Reduce(36), 67 => TE::Reduce(36), 68 => TE::Reduce(36), 132 => TE::Reduce(36), 133 => TE::Reduce(36), 134 => TE::Reduce(36), 135 => TE::Reduce(36), 136 => TE::Reduce(36), 137 => TE::Reduce(36), 138 => TE::Reduce(36), 140 => TE::Reduce(36), 144 => TE::Reduce(36) },
+    hashmap! { 57 => TE::Reduce(43), 58 => TE::Reduce(43), 59 => TE::Reduce(43), 65 => TE::Reduce(43), 66 => TE::Reduce(43), 67 => TE::Reduce(43), 68 => TE::Reduce(43), 69 => TE::Reduce(43), 70 => TE::Reduce(43), 71 => TE::Reduce(43), 72 => TE::Reduce(43), 73 => TE::Reduce(43), 74 => TE::Reduce(43), 75 => TE::Reduce(43), 77 => TE::Reduce(43), 78 => TE::Reduce(43), 79 => TE::Reduce(43), 80 => TE::Reduce(43), 81 => TE::Reduce(43), 82 => TE::Reduce(43), 83 => TE::Reduce(43), 84 => TE::Reduce(43), 85 => TE::Reduce(43), 86 => TE::Reduce(43), 87 => TE::Reduce(43), 88 => TE::Reduce(43), 89 => TE::Reduce(43), 90 => TE::Reduce(43), 91 => TE::Reduce(43), 92 => TE::Reduce(43), 103 => TE::Reduce(43), 104 => TE::Reduce(43), 105 => TE::Reduce(43), 106 => TE::Reduce(43), 107 => TE::Reduce(43), 108 => TE::Reduce(43), 109 => TE::Reduce(43), 110 => TE::Reduce(43), 111 => TE::Reduce(43), 112 => TE::Reduce(43), 113 => TE::Reduce(43), 114 => TE::Reduce(43), 115 => TE::Reduce(43), 116 => TE::Reduce(43), 117 => TE::Reduce(43), 118 => TE::Reduce(43), 119 => TE::Reduce(43), 120 => TE::Reduce(43), 121 => TE::Reduce(43), 122 => TE::Reduce(43), 123 => TE::Reduce(43), 124 => TE::Reduce(43), 125 => TE::Reduce(43), 126 => TE::Reduce(43), 127 => TE::Reduce(43), 128 => TE::Reduce(43), 129 => TE::Reduce(43), 130 => TE::Reduce(43), 132 => TE::Reduce(43), 133 => TE::Reduce(43), 134 => TE::Reduce(43), 135 => TE::Reduce(43), 136 => TE::Reduce(43), 137 => TE::Reduce(43), 138 => TE::Reduce(43), 139 => TE::Reduce(43), 140 => TE::Reduce(43), 141 => TE::Reduce(43), 142 => TE::Reduce(43), 143 => TE::Reduce(43), 144 => TE::Reduce(43), 145 => TE::Reduce(43), 146 => TE::Reduce(43), 148 => TE::Reduce(43), 149 => TE::Reduce(43), 150 => TE::Reduce(43), 151 => TE::Reduce(43), 152 => TE::Reduce(43), 153 => TE::Reduce(43), 154 => TE::Reduce(43), 155 => TE::Reduce(43), 156 => TE::Reduce(43), 157 => TE::Reduce(43), 158 => TE::Reduce(43), 159 => TE::Reduce(43), 160 => TE::Reduce(43), 161 => TE::Reduce(43) },
+    hashmap! { 57 => TE::Reduce(44), 58 => TE::Reduce(44), 69 => TE::Reduce(44), 70 => TE::Reduce(44), 71 => TE::Reduce(44), 72 => TE::Reduce(44), 73 => TE::Reduce(44), 74 => TE::Reduce(44), 75 => TE::Reduce(44), 77 => TE::Reduce(44), 78 => TE::Reduce(44), 79 => TE::Reduce(44), 81 => TE::Reduce(44), 82 => TE::Reduce(44), 83 => TE::Reduce(44), 84 => TE::Reduce(44), 85 => TE::Reduce(44), 86 => TE::Reduce(44), 88 => TE::Reduce(44), 91 => TE::Reduce(44), 92 => TE::Reduce(44), 103 => TE::Reduce(44), 104 => TE::Reduce(44), 105 => TE::Reduce(44), 106 => TE::Reduce(44), 107 => TE::Reduce(44), 108 => TE::Reduce(44), 109 => TE::Reduce(44), 119 => TE::Reduce(44), 120 => TE::Reduce(44), 121 => TE::Reduce(44), 122 => TE::Reduce(44), 123 => TE::Reduce(44), 124 => TE::Reduce(44), 125 => TE::Reduce(44), 126 => TE::Reduce(44), 127 => TE::Reduce(44), 128 => TE::Reduce(44), 129 => TE::Reduce(44), 130 => TE::Reduce(44), 132 => TE::Reduce(44), 133 => TE::Reduce(44), 134 => TE::Reduce(44), 135 => TE::Reduce(44), 136 => TE::Reduce(44), 137 => TE::Reduce(44), 138 => TE::Reduce(44), 139 => TE::Reduce(44), 140 => TE::Reduce(44), 144 => TE::Reduce(44), 146 => TE::Reduce(44), 149 => TE::Reduce(44), 150 => TE::Reduce(44), 160 => TE::Reduce(44) },
+    hashmap! { 146 => TE::Shift(319) },
+    hashmap! { 57 => TE::Reduce(47), 58 => TE::Reduce(47), 69 => TE::Reduce(47), 70 => TE::Reduce(47), 71 => TE::Reduce(47), 72 => TE::Reduce(47), 73 => TE::Reduce(47), 74 => TE::Reduce(47), 75 => TE::Reduce(47), 77 => TE::Reduce(47), 78 => TE::Reduce(47), 79 => TE::Reduce(47), 80 => TE::Reduce(47), 81 => TE::Reduce(47), 82 => TE::Reduce(47), 83 => TE::Reduce(47), 84 => TE::Reduce(47), 85 => TE::Reduce(47), 86 => TE::Reduce(47), 87 => TE::Reduce(47), 88 => TE::Reduce(47), 91 => TE::Reduce(47), 92 => TE::Reduce(47), 103 => TE::Reduce(47), 104 => TE::Reduce(47), 105 => TE::Reduce(47), 106 => TE::Reduce(47), 107 => TE::Reduce(47), 108 => TE::Reduce(47), 109 => TE::Reduce(47), 119 => TE::Reduce(47), 120 => TE::Reduce(47), 121 => TE::Reduce(47), 122 => TE::Reduce(47), 123 => TE::Reduce(47), 124 => TE::Reduce(47), 125 => TE::Reduce(47), 126 => TE::Reduce(47), 127 => TE::Reduce(47), 128 => TE::Reduce(47), 129 => TE::Reduce(47), 130 => TE::Reduce(47), 132 => TE::Reduce(47), 133 => TE::Reduce(47), 134 => TE::Reduce(47), 135 => TE::Reduce(47), 136 => TE::Reduce(47), 137 => TE::Reduce(47), 138 => TE::Reduce(47), 139 => TE::Reduce(47), 140 => TE::Reduce(47), 144 => TE::Reduce(47), 146 => TE::Reduce(47), 149 => TE::Reduce(47), 150 => TE::Reduce(47), 160 => TE::Reduce(47) },
+    hashmap! { 57 => TE::Reduce(48), 58 => TE::Reduce(48), 69 => TE::Reduce(48), 70 => TE::Reduce(48), 71 => TE::Reduce(48), 72 => TE::Reduce(48), 73 => TE::Reduce(48), 74 => TE::Reduce(48), 75 => TE::Reduce(48), 77 => TE::Reduce(48), 78 => TE::Reduce(48), 79 => TE::Reduce(48), 80 => TE::Reduce(48), 81 => TE::Reduce(48), 82 => TE::Reduce(48), 83 => TE::Reduce(48), 84 => TE::Reduce(48), 85 => TE::Reduce(48), 86 => TE::Reduce(48), 87 => TE::Reduce(48), 88 => TE::Reduce(48), 91 => TE::Reduce(48), 92 => TE::Reduce(48), 103 => TE::Reduce(48), 104 => TE::Reduce(48), 105 => TE::Reduce(48), 106 => TE::Reduce(48), 107 => TE::Reduce(48), 108 => TE::Reduce(48), 109 => TE::Reduce(48), 119 => TE::Reduce(48), 120 => TE::Reduce(48), 121 => TE::Reduce(48), 122 => TE::Reduce(48), 123 => TE::Reduce(48), 124 => TE::Reduce(48), 125 => TE::Reduce(48), 126 => TE::Reduce(48), 127 => TE::Reduce(48), 128 => TE::Reduce(48), 129 => TE::Reduce(48), 130 => TE::Reduce(48), 132 => TE::Reduce(48), 133 => TE::Reduce(48), 134 => TE::Reduce(48), 135 => TE::Reduce(48), 136 => TE::Reduce(48), 137 => TE::Reduce(48), 138 => TE::Reduce(48), 139 => TE::Reduce(48), 140 => TE::Reduce(48), 144 => TE::Reduce(48), 146 => TE::Reduce(48), 149 => TE::Reduce(48), 150 => TE::Reduce(48), 160 => TE::Reduce(48) },
+    hashmap! { 146 => TE::Shift(320) },
+    hashmap! { 146 => TE::Shift(321) },
+    hashmap! { 146 => TE::Shift(322) },
+    hashmap! { 146 => TE::Shift(323) },
+    hashmap! { 93 => TE::Reduce(188), 94 => TE::Reduce(188), 95 => TE::Reduce(188), 96 => TE::Reduce(188), 97 => TE::Reduce(188), 98 => TE::Reduce(188), 99 => TE::Reduce(188), 100 => TE::Reduce(188), 101 => TE::Reduce(188), 102 => TE::Reduce(188), 110 => TE::Reduce(188), 111 => TE::Reduce(188), 112 => TE::Reduce(188), 113 => TE::Reduce(188), 114 => TE::Reduce(188), 115 => TE::Reduce(188), 116 => TE::Reduce(188), 117 => TE::Reduce(188), 118 => TE::Reduce(188), 119 => TE::Reduce(188), 120 => TE::Reduce(188), 142 => TE::Reduce(188), 143 => TE::Reduce(188), 144 => TE::Shift(185), 146 => TE::Reduce(188), 147 => TE::Reduce(188), 148 => TE::Shift(324), 149 => TE::Reduce(188), 150 => TE::Reduce(188), 152 => TE::Reduce(188), 153 => TE::Reduce(188), 154 => TE::Reduce(188), 155 => TE::Reduce(188), 156 => TE::Reduce(188), 157 => TE::Reduce(188), 158 => TE::Reduce(188), 159 => TE::Reduce(188), 161 => TE::Reduce(188) },
+    hashmap! { 146 => TE::Shift(325) },
+    hashmap! { 57 => TE::Reduce(55), 58 => TE::Reduce(55), 69 => TE::Reduce(55), 70 => TE::Reduce(55), 71 => TE::Reduce(55), 72 => TE::Reduce(55), 73 => TE::Reduce(55), 74 => TE::Reduce(55), 75 => TE::Reduce(55), 77 => TE::Reduce(55), 78 => TE::Reduce(55), 79 => TE::Reduce(55), 80 => TE::Reduce(55), 81 => TE::Reduce(55), 82 => TE::Reduce(55), 83 => TE::Reduce(55), 84 => TE::Reduce(55), 85 => TE::Reduce(55), 86 => TE::Reduce(55), 87 => TE::Reduce(55), 88 => TE::Reduce(55), 91 => TE::Reduce(55), 92 => TE::Reduce(55), 103 => TE::Reduce(55), 104 => TE::Reduce(55), 105 => TE::Reduce(55), 106 => TE::Reduce(55), 107 => TE::Reduce(55), 108 => TE::Reduce(55), 109 => TE::Reduce(55), 119 => TE::Reduce(55), 120 => TE::Reduce(55), 121 => TE::Reduce(55), 122 => TE::Reduce(55), 123 => TE::Reduce(55), 124 => TE::Reduce(55), 125 => TE::Reduce(55), 126 => TE::Reduce(55), 127 => TE::Reduce(55), 128 => TE::Reduce(55), 129 => TE::Reduce(55), 130 => TE::Reduce(55), 132 => TE::Reduce(55), 133 => TE::Reduce(55), 134 => TE::Reduce(55), 135 => TE::Reduce(55), 136 => TE::Reduce(55), 137 => TE::Reduce(55), 138 => TE::Reduce(55), 139 => TE::Reduce(55), 140 => TE::Reduce(55), 144 => TE::Reduce(55), 146 => TE::Reduce(55), 149 => TE::Reduce(55), 150 => TE::Reduce(55), 160 => TE::Reduce(55) },
+    hashmap! { 57 => TE::Reduce(56), 58 => TE::Reduce(56), 69 => TE::Reduce(56), 70 => TE::Reduce(56), 71 => TE::Reduce(56), 72 => TE::Reduce(56), 73 => TE::Reduce(56), 74 => TE::Reduce(56), 75 => TE::Reduce(56), 77 => TE::Reduce(56), 78 => TE::Reduce(56), 79 => TE::Reduce(56), 80 => TE::Reduce(56), 81 => TE::Reduce(56), 82 => TE::Reduce(56), 83 => TE::Reduce(56), 84 => TE::Reduce(56), 85 => TE::Reduce(56), 86 => TE::Reduce(56), 87 => TE::Reduce(56), 88 => TE::Reduce(56), 91 => TE::Reduce(56), 92 => TE::Reduce(56), 103 => TE::Reduce(56), 104 => TE::Reduce(56), 105 => TE::Reduce(56), 106 => TE::Reduce(56), 107 => TE::Reduce(56), 108 => TE::Reduce(56), 109 => TE::Reduce(56), 119 => TE::Reduce(56), 120 => TE::Reduce(56), 121 => TE::Reduce(56), 122 => TE::Reduce(56), 123 => TE::Reduce(56), 124 => TE::Reduce(56), 125 => TE::Reduce(56), 126 => TE::Reduce(56), 127 => TE::Reduce(56), 128 => TE::Reduce(56), 129 => TE::Reduce(56), 130 => TE::Reduce(56), 132 => TE::Reduce(56), 133 => TE::Reduce(56), 134 => TE::Reduce(56), 135 => TE::Reduce(56), 136 => TE::Reduce(56), 137 => TE::Reduce(56), 138 => TE::Reduce(56), 139 => TE::Reduce(56), 140 => TE::Reduce(56), 144 => TE::Reduce(56), 146 => TE::Reduce(56), 149 => TE::Reduce(56), 150 => TE::Reduce(56), 160 => TE::Reduce(56) },
+    hashmap! { 57 => TE::Reduce(57), 58 => TE::Reduce(57), 69 => TE::Reduce(57), 70 => TE::Reduce(57), 71 => TE::Reduce(57), 72 => TE::Reduce(57), 73 => TE::Reduce(57), 74 => TE::Reduce(57), 75 => TE::Reduce(57), 77 => TE::Reduce(57), 78 => TE::Reduce(57), 79 => TE::Reduce(57), 80 => TE::Reduce(57), 81 => TE::Reduce(57), 82 => TE::Reduce(57), 83 => TE::Reduce(57), 84 => TE::Reduce(57), 85 => TE::Reduce(57), 86 => TE::Reduce(57), 87 => TE::Reduce(57), 88 => TE::Reduce(57), 91 => TE::Reduce(57), 92 => TE::Reduce(57), 103 => TE::Reduce(57), 104 => TE::Reduce(57), 105 => TE::Reduce(57), 106 => TE::Reduce(57), 107 => TE::Reduce(57), 108 => TE::Reduce(57), 109 => TE::Reduce(57), 119 => TE::Reduce(57), 120 => TE::Reduce(57), 121 => TE::Reduce(57), 122 => TE::Reduce(57), 123 => TE::Reduce(57), 124 => TE::Reduce(57), 125 => TE::Reduce(57), 126 => TE::Reduce(57), 127 => TE::Reduce(57), 128 => TE::Reduce(57), 129 => TE::Reduce(57), 130 => TE::Reduce(57), 132 => TE::Reduce(57), 133 => TE::Reduce(57), 134 => TE::Reduce(57), 135 => TE::Reduce(57), 136 => TE::Reduce(57), 137 => TE::Reduce(57), 138 => TE::Reduce(57), 139 => TE::Reduce(57), 140 => TE::Reduce(57), 144 => TE::Reduce(57), 146 => TE::Reduce(57), 149 => TE::Reduce(57), 150 => TE::Reduce(57), 160 => TE::Reduce(57) },
+    hashmap! { 57 => TE::Reduce(58), 58 => TE::Reduce(58), 69 => TE::Reduce(58), 70 => TE::Reduce(58), 71 => TE::Reduce(58), 72 => TE::Reduce(58), 73 => TE::Reduce(58), 74 => TE::Reduce(58), 75 => TE::Reduce(58), 77 => TE::Reduce(58), 78 => TE::Reduce(58), 79 => TE::Reduce(58), 80 => TE::Reduce(58), 81 => TE::Reduce(58), 82 => TE::Reduce(58), 83 => TE::Reduce(58), 84 => TE::Reduce(58), 85 => TE::Reduce(58), 86 => TE::Reduce(58), 87 => TE::Reduce(58), 88 => TE::Reduce(58), 91 => TE::Reduce(58), 92 => TE::Reduce(58), 103 => TE::Reduce(58), 104 => TE::Reduce(58), 105 => TE::Reduce(58), 106 => TE::Reduce(58), 107 => TE::Reduce(58), 108 => TE::Reduce(58), 109 => TE::Reduce(58), 119 => TE::Reduce(58), 120 => TE::Reduce(58), 121 => TE::Reduce(58), 122 => TE::Reduce(58), 123 => TE::Reduce(58), 124 => TE::Reduce(58), 125 => TE::Reduce(58), 126 => TE::Reduce(58), 127 => TE::Reduce(58), 128 => TE::Reduce(58), 129 => TE::Reduce(58), 130 => TE::Reduce(58), 132 => TE::Reduce(58), 133 => TE::Reduce(58), 134 => TE::Reduce(58), 135 => TE::Reduce(58), 136 => TE::Reduce(58), 137 => TE::Reduce(58), 138 => TE::Reduce(58), 139 => TE::Reduce(58), 140 => TE::Reduce(58), 144 => TE::Reduce(58), 146 => TE::Reduce(58), 149 => TE::Reduce(58), 150 => TE::Reduce(58), 160 => TE::Reduce(58) },
+    hashmap! { 48 => TE::Transit(326), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 144 => TE::Shift(327) },
+    hashmap! { 144 => TE::Shift(328), 161 => TE::Shift(207) },
+    hashmap! { 93 => TE::Shift(330), 94 => TE::Shift(331), 95 => TE::Shift(332), 96 => TE::Shift(333), 97 => TE::Shift(334), 98 => TE::Shift(335), 99 => TE::Shift(336), 100 => TE::Shift(337), 101 => TE::Shift(338), 102 => TE::Shift(339), 110 => TE::Reduce(124), 111 => TE::Reduce(124), 112 => TE::Reduce(124), 113 => TE::Reduce(124), 114 => TE::Reduce(124), 115 => TE::Reduce(124), 116 => TE::Reduce(124), 117 => TE::Reduce(124), 118 => TE::Reduce(124), 119 => TE::Reduce(124), 120 => TE::Reduce(124), 142 => TE::Reduce(124), 143 => TE::Reduce(124), 144 => TE::Reduce(124), 145 => TE::Reduce(124), 146 => TE::Reduce(124), 147 => TE::Shift(329), 149 => TE::Reduce(124), 150 => TE::Reduce(124), 152 => TE::Reduce(124), 153 => TE::Reduce(124), 154 => TE::Reduce(124), 155 => TE::Reduce(124), 156 => TE::Reduce(124), 157 => TE::Reduce(124), 158 => TE::Reduce(124), 159 => TE::Reduce(124), 161 => TE::Reduce(124) },
+    hashmap! { 58 => TE::Shift(340), 150 => TE::Shift(51) },
+    hashmap! { 58 => TE::Shift(341) },
+    hashmap! { 145 => TE::Reduce(121), 146 => TE::Reduce(121) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(122), 146 => TE::Reduce(122), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 139 => TE::Shift(343), 144 => TE::Shift(342) },
+    hashmap! { 57 => TE::Reduce(64), 58 => TE::Reduce(64), 69 => TE::Reduce(64), 70 => TE::Reduce(64), 71 => TE::Reduce(64), 72 => TE::Reduce(64), 73 => TE::Reduce(64), 74 => TE::Reduce(64), 75 => TE::Reduce(64), 77 => TE::Reduce(64), 78 => TE::Reduce(64), 79 => TE::Reduce(64), 80 => TE::Reduce(64), 81 => TE::Reduce(64), 82 => TE::Reduce(64), 83 => TE::Reduce(64), 84 => TE::Reduce(64), 85 => TE::Reduce(64), 86 => TE::Reduce(64), 87 => TE::Reduce(64), 88 => TE::Reduce(64), 91 => TE::Reduce(64), 92 => TE::Reduce(64), 103 => TE::Reduce(64), 104 => TE::Reduce(64), 105 => TE::Reduce(64), 106 => TE::Reduce(64), 107 => TE::Reduce(64), 108 => TE::Reduce(64), 109 => TE::Reduce(64), 119 => TE::Reduce(64), 120 => TE::Reduce(64), 121 => TE::Reduce(64), 122 => TE::Reduce(64), 123 => TE::Reduce(64), 124 => TE::Reduce(64), 125 => TE::Reduce(64), 126 => TE::Reduce(64), 127 => TE::Reduce(64), 128 => TE::Reduce(64), 129 => TE::Reduce(64), 130 => TE::Reduce(64), 132 => TE::Reduce(64), 133 => TE::Reduce(64), 134 => TE::Reduce(64), 135 => TE::Reduce(64), 136 => TE::Reduce(64), 137 => TE::Reduce(64), 138 => TE::Reduce(64), 139 => TE::Reduce(64), 140 => TE::Reduce(64), 144 => TE::Reduce(64), 146 => TE::Reduce(64), 149 => TE::Reduce(64), 150 => TE::Reduce(64), 160 => TE::Reduce(64) },
+    hashmap! { 146 => TE::Shift(344) },
+    hashmap! { 57 => TE::Reduce(66), 58 => TE::Reduce(66), 69 => TE::Reduce(66), 70 => TE::Reduce(66), 71 => TE::Reduce(66), 72 => TE::Reduce(66), 73 => TE::Reduce(66), 74 => TE::Reduce(66), 75 => TE::Reduce(66), 77 => TE::Reduce(66), 78 => TE::Reduce(66), 79 => TE::Reduce(66), 80 => TE::Reduce(66), 81 => TE::Reduce(66), 82 => TE::Reduce(66), 83 => TE::Reduce(66), 84 => TE::Reduce(66), 85 => TE::Reduce(66), 86 => TE::Reduce(66), 87 => TE::Reduce(66), 88 => TE::Reduce(66), 91 => TE::Reduce(66), 92 => TE::Reduce(66), 103 => TE::Reduce(66), 104 => TE::Reduce(66), 105 => TE::Reduce(66), 106 => TE::Reduce(66), 107 => TE::Reduce(66), 108 => TE::Reduce(66), 109 => TE::Reduce(66), 119 => TE::Reduce(66), 120 => TE::Reduce(66), 121 => TE::Reduce(66), 122 => TE::Reduce(66), 123 => TE::Reduce(66), 124 => TE::Reduce(66), 125 => TE::Reduce(66), 126 => TE::Reduce(66), 127 => TE::Reduce(66), 128 => TE::Reduce(66), 129 => TE::Reduce(66), 130 => TE::Reduce(66), 132 => TE::Reduce(66), 133 => TE::Reduce(66), 134 => TE::Reduce(66), 135 => TE::Reduce(66), 136 => TE::Reduce(66), 137 => TE::Reduce(66), 138 => TE::Reduce(66), 139 => TE::Reduce(66), 140 => TE::Reduce(66), 144 => TE::Reduce(66), 146 => TE::Reduce(66), 149 => TE::Reduce(66), 150 => TE::Reduce(66), 160 => TE::Reduce(66) },
+    hashmap! { 57 => TE::Reduce(67), 58 => TE::Reduce(67), 69 => TE::Reduce(67), 70 => TE::Reduce(67), 71 => TE::Reduce(67), 72 => TE::Reduce(67), 73 => TE::Reduce(67), 74 => TE::Reduce(67), 75 => TE::Reduce(67), 77 => TE::Reduce(67), 78 => TE::Reduce(67), 79 => TE::Reduce(67), 80 => TE::Reduce(67), 81 => TE::Reduce(67), 82 => TE::Reduce(67), 83 => TE::Reduce(67), 84 => TE::Reduce(67), 85 => TE::Reduce(67), 86 => TE::Reduce(67), 87 => TE::Reduce(67), 88 => TE::Reduce(67), 91 => TE::Reduce(67), 92 => TE::Reduce(67), 103 => TE::Reduce(67), 104 => TE::Reduce(67), 105 => TE::Reduce(67), 106 => TE::Reduce(67), 107 => TE::Reduce(67), 108 => TE::Reduce(67), 109 => TE::Reduce(67), 119 => TE::Reduce(67), 120 => TE::Reduce(67), 121 => TE::Reduce(67), 122 => TE::Reduce(67), 123 => TE::Reduce(67), 124 => TE::Reduce(67), 125 => TE::Reduce(67), 126 => TE::Reduce(67), 127 => TE::Reduce(67), 128 => TE::Reduce(67), 129 => TE::Reduce(67), 130 => TE::Reduce(67), 132 => TE::Reduce(67), 133 => TE::Reduce(67), 134 => TE::Reduce(67), 135 => TE::Reduce(67), 136 => TE::Reduce(67), 137 => TE::Reduce(67), 138 => TE::Reduce(67), 139 => TE::Reduce(67), 140 => TE::Reduce(67), 144 => TE::Reduce(67), 146 => TE::Reduce(67), 149 => TE::Reduce(67), 150 => TE::Reduce(67), 160 => TE::Reduce(67) },
+    hashmap! { 48 => TE::Transit(345), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 146 => TE::Reduce(104), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 144 => TE::Shift(346) },
+    hashmap! { 58 => TE::Shift(347), 146 => TE::Reduce(72) },
+    hashmap! { 58 => TE::Shift(348), 146 => TE::Reduce(74) },
+    hashmap! { 144 => TE::Shift(349) },
+    hashmap! { 144 => TE::Shift(350) },
+    hashmap! { 18 => TE::Transit(351), 139 => TE::Shift(155) },
+    hashmap! { 144 => TE::Shift(352) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(354), 21 => TE::Transit(353), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 144 => TE::Shift(355) },
+    hashmap! { 144 => TE::Shift(356) },
+    hashmap! { 57 => TE::Reduce(38), 58 => TE::Reduce(38), 59 => TE::Reduce(38), 65 => TE::Reduce(38), 66 => TE::Reduce(38), 67 => TE::Reduce(38), 68 => TE::Reduce(38), 132 => TE::Reduce(38), 133 => TE::Reduce(38), 134 => TE::Reduce(38), 135 => TE::Reduce(38), 136 => TE::Reduce(38), 137 => TE::Reduce(38), 138 => TE::Reduce(38), 140 => TE::Reduce(38), 144 => TE::Reduce(38) },
+    hashmap! { 48 => TE::Transit(357), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(189), 74 => TE::Reduce(189), 79 => TE::Reduce(189), 85 => TE::Shift(358), 93 => TE::Reduce(189), 94 => TE::Reduce(189), 95 => TE::Reduce(189), 96 => TE::Reduce(189), 97 => TE::Reduce(189), 98 => TE::Reduce(189), 99 => TE::Reduce(189), 100 => TE::Reduce(189), 101 => TE::Reduce(189), 102 => TE::Reduce(189), 110 => TE::Reduce(189), 111 => TE::Reduce(189), 112 => TE::Reduce(189), 113 => TE::Reduce(189), 114 => TE::Reduce(189), 115 => TE::Reduce(189), 116 => TE::Reduce(189), 117 => TE::Reduce(189), 118 => TE::Reduce(189), 119 => TE::Reduce(189), 120 => TE::Reduce(189), 141 => TE::Reduce(189), 142 => TE::Reduce(189), 143 => TE::Reduce(189), 144 => TE::Reduce(189), 145 => TE::Reduce(189), 146 => TE::Reduce(189), 147 => TE::Reduce(189), 148 => TE::Reduce(189), 149 => TE::Reduce(189), 150 => TE::Reduce(189), 151 => TE::Reduce(189), 152 => TE::Reduce(189), 153 => TE::Reduce(189), 154 => TE::Reduce(189), 155 => TE::Reduce(189), 156 => TE::Reduce(189), 157 => TE::Reduce(189), 158 => TE::Reduce(189), 159 => TE::Reduce(189), 161 => TE::Reduce(189) },
+    hashmap! { 48 => TE::Transit(359), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(180), 74 => TE::Reduce(180), 79 => TE::Reduce(180), 110 => TE::Reduce(180), 111 => TE::Reduce(180), 112 => TE::Reduce(180), 113 => TE::Reduce(180), 114 => TE::Reduce(180), 115 => TE::Reduce(180), 116 => TE::Reduce(180), 117 => TE::Reduce(180), 118 => TE::Reduce(180), 119 => TE::Reduce(180), 120 => TE::Reduce(180), 141 => TE::Reduce(180), 142 => TE::Reduce(180), 143 => TE::Reduce(180), 144 => TE::Reduce(180), 145 => TE::Reduce(180), 146 => TE::Reduce(180), 148 => TE::Reduce(180), 149 => TE::Reduce(180), 150 => TE::Reduce(180), 151 => TE::Reduce(180), 152 => TE::Reduce(180), 153 => TE::Reduce(180), 154 => TE::Reduce(180), 155 => TE::Reduce(180), 156 => TE::Reduce(180), 157 => TE::Reduce(180), 158 => TE::Reduce(180), 159 => TE::Reduce(180), 161 => TE::Reduce(180) },
+    hashmap! { 145 => TE::Shift(360) },
+    hashmap! { 72 => TE::Reduce(126), 74 => TE::Reduce(126), 79 => TE::Reduce(126), 110 => TE::Reduce(126), 111 => TE::Reduce(126), 112 => TE::Reduce(126), 113 => TE::Reduce(126), 114 => TE::Reduce(126), 115 => TE::Reduce(126), 116 => TE::Reduce(126), 117 => TE::Reduce(126), 118 => TE::Reduce(126), 119 => TE::Reduce(126), 120 => TE::Reduce(126), 141 => TE::Reduce(126), 142 => TE::Reduce(126), 143 => TE::Reduce(126), 144 => TE::Reduce(126), 145 => TE::Reduce(126), 146 => TE::Reduce(126), 148 => TE::Reduce(126), 149 => TE::Reduce(126), 150 => TE::Reduce(126), 151 => TE::Reduce(126), 152 => TE::Reduce(126), 153 => TE::Reduce(126), 154 => TE::Reduce(126), 155 => TE::Reduce(126), 156 => TE::Reduce(126), 157 => TE::Reduce(126), 158 => TE::Reduce(126), 159 => TE::Reduce(126), 161 => TE::Reduce(126) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(106), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(106), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(106), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 76 => TE::Shift(361) },
+    hashmap! { 72 => TE::Reduce(179), 74 => TE::Reduce(179), 79 => TE::Reduce(179), 110 => TE::Reduce(179), 111 => TE::Reduce(179), 112 => TE::Reduce(179), 113 => TE::Reduce(179), 114 => TE::Reduce(179), 115 => TE::Reduce(179), 116 => TE::Reduce(179), 117 => TE::Reduce(179), 118 => TE::Reduce(179), 119 => TE::Reduce(179), 120 => TE::Reduce(179), 141 => TE::Reduce(179), 142 => TE::Reduce(179), 143 => TE::Reduce(179), 144 => TE::Reduce(179), 145 => TE::Reduce(179), 146 => TE::Reduce(179), 148 => TE::Reduce(179), 149 => TE::Reduce(179), 150 => TE::Reduce(179), 151 => TE::Reduce(179), 152 => TE::Reduce(179), 153 => TE::Reduce(179), 154 => TE::Reduce(179), 155 => TE::Reduce(179), 156 => TE::Reduce(179), 157 => TE::Reduce(179), 158 => TE::Reduce(179), 159 => TE::Reduce(179), 161 => TE::Reduce(179) },
+    hashmap! { 48 => TE::Transit(307), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 131 => TE::Shift(77), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(169), 74 => TE::Reduce(169), 79 => TE::Reduce(169), 110 => TE::Reduce(169), 111 => TE::Reduce(169), 112 => TE::Reduce(169), 113 => TE::Reduce(169), 114 => TE::Reduce(169), 115 => TE::Reduce(169), 116 => TE::Reduce(169), 117 => TE::Reduce(169), 118 => TE::Reduce(169), 119 => TE::Reduce(169), 120 => TE::Reduce(169), 141 => TE::Reduce(169), 142 => TE::Reduce(169), 143 => TE::Reduce(169), 144 => TE::Reduce(169), 145 => TE::Reduce(169), 146 => TE::Reduce(169), 148 => TE::Reduce(169), 149 => TE::Reduce(169), 150 => TE::Reduce(169), 151 => TE::Reduce(169), 152 => TE::Reduce(169), 153 => TE::Reduce(169), 154 => TE::Reduce(169), 155 => TE::Reduce(169), 156 => TE::Reduce(169), 157 => TE::Reduce(169), 158 => TE::Reduce(169), 159 => TE::Reduce(169), 161 => TE::Reduce(169) },
+    hashmap! { 72 => TE::Reduce(170), 74 => TE::Reduce(170), 79 => TE::Reduce(170), 110 => TE::Reduce(170), 111 => TE::Reduce(170), 112 => TE::Reduce(170), 113 => TE::Reduce(170), 114 => TE::Reduce(170), 115 => TE::Reduce(170), 116 => TE::Reduce(170), 117 => TE::Reduce(170), 118 => TE::Reduce(170), 119 => TE::Reduce(170), 120 => TE::Reduce(170), 141 => TE::Reduce(170), 142 => TE::Reduce(170), 143 => TE::Reduce(170), 144 => TE::Reduce(170), 145 => TE::Reduce(170), 146 => TE::Reduce(170), 148 => TE::Reduce(170), 149 => TE::Reduce(170), 150 => TE::Reduce(170), 151 => TE::Reduce(170), 152 => TE::Reduce(170), 153 => TE::Reduce(170), 154 => TE::Reduce(170), 155 => TE::Reduce(170), 156 => TE::Reduce(170), 157 => TE::Reduce(170), 158 => TE::Reduce(170), 159 => TE::Reduce(170), 161 => TE::Reduce(170) },
+    hashmap! { 72 => TE::Reduce(171), 74 => TE::Reduce(171), 79 => TE::Reduce(171), 110 => TE::Reduce(171), 111 => TE::Reduce(171), 112 => TE::Reduce(171), 113 => TE::Reduce(171), 114 => TE::Reduce(171), 115 => TE::Reduce(171), 116 => TE::Reduce(171), 117 => TE::Reduce(171), 118 => TE::Reduce(171), 119 => TE::Reduce(171), 120 => TE::Reduce(171), 141 => TE::Reduce(171), 142 => TE::Reduce(171), 143 => TE::Reduce(171), 144 => TE::Reduce(171), 145 => TE::Reduce(171), 146 => TE::Reduce(171), 148 => TE::Reduce(171), 149 => TE::Reduce(171), 150 => TE::Reduce(171), 151 => TE::Reduce(171), 152 => TE::Reduce(171), 153 => TE::Reduce(171), 154 => TE::Reduce(171), 155 => TE::Reduce(171), 156 => TE::Reduce(171), 157 => TE::Reduce(171), 158 => TE::Reduce(171), 159 => TE::Reduce(171), 161 => TE::Reduce(171) },
+    hashmap! { 72 => TE::Reduce(172), 74 => TE::Reduce(172), 79 => TE::Reduce(172), 110 => TE::Reduce(172), 111 => TE::Reduce(172), 112 => TE::Reduce(172), 113 => TE::Reduce(172), 114 => TE::Reduce(172), 115 => TE::Reduce(172), 116 => TE::Reduce(172), 117 => TE::Reduce(172), 118 => TE::Reduce(172), 119 => TE::Reduce(172), 120 => TE::Reduce(172), 141 => TE::Reduce(172), 142 => TE::Reduce(172), 143 => TE::Reduce(172), 144 => TE::Reduce(172), 145 => TE::Reduce(172), 146 => TE::Reduce(172), 148 => TE::Reduce(172), 149 => TE::Reduce(172), 150 => TE::Reduce(172), 151 => TE::Reduce(172), 152 => TE::Reduce(172), 153 => TE::Reduce(172), 154 => TE::Reduce(172), 155 => TE::Reduce(172), 156 => TE::Reduce(172), 157 => TE::Reduce(172), 158 => TE::Reduce(172), 159 => TE::Reduce(172), 161 => TE::Reduce(172) },
+    hashmap! { 145 => TE::Shift(362) },
+    hashmap! { 141 => TE::Shift(97), 143 => TE::Shift(363) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Shift(364), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(184), 74 => TE::Reduce(184), 79 => TE::Reduce(184), 110 => TE::Reduce(184), 111 => TE::Reduce(184), 112 => TE::Reduce(184), 113 => TE::Reduce(184), 114 => TE::Reduce(184), 115 => TE::Reduce(184), 116 => TE::Reduce(184), 117 => TE::Reduce(184), 118 => TE::Reduce(184), 119 => TE::Reduce(184), 120 => TE::Reduce(184), 141 => TE::Reduce(184), 142 => TE::Reduce(184), 143 => TE::Reduce(184), 144 => TE::Reduce(184), 145 => TE::Reduce(184), 146 => TE::Reduce(184), 148 => TE::Reduce(184), 149 => TE::Reduce(184), 150 => TE::Reduce(184), 151 => TE::Reduce(184), 152 => TE::Reduce(184), 153 => TE::Reduce(184), 154 => TE::Reduce(184), 155 => TE::Reduce(184), 156 => TE::Reduce(184), 157 => TE::Reduce(184), 158 => TE::Reduce(184), 159 => TE::Reduce(184), 161 => TE::Reduce(184) },
+    hashmap! { 58 => TE::Shift(365) },
+    hashmap! { 18 => TE::Transit(367), 131 => TE::Shift(366), 139 => TE::Shift(155) },
+    hashmap! { 57 => TE::Reduce(46), 58 => TE::Reduce(46), 69 => TE::Reduce(46), 70 => TE::Reduce(46), 71 => TE::Reduce(46), 72 => TE::Reduce(46), 73 => TE::Reduce(46), 74 => TE::Reduce(46), 75 => TE::Reduce(46), 77 => TE::Reduce(46), 78 => TE::Reduce(46), 79 => TE::Reduce(46), 80 => TE::Reduce(46), 81 => TE::Reduce(46), 82 => TE::Reduce(46), 83 => TE::Reduce(46), 84 => TE::Reduce(46), 85 => TE::Reduce(46), 86 => TE::Reduce(46), 87 => TE::Reduce(46), 88 => TE::Reduce(46), 91 => TE::Reduce(46), 92 => TE::Reduce(46), 103 => TE::Reduce(46), 104 => TE::Reduce(46), 105 => TE::Reduce(46), 106 => TE::Reduce(46), 107 => TE::Reduce(46), 108 => TE::Reduce(46), 109 => TE::Reduce(46), 119 => TE::Reduce(46), 120 => TE::Reduce(46), 121 => TE::Reduce(46), 122 => TE::Reduce(46), 123 => TE::Reduce(46), 124 => TE::Reduce(46), 125 => TE::Reduce(46), 126 => TE::Reduce(46), 127 => TE::Reduce(46), 128 => TE::Reduce(46), 129 => TE::Reduce(46), 130 => TE::Reduce(46), 132 => TE::Reduce(46), 133 => TE::Reduce(46), 134 => TE::Reduce(46), 135 => TE::Reduce(46), 136 => TE::Reduce(46), 137 => TE::Reduce(46), 138 => TE::Reduce(46), 139 => TE::Reduce(46), 140 => TE::Reduce(46), 144 => TE::Reduce(46), 146 => TE::Reduce(46), 149 => TE::Reduce(46), 150 => TE::Reduce(46), 160 => TE::Reduce(46) },
+    hashmap! { 57 => TE::Reduce(49), 58 => TE::Reduce(49), 69 => TE::Reduce(49), 70 => TE::Reduce(49), 71 => TE::Reduce(49), 72 => TE::Reduce(49), 73 => TE::Reduce(49), 74 => TE::Reduce(49), 75 => TE::Reduce(49), 77 => TE::Reduce(49), 78 => TE::Reduce(49), 79 => TE::Reduce(49), 80 => TE::Reduce(49), 81 => TE::Reduce(49), 82 => TE::Reduce(49), 83 => TE::Reduce(49), 84 => TE::Reduce(49), 85 => TE::Reduce(49), 86 => TE::Reduce(49), 87 => TE::Reduce(49), 88 => TE::Reduce(49), 91 => TE::Reduce(49), 92 => TE::Reduce(49), 103 => TE::Reduce(49), 104 => TE::Reduce(49), 105 => TE::Reduce(49), 106 => TE::Reduce(49), 107 => TE::Reduce(49), 108 => TE::Reduce(49), 109 => TE::Reduce(49), 119 => TE::Reduce(49), 120 => TE::Reduce(49), 121 => TE::Reduce(49), 122 => TE::Reduce(49), 123 => TE::Reduce(49), 124 => TE::Reduce(49), 125 => TE::Reduce(49), 126 => TE::Reduce(49), 127 => TE::Reduce(49), 128 => TE::Reduce(49), 129 => TE::Reduce(49), 130 => TE::Reduce(49), 132 => TE::Reduce(49), 133 => TE::Reduce(49), 134 => TE::Reduce(49), 135 => TE::Reduce(49), 136 => TE::Reduce(49), 137 => TE::Reduce(49), 138 => TE::Reduce(49), 139 => TE::Reduce(49), 140 => TE::Reduce(49), 144 => TE::Reduce(49), 146 => TE::Reduce(49), 149 => TE::Reduce(49), 150 => TE::Reduce(49), 160 => TE::Reduce(49) },
+    hashmap! { 57 => TE::Reduce(50), 58 => TE::Reduce(50), 69 => TE::Reduce(50), 70 => TE::Reduce(50), 71 => TE::Reduce(50), 72 => TE::Reduce(50), 73 => TE::Reduce(50), 74 => TE::Reduce(50), 75 => TE::Reduce(50), 77 => TE::Reduce(50), 78 => TE::Reduce(50), 79 => TE::Reduce(50), 80 => TE::Reduce(50), 81 => TE::Reduce(50), 82 => TE::Reduce(50), 83 => TE::Reduce(50), 84 => TE::Reduce(50), 85 => TE::Reduce(50), 86 => TE::Reduce(50), 87 => TE::Reduce(50), 88 => TE::Reduce(50), 91 => TE::Reduce(50), 92 => TE::Reduce(50), 103 => TE::Reduce(50), 104 => TE::Reduce(50), 105 => TE::Reduce(50), 106 => TE::Reduce(50), 107 => TE::Reduce(50), 108 => TE::Reduce(50), 109 => TE::Reduce(50), 119 => TE::Reduce(50), 120 => TE::Reduce(50), 121 => TE::Reduce(50), 122 => TE::Reduce(50), 123 => TE::Reduce(50), 124 => TE::Reduce(50), 125 => TE::Reduce(50), 126 => TE::Reduce(50), 127 => TE::Reduce(50), 128 => TE::Reduce(50), 129 => TE::Reduce(50), 130 => TE::Reduce(50), 132 => TE::Reduce(50), 133 => TE::Reduce(50), 134 => TE::Reduce(50), 135 => TE::Reduce(50), 136 => TE::Reduce(50), 137 => TE::Reduce(50), 138 => TE::Reduce(50), 139 => TE::Reduce(50), 140 => TE::Reduce(50), 144 => TE::Reduce(50), 146 => TE::Reduce(50), 149 => TE::Reduce(50), 150 => TE::Reduce(50), 160 => TE::Reduce(50) },
+    hashmap! { 57 => TE::Reduce(51), 58 => TE::Reduce(51), 69 => TE::Reduce(51), 70 => TE::Reduce(51), 71 => TE::Reduce(51), 72 => TE::Reduce(51), 73 => TE::Reduce(51), 74 => TE::Reduce(51), 75 => TE::Reduce(51), 77 => TE::Reduce(51), 78 => TE::Reduce(51), 79 => TE::Reduce(51), 80 => TE::Reduce(51), 81 => TE::Reduce(51), 82 => TE::Reduce(51), 83 => TE::Reduce(51), 84 => TE::Reduce(51), 85 => TE::Reduce(51), 86 => TE::Reduce(51), 87 => TE::Reduce(51), 88 => TE::Reduce(51), 91 => TE::Reduce(51), 92 => TE::Reduce(51), 103 => TE::Reduce(51), 104 => TE::Reduce(51), 105 => TE::Reduce(51), 106 => TE::Reduce(51), 107 => TE::Reduce(51), 108 => TE::Reduce(51), 109 => TE::Reduce(51), 119 => TE::Reduce(51), 120 => TE::Reduce(51), 121 => TE::Reduce(51), 122 => TE::Reduce(51), 123 => TE::Reduce(51), 124 => TE::Reduce(51), 125 => TE::Reduce(51), 126 => TE::Reduce(51), 127 => TE::Reduce(51), 128 => TE::Reduce(51), 129 => TE::Reduce(51), 130 => TE::Reduce(51), 132 => TE::Reduce(51), 133 => TE::Reduce(51), 134 => TE::Reduce(51), 135 => TE::Reduce(51), 136 => TE::Reduce(51), 137 => TE::Reduce(51), 138 => TE::Reduce(51), 139 => TE::Reduce(51), 140 => TE::Reduce(51), 144 => TE::Reduce(51), 146 => TE::Reduce(51), 149 => TE::Reduce(51), 150 => TE::Reduce(51), 160 => TE::Reduce(51) },
+    hashmap! { 57 => TE::Reduce(52), 58 => TE::Reduce(52), 69 => TE::Reduce(52), 70 => TE::Reduce(52), 71 => TE::Reduce(52), 72 => TE::Reduce(52), 73 => TE::Reduce(52), 74 => TE::Reduce(52), 75 => TE::Reduce(52), 77 => TE::Reduce(52), 78 => TE::Reduce(52), 79 => TE::Reduce(52), 80 => TE::Reduce(52), 81 => TE::Reduce(52), 82 => TE::Reduce(52), 83 => TE::Reduce(52), 84 => TE::Reduce(52), 85 => TE::Reduce(52), 86 => TE::Reduce(52), 87 => TE::Reduce(52), 88 => TE::Reduce(52), 91 => TE::Reduce(52), 92 => TE::Reduce(52), 103 => TE::Reduce(52), 104 => TE::Reduce(52), 105 => TE::Reduce(52), 106 => TE::Reduce(52), 107 => TE::Reduce(52), 108 => TE::Reduce(52), 109 => TE::Reduce(52), 119 => TE::Reduce(52), 120 => TE::Reduce(52), 121 => TE::Reduce(52), 122 => TE::Reduce(52), 123 => TE::Reduce(52), 124 => TE::Reduce(52), 125 => TE::Reduce(52), 126 => TE::Reduce(52), 127 => TE::Reduce(52), 128 => TE::Reduce(52), 129 => TE::Reduce(52), 130 => TE::Reduce(52), 132 => TE::Reduce(52), 133 => TE::Reduce(52), 134 => TE::Reduce(52), 135 => TE::Reduce(52), 136 => TE::Reduce(52), 137 => TE::Reduce(52), 138 => TE::Reduce(52), 139 => TE::Reduce(52), 140 => TE::Reduce(52), 144 => TE::Reduce(52), 146 => TE::Reduce(52), 149 => TE::Reduce(52), 150 => TE::Reduce(52), 160 => TE::Reduce(52) },
+    hashmap! { 22 => TE::Transit(368), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297) },
+    hashmap! { 57 => TE::Reduce(54), 58 => TE::Reduce(54), 69 => TE::Reduce(54), 70 => TE::Reduce(54), 71 => TE::Reduce(54), 72 => TE::Reduce(54), 73 => TE::Reduce(54), 74 => TE::Reduce(54), 75 => TE::Reduce(54), 77 => TE::Reduce(54), 78 => TE::Reduce(54), 79 => TE::Reduce(54), 80 => TE::Reduce(54), 81 => TE::Reduce(54), 82 => TE::Reduce(54), 83 => TE::Reduce(54), 84 => TE::Reduce(54), 85 => TE::Reduce(54), 86 => TE::Reduce(54), 87 => TE::Reduce(54), 88 => TE::Reduce(54), 91 => TE::Reduce(54), 92 => TE::Reduce(54), 103 => TE::Reduce(54), 104 => TE::Reduce(54), 105 => TE::Reduce(54), 106 => TE::Reduce(54), 107 => TE::Reduce(54), 108 => TE::Reduce(54), 109 => TE::Reduce(54), 119 => TE::Reduce(54), 120 => TE::Reduce(54), 121 => TE::Reduce(54), 122 => TE::Reduce(54), 123 => TE::Reduce(54), 124 => TE::Reduce(54), 125 => TE::Reduce(54), 126 => TE::Reduce(54), 127 => TE::Reduce(54), 128 => TE::Reduce(54), 129 => TE::Reduce(54), 130 => TE::Reduce(54), 132 => TE::Reduce(54), 133 => TE::Reduce(54), 134 => TE::Reduce(54), 135 => TE::Reduce(54), 136 => TE::Reduce(54), 137 => TE::Reduce(54), 138 => TE::Reduce(54), 139 => TE::Reduce(54), 140 => TE::Reduce(54), 144 => TE::Reduce(54), 146 => TE::Reduce(54), 149 => TE::Reduce(54), 150 => TE::Reduce(54), 160 => TE::Reduce(54) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 146 => TE::Shift(369), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 48 => TE::Transit(370), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 46 => TE::Transit(234), 48 => TE::Transit(235), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 53 => TE::Transit(371), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 145 => TE::Reduce(193), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(372), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(373), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(374), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(375), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(376), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(377), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(378), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(379), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(380), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(381), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(382), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 145 => TE::Reduce(194), 146 => TE::Reduce(194), 147 => TE::Shift(383) },
+    hashmap! { 147 => TE::Shift(384) },
+    hashmap! { 48 => TE::Transit(385), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 39 => TE::Transit(386), 40 => TE::Transit(387), 48 => TE::Transit(388), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 140 => TE::Reduce(94), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 57 => TE::Reduce(65), 58 => TE::Reduce(65), 69 => TE::Reduce(65), 70 => TE::Reduce(65), 71 => TE::Reduce(65), 72 => TE::Reduce(65), 73 => TE::Reduce(65), 74 => TE::Reduce(65), 75 => TE::Reduce(65), 77 => TE::Reduce(65), 78 => TE::Reduce(65), 79 => TE::Reduce(65), 80 => TE::Reduce(65), 81 => TE::Reduce(65), 82 => TE::Reduce(65), 83 => TE::Reduce(65), 84 => TE::Reduce(65), 85 => TE::Reduce(65), 86 => TE::Reduce(65), 87 => TE::Reduce(65), 88 => TE::Reduce(65), 91 => TE::Reduce(65), 92 => TE::Reduce(65), 103 => TE::Reduce(65), 104 => TE::Reduce(65), 105 => TE::Reduce(65), 106 => TE::Reduce(65), 107 => TE::Reduce(65), 108 => TE::Reduce(65), 109 => TE::Reduce(65), 119 => TE::Reduce(65), 120 => TE::Reduce(65), 121 => TE::Reduce(65), 122 => TE::Reduce(65), 123 => TE::Reduce(65), 124 => TE::Reduce(65), 125 => TE::Reduce(65), 126 => TE::Reduce(65), 127 => TE::Reduce(65), 128 => TE::Reduce(65), 129 => TE::Reduce(65), 130 => TE::Reduce(65), 132 => TE::Reduce(65), 133 => TE::Reduce(65), 134 => TE::Reduce(65), 135 => TE::Reduce(65), 136 => TE::Reduce(65), 137 => TE::Reduce(65), 138 => TE::Reduce(65), 139 => TE::Reduce(65), 140 => TE::Reduce(65), 144 => TE::Reduce(65), 146 => TE::Reduce(65), 149 => TE::Reduce(65), 150 => TE::Reduce(65), 160 => TE::Reduce(65) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 146 => TE::Reduce(103), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 46 => TE::Transit(389), 48 => TE::Transit(235), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 146 => TE::Reduce(73) },
+    hashmap! { 146 => TE::Reduce(75) },
+    hashmap! { 58 => TE::Shift(390) },
+    hashmap! { 48 => TE::Transit(391), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 42 => TE::Transit(392), 43 => TE::Transit(394), 89 => TE::Shift(393), 90 => TE::Shift(395) },
+    hashmap! { 48 => TE::Transit(396), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Shift(397) },
+    hashmap! { 57 => TE::Reduce(63), 58 => TE::Reduce(63), 69 => TE::Reduce(63), 70 => TE::Reduce(63), 71 => TE::Reduce(63), 72 => TE::Reduce(63), 73 => TE::Reduce(63), 74 => TE::Reduce(63), 75 => TE::Reduce(63), 77 => TE::Reduce(63), 78 => TE::Reduce(63), 79 => TE::Reduce(63), 80 => TE::Reduce(63), 81 => TE::Reduce(63), 82 => TE::Reduce(63), 83 => TE::Reduce(63), 84 => TE::Reduce(63), 85 => TE::Reduce(63), 86 => TE::Reduce(63), 87 => TE::Reduce(63), 88 => TE::Reduce(63), 91 => TE::Reduce(63), 92 => TE::Reduce(63), 103 => TE::Reduce(63), 104 => TE::Reduce(63), 105 => TE::Reduce(63), 106 => TE::Reduce(63), 107 => TE::Reduce(63), 108 => TE::Reduce(63), 109 => TE::Reduce(63), 119 => TE::Reduce(63), 120 => TE::Reduce(63), 121 => TE::Reduce(63), 122 => TE::Reduce(63), 123 => TE::Reduce(63), 124 => TE::Reduce(63), 125 => TE::Reduce(63), 126 => TE::Reduce(63), 127 => TE::Reduce(63), 128 => TE::Reduce(63), 129 => TE::Reduce(63), 130 => TE::Reduce(63), 132 => TE::Reduce(63), 133 => TE::Reduce(63), 134 => TE::Reduce(63), 135 => TE::Reduce(63), 136 => TE::Reduce(63), 137 => TE::Reduce(63), 138 => TE::Reduce(63), 139 => TE::Reduce(63), 140 => TE::Reduce(63), 144 => TE::Reduce(63), 146 => TE::Reduce(63), 149 => TE::Reduce(63), 150 => TE::Reduce(63), 160 => TE::Reduce(63) },
+    hashmap! { 47 => TE::Transit(398), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(125), 71 => TE::Shift(152), 82 => TE::Shift(279), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 32 => TE::Transit(399), 56 => TE::Transit(401), 57 => TE::Shift(39), 82 => TE::Shift(400), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Shift(402), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 48 => TE::Transit(403), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(156), 74 => TE::Reduce(156), 79 => TE::Reduce(156), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(156), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(156), 146 => TE::Reduce(156), 148 => TE::Reduce(156), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(156), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(125), 74 => TE::Reduce(125), 79 => TE::Reduce(125), 110 => TE::Reduce(125), 111 => TE::Reduce(125), 112 => TE::Reduce(125), 113 => TE::Reduce(125), 114 => TE::Reduce(125), 115 => TE::Reduce(125), 116 => TE::Reduce(125), 117 => TE::Reduce(125), 118 => TE::Reduce(125), 119 => TE::Reduce(125), 120 => TE::Reduce(125), 141 => TE::Reduce(125), 142 => TE::Reduce(125), 143 => TE::Reduce(125), 144 => TE::Reduce(125), 145 => TE::Reduce(125), 146 => TE::Reduce(125), 148 => TE::Reduce(125), 149 => TE::Reduce(125), 150 => TE::Reduce(125), 151 => TE::Reduce(125), 152 => TE::Reduce(125), 153 => TE::Reduce(125), 154 => TE::Reduce(125), 155 => TE::Reduce(125), 156 => TE::Reduce(125), 157 => TE::Reduce(125), 158 => TE::Reduce(125), 159 => TE::Reduce(125), 161 => TE::Reduce(125) },
+    hashmap! { 48 => TE::Transit(404), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(174), 74 => TE::Reduce(174), 79 => TE::Reduce(174), 110 => TE::Reduce(174), 111 => TE::Reduce(174), 112 => TE::Reduce(174), 113 => TE::Reduce(174), 114 => TE::Reduce(174), 115 => TE::Reduce(174), 116 => TE::Reduce(174), 117 => TE::Reduce(174), 118 => TE::Reduce(174), 119 => TE::Reduce(174), 120 => TE::Reduce(174), 141 => TE::Reduce(174), 142 => TE::Reduce(174), 143 => TE::Reduce(174), 144 => TE::Reduce(174), 145 => TE::Reduce(174), 146 => TE::Reduce(174), 148 => TE::Reduce(174), 149 => TE::Reduce(174), 150 => TE::Reduce(174), 151 => TE::Reduce(174), 152 => TE::Reduce(174), 153 => TE::Reduce(174), 154 => TE::Reduce(174), 155 => TE::Reduce(174), 156 => TE::Reduce(174), 157 => TE::Reduce(174), 158 => TE::Reduce(174), 159 => TE::Reduce(174), 161 => TE::Reduce(174) },
+    hashmap! { 144 => TE::Shift(405) },
+    hashmap! { 72 => TE::Reduce(183), 74 => TE::Reduce(183), 79 => TE::Reduce(183), 110 => TE::Reduce(183), 111 => TE::Reduce(183), 112 => TE::Reduce(183), 113 => TE::Reduce(183), 114 => TE::Reduce(183), 115 => TE::Reduce(183), 116 => TE::Reduce(183), 117 => TE::Reduce(183), 118 => TE::Reduce(183), 119 => TE::Reduce(183), 120 => TE::Reduce(183), 141 => TE::Reduce(183), 142 => TE::Reduce(183), 143 => TE::Reduce(183), 144 => TE::Reduce(183), 145 => TE::Reduce(183), 146 => TE::Reduce(183), 148 => TE::Reduce(183), 149 => TE::Reduce(183), 150 => TE::Reduce(183), 151 => TE::Reduce(183), 152 => TE::Reduce(183), 153 => TE::Reduce(183), 154 => TE::Reduce(183), 155 => TE::Reduce(183), 156 => TE::Reduce(183), 157 => TE::Reduce(183), 158 => TE::Reduce(183), 159 => TE::Reduce(183), 161 => TE::Reduce(183) },
+    hashmap! { 145 => TE::Shift(406) },
+    hashmap! { 48 => TE::Transit(407), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(182), 74 => TE::Reduce(182), 79 => TE::Reduce(182), 110 => TE::Reduce(182), 111 => TE::Reduce(182), 112 => TE::Reduce(182), 113 => TE::Reduce(182), 114 => TE::Reduce(182), 115 => TE::Reduce(182), 116 => TE::Reduce(182), 117 => TE::Reduce(182), 118 => TE::Reduce(182), 119 => TE::Reduce(182), 120 => TE::Reduce(182), 141 => TE::Reduce(182), 142 => TE::Reduce(182), 143 => TE::Reduce(182), 144 => TE::Reduce(182), 145 => TE::Reduce(182), 146 => TE::Reduce(182), 148 => TE::Reduce(182), 149 => TE::Reduce(182), 150 => TE::Reduce(182), 151 => TE::Reduce(182), 152 => TE::Reduce(182), 153 => TE::Reduce(182), 154 => TE::Reduce(182), 155 => TE::Reduce(182), 156 => TE::Reduce(182), 157 => TE::Reduce(182), 158 => TE::Reduce(182), 159 => TE::Reduce(182), 161 => TE::Reduce(182) },
+    hashmap! { 57 => TE::Reduce(53), 58 => TE::Reduce(53), 69 => TE::Reduce(53), 70 => TE::Reduce(53), 71 => TE::Reduce(53), 72 => TE::Reduce(53), 73 => TE::Reduce(53), 74 => TE::Reduce(53), 75 => TE::Reduce(53), 77 => TE::Reduce(53), 78 => TE::Reduce(53), 79 => TE::Reduce(53), 80 => TE::Reduce(53), 81 => TE::Reduce(53), 82 => TE::Reduce(53), 83 => TE::Reduce(53), 84 => TE::Reduce(53), 85 => TE::Reduce(53), 86 => TE::Reduce(53), 87 => TE::Reduce(53), 88 => TE::Reduce(53), 91 => TE::Reduce(53), 92 => TE::Reduce(53), 103 => TE::Reduce(53), 104 => TE::Reduce(53), 105 => TE::Reduce(53), 106 => TE::Reduce(53), 107 => TE::Reduce(53), 108 => TE::Reduce(53), 109 => TE::Reduce(53), 119 => TE::Reduce(53), 120 => TE::Reduce(53), 121 => TE::Reduce(53), 122 => TE::Reduce(53), 123 => TE::Reduce(53), 124 => TE::Reduce(53), 125 => TE::Reduce(53), 126 => TE::Reduce(53), 127 => TE::Reduce(53), 128 => TE::Reduce(53), 129 => TE::Reduce(53), 130 => TE::Reduce(53), 132 => TE::Reduce(53), 133 => TE::Reduce(53), 134 => TE::Reduce(53), 135 => TE::Reduce(53), 136 => TE::Reduce(53), 137 => TE::Reduce(53), 138 => TE::Reduce(53), 139 => TE::Reduce(53), 140 => TE::Reduce(53), 144 => TE::Reduce(53), 146 => TE::Reduce(53), 149 => TE::Reduce(53), 150 => TE::Reduce(53), 160 => TE::Reduce(53) },
+    hashmap! { 57 => TE::Reduce(59), 58 => TE::Reduce(59), 69 => TE::Reduce(59), 70 => TE::Reduce(59), 71 => TE::Reduce(59), 72 => TE::Reduce(59), 73 => TE::Reduce(59), 74 => TE::Reduce(59), 75 => TE::Reduce(59), 77 => TE::Reduce(59), 78 => TE::Reduce(59), 79 => TE::Reduce(59), 80 => TE::Reduce(59), 81 => TE::Reduce(59), 82 => TE::Reduce(59), 83 => TE::Reduce(59), 84 => TE::Reduce(59), 85 => TE::Reduce(59), 86 => TE::Reduce(59), 87 => TE::Reduce(59), 88 => TE::Reduce(59), 91 => TE::Reduce(59), 92 => TE::Reduce(59), 103 => TE::Reduce(59), 104 => TE::Reduce(59), 105 => TE::Reduce(59), 106 => TE::Reduce(59), 107 => TE::Reduce(59), 108 => TE::Reduce(59), 109 => TE::Reduce(59), 119 => TE::Reduce(59), 120 => TE::Reduce(59), 121 => TE::Reduce(59), 122 => TE::Reduce(59), 123 => TE::Reduce(59), 124 => TE::Reduce(59), 125 => TE::Reduce(59), 126 => TE::Reduce(59), 127 => TE::Reduce(59), 128 => TE::Reduce(59), 129 => TE::Reduce(59), 130 => TE::Reduce(59), 132 => TE::Reduce(59), 133 => TE::Reduce(59), 134 => TE::Reduce(59), 135 => TE::Reduce(59), 136 => TE::Reduce(59), 137 => TE::Reduce(59), 138 => TE::Reduce(59), 139 => TE::Reduce(59), 140 => TE::Reduce(59), 144 => TE::Reduce(59), 146 => TE::Reduce(59), 149 => TE::Reduce(59), 150 => TE::Reduce(59), 160 => TE::Reduce(59) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Shift(409), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(408), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 145 => TE::Shift(410) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(108), 146 => TE::Reduce(108), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(109), 146 => TE::Reduce(109), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(110), 146 => TE::Reduce(110), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(111), 146 => TE::Reduce(111), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(112), 146 => TE::Reduce(112), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(113), 146 => TE::Reduce(113), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(114), 146 => TE::Reduce(114), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(115), 146 => TE::Reduce(115), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(116), 146 => TE::Reduce(116), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(117), 146 => TE::Reduce(117), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(118), 146 => TE::Reduce(118), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 48 => TE::Transit(411), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(412), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(413), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 140 => TE::Shift(414) },
+    hashmap! { 87 => TE::Shift(415), 140 => TE::Reduce(93) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 148 => TE::Shift(416), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 141 => TE::Shift(239), 145 => TE::Shift(417) },
+    hashmap! { 141 => TE::Shift(418) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(419), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 43 => TE::Transit(421), 57 => TE::Reduce(97), 58 => TE::Reduce(97), 69 => TE::Reduce(97), 70 => TE::Reduce(97), 71 => TE::Reduce(97), 72 => TE::Reduce(97), 73 => TE::Reduce(97), 74 => TE::Reduce(97), 75 => TE::Reduce(97), 77 => TE::Reduce(97), 78 => TE::Reduce(97), 79 => TE::Reduce(97), 80 => TE::Reduce(97), 81 => TE::Reduce(97), 82 => TE::Reduce(97), 83 => TE::Reduce(97), 84 => TE::Reduce(97), 85 => TE::Reduce(97), 86 => TE::Reduce(97), 87 => TE::Reduce(97), 88 => TE::Reduce(97), 89 => TE::Shift(420), 90 => TE::Shift(395), 91 => TE::Reduce(97), 92 => TE::Reduce(97), 103 => TE::Reduce(97), 104 => TE::Reduce(97), 105 => TE::Reduce(97), 106 => TE::Reduce(97), 107 => TE::Reduce(97), 108 => TE::Reduce(97), 109 => TE::Reduce(97), 119 => TE::Reduce(97), 120 => TE::Reduce(97), 121 => TE::Reduce(97), 122 => TE::Reduce(97), 123 => TE::Reduce(97), 124 => TE::Reduce(97), 125 => TE::Reduce(97), 126 => TE::Reduce(97), 127 => TE::Reduce(97), 128 => TE::Reduce(97), 129 => TE::Reduce(97), 130 => TE::Reduce(97), 132 => TE::Reduce(97), 133 => TE::Reduce(97), 134 => TE::Reduce(97), 135 => TE::Reduce(97), 136 => TE::Reduce(97), 137 => TE::Reduce(97), 138 => TE::Reduce(97), 139 => TE::Reduce(97), 140 => TE::Reduce(97), 144 => TE::Reduce(97), 146 => TE::Reduce(97), 149 => TE::Reduce(97), 150 => TE::Reduce(97), 160 => TE::Reduce(97) },
+    hashmap! { 18 => TE::Transit(422), 139 => TE::Shift(155) },
+    hashmap! { 57 => TE::Reduce(101), 58 => TE::Reduce(101), 69 => TE::Reduce(101), 70 => TE::Reduce(101), 71 => TE::Reduce(101), 72 => TE::Reduce(101), 73 => TE::Reduce(101), 74 => TE::Reduce(101), 75 => TE::Reduce(101), 77 => TE::Reduce(101), 78 => TE::Reduce(101), 79 => TE::Reduce(101), 80 => TE::Reduce(101), 81 => TE::Reduce(101), 82 => TE::Reduce(101), 83 => TE::Reduce(101), 84 => TE::Reduce(101), 85 => TE::Reduce(101), 86 => TE::Reduce(101), 87 => TE::Reduce(101), 88 => TE::Reduce(101), 89 => TE::Reduce(101), 90 => TE::Reduce(101), 91 => TE::Reduce(101), 92 => TE::Reduce(101), 103 => TE::Reduce(101), 104 => TE::Reduce(101), 105 => TE::Reduce(101), 106 => TE::Reduce(101), 107 => TE::Reduce(101), 108 => TE::Reduce(101), 109 => TE::Reduce(101), 119 => TE::Reduce(101), 120 => TE::Reduce(101), 121 => TE::Reduce(101), 122 => TE::Reduce(101), 123 => TE::Reduce(101), 124 => TE::Reduce(101), 125 => TE::Reduce(101), 126 => TE::Reduce(101), 127 => TE::Reduce(101), 128 => TE::Reduce(101), 129 => TE::Reduce(101), 130 => TE::Reduce(101), 132 => TE::Reduce(101), 133 => TE::Reduce(101), 134 => TE::Reduce(101), 135 => TE::Reduce(101), 136 => TE::Reduce(101), 137 => TE::Reduce(101), 138 => TE::Reduce(101), 139 => TE::Reduce(101), 140 => TE::Reduce(101), 144 => TE::Reduce(101), 146 => TE::Reduce(101), 149 => TE::Reduce(101), 150 => TE::Reduce(101), 160 => TE::Reduce(101) },
+    hashmap! { 144 => TE::Shift(423) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(424), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 144 => TE::Shift(425) },
+    hashmap! { 146 => TE::Shift(426) },
+    hashmap! { 58 => TE::Shift(427) },
+    hashmap! { 58 => TE::Reduce(80) },
+    hashmap! { 58 => TE::Reduce(81), 150 => TE::Shift(51) },
+    hashmap! { 72 => TE::Reduce(155), 74 => TE::Reduce(155), 79 => TE::Reduce(155), 110 => TE::Reduce(155), 111 => TE::Reduce(155), 112 => TE::Reduce(155), 113 => TE::Reduce(155), 114 => TE::Reduce(155), 115 => TE::Reduce(155), 116 => TE::Reduce(155), 117 => TE::Reduce(155), 118 => TE::Reduce(155), 119 => TE::Reduce(155), 120 => TE::Reduce(155), 141 => TE::Reduce(155), 142 => TE::Reduce(155), 143 => TE::Reduce(155), 144 => TE::Reduce(155), 145 => TE::Reduce(155), 146 => TE::Reduce(155), 148 => TE::Reduce(155), 149 => TE::Reduce(155), 150 => TE::Reduce(155), 151 => TE::Reduce(155), 152 => TE::Reduce(155), 153 => TE::Reduce(155), 154 => TE::Reduce(155), 155 => TE::Reduce(155), 156 => TE::Reduce(155), 157 => TE::Reduce(155), 158 => TE::Reduce(155), 159 => TE::Reduce(155), 161 => TE::Reduce(155) },
+    hashmap! { 72 => TE::Reduce(157), 74 => TE::Reduce(157), 79 => TE::Reduce(157), 110 => TE::Reduce(157), 111 => TE::Reduce(157), 112 => TE::Reduce(157), 113 => TE::Reduce(157), 114 => TE::Reduce(157), 115 => TE::Reduce(157), 116 => TE::Reduce(157), 117 => TE::Reduce(157), 118 => TE::Reduce(157), 119 => TE::Reduce(157), 120 => TE::Reduce(157), 141 => TE::Reduce(157), 142 => TE::Reduce(157), 143 => TE::Reduce(157), 145 => TE::Reduce(157), 146 => TE::Reduce(157), 148 => TE::Reduce(157), 149 => TE::Reduce(157), 151 => TE::Reduce(157), 152 => TE::Reduce(157), 153 => TE::Reduce(157), 154 => TE::Reduce(157), 155 => TE::Reduce(157), 156 => TE::Reduce(157), 157 => TE::Reduce(157), 158 => TE::Reduce(157), 159 => TE::Reduce(157) },
+    hashmap! { 79 => TE::Shift(429), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Shift(428), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 46 => TE::Transit(234), 48 => TE::Transit(235), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 53 => TE::Transit(430), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 145 => TE::Reduce(193), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 72 => TE::Reduce(178), 74 => TE::Reduce(178), 79 => TE::Reduce(178), 110 => TE::Reduce(178), 111 => TE::Reduce(178), 112 => TE::Reduce(178), 113 => TE::Reduce(178), 114 => TE::Reduce(178), 115 => TE::Reduce(178), 116 => TE::Reduce(178), 117 => TE::Reduce(178), 118 => TE::Reduce(178), 119 => TE::Reduce(178), 120 => TE::Reduce(178), 141 => TE::Reduce(178), 142 => TE::Reduce(178), 143 => TE::Reduce(178), 144 => TE::Reduce(178), 145 => TE::Reduce(178), 146 => TE::Reduce(178), 148 => TE::Reduce(178), 149 => TE::Reduce(178), 150 => TE::Reduce(178), 151 => TE::Reduce(178), 152 => TE::Reduce(178), 153 => TE::Reduce(178), 154 => TE::Reduce(178), 155 => TE::Reduce(178), 156 => TE::Reduce(178), 157 => TE::Reduce(178), 158 => TE::Reduce(178), 159 => TE::Reduce(178), 161 => TE::Reduce(178) },
+    hashmap! { 72 => TE::Reduce(181), 74 => TE::Reduce(181), 79 => TE::Reduce(181), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 141 => TE::Reduce(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(181), 146 => TE::Reduce(181), 148 => TE::Reduce(181), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Reduce(181), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 146 => TE::Shift(431) },
+    hashmap! { 48 => TE::Transit(432), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 146 => TE::Shift(433) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(119), 146 => TE::Reduce(119), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(120), 146 => TE::Reduce(120), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(354), 21 => TE::Transit(434), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 57 => TE::Reduce(84), 58 => TE::Reduce(84), 69 => TE::Reduce(84), 70 => TE::Reduce(84), 71 => TE::Reduce(84), 72 => TE::Reduce(84), 73 => TE::Reduce(84), 74 => TE::Reduce(84), 75 => TE::Reduce(84), 77 => TE::Reduce(84), 78 => TE::Reduce(84), 79 => TE::Reduce(84), 80 => TE::Reduce(84), 81 => TE::Reduce(84), 82 => TE::Reduce(84), 83 => TE::Reduce(84), 84 => TE::Reduce(84), 85 => TE::Reduce(84), 86 => TE::Reduce(84), 87 => TE::Reduce(84), 88 => TE::Reduce(84), 91 => TE::Reduce(84), 92 => TE::Reduce(84), 103 => TE::Reduce(84), 104 => TE::Reduce(84), 105 => TE::Reduce(84), 106 => TE::Reduce(84), 107 => TE::Reduce(84), 108 => TE::Reduce(84), 109 => TE::Reduce(84), 119 => TE::Reduce(84), 120 => TE::Reduce(84), 121 => TE::Reduce(84), 122 => TE::Reduce(84), 123 => TE::Reduce(84), 124 => TE::Reduce(84), 125 => TE::Reduce(84), 126 => TE::Reduce(84), 127 => TE::Reduce(84), 128 => TE::Reduce(84), 129 => TE::Reduce(84), 130 => TE::Reduce(84), 132 => TE::Reduce(84), 133 => TE::Reduce(84), 134 => TE::Reduce(84), 135 => TE::Reduce(84), 136 => TE::Reduce(84), 137 => TE::Reduce(84), 138 => TE::Reduce(84), 139 => TE::Reduce(84), 140 => TE::Reduce(84), 144 => TE::Reduce(84), 146 => TE::Reduce(84), 149 => TE::Reduce(84), 150 => TE::Reduce(84), 160 => TE::Reduce(84) },
+    hashmap! { 48 => TE::Transit(435), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(354), 21 => TE::Transit(436), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 146 => TE::Reduce(105) },
+    hashmap! { 48 => TE::Transit(437), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 139 => TE::Shift(438) },
+    hashmap! { 18 => TE::Transit(439), 139 => TE::Shift(155) },
+    hashmap! { 57 => TE::Reduce(100), 58 => TE::Reduce(100), 69 => TE::Reduce(100), 70 => TE::Reduce(100), 71 => TE::Reduce(100), 72 => TE::Reduce(100), 73 => TE::Reduce(100), 74 => TE::Reduce(100), 75 => TE::Reduce(100), 77 => TE::Reduce(100), 78 => TE::Reduce(100), 79 => TE::Reduce(100), 80 => TE::Reduce(100), 81 => TE::Reduce(100), 82 => TE::Reduce(100), 83 => TE::Reduce(100), 84 => TE::Reduce(100), 85 => TE::Reduce(100), 86 => TE::Reduce(100), 87 => TE::Reduce(100), 88 => TE::Reduce(100), 89 => TE::Reduce(100), 90 => TE::Reduce(100), 91 => TE::Reduce(100), 92 => TE::Reduce(100), 103 => TE::Reduce(100), 104 => TE::Reduce(100), 105 => TE::Reduce(100), 106 => TE::Reduce(100), 107 => TE::Reduce(100), 108 => TE::Reduce(100), 109 => TE::Reduce(100), 119 => TE::Reduce(100), 120 => TE::Reduce(100), 121 => TE::Reduce(100), 122 => TE::Reduce(100), 123 => TE::Reduce(100), 124 => TE::Reduce(100), 125 => TE::Reduce(100), 126 => TE::Reduce(100), 127 => TE::Reduce(100), 128 => TE::Reduce(100), 129 => TE::Reduce(100), 130 => TE::Reduce(100), 132 => TE::Reduce(100), 133 => TE::Reduce(100), 134 => TE::Reduce(100), 135 => TE::Reduce(100), 136 => TE::Reduce(100), 137 => TE::Reduce(100), 138 => TE::Reduce(100), 139 => TE::Reduce(100), 140 => TE::Reduce(100), 144 => TE::Reduce(100), 146 => TE::Reduce(100), 149 => TE::Reduce(100), 150 => TE::Reduce(100), 160 => TE::Reduce(100) },
+    hashmap! { 57 => TE::Reduce(99), 58 => TE::Reduce(99), 69 => TE::Reduce(99), 70 => TE::Reduce(99), 71 => TE::Reduce(99), 72 => TE::Reduce(99), 73 => TE::Reduce(99), 74 => TE::Reduce(99), 75 => TE::Reduce(99), 77 => TE::Reduce(99), 78 => TE::Reduce(99), 79 => TE::Reduce(99), 80 => TE::Reduce(99), 81 => TE::Reduce(99), 82 => TE::Reduce(99), 83 => TE::Reduce(99), 84 => TE::Reduce(99), 85 => TE::Reduce(99), 86 => TE::Reduce(99), 87 => TE::Reduce(99), 88 => TE::Reduce(99), 91 => TE::Reduce(99), 92 => TE::Reduce(99), 103 => TE::Reduce(99), 104 => TE::Reduce(99), 105 => TE::Reduce(99), 106 => TE::Reduce(99), 107 => TE::Reduce(99), 108 => TE::Reduce(99), 109 => TE::Reduce(99), 119 => TE::Reduce(99), 120 => TE::Reduce(99), 121 => TE::Reduce(99), 122 => TE::Reduce(99), 123 => TE::Reduce(99), 124 => TE::Reduce(99), 125 => TE::Reduce(99), 126 => TE::Reduce(99), 127 => TE::Reduce(99), 128 => TE::Reduce(99), 129 => TE::Reduce(99), 130 => TE::Reduce(99), 132 => TE::Reduce(99), 133 => TE::Reduce(99), 134 => TE::Reduce(99), 135 => TE::Reduce(99), 136 => TE::Reduce(99), 137 => TE::Reduce(99), 138 => TE::Reduce(99), 139 => TE::Reduce(99), 140 => TE::Reduce(99), 144 => TE::Reduce(99), 146 => TE::Reduce(99), 149 => TE::Reduce(99), 150 => TE::Reduce(99), 160 => TE::Reduce(99) },
+    hashmap! { 56 => TE::Transit(440), 57 => TE::Shift(39), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(40) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(354), 21 => TE::Transit(441), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(442), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 48 => TE::Transit(443), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 76 => TE::Shift(444) },
+    hashmap! { 72 => TE::Reduce(158), 74 => TE::Reduce(158), 79 => TE::Reduce(158), 110 => TE::Reduce(158), 111 => TE::Reduce(158), 112 => TE::Reduce(158), 113 => TE::Reduce(158), 114 => TE::Reduce(158), 115 => TE::Reduce(158), 116 => TE::Reduce(158), 117 => TE::Reduce(158), 118 => TE::Reduce(158), 119 => TE::Reduce(158), 120 => TE::Reduce(158), 141 => TE::Reduce(158), 142 => TE::Reduce(158), 143 => TE::Reduce(158), 144 => TE::Reduce(158), 145 => TE::Reduce(158), 146 => TE::Reduce(158), 148 => TE::Reduce(158), 149 => TE::Reduce(158), 150 => TE::Reduce(158), 151 => TE::Reduce(158), 152 => TE::Reduce(158), 153 => TE::Reduce(158), 154 => TE::Reduce(158), 155 => TE::Reduce(158), 156 => TE::Reduce(158), 157 => TE::Reduce(158), 158 => TE::Reduce(158), 159 => TE::Reduce(158), 161 => TE::Reduce(158) },
+    hashmap! { 48 => TE::Transit(445), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 145 => TE::Shift(446) },
+    hashmap! { 57 => TE::Reduce(60), 58 => TE::Reduce(60), 69 => TE::Reduce(60), 70 => TE::Reduce(60), 71 => TE::Reduce(60), 72 => TE::Reduce(60), 73 => TE::Reduce(60), 74 => TE::Reduce(60), 75 => TE::Reduce(60), 77 => TE::Reduce(60), 78 => TE::Reduce(60), 79 => TE::Reduce(60), 80 => TE::Reduce(60), 81 => TE::Reduce(60), 82 => TE::Reduce(60), 83 => TE::Reduce(60), 84 => TE::Reduce(60), 85 => TE::Reduce(60), 86 => TE::Reduce(60), 87 => TE::Reduce(60), 88 => TE::Reduce(60), 91 => TE::Reduce(60), 92 => TE::Reduce(60), 103 => TE::Reduce(60), 104 => TE::Reduce(60), 105 => TE::Reduce(60), 106 => TE::Reduce(60), 107 => TE::Reduce(60), 108 => TE::Reduce(60), 109 => TE::Reduce(60), 119 => TE::Reduce(60), 120 => TE::Reduce(60), 121 => TE::Reduce(60), 122 => TE::Reduce(60), 123 => TE::Reduce(60), 124 => TE::Reduce(60), 125 => TE::Reduce(60), 126 => TE::Reduce(60), 127 => TE::Reduce(60), 128 => TE::Reduce(60), 129 => TE::Reduce(60), 130 => TE::Reduce(60), 132 => TE::Reduce(60), 133 => TE::Reduce(60), 134 => TE::Reduce(60), 135 => TE::Reduce(60), 136 => TE::Reduce(60), 137 => TE::Reduce(60), 138 => TE::Reduce(60), 139 => TE::Reduce(60), 140 => TE::Reduce(60), 144 => TE::Reduce(60), 146 => TE::Reduce(60), 149 => TE::Reduce(60), 150 => TE::Reduce(60), 160 => TE::Reduce(60) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(447), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 57 => TE::Reduce(62), 58 => TE::Reduce(62), 69 => TE::Reduce(62), 70 => TE::Reduce(62), 71 => TE::Reduce(62), 72 => TE::Reduce(62), 73 => TE::Reduce(62), 74 => TE::Reduce(62), 75 => TE::Reduce(62), 77 => TE::Reduce(62), 78 => TE::Reduce(62), 79 => TE::Reduce(62), 80 => TE::Reduce(62), 81 => TE::Reduce(62), 82 => TE::Reduce(62), 83 => TE::Reduce(62), 84 => TE::Reduce(62), 85 => TE::Reduce(62), 86 => TE::Reduce(62), 87 => TE::Reduce(62), 88 => TE::Reduce(62), 91 => TE::Reduce(62), 92 => TE::Reduce(62), 103 => TE::Reduce(62), 104 => TE::Reduce(62), 105 => TE::Reduce(62), 106 => TE::Reduce(62), 107 => TE::Reduce(62), 108 => TE::Reduce(62), 109 => TE::Reduce(62), 119 => TE::Reduce(62), 120 => TE::Reduce(62), 121 => TE::Reduce(62), 122 => TE::Reduce(62), 123 => TE::Reduce(62), 124 => TE::Reduce(62), 125 => TE::Reduce(62), 126 => TE::Reduce(62), 127 => TE::Reduce(62), 128 => TE::Reduce(62), 129 => TE::Reduce(62), 130 => TE::Reduce(62), 132 => TE::Reduce(62), 133 => TE::Reduce(62), 134 => TE::Reduce(62), 135 => TE::Reduce(62), 136 => TE::Reduce(62), 137 => TE::Reduce(62), 138 => TE::Reduce(62), 139 => TE::Reduce(62), 140 => TE::Reduce(62), 144 => TE::Reduce(62), 146 => TE::Reduce(62), 149 => TE::Reduce(62), 150 => TE::Reduce(62), 160 => TE::Reduce(62) },
+    hashmap! { 30 => TE::Transit(448), 57 => TE::Reduce(78), 58 => TE::Reduce(78), 69 => TE::Reduce(78), 70 => TE::Reduce(78), 71 => TE::Reduce(78), 72 => TE::Reduce(78), 73 => TE::Reduce(78), 74 => TE::Reduce(78), 75 => TE::Reduce(78), 77 => TE::Reduce(78), 78 => TE::Reduce(78), 79 => TE::Reduce(78), 80 => TE::Shift(449), 81 => TE::Reduce(78), 82 => TE::Reduce(78), 83 => TE::Reduce(78), 84 => TE::Reduce(78), 85 => TE::Reduce(78), 86 => TE::Reduce(78), 87 => TE::Reduce(78), 88 => TE::Reduce(78), 91 => TE::Reduce(78), 92 => TE::Reduce(78), 103 => TE::Reduce(78), 104 => TE::Reduce(78), 105 => TE::Reduce(78), 106 => TE::Reduce(78), 107 => TE::Reduce(78), 108 => TE::Reduce(78), 109 => TE::Reduce(78), 119 => TE::Reduce(78), 120 => TE::Reduce(78), 121 => TE::Reduce(78), 122 => TE::Reduce(78), 123 => TE::Reduce(78), 124 => TE::Reduce(78), 125 => TE::Reduce(78), 126 => TE::Reduce(78), 127 => TE::Reduce(78), 128 => TE::Reduce(78), 129 => TE::Reduce(78), 130 => TE::Reduce(78), 132 => TE::Reduce(78), 133 => TE::Reduce(78), 134 => TE::Reduce(78), 135 => TE::Reduce(78), 136 => TE::Reduce(78), 137 => TE::Reduce(78), 138 => TE::Reduce(78), 139 => TE::Reduce(78), 140 => TE::Reduce(78), 144 => TE::Reduce(78), 146 => TE::Reduce(78), 149 => TE::Reduce(78), 150 => TE::Reduce(78), 160 => TE::Reduce(78) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 148 => TE::Shift(450), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 87 => TE::Reduce(96), 140 => TE::Reduce(96) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(451), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 36 => TE::Transit(452), 84 => TE::Reduce(87), 85 => TE::Reduce(87), 140 => TE::Reduce(87) },
+    hashmap! { 57 => TE::Reduce(98), 58 => TE::Reduce(98), 69 => TE::Reduce(98), 70 => TE::Reduce(98), 71 => TE::Reduce(98), 72 => TE::Reduce(98), 73 => TE::Reduce(98), 74 => TE::Reduce(98), 75 => TE::Reduce(98), 77 => TE::Reduce(98), 78 => TE::Reduce(98), 79 => TE::Reduce(98), 80 => TE::Reduce(98), 81 => TE::Reduce(98), 82 => TE::Reduce(98), 83 => TE::Reduce(98), 84 => TE::Reduce(98), 85 => TE::Reduce(98), 86 => TE::Reduce(98), 87 => TE::Reduce(98), 88 => TE::Reduce(98), 91 => TE::Reduce(98), 92 => TE::Reduce(98), 103 => TE::Reduce(98), 104 => TE::Reduce(98), 105 => TE::Reduce(98), 106 => TE::Reduce(98), 107 => TE::Reduce(98), 108 => TE::Reduce(98), 109 => TE::Reduce(98), 119 => TE::Reduce(98), 120 => TE::Reduce(98), 121 => TE::Reduce(98), 122 => TE::Reduce(98), 123 => TE::Reduce(98), 124 => TE::Reduce(98), 125 => TE::Reduce(98), 126 => TE::Reduce(98), 127 => TE::Reduce(98), 128 => TE::Reduce(98), 129 => TE::Reduce(98), 130 => TE::Reduce(98), 132 => TE::Reduce(98), 133 => TE::Reduce(98), 134 => TE::Reduce(98), 135 => TE::Reduce(98), 136 => TE::Reduce(98), 137 => TE::Reduce(98), 138 => TE::Reduce(98), 139 => TE::Reduce(98), 140 => TE::Reduce(98), 144 => TE::Reduce(98), 146 => TE::Reduce(98), 149 => TE::Reduce(98), 150 => TE::Reduce(98), 160 => TE::Reduce(98) },
+    hashmap! { 58 => TE::Shift(453), 150 => TE::Shift(51) },
+    hashmap! { 57 => TE::Reduce(68), 58 => TE::Reduce(68), 69 => TE::Reduce(68), 70 => TE::Reduce(68), 71 => TE::Reduce(68), 72 => TE::Reduce(68), 73 => TE::Reduce(68), 74 => TE::Reduce(68), 75 => TE::Reduce(68), 77 => TE::Reduce(68), 78 => TE::Reduce(68), 79 => TE::Reduce(68), 80 => TE::Reduce(68), 81 => TE::Reduce(68), 82 => TE::Reduce(68), 83 => TE::Reduce(68), 84 => TE::Reduce(68), 85 => TE::Reduce(68), 86 => TE::Reduce(68), 87 => TE::Reduce(68), 88 => TE::Reduce(68), 91 => TE::Reduce(68), 92 => TE::Reduce(68), 103 => TE::Reduce(68), 104 => TE::Reduce(68), 105 => TE::Reduce(68), 106 => TE::Reduce(68), 107 => TE::Reduce(68), 108 => TE::Reduce(68), 109 => TE::Reduce(68), 119 => TE::Reduce(68), 120 => TE::Reduce(68), 121 => TE::Reduce(68), 122 => TE::Reduce(68), 123 => TE::Reduce(68), 124 => TE::Reduce(68), 125 => TE::Reduce(68), 126 => TE::Reduce(68), 127 => TE::Reduce(68), 128 => TE::Reduce(68), 129 => TE::Reduce(68), 130 => TE::Reduce(68), 132 => TE::Reduce(68), 133 => TE::Reduce(68), 134 => TE::Reduce(68), 135 => TE::Reduce(68), 136 => TE::Reduce(68), 137 => TE::Reduce(68), 138 => TE::Reduce(68), 139 => TE::Reduce(68), 140 => TE::Reduce(68), 144 => TE::Reduce(68), 146 => TE::Reduce(68), 149 => TE::Reduce(68), 150 => TE::Reduce(68), 160 => TE::Reduce(68) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Shift(454), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 146 => TE::Shift(455), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 48 => TE::Transit(456), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 149 => TE::Shift(160), 150 => TE::Shift(178), 151 => TE::Shift(457), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(175), 74 => TE::Reduce(175), 79 => TE::Reduce(175), 110 => TE::Reduce(175), 111 => TE::Reduce(175), 112 => TE::Reduce(175), 113 => TE::Reduce(175), 114 => TE::Reduce(175), 115 => TE::Reduce(175), 116 => TE::Reduce(175), 117 => TE::Reduce(175), 118 => TE::Reduce(175), 119 => TE::Reduce(175), 120 => TE::Reduce(175), 141 => TE::Reduce(175), 142 => TE::Reduce(175), 143 => TE::Reduce(175), 144 => TE::Reduce(175), 145 => TE::Reduce(175), 146 => TE::Reduce(175), 148 => TE::Reduce(175), 149 => TE::Reduce(175), 150 => TE::Reduce(175), 151 => TE::Reduce(175), 152 => TE::Reduce(175), 153 => TE::Reduce(175), 154 => TE::Reduce(175), 155 => TE::Reduce(175), 156 => TE::Reduce(175), 157 => TE::Reduce(175), 158 => TE::Reduce(175), 159 => TE::Reduce(175), 161 => TE::Reduce(175) },
+    hashmap! { 146 => TE::Shift(458) },
+    hashmap! { 57 => TE::Reduce(76), 58 => TE::Reduce(76), 69 => TE::Reduce(76), 70 => TE::Reduce(76), 71 => TE::Reduce(76), 72 => TE::Reduce(76), 73 => TE::Reduce(76), 74 => TE::Reduce(76), 75 => TE::Reduce(76), 77 => TE::Reduce(76), 78 => TE::Reduce(76), 79 => TE::Reduce(76), 80 => TE::Reduce(76), 81 => TE::Reduce(76), 82 => TE::Reduce(76), 83 => TE::Reduce(76), 84 => TE::Reduce(76), 85 => TE::Reduce(76), 86 => TE::Reduce(76), 87 => TE::Reduce(76), 88 => TE::Reduce(76), 91 => TE::Reduce(76), 92 => TE::Reduce(76), 103 => TE::Reduce(76), 104 => TE::Reduce(76), 105 => TE::Reduce(76), 106 => TE::Reduce(76), 107 => TE::Reduce(76), 108 => TE::Reduce(76), 109 => TE::Reduce(76), 119 => TE::Reduce(76), 120 => TE::Reduce(76), 121 => TE::Reduce(76), 122 => TE::Reduce(76), 123 => TE::Reduce(76), 124 => TE::Reduce(76), 125 => TE::Reduce(76), 126 => TE::Reduce(76), 127 => TE::Reduce(76), 128 => TE::Reduce(76), 129 => TE::Reduce(76), 130 => TE::Reduce(76), 132 => TE::Reduce(76), 133 => TE::Reduce(76), 134 => TE::Reduce(76), 135 => TE::Reduce(76), 136 => TE::Reduce(76), 137 => TE::Reduce(76), 138 => TE::Reduce(76), 139 => TE::Reduce(76), 140 => TE::Reduce(76), 144 => TE::Reduce(76), 146 => TE::Reduce(76), 149 => TE::Reduce(76), 150 => TE::Reduce(76), 160 => TE::Reduce(76) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(354), 21 => TE::Transit(459), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(354), 21 => TE::Transit(460), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 146 => TE::Reduce(79) },
+    hashmap! { 37 => TE::Transit(462), 84 => TE::Shift(463), 85 => TE::Shift(464), 140 => TE::Shift(461) },
+    hashmap! { 145 => TE::Shift(465) },
+    hashmap! { 146 => TE::Reduce(69) },
+    hashmap! { 47 => TE::Transit(466), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(125), 71 => TE::Shift(152), 82 => TE::Shift(279), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 144 => TE::Shift(190), 145 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 33 => TE::Transit(467), 72 => TE::Shift(468), 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(83), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 72 => TE::Reduce(159), 74 => TE::Reduce(159), 79 => TE::Reduce(159), 110 => TE::Reduce(159), 111 => TE::Reduce(159), 112 => TE::Reduce(159), 113 => TE::Reduce(159), 114 => TE::Reduce(159), 115 => TE::Reduce(159), 116 => TE::Reduce(159), 117 => TE::Reduce(159), 118 => TE::Reduce(159), 119 => TE::Reduce(159), 120 => TE::Reduce(159), 141 => TE::Reduce(159), 142 => TE::Reduce(159), 143 => TE::Reduce(159), 144 => TE::Reduce(159), 145 => TE::Reduce(159), 146 => TE::Reduce(159), 148 => TE::Reduce(159), 149 => TE::Reduce(159), 150 => TE::Reduce(159), 151 => TE::Reduce(159), 152 => TE::Reduce(159), 153 => TE::Reduce(159), 154 => TE::Reduce(159), 155 => TE::Reduce(159), 156 => TE::Reduce(159), 157 => TE::Reduce(159), 158 => TE::Reduce(159), 159 => TE::Reduce(159), 161 => TE::Reduce(159) },
+    hashmap! { 57 => TE::Reduce(61), 58 => TE::Reduce(61), 69 => TE::Reduce(61), 70 => TE::Reduce(61), 71 => TE::Reduce(61), 72 => TE::Reduce(61), 73 => TE::Reduce(61), 74 => TE::Reduce(61), 75 => TE::Reduce(61), 77 => TE::Reduce(61), 78 => TE::Reduce(61), 79 => TE::Reduce(61), 80 => TE::Reduce(61), 81 => TE::Reduce(61), 82 => TE::Reduce(61), 83 => TE::Reduce(61), 84 => TE::Reduce(61), 85 => TE::Reduce(61), 86 => TE::Reduce(61), 87 => TE::Reduce(61), 88 => TE::Reduce(61), 91 => TE::Reduce(61), 92 => TE::Reduce(61), 103 => TE::Reduce(61), 104 => TE::Reduce(61), 105 => TE::Reduce(61), 106 => TE::Reduce(61), 107 => TE::Reduce(61), 108 => TE::Reduce(61), 109 => TE::Reduce(61), 119 => TE::Reduce(61), 120 => TE::Reduce(61), 121 => TE::Reduce(61), 122 => TE::Reduce(61), 123 => TE::Reduce(61), 124 => TE::Reduce(61), 125 => TE::Reduce(61), 126 => TE::Reduce(61), 127 => TE::Reduce(61), 128 => TE::Reduce(61), 129 => TE::Reduce(61), 130 => TE::Reduce(61), 132 => TE::Reduce(61), 133 => TE::Reduce(61), 134 => TE::Reduce(61), 135 => TE::Reduce(61), 136 => TE::Reduce(61), 137 => TE::Reduce(61), 138 => TE::Reduce(61), 139 => TE::Reduce(61), 140 => TE::Reduce(61), 144 => TE::Reduce(61), 146 => TE::Reduce(61), 149 => TE::Reduce(61), 150 => TE::Reduce(61), 160 => TE::Reduce(61) },
+    hashmap! { 57 => TE::Reduce(77), 58 => TE::Reduce(77), 69 => TE::Reduce(77), 70 => TE::Reduce(77), 71 => TE::Reduce(77), 72 => TE::Reduce(77), 73 => TE::Reduce(77), 74 => TE::Reduce(77), 75 => TE::Reduce(77), 77 => TE::Reduce(77), 78 => TE::Reduce(77), 79 => TE::Reduce(77), 80 => TE::Reduce(77), 81 => TE::Reduce(77), 82 => TE::Reduce(77), 83 => TE::Reduce(77), 84 => TE::Reduce(77), 85 => TE::Reduce(77), 86 => TE::Reduce(77), 87 => TE::Reduce(77), 88 => TE::Reduce(77), 91 => TE::Reduce(77), 92 => TE::Reduce(77), 103 => TE::Reduce(77), 104 => TE::Reduce(77), 105 => TE::Reduce(77), 106 => TE::Reduce(77), 107 => TE::Reduce(77), 108 => TE::Reduce(77), 109 => TE::Reduce(77), 119 => TE::Reduce(77), 120 => TE::Reduce(77), 121 => TE::Reduce(77), 122 => TE::Reduce(77), 123 => TE::Reduce(77), 124 => TE::Reduce(77), 125 => TE::Reduce(77), 126 => TE::Reduce(77), 127 => TE::Reduce(77), 128 => TE::Reduce(77), 129 => TE::Reduce(77), 130 => TE::Reduce(77), 132 => TE::Reduce(77), 133 => TE::Reduce(77), 134 => TE::Reduce(77), 135 => TE::Reduce(77), 136 => TE::Reduce(77), 137 => TE::Reduce(77), 138 => TE::Reduce(77), 139 => TE::Reduce(77), 140 => TE::Reduce(77), 144 => TE::Reduce(77), 146 => TE::Reduce(77), 149 => TE::Reduce(77), 150 => TE::Reduce(77), 160 => TE::Reduce(77) },
+    hashmap! { 87 => TE::Reduce(95), 140 => TE::Reduce(95) },
+    hashmap! { 57 => TE::Reduce(85), 58 => TE::Reduce(85), 69 => TE::Reduce(85), 70 => TE::Reduce(85), 71 => TE::Reduce(85), 72 => TE::Reduce(85), 73 => TE::Reduce(85), 74 => TE::Reduce(85), 75 => TE::Reduce(85), 77 => TE::Reduce(85), 78 => TE::Reduce(85), 79 => TE::Reduce(85), 80 => TE::Reduce(85), 81 => TE::Reduce(85), 82 => TE::Reduce(85), 83 => TE::Reduce(85), 84 => TE::Reduce(85), 85 => TE::Reduce(85), 86 => TE::Reduce(85), 87 => TE::Reduce(85), 88 => TE::Reduce(85), 91 => TE::Reduce(85), 92 => TE::Reduce(85), 103 => TE::Reduce(85), 104 => TE::Reduce(85), 105 => TE::Reduce(85), 106 => TE::Reduce(85), 107 => TE::Reduce(85), 108 => TE::Reduce(85), 109 => TE::Reduce(85), 119 => TE::Reduce(85), 120 => TE::Reduce(85), 121 => TE::Reduce(85), 122 => TE::Reduce(85), 123 => TE::Reduce(85), 124 => TE::Reduce(85), 125 => TE::Reduce(85), 126 => TE::Reduce(85), 127 => TE::Reduce(85), 128 => TE::Reduce(85), 129 => TE::Reduce(85), 130 => TE::Reduce(85), 132 => TE::Reduce(85), 133 => TE::Reduce(85), 134 => TE::Reduce(85), 135 => TE::Reduce(85), 136 => TE::Reduce(85), 137 => TE::Reduce(85), 138 => TE::Reduce(85), 139 => TE::Reduce(85), 140 => TE::Reduce(85), 144 => TE::Reduce(85), 146 => TE::Reduce(85), 149 => TE::Reduce(85), 150 => TE::Reduce(85), 160 => TE::Reduce(85) },
+    hashmap! { 84 => TE::Reduce(86), 85 => TE::Reduce(86), 140 => TE::Reduce(86) },
+    hashmap! { 38 => TE::Transit(469), 58 => TE::Shift(470), 86 => TE::Shift(471), 149 => TE::Shift(472) },
+    hashmap! { 148 => TE::Shift(473) },
+    hashmap! { 18 => TE::Transit(474), 139 => TE::Shift(155) },
+    hashmap! { 145 => TE::Shift(475) },
+    hashmap! { 145 => TE::Shift(476) },
+    hashmap! { 48 => TE::Transit(477), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(123), 52 => TE::Transit(124), 58 => TE::Shift(125), 71 => TE::Shift(152), 86 => TE::Shift(126), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 144 => TE::Shift(135), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 148 => TE::Shift(478) },
+    hashmap! { 148 => TE::Shift(479) },
+    hashmap! { 148 => TE::Reduce(91) },
+    hashmap! { 86 => TE::Shift(480) },
+    hashmap! { 19 => TE::Transit(481), 57 => TE::Reduce(45), 58 => TE::Reduce(45), 69 => TE::Reduce(45), 70 => TE::Reduce(45), 71 => TE::Reduce(45), 72 => TE::Reduce(45), 73 => TE::Reduce(45), 74 => TE::Reduce(45), 75 => TE::Reduce(45), 77 => TE::Reduce(45), 78 => TE::Reduce(45), 79 => TE::Reduce(45), 81 => TE::Reduce(45), 82 => TE::Reduce(45), 83 => TE::Reduce(45), 84 => TE::Reduce(45), 85 => TE::Reduce(45), 86 => TE::Reduce(45), 88 => TE::Reduce(45), 91 => TE::Reduce(45), 92 => TE::Reduce(45), 103 => TE::Reduce(45), 104 => TE::Reduce(45), 105 => TE::Reduce(45), 106 => TE::Reduce(45), 107 => TE::Reduce(45), 108 => TE::Reduce(45), 109 => TE::Reduce(45), 119 => TE::Reduce(45), 120 => TE::Reduce(45), 121 => TE::Reduce(45), 122 => TE::Reduce(45), 123 => TE::Reduce(45), 124 => TE::Reduce(45), 125 => TE::Reduce(45), 126 => TE::Reduce(45), 127 => TE::Reduce(45), 128 => TE::Reduce(45), 129 => TE::Reduce(45), 130 => TE::Reduce(45), 132 => TE::Reduce(45), 133 => TE::Reduce(45), 134 => TE::Reduce(45), 135 => TE::Reduce(45), 136 => TE::Reduce(45), 137 => TE::Reduce(45), 138 => TE::Reduce(45), 139 => TE::Reduce(45), 140 => TE::Reduce(45), 144 => TE::Reduce(45), 146 => TE::Reduce(45), 149 => TE::Reduce(45), 150 => TE::Reduce(45), 160 => TE::Reduce(45) },
+    hashmap! { 57 => TE::Reduce(102), 58 => TE::Reduce(102), 69 => TE::Reduce(102), 70 => TE::Reduce(102), 71 => TE::Reduce(102), 72 => TE::Reduce(102), 73 => TE::Reduce(102), 74 => TE::Reduce(102), 75 => TE::Reduce(102), 77 => TE::Reduce(102), 78 => TE::Reduce(102), 79 => TE::Reduce(102), 80 => TE::Reduce(102), 81 => TE::Reduce(102), 82 => TE::Reduce(102), 83 => TE::Reduce(102), 84 => TE::Reduce(102), 85 => TE::Reduce(102), 86 => TE::Reduce(102), 87 => TE::Reduce(102), 88 => TE::Reduce(102), 89 => TE::Reduce(102), 90 => TE::Reduce(102), 91 => TE::Reduce(102), 92 => TE::Reduce(102), 103 => TE::Reduce(102), 104 => TE::Reduce(102), 105 => TE::Reduce(102), 106 => TE::Reduce(102), 107 => TE::Reduce(102), 108 => TE::Reduce(102), 109 => TE::Reduce(102), 119 => TE::Reduce(102), 120 => TE::Reduce(102), 121 => TE::Reduce(102), 122 => TE::Reduce(102), 123 => TE::Reduce(102), 124 => TE::Reduce(102), 125 => TE::Reduce(102), 126 => TE::Reduce(102), 127 => TE::Reduce(102), 128 => TE::Reduce(102), 129 => TE::Reduce(102), 130 => TE::Reduce(102), 132 => TE::Reduce(102), 133 => TE::Reduce(102), 134 => TE::Reduce(102), 135 => TE::Reduce(102), 136 => TE::Reduce(102), 137 => TE::Reduce(102), 138 => TE::Reduce(102), 139 => TE::Reduce(102), 140 => TE::Reduce(102), 144 => TE::Reduce(102), 146 => TE::Reduce(102), 149 => TE::Reduce(102), 150 => TE::Reduce(102), 160 => TE::Reduce(102) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(354), 21 => TE::Transit(482), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(354), 21 => TE::Transit(483), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 110 => TE::Shift(164), 111 => TE::Shift(165), 112 => TE::Shift(168), 113 => TE::Shift(169), 114 => TE::Shift(170), 115 => TE::Shift(171), 116 => TE::Shift(172), 117 => TE::Shift(176), 118 => TE::Shift(177), 119 => TE::Shift(180), 120 => TE::Shift(181), 142 => TE::Shift(166), 143 => TE::Shift(167), 144 => TE::Shift(182), 145 => TE::Reduce(82), 149 => TE::Shift(160), 150 => TE::Shift(178), 152 => TE::Shift(159), 153 => TE::Shift(161), 154 => TE::Shift(162), 155 => TE::Shift(163), 156 => TE::Shift(173), 157 => TE::Shift(174), 158 => TE::Shift(175), 159 => TE::Shift(179), 161 => TE::Shift(183) },
+    hashmap! { 19 => TE::Transit(484), 57 => TE::Reduce(45), 58 => TE::Reduce(45), 69 => TE::Reduce(45), 70 => TE::Reduce(45), 71 => TE::Reduce(45), 72 => TE::Reduce(45), 73 => TE::Reduce(45), 74 => TE::Reduce(45), 75 => TE::Reduce(45), 77 => TE::Reduce(45), 78 => TE::Reduce(45), 79 => TE::Reduce(45), 81 => TE::Reduce(45), 82 => TE::Reduce(45), 83 => TE::Reduce(45), 84 => TE::Reduce(45), 85 => TE::Reduce(45), 86 => TE::Reduce(45), 88 => TE::Reduce(45), 91 => TE::Reduce(45), 92 => TE::Reduce(45), 103 => TE::Reduce(45), 104 => TE::Reduce(45), 105 => TE::Reduce(45), 106 => TE::Reduce(45), 107 => TE::Reduce(45), 108 => TE::Reduce(45), 109 => TE::Reduce(45), 119 => TE::Reduce(45), 120 => TE::Reduce(45), 121 => TE::Reduce(45), 122 => TE::Reduce(45), 123 => TE::Reduce(45), 124 => TE::Reduce(45), 125 => TE::Reduce(45), 126 => TE::Reduce(45), 127 => TE::Reduce(45), 128 => TE::Reduce(45), 129 => TE::Reduce(45), 130 => TE::Reduce(45), 132 => TE::Reduce(45), 133 => TE::Reduce(45), 134 => TE::Reduce(45), 135 => TE::Reduce(45), 136 => TE::Reduce(45), 137 => TE::Reduce(45), 138 => TE::Reduce(45), 139 => TE::Reduce(45), 140 => TE::Reduce(45), 144 => TE::Reduce(45), 146 => TE::Reduce(45), 149 => TE::Reduce(45), 150 => TE::Reduce(45), 160 => TE::Reduce(45) },
+    hashmap! { 19 => TE::Transit(485), 57 => TE::Reduce(45), 58 => TE::Reduce(45), 69 => TE::Reduce(45), 70 => TE::Reduce(45), 71 => TE::Reduce(45), 72 => TE::Reduce(45), 73 => TE::Reduce(45), 74 => TE::Reduce(45), 75 => TE::Reduce(45), 77 => TE::Reduce(45), 78 => TE::Reduce(45), 79 => TE::Reduce(45), 81 => TE::Reduce(45), 82 => TE::Reduce(45), 83 => TE::Reduce(45), 84 => TE::Reduce(45), 85 => TE::Reduce(45), 86 => TE::Reduce(45), 88 => TE::Reduce(45), 91 => TE::Reduce(45), 92 => TE::Reduce(45), 103 => TE::Reduce(45), 104 => TE::Reduce(45), 105 => TE::Reduce(45), 106 => TE::Reduce(45), 107 => TE::Reduce(45), 108 => TE::Reduce(45), 109 => TE::Reduce(45), 119 => TE::Reduce(45), 120 => TE::Reduce(45), 121 => TE::Reduce(45), 122 => TE::Reduce(45), 123 => TE::Reduce(45), 124 => TE::Reduce(45), 125 => TE::Reduce(45), 126 => TE::Reduce(45), 127 => TE::Reduce(45), 128 => TE::Reduce(45), 129 => TE::Reduce(45), 130 => TE::Reduce(45), 132 => TE::Reduce(45), 133 => TE::Reduce(45), 134 => TE::Reduce(45), 135 => TE::Reduce(45), 136 => TE::Reduce(45), 137 => TE::Reduce(45), 138 => TE::Reduce(45), 139 => TE::Reduce(45), 140 => TE::Reduce(45), 144 => TE::Reduce(45), 146 => TE::Reduce(45), 149 => TE::Reduce(45), 150 => TE::Reduce(45), 160 => TE::Reduce(45) },
+    hashmap! { 148 => TE::Reduce(92) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(260), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 84 => TE::Reduce(90), 85 => TE::Reduce(90), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 140 => TE::Reduce(90), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 57 => TE::Reduce(70), 58 => TE::Reduce(70), 69 => TE::Reduce(70), 70 => TE::Reduce(70), 71 => TE::Reduce(70), 72 => TE::Reduce(70), 73 => TE::Reduce(70), 74 => TE::Reduce(70), 75 => TE::Reduce(70), 77 => TE::Reduce(70), 78 => TE::Reduce(70), 79 => TE::Reduce(70), 80 => TE::Reduce(70), 81 => TE::Reduce(70), 82 => TE::Reduce(70), 83 => TE::Reduce(70), 84 => TE::Reduce(70), 85 => TE::Reduce(70), 86 => TE::Reduce(70), 87 => TE::Reduce(70), 88 => TE::Reduce(70), 91 => TE::Reduce(70), 92 => TE::Reduce(70), 103 => TE::Reduce(70), 104 => TE::Reduce(70), 105 => TE::Reduce(70), 106 => TE::Reduce(70), 107 => TE::Reduce(70), 108 => TE::Reduce(70), 109 => TE::Reduce(70), 119 => TE::Reduce(70), 120 => TE::Reduce(70), 121 => TE::Reduce(70), 122 => TE::Reduce(70), 123 => TE::Reduce(70), 124 => TE::Reduce(70), 125 => TE::Reduce(70), 126 => TE::Reduce(70), 127 => TE::Reduce(70), 128 => TE::Reduce(70), 129 => TE::Reduce(70), 130 => TE::Reduce(70), 132 => TE::Reduce(70), 133 => TE::Reduce(70), 134 => TE::Reduce(70), 135 => TE::Reduce(70), 136 => TE::Reduce(70), 137 => TE::Reduce(70), 138 => TE::Reduce(70), 139 => TE::Reduce(70), 140 => TE::Reduce(70), 144 => TE::Reduce(70), 146 => TE::Reduce(70), 149 => TE::Reduce(70), 150 => TE::Reduce(70), 160 => TE::Reduce(70) },
+    hashmap! { 57 => TE::Reduce(71), 58 => TE::Reduce(71), 69 => TE::Reduce(71), 70 => TE::Reduce(71), 71 => TE::Reduce(71), 72 => TE::Reduce(71), 73 => TE::Reduce(71), 74 => TE::Reduce(71), 75 => TE::Reduce(71), 77 => TE::Reduce(71), 78 => TE::Reduce(71), 79 => TE::Reduce(71), 80 => TE::Reduce(71), 81 => TE::Reduce(71), 82 => TE::Reduce(71), 83 => TE::Reduce(71), 84 => TE::Reduce(71), 85 => TE::Reduce(71), 86 => TE::Reduce(71), 87 => TE::Reduce(71), 88 => TE::Reduce(71), 91 => TE::Reduce(71), 92 => TE::Reduce(71), 103 => TE::Reduce(71), 104 => TE::Reduce(71), 105 => TE::Reduce(71), 106 => TE::Reduce(71), 107 => TE::Reduce(71), 108 => TE::Reduce(71), 109 => TE::Reduce(71), 119 => TE::Reduce(71), 120 => TE::Reduce(71), 121 => TE::Reduce(71), 122 => TE::Reduce(71), 123 => TE::Reduce(71), 124 => TE::Reduce(71), 125 => TE::Reduce(71), 126 => TE::Reduce(71), 127 => TE::Reduce(71), 128 => TE::Reduce(71), 129 => TE::Reduce(71), 130 => TE::Reduce(71), 132 => TE::Reduce(71), 133 => TE::Reduce(71), 134 => TE::Reduce(71), 135 => TE::Reduce(71), 136 => TE::Reduce(71), 137 => TE::Reduce(71), 138 => TE::Reduce(71), 139 => TE::Reduce(71), 140 => TE::Reduce(71), 144 => TE::Reduce(71), 146 => TE::Reduce(71), 149 => TE::Reduce(71), 150 => TE::Reduce(71), 160 => TE::Reduce(71) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(260), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 84 => TE::Reduce(88), 85 => TE::Reduce(88), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 140 => TE::Reduce(88), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) },
+    hashmap! { 18 => TE::Transit(272), 20 => TE::Transit(260), 22 => TE::Transit(263), 23 => TE::Transit(283), 24 => TE::Transit(284), 25 => TE::Transit(285), 26 => TE::Transit(286), 27 => TE::Transit(266), 28 => TE::Transit(267), 29 => TE::Transit(262), 31 => TE::Transit(269), 34 => TE::Transit(270), 35 => TE::Transit(271), 41 => TE::Transit(273), 44 => TE::Transit(264), 45 => TE::Transit(265), 47 => TE::Transit(261), 48 => TE::Transit(281), 49 => TE::Transit(148), 50 => TE::Transit(149), 51 => TE::Transit(277), 52 => TE::Transit(124), 54 => TE::Transit(280), 56 => TE::Transit(278), 57 => TE::Shift(39), 58 => TE::Shift(268), 69 => TE::Shift(274), 70 => TE::Shift(275), 71 => TE::Shift(276), 72 => TE::Shift(294), 73 => TE::Shift(295), 74 => TE::Shift(296), 75 => TE::Shift(297), 77 => TE::Shift(289), 78 => TE::Shift(290), 79 => TE::Shift(282), 81 => TE::Shift(291), 82 => TE::Shift(279), 83 => TE::Shift(292), 84 => TE::Reduce(89), 85 => TE::Reduce(89), 86 => TE::Shift(126), 88 => TE::Shift(293), 91 => TE::Shift(287), 92 => TE::Shift(288), 103 => TE::Shift(127), 104 => TE::Shift(128), 105 => TE::Shift(129), 106 => TE::Shift(130), 107 => TE::Shift(131), 108 => TE::Shift(132), 109 => TE::Shift(134), 119 => TE::Shift(138), 120 => TE::Shift(139), 121 => TE::Shift(140), 122 => TE::Shift(141), 123 => TE::Shift(142), 124 => TE::Shift(143), 125 => TE::Shift(144), 126 => TE::Shift(145), 127 => TE::Shift(146), 128 => TE::Shift(147), 129 => TE::Shift(150), 130 => TE::Shift(151), 132 => TE::Shift(32), 133 => TE::Shift(33), 134 => TE::Shift(34), 135 => TE::Shift(35), 136 => TE::Shift(36), 137 => TE::Shift(37), 138 => TE::Shift(38), 139 => TE::Shift(155), 140 => TE::Reduce(89), 144 => TE::Shift(190), 146 => TE::Reduce(123), 149 => TE::Shift(136), 150 => TE::Shift(133), 160 => TE::Shift(137) }
 ];
 }
 
@@ -470,6 +759,7 @@ use std::process;
 use std::mem;
 use std::ptr;
 use std::default::Default as D;
+use std::iter;
 
 use super::ast::*;
 use super::types::*;
@@ -496,6 +786,57 @@ fn gen_binary(l: Expr, opt: Token, r: Expr, op: Operator) -> Expr {
             ExprData::Binary(Binary { op, l: Box::new(l), r: Box::new(r) }))
 }
 
+fn gen_compound(dst: Expr, opt: Token, src: Expr, op: Operator) -> Simple {
+  Simple::Assign(Assign { loc: opt.get_loc(), dst, src, op: Some(op) })
+}
+
+fn gen_convert(opt: Token, expr: Expr, op: ConvertOp) -> Expr {
+  Expr::new(opt.get_loc(),
+            ExprData::Convert(Convert { op, expr: Box::new(expr) }))
+}
+
+// the value of a char literal like 'a' or '\n', None if it is not a single ascii char
+fn parse_char(s: &str) -> Option<u8> {
+  Some(match &s[1..s.len() - 1] {
+    "\\n" => b'\n',
+    "\\t" => b'\t',
+    "\\r" => b'\r',
+    "\\0" => 0,
+    "\\\\" => b'\\',
+    "\\'" => b'\'',
+    "\\\"" => b'"',
+    s if s.len() == 1 => s.as_bytes()[0],
+    _ => return None,
+  })
+}
+
+// one more dimension of `new T[e1]...`, dim is None for an empty `[]`
+fn gen_new_array_dim(mut arr: Expr, dim: Option<Expr>) -> Expr {
+  if let ExprData::NewArray { elem_t, len: _, dims } = &mut arr.data {
+    elem_t.sem = SemanticType::Array(Box::new(mem::replace(&mut elem_t.sem, SemanticType::Error)));
+    if let Some(dim) = dim { dims.push(dim); }
+  }
+  arr
+}
+
+// `(T) e` is a numeric cast or a class cast depending on T, Err gives them back for other types
+fn gen_cast(t: Type, expr: Expr) -> Result<Expr, (Type, Expr)> {
+  let op = match &t.sem {
+    SemanticType::Int => ConvertOp::ToInt,
+    SemanticType::Double => ConvertOp::ToDouble,
+    SemanticType::Long => ConvertOp::ToLong,
+    &SemanticType::Named(name, ref arg) if arg.is_empty() => return Ok(Expr::new(expr.loc, ExprData::TypeCast { name, expr: Box::new(expr) })),
+    _ => return Err((t, expr)),
+  };
+  Ok(Expr::new(expr.loc, ExprData::Convert(Convert { op, expr: Box::new(expr) })))
+}
+
+// the return type is inferred by type checker
+fn gen_lambda(loc: Loc, param: Vec<VarDef>, body: Block, expr: bool) -> Expr {
+  let def = MethodDef::new(loc, "lambda", Type { loc, sem: SemanticType::Var }, param, false, body);
+  Expr::new(loc, ExprData::Lambda(Box::new(Lambda { loc, def, expr, capture: Vec::new(), llvm_env_t: ptr::null_mut() })))
+}
+
 fn gen_unary(opt: Token, r: Expr, op: Operator) -> Expr {
   Expr::new(opt.get_loc(),
             ExprData::Unary(Unary { op, r: Box::new(r) }))
@@ -526,6 +867,12 @@ type VarDefList = Vec<VarDef>;
 type StmtList = Vec<Stmt>;
 type ExprList = Vec<Expr>;
 type GuardedList = Vec<(Expr, Block)>;
+type CaseList = Vec<Case>;
+type CatchList = Vec<Catch>;
+type IdentList = Vec<&'static str>;
+type ConstList = Vec<(Loc, &'static str)>;
+type TypeList = Vec<Type>;
+type TypeParamList = Vec<TypeParam>;
 type Flag = bool;
 
 // ---  end of Module include ---------
@@ -591,7 +938,7 @@ struct Tokenizer {
 
   string_builder: (String, i32, i32),
 
-  handlers: [fn(&mut Tokenizer) -> &'static str; 98],
+  handlers: [fn(&mut Tokenizer) -> &'static str; 139],
 }
 
 impl Tokenizer {
@@ -621,7 +968,7 @@ impl Tokenizer {
 
       string_builder: (String::new(), 0, 0),
 
-      handlers: [Tokenizer::_lex_rule0, Tokenizer::_lex_rule1, Tokenizer::_lex_rule2, Tokenizer::_lex_rule3, Tokenizer::_lex_rule4, Tokenizer::_lex_rule5, Tokenizer::_lex_rule6, Tokenizer::_lex_rule7, Tokenizer::_lex_rule8, Tokenizer::_lex_rule9, Tokenizer::_lex_rule10, Tokenizer::_lex_rule11, Tokenizer::_lex_rule12, Tokenizer::_lex_rule13, Tokenizer::_lex_rule14, Tokenizer::_lex_rule15, Tokenizer::_lex_rule16, Tokenizer::_lex_rule17, Tokenizer::_lex_rule18, Tokenizer::_lex_rule19, Tokenizer::_lex_rule20, Tokenizer::_lex_rule21, Tokenizer::_lex_rule22, Tokenizer::_lex_rule23, Tokenizer::_lex_rule24, Tokenizer::_lex_rule25, Tokenizer::_lex_rule26, Tokenizer::_lex_rule27, Tokenizer::_lex_rule28, Tokenizer::_lex_rule29, Tokenizer::_lex_rule30, Tokenizer::_lex_rule31, Tokenizer::_lex_rule32, Tokenizer::_lex_rule33, Tokenizer::_lex_rule34, Tokenizer::_lex_rule35, Tokenizer::_lex_rule36, Tokenizer::_lex_rule37, Tokenizer::_lex_rule38, Tokenizer::_lex_rule39, Tokenizer::_lex_rule40, Tokenizer::_lex_rule41, Tokenizer::_lex_rule42, Tokenizer::_lex_rule43, Tokenizer::_lex_rule44, Tokenizer::_lex_rule45, Tokenizer::_lex_rule46, Tokenizer::_lex_rule47, Tokenizer::_lex_rule48, Tokenizer::_lex_rule49, Tokenizer::_lex_rule50, Tokenizer::_lex_rule51, Tokenizer::_lex_rule52, Tokenizer::_lex_rule53, Tokenizer::_lex_rule54, Tokenizer::_lex_rule55, Tokenizer::_lex_rule56, Tokenizer::_lex_rule57, Tokenizer::_lex_rule58, Tokenizer::_lex_rule59, Tokenizer::_lex_rule60, Tokenizer::_lex_rule61, Tokenizer::_lex_rule62, Tokenizer::_lex_rule63, Tokenizer::_lex_rule64, Tokenizer::_lex_rule65, Tokenizer::_lex_rule66, Tokenizer::_lex_rule67, Tokenizer::_lex_rule68, Tokenizer::_lex_rule69, Tokenizer::_lex_rule70, Tokenizer::_lex_rule71, Tokenizer::_lex_rule72, Tokenizer::_lex_rule73, Tokenizer::_lex_rule74, Tokenizer::_lex_rule75, Tokenizer::_lex_rule76, Tokenizer::_lex_rule77, Tokenizer::_lex_rule78, Tokenizer::_lex_rule79, Tokenizer::_lex_rule80, Tokenizer::_lex_rule81, Tokenizer::_lex_rule82, Tokenizer::_lex_rule83, Tokenizer::_lex_rule84, Tokenizer::_lex_rule85, Tokenizer::_lex_rule86, Tokenizer::_lex_rule87, Tokenizer::_lex_rule88, Tokenizer::_lex_rule89, Tokenizer::_lex_rule90, Tokenizer::_lex_rule91, Tokenizer::_lex_rule92, Tokenizer::_lex_rule93, Tokenizer::_lex_rule94, Tokenizer::_lex_rule95, Tokenizer::_lex_rule96, Tokenizer::_lex_rule97],
+      handlers: [Tokenizer::_lex_rule0, Tokenizer::_lex_rule1, Tokenizer::_lex_rule2, Tokenizer::_lex_rule3, Tokenizer::_lex_rule4, Tokenizer::_lex_rule5, Tokenizer::_lex_rule6, Tokenizer::_lex_rule7, Tokenizer::_lex_rule8, Tokenizer::_lex_rule9, Tokenizer::_lex_rule10, Tokenizer::_lex_rule11, Tokenizer::_lex_rule12, Tokenizer::_lex_rule13, Tokenizer::_lex_rule14, Tokenizer::_lex_rule15, Tokenizer::_lex_rule16, Tokenizer::_lex_rule17, Tokenizer::_lex_rule18, Tokenizer::_lex_rule19, Tokenizer::_lex_rule20, Tokenizer::_lex_rule21, Tokenizer::_lex_rule22, Tokenizer::_lex_rule23, Tokenizer::_lex_rule24, Tokenizer::_lex_rule25, Tokenizer::_lex_rule26, Tokenizer::_lex_rule27, Tokenizer::_lex_rule28, Tokenizer::_lex_rule29, Tokenizer::_lex_rule30, Tokenizer::_lex_rule31, Tokenizer::_lex_rule32, Tokenizer::_lex_rule33, Tokenizer::_lex_rule34, Tokenizer::_lex_rule35, Tokenizer::_lex_rule36, Tokenizer::_lex_rule37, Tokenizer::_lex_rule38, Tokenizer::_lex_rule39, Tokenizer::_lex_rule40, Tokenizer::_lex_rule41, Tokenizer::_lex_rule42, Tokenizer::_lex_rule43, Tokenizer::_lex_rule44, Tokenizer::_lex_rule45, Tokenizer::_lex_rule46, Tokenizer::_lex_rule47, Tokenizer::_lex_rule48, Tokenizer::_lex_rule49, Tokenizer::_lex_rule50, Tokenizer::_lex_rule51, Tokenizer::_lex_rule52, Tokenizer::_lex_rule53, Tokenizer::_lex_rule54, Tokenizer::_lex_rule55, Tokenizer::_lex_rule56, Tokenizer::_lex_rule57, Tokenizer::_lex_rule58, Tokenizer::_lex_rule59, Tokenizer::_lex_rule60, Tokenizer::_lex_rule61, Tokenizer::_lex_rule62, Tokenizer::_lex_rule63, Tokenizer::_lex_rule64, Tokenizer::_lex_rule65, Tokenizer::_lex_rule66, Tokenizer::_lex_rule67, Tokenizer::_lex_rule68, Tokenizer::_lex_rule69, Tokenizer::_lex_rule70, Tokenizer::_lex_rule71, Tokenizer::_lex_rule72, Tokenizer::_lex_rule73, Tokenizer::_lex_rule74, Tokenizer::_lex_rule75, Tokenizer::_lex_rule76, Tokenizer::_lex_rule77, Tokenizer::_lex_rule78, Tokenizer::_lex_rule79, Tokenizer::_lex_rule80, Tokenizer::_lex_rule81, Tokenizer::_lex_rule82, Tokenizer::_lex_rule83, Tokenizer::_lex_rule84, Tokenizer::_lex_rule85, Tokenizer::_lex_rule86, Tokenizer::_lex_rule87, Tokenizer::_lex_rule88, Tokenizer::_lex_rule89, Tokenizer::_lex_rule90, Tokenizer::_lex_rule91, Tokenizer::_lex_rule92, Tokenizer::_lex_rule93, Tokenizer::_lex_rule94, Tokenizer::_lex_rule95, Tokenizer::_lex_rule96, Tokenizer::_lex_rule97, Tokenizer::_lex_rule98, Tokenizer::_lex_rule99, Tokenizer::_lex_rule100, Tokenizer::_lex_rule101, Tokenizer::_lex_rule102, Tokenizer::_lex_rule103, Tokenizer::_lex_rule104, Tokenizer::_lex_rule105, Tokenizer::_lex_rule106, Tokenizer::_lex_rule107, Tokenizer::_lex_rule108, Tokenizer::_lex_rule109, Tokenizer::_lex_rule110, Tokenizer::_lex_rule111, Tokenizer::_lex_rule112, Tokenizer::_lex_rule113, Tokenizer::_lex_rule114, Tokenizer::_lex_rule115, Tokenizer::_lex_rule116, Tokenizer::_lex_rule117, Tokenizer::_lex_rule118, Tokenizer::_lex_rule119, Tokenizer::_lex_rule120, Tokenizer::_lex_rule121, Tokenizer::_lex_rule122, Tokenizer::_lex_rule123, Tokenizer::_lex_rule124, Tokenizer::_lex_rule125, Tokenizer::_lex_rule126, Tokenizer::_lex_rule127, Tokenizer::_lex_rule128, Tokenizer::_lex_rule129, Tokenizer::_lex_rule130, Tokenizer::_lex_rule131, Tokenizer::_lex_rule132, Tokenizer::_lex_rule133, Tokenizer::_lex_rule134, Tokenizer::_lex_rule135, Tokenizer::_lex_rule136, Tokenizer::_lex_rule137, Tokenizer::_lex_rule138],
     };
 
     tokenizer
@@ -850,436 +1197,600 @@ impl Tokenizer {
   }
 
   fn _lex_rule4(&mut self) -> &'static str {
-    return "NEW";
+    return "CHAR";
   }
 
   fn _lex_rule5(&mut self) -> &'static str {
-    return "NULL";
+    return "DOUBLE";
   }
 
   fn _lex_rule6(&mut self) -> &'static str {
-    return "TRUE";
+    return "LONG";
   }
 
   fn _lex_rule7(&mut self) -> &'static str {
-    return "FALSE";
+    return "NEW";
   }
 
   fn _lex_rule8(&mut self) -> &'static str {
-    return "CLASS";
+    return "NULL";
   }
 
   fn _lex_rule9(&mut self) -> &'static str {
-    return "EXTENDS";
+    return "TRUE";
   }
 
   fn _lex_rule10(&mut self) -> &'static str {
-    return "THIS";
+    return "FALSE";
   }
 
   fn _lex_rule11(&mut self) -> &'static str {
-    return "WHILE";
+    return "CLASS";
   }
 
   fn _lex_rule12(&mut self) -> &'static str {
-    return "FOREACH";
+    return "EXTENDS";
   }
 
   fn _lex_rule13(&mut self) -> &'static str {
-    return "FOR";
+    return "INTERFACE";
   }
 
   fn _lex_rule14(&mut self) -> &'static str {
-    return "IF";
+    return "IMPLEMENTS";
   }
 
   fn _lex_rule15(&mut self) -> &'static str {
-    return "ELSE";
+    return "ABSTRACT";
   }
 
   fn _lex_rule16(&mut self) -> &'static str {
-    return "RETURN";
+    return "SUPER";
   }
 
   fn _lex_rule17(&mut self) -> &'static str {
-    return "BREAK";
+    return "THIS";
   }
 
   fn _lex_rule18(&mut self) -> &'static str {
-    return "PRINT";
+    return "WHILE";
   }
 
   fn _lex_rule19(&mut self) -> &'static str {
-    return "READ_INTEGER";
+    return "DO";
   }
 
   fn _lex_rule20(&mut self) -> &'static str {
-    return "READ_LINE";
+    return "FOREACH";
   }
 
   fn _lex_rule21(&mut self) -> &'static str {
-    return "STATIC";
+    return "FOR";
   }
 
   fn _lex_rule22(&mut self) -> &'static str {
-    return "INSTANCEOF";
+    return "IF";
   }
 
   fn _lex_rule23(&mut self) -> &'static str {
-    return "SCOPY";
+    return "ELSE";
   }
 
   fn _lex_rule24(&mut self) -> &'static str {
-    return "SEALED";
+    return "RETURN";
   }
 
   fn _lex_rule25(&mut self) -> &'static str {
-    return "VAR";
+    return "BREAK";
   }
 
   fn _lex_rule26(&mut self) -> &'static str {
-    return "DEFAULT";
+    return "CONTINUE";
   }
 
   fn _lex_rule27(&mut self) -> &'static str {
-    return "IN";
+    return "SWITCH";
   }
 
   fn _lex_rule28(&mut self) -> &'static str {
-    return "GUARD_SPLIT";
+    return "CASE";
   }
 
   fn _lex_rule29(&mut self) -> &'static str {
-    return "LESS_EQUAL";
+    return "PRINT";
   }
 
   fn _lex_rule30(&mut self) -> &'static str {
-    return "GREATER_EQUAL";
+    return "READ_INTEGER";
   }
 
   fn _lex_rule31(&mut self) -> &'static str {
-    return "EQUAL";
+    return "READ_LINE";
   }
 
   fn _lex_rule32(&mut self) -> &'static str {
-    return "NOT_EQUAL";
+    return "CHAR_TO_INT";
   }
 
   fn _lex_rule33(&mut self) -> &'static str {
-    return "AND";
+    return "INT_TO_CHAR";
   }
 
   fn _lex_rule34(&mut self) -> &'static str {
-    return "OR";
+    return "STRING_TO_CHARS";
   }
 
   fn _lex_rule35(&mut self) -> &'static str {
-    return "REPEAT";
+    return "CHARS_TO_STRING";
   }
 
   fn _lex_rule36(&mut self) -> &'static str {
-    return "INC";
+    return "STATIC";
   }
 
   fn _lex_rule37(&mut self) -> &'static str {
-    return "DEC";
+    return "PUBLIC";
   }
 
   fn _lex_rule38(&mut self) -> &'static str {
-    return "SHL";
+    return "PRIVATE";
   }
 
   fn _lex_rule39(&mut self) -> &'static str {
-    return "SHR";
+    return "PROTECTED";
   }
 
   fn _lex_rule40(&mut self) -> &'static str {
-    return "'+'";
+    return "INSTANCEOF";
   }
 
   fn _lex_rule41(&mut self) -> &'static str {
-    return "'-'";
+    return "SCOPY";
   }
 
   fn _lex_rule42(&mut self) -> &'static str {
-    return "'*'";
+    return "SEALED";
   }
 
   fn _lex_rule43(&mut self) -> &'static str {
-    return "'/'";
+    return "VAR";
   }
 
   fn _lex_rule44(&mut self) -> &'static str {
-    return "'%'";
+    return "DEFAULT";
   }
 
   fn _lex_rule45(&mut self) -> &'static str {
-    return "'&'";
+    return "IN";
   }
 
   fn _lex_rule46(&mut self) -> &'static str {
-    return "'|'";
+    return "TRY";
   }
 
   fn _lex_rule47(&mut self) -> &'static str {
-    return "'^'";
+    return "CATCH";
   }
 
   fn _lex_rule48(&mut self) -> &'static str {
-    return "'='";
+    return "FINALLY";
   }
 
   fn _lex_rule49(&mut self) -> &'static str {
-    return "'<'";
+    return "THROW";
   }
 
   fn _lex_rule50(&mut self) -> &'static str {
-    return "'>'";
+    return "ASSERT";
   }
 
   fn _lex_rule51(&mut self) -> &'static str {
-    return "'.'";
+    return "ENUM";
   }
 
   fn _lex_rule52(&mut self) -> &'static str {
-    return "','";
+    return "LAMBDA";
   }
 
   fn _lex_rule53(&mut self) -> &'static str {
-    return "';'";
+    return "GUARD_SPLIT";
   }
 
   fn _lex_rule54(&mut self) -> &'static str {
-    return "'!'";
+    return "ARROW";
   }
 
   fn _lex_rule55(&mut self) -> &'static str {
-    return "'('";
+    return "LESS_EQUAL";
   }
 
   fn _lex_rule56(&mut self) -> &'static str {
-    return "')'";
+    return "GREATER_EQUAL";
   }
 
   fn _lex_rule57(&mut self) -> &'static str {
-    return "'['";
+    return "EQUAL";
   }
 
   fn _lex_rule58(&mut self) -> &'static str {
-    return "']'";
+    return "NOT_EQUAL";
   }
 
   fn _lex_rule59(&mut self) -> &'static str {
-    return "'{'";
+    return "AND";
   }
 
   fn _lex_rule60(&mut self) -> &'static str {
-    return "'}'";
+    return "OR";
   }
 
   fn _lex_rule61(&mut self) -> &'static str {
-    return "':'";
+    return "REPEAT";
   }
 
   fn _lex_rule62(&mut self) -> &'static str {
-    self.begin("S");
-    self.string_builder.0.clear();
-    self.string_builder.1 = self.token_start_line;
-    self.string_builder.2 = self.token_start_column + 1;
-    return "";
+    return "INC";
   }
 
   fn _lex_rule63(&mut self) -> &'static str {
-    let loc = Loc(self.string_builder.1, self.string_builder.2);
-    let string = print::quote(&self.string_builder.0.clone());
-    self.report_error(Error::new(loc, NewlineInStr { string }));
-    return "";
+    return "DEC";
   }
 
   fn _lex_rule64(&mut self) -> &'static str {
-    return "";
+    return "SHL";
   }
 
   fn _lex_rule65(&mut self) -> &'static str {
-    let loc = Loc(self.string_builder.1, self.string_builder.2);
-    let string = print::quote(&self.string_builder.0.clone());
-    self.report_error(Error::new(loc, UnterminatedStr { string }));
-    self.begin("INITIAL");
-    return "";
+    return "SHR";
   }
 
   fn _lex_rule66(&mut self) -> &'static str {
-    self.begin("INITIAL");
-    return "STRING_CONST";
+    return "ADD_ASSIGN";
   }
 
   fn _lex_rule67(&mut self) -> &'static str {
-    self.string_builder.0.push('\n');
-    return "";
+    return "SUB_ASSIGN";
   }
 
   fn _lex_rule68(&mut self) -> &'static str {
-    self.string_builder.0.push('\t');
-    return "";
+    return "MUL_ASSIGN";
   }
 
   fn _lex_rule69(&mut self) -> &'static str {
-    self.string_builder.0.push('"');
-    return "";
+    return "DIV_ASSIGN";
   }
 
   fn _lex_rule70(&mut self) -> &'static str {
-    self.string_builder.0.push('\\');
-    return "";
+    return "MOD_ASSIGN";
   }
 
   fn _lex_rule71(&mut self) -> &'static str {
-    self.string_builder.0.push_str(self.yytext);
-    return "";
+    return "AND_ASSIGN";
   }
 
   fn _lex_rule72(&mut self) -> &'static str {
-    return "";
+    return "OR_ASSIGN";
   }
 
   fn _lex_rule73(&mut self) -> &'static str {
-    return "";
+    return "XOR_ASSIGN";
   }
 
   fn _lex_rule74(&mut self) -> &'static str {
-    return "INT_CONST";
+    return "SHL_ASSIGN";
   }
 
   fn _lex_rule75(&mut self) -> &'static str {
-    return "IDENTIFIER";
+    return "SHR_ASSIGN";
   }
 
   fn _lex_rule76(&mut self) -> &'static str {
-    return "'{'";
+    return "'+'";
   }
 
   fn _lex_rule77(&mut self) -> &'static str {
-    return "'}'";
+    return "'-'";
   }
 
   fn _lex_rule78(&mut self) -> &'static str {
-    return "';'";
+    return "'*'";
   }
 
   fn _lex_rule79(&mut self) -> &'static str {
-    return "'('";
+    return "'/'";
   }
 
   fn _lex_rule80(&mut self) -> &'static str {
-    return "')'";
+    return "'%'";
   }
 
   fn _lex_rule81(&mut self) -> &'static str {
-    return "','";
+    return "'&'";
   }
 
   fn _lex_rule82(&mut self) -> &'static str {
-    return "':'";
+    return "'|'";
   }
 
   fn _lex_rule83(&mut self) -> &'static str {
-    return "'='";
+    return "'^'";
   }
 
   fn _lex_rule84(&mut self) -> &'static str {
-    return "'['";
+    return "'='";
   }
 
   fn _lex_rule85(&mut self) -> &'static str {
-    return "']'";
+    return "'<'";
   }
 
   fn _lex_rule86(&mut self) -> &'static str {
-    return "'+'";
+    return "'>'";
   }
 
   fn _lex_rule87(&mut self) -> &'static str {
-    return "'-'";
+    return "'.'";
   }
 
   fn _lex_rule88(&mut self) -> &'static str {
-    return "'*'";
+    return "','";
   }
 
   fn _lex_rule89(&mut self) -> &'static str {
-    return "'/'";
+    return "';'";
   }
 
   fn _lex_rule90(&mut self) -> &'static str {
-    return "'%'";
+    return "'!'";
   }
 
   fn _lex_rule91(&mut self) -> &'static str {
-    return "'<'";
+    return "'('";
   }
 
   fn _lex_rule92(&mut self) -> &'static str {
-    return "'>'";
+    return "')'";
   }
 
   fn _lex_rule93(&mut self) -> &'static str {
-    return "'&'";
+    return "'['";
   }
 
   fn _lex_rule94(&mut self) -> &'static str {
-    return "'|'";
+    return "']'";
   }
 
   fn _lex_rule95(&mut self) -> &'static str {
-    return "'^'";
+    return "'{'";
   }
 
   fn _lex_rule96(&mut self) -> &'static str {
-    return "'!'";
+    return "'}'";
   }
 
   fn _lex_rule97(&mut self) -> &'static str {
-    return "'.'";
+    return "':'";
   }
-}
 
-// ------------------------------------------------------------------
-// Parser.
-
-pub struct Parser {
-  // Parsing stack: semantic values.
-  values_stack: Vec<SV>,
+  fn _lex_rule98(&mut self) -> &'static str {
+    return "'?'";
+  }
 
-  // Parsing stack: state numbers.
-  states_stack: Vec<usize>,
+  fn _lex_rule99(&mut self) -> &'static str {
+    self.begin("S");
+    self.string_builder.0.clear();
+    self.string_builder.1 = self.token_start_line;
+    self.string_builder.2 = self.token_start_column + 1;
+    return "";
+  }
 
-  // Tokenizer instance.
-  tokenizer: Tokenizer,
+  fn _lex_rule100(&mut self) -> &'static str {
+    let loc = Loc(self.string_builder.1, self.string_builder.2);
+    let string = print::quote(&self.string_builder.0.clone());
+    self.report_error(Error::new(loc, NewlineInStr { string }));
+    return "";
+  }
 
-  // errors
-  errors: Vec<TError>,
+  fn _lex_rule101(&mut self) -> &'static str {
+    return "";
+  }
 
-  // Semantic action handlers.
-  handlers: [fn(&mut Parser) -> SV; 119],
-}
+  fn _lex_rule102(&mut self) -> &'static str {
+    let loc = Loc(self.string_builder.1, self.string_builder.2);
+    let string = print::quote(&self.string_builder.0.clone());
+    self.report_error(Error::new(loc, UnterminatedStr { string }));
+    self.begin("INITIAL");
+    return "";
+  }
 
-impl Parser {
-  // Creates a new Parser instance.
-  pub fn new() -> Parser {
-    Parser {
-      // Stacks.
-      values_stack: Vec::new(),
-      states_stack: Vec::new(),
+  fn _lex_rule103(&mut self) -> &'static str {
+    self.begin("INITIAL");
+    return "STRING_CONST";
+  }
 
-      tokenizer: Tokenizer::new(),
-      errors: Vec::new(),
+  fn _lex_rule104(&mut self) -> &'static str {
+    self.string_builder.0.push('\n');
+    return "";
+  }
 
-      handlers: [Parser::_handler0, Parser::_handler1, Parser::_handler2, Parser::_handler3, Parser::_handler4, Parser::_handler5, Parser::_handler6, Parser::_handler7, Parser::_handler8, Parser::_handler9, Parser::_handler10, Parser::_handler11, Parser::_handler12, Parser::_handler13, Parser::_handler14, Parser::_handler15, Parser::_handler16, Parser::_handler17, Parser::_handler18, Parser::_handler19, Parser::_handler20, Parser::_handler21, Parser::_handler22, Parser::_handler23, Parser::_handler24, Parser::_handler25, Parser::_handler26, Parser::_handler27, Parser::_handler28, Parser::_handler29, Parser::_handler30, Parser::_handler31, Parser::_handler32, Parser::_handler33, Parser::_handler34, Parser::_handler35, Parser::_handler36, Parser::_handler37, Parser::_handler38, Parser::_handler39, Parser::_handler40, Parser::_handler41, Parser::_handler42, Parser::_handler43, Parser::_handler44, Parser::_handler45, Parser::_handler46, Parser::_handler47, Parser::_handler48, Parser::_handler49, Parser::_handler50, Parser::_handler51, Parser::_handler52, Parser::_handler53, Parser::_handler54, Parser::_handler55, Parser::_handler56, Parser::_handler57, Parser::_handler58, Parser::_handler59, Parser::_handler60, Parser::_handler61, Parser::_handler62, Parser::_handler63, Parser::_handler64, Parser::_handler65, Parser::_handler66, Parser::_handler67, Parser::_handler68, Parser::_handler69, Parser::_handler70, Parser::_handler71, Parser::_handler72, Parser::_handler73, Parser::_handler74, Parser::_handler75, Parser::_handler76, Parser::_handler77, Parser::_handler78, Parser::_handler79, Parser::_handler80, Parser::_handler81, Parser::_handler82, Parser::_handler83, Parser::_handler84, Parser::_handler85, Parser::_handler86, Parser::_handler87, Parser::_handler88, Parser::_handler89, Parser::_handler90, Parser::_handler91, Parser::_handler92, Parser::_handler93, Parser::_handler94, Parser::_handler95, Parser::_handler96, Parser::_handler97, Parser::_handler98, Parser::_handler99, Parser::_handler100, Parser::_handler101, Parser::_handler102, Parser::_handler103, Parser::_handler104, Parser::_handler105, Parser::_handler106, Parser::_handler107, Parser::_handler108, Parser::_handler109, Parser::_handler110, Parser::_handler111, Parser::_handler112, Parser::_handler113, Parser::_handler114, Parser::_handler115, Parser::_handler116, Parser::_handler117, Parser::_handler118],
-    }
+  fn _lex_rule105(&mut self) -> &'static str {
+    self.string_builder.0.push('\t');
+    return "";
   }
 
-  // Parses a string.
-  pub fn parse(&mut self, string: &'static str) -> TResult {
+  fn _lex_rule106(&mut self) -> &'static str {
+    self.string_builder.0.push('"');
+    return "";
+  }
+
+  fn _lex_rule107(&mut self) -> &'static str {
+    self.string_builder.0.push('\\');
+    return "";
+  }
+
+  fn _lex_rule108(&mut self) -> &'static str {
+    self.string_builder.0.push_str(self.yytext);
+    return "";
+  }
+
+  fn _lex_rule109(&mut self) -> &'static str {
+    return "";
+  }
+
+  fn _lex_rule110(&mut self) -> &'static str {
+    return "";
+  }
+
+  fn _lex_rule111(&mut self) -> &'static str {
+    return "DOUBLE_CONST";
+  }
+
+  fn _lex_rule112(&mut self) -> &'static str {
+    return "LONG_CONST";
+  }
+
+  fn _lex_rule113(&mut self) -> &'static str {
+    return "INT_CONST";
+  }
+
+  fn _lex_rule114(&mut self) -> &'static str {
+    return "CHAR_CONST";
+  }
+
+  fn _lex_rule115(&mut self) -> &'static str {
+    return "IDENTIFIER";
+  }
+
+  fn _lex_rule116(&mut self) -> &'static str {
+    return "'{'";
+  }
+
+  fn _lex_rule117(&mut self) -> &'static str {
+    return "'}'";
+  }
+
+  fn _lex_rule118(&mut self) -> &'static str {
+    return "','";
+  }
+
+  fn _lex_rule119(&mut self) -> &'static str {
+    return "'<'";
+  }
+
+  fn _lex_rule120(&mut self) -> &'static str {
+    return "'>'";
+  }
+
+  fn _lex_rule121(&mut self) -> &'static str {
+    return "'('";
+  }
+
+  fn _lex_rule122(&mut self) -> &'static str {
+    return "')'";
+  }
+
+  fn _lex_rule123(&mut self) -> &'static str {
+    return "';'";
+  }
+
+  fn _lex_rule124(&mut self) -> &'static str {
+    return "'='";
+  }
+
+  fn _lex_rule125(&mut self) -> &'static str {
+    return "':'";
+  }
+
+  fn _lex_rule126(&mut self) -> &'static str {
+    return "'-'";
+  }
+
+  fn _lex_rule127(&mut self) -> &'static str {
+    return "'['";
+  }
+
+  fn _lex_rule128(&mut self) -> &'static str {
+    return "']'";
+  }
+
+  fn _lex_rule129(&mut self) -> &'static str {
+    return "'+'";
+  }
+
+  fn _lex_rule130(&mut self) -> &'static str {
+    return "'*'";
+  }
+
+  fn _lex_rule131(&mut self) -> &'static str {
+    return "'/'";
+  }
+
+  fn _lex_rule132(&mut self) -> &'static str {
+    return "'%'";
+  }
+
+  fn _lex_rule133(&mut self) -> &'static str {
+    return "'&'";
+  }
+
+  fn _lex_rule134(&mut self) -> &'static str {
+    return "'|'";
+  }
+
+  fn _lex_rule135(&mut self) -> &'static str {
+    return "'^'";
+  }
+
+  fn _lex_rule136(&mut self) -> &'static str {
+    return "'?'";
+  }
+
+  fn _lex_rule137(&mut self) -> &'static str {
+    return "'!'";
+  }
+
+  fn _lex_rule138(&mut self) -> &'static str {
+    return "'.'";
+  }
+}
+
+// ------------------------------------------------------------------
+// Parser.
+
+pub struct Parser {
+  // Parsing stack: semantic values.
+  values_stack: Vec<SV>,
+
+  // Parsing stack: state numbers.
+  states_stack: Vec<usize>,
+
+  // Tokenizer instance.
+  tokenizer: Tokenizer,
+
+  // errors
+  errors: Vec<TError>,
+
+  // Semantic action handlers.
+  handlers: [fn(&mut Parser) -> SV; 210],
+}
+
+impl Parser {
+  // Creates a new Parser instance.
+  pub fn new() -> Parser {
+    Parser {
+      // Stacks.
+      values_stack: Vec::new(),
+      states_stack: Vec::new(),
+
+      tokenizer: Tokenizer::new(),
+      errors: Vec::new(),
+
+      handlers: [Parser::_handler0, Parser::_handler1, Parser::_handler2, Parser::_handler3, Parser::_handler4, Parser::_handler5, Parser::_handler6, Parser::_handler7, Parser::_handler8, Parser::_handler9, Parser::_handler10, Parser::_handler11, Parser::_handler12, Parser::_handler13, Parser::_handler14, Parser::_handler15, Parser::_handler16, Parser::_handler17, Parser::_handler18, Parser::_handler19, Parser::_handler20, Parser::_handler21, Parser::_handler22, Parser::_handler23, Parser::_handler24, Parser::_handler25, Parser::_handler26, Parser::_handler27, Parser::_handler28, Parser::_handler29, Parser::_handler30, Parser::_handler31, Parser::_handler32, Parser::_handler33, Parser::_handler34, Parser::_handler35, Parser::_handler36, Parser::_handler37, Parser::_handler38, Parser::_handler39, Parser::_handler40, Parser::_handler41, Parser::_handler42, Parser::_handler43, Parser::_handler44, Parser::_handler45, Parser::_handler46, Parser::_handler47, Parser::_handler48, Parser::_handler49, Parser::_handler50, Parser::_handler51, Parser::_handler52, Parser::_handler53, Parser::_handler54, Parser::_handler55, Parser::_handler56, Parser::_handler57, Parser::_handler58, Parser::_handler59, Parser::_handler60, Parser::_handler61, Parser::_handler62, Parser::_handler63, Parser::_handler64, Parser::_handler65, Parser::_handler66, Parser::_handler67, Parser::_handler68, Parser::_handler69, Parser::_handler70, Parser::_handler71, Parser::_handler72, Parser::_handler73, Parser::_handler74, Parser::_handler75, Parser::_handler76, Parser::_handler77, Parser::_handler78, Parser::_handler79, Parser::_handler80, Parser::_handler81, Parser::_handler82, Parser::_handler83, Parser::_handler84, Parser::_handler85, Parser::_handler86, Parser::_handler87, Parser::_handler88, Parser::_handler89, Parser::_handler90, Parser::_handler91, Parser::_handler92, Parser::_handler93, Parser::_handler94, Parser::_handler95, Parser::_handler96, Parser::_handler97, Parser::_handler98, Parser::_handler99, Parser::_handler100, Parser::_handler101, Parser::_handler102, Parser::_handler103, Parser::_handler104, Parser::_handler105, Parser::_handler106, Parser::_handler107, Parser::_handler108, Parser::_handler109, Parser::_handler110, Parser::_handler111, Parser::_handler112, Parser::_handler113, Parser::_handler114, Parser::_handler115, Parser::_handler116, Parser::_handler117, Parser::_handler118, Parser::_handler119, Parser::_handler120, Parser::_handler121, Parser::_handler122, Parser::_handler123, Parser::_handler124, Parser::_handler125, Parser::_handler126, Parser::_handler127, Parser::_handler128, Parser::_handler129, Parser::_handler130, Parser::_handler131, Parser::_handler132, Parser::_handler133, Parser::_handler134, Parser::_handler135, Parser::_handler136, Parser::_handler137, Parser::_handler138, Parser::_handler139, Parser::_handler140, Parser::_handler141, Parser::_handler142, Parser::_handler143, Parser::_handler144, Parser::_handler145, Parser::_handler146, Parser::_handler147, Parser::_handler148, Parser::_handler149, Parser::_handler150, Parser::_handler151, Parser::_handler152, Parser::_handler153, Parser::_handler154, Parser::_handler155, Parser::_handler156, Parser::_handler157, Parser::_handler158, Parser::_handler159, Parser::_handler160, Parser::_handler161, Parser::_handler162, Parser::_handler163, Parser::_handler164, Parser::_handler165, Parser::_handler166, Parser::_handler167, Parser::_handler168, Parser::_handler169, Parser::_handler170, Parser::_handler171, Parser::_handler172, Parser::_handler173, Parser::_handler174, Parser::_handler175, Parser::_handler176, Parser::_handler177, Parser::_handler178, Parser::_handler179, Parser::_handler180, Parser::_handler181, Parser::_handler182, Parser::_handler183, Parser::_handler184, Parser::_handler185, Parser::_handler186, Parser::_handler187, Parser::_handler188, Parser::_handler189, Parser::_handler190, Parser::_handler191, Parser::_handler192, Parser::_handler193, Parser::_handler194, Parser::_handler195, Parser::_handler196, Parser::_handler197, Parser::_handler198, Parser::_handler199, Parser::_handler200, Parser::_handler201, Parser::_handler202, Parser::_handler203, Parser::_handler204, Parser::_handler205, Parser::_handler206, Parser::_handler207, Parser::_handler208, Parser::_handler209],
+    }
+  }
+
+  // Parses a string.
+  pub fn parse(&mut self, string: &'static str) -> TResult {
 
 
     // Initialize the tokenizer and the string.
@@ -1412,8 +1923,10 @@ impl Parser {
 
   fn _handler4(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _6 = pop!(self.values_stack, _6);
+    let mut _8 = pop!(self.values_stack, _8);
     self.values_stack.pop();
+    let mut _6 = pop!(self.values_stack, _7);
+    let mut _5 = pop!(self.values_stack, _6);
     let mut _4 = pop!(self.values_stack, _5);
     let mut _3 = pop!(self.values_stack, _0);
     let mut _2 = pop!(self.values_stack, _0);
@@ -1421,9 +1934,11 @@ impl Parser {
     let _0 = ClassDef {
       loc: _2.get_loc(),
       name: _3.value,
-      parent: _4,
-      field: _6,
+      parent: _5,
+      type_param: _4,
+      field: _8,
       sealed: _1,
+      implements: _6,
       ..D::default()
     };
     SV::_3(_0)
@@ -1431,196 +1946,493 @@ impl Parser {
 
   fn _handler5(&mut self) -> SV {
     self.values_stack.pop();
-    let _0 = true;
-    SV::_4(_0)
+    let mut _8 = pop!(self.values_stack, _8);
+    self.values_stack.pop();
+    let mut _6 = pop!(self.values_stack, _7);
+    let mut _5 = pop!(self.values_stack, _6);
+    let mut _4 = pop!(self.values_stack, _5);
+    let mut _3 = pop!(self.values_stack, _0);
+    let mut _2 = pop!(self.values_stack, _0);
+    self.values_stack.pop();
+    let _0 = ClassDef {
+      loc: _2.get_loc(),
+      name: _3.value,
+      parent: _5,
+      type_param: _4,
+      field: _8,
+      abstract_: true,
+      implements: _6,
+      ..D::default()
+    };
+    SV::_3(_0)
   }
 
   fn _handler6(&mut self) -> SV {
-    let _0 = false;
-    SV::_4(_0)
+    self.values_stack.pop();
+    let mut _4 = pop!(self.values_stack, _8);
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = ClassDef {
+      loc: _1.get_loc(),
+      name: _2.value,
+      field: _4,
+      interface: true,
+      ..D::default()
+    };
+    SV::_3(_0)
   }
 
   fn _handler7(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _0);
     self.values_stack.pop();
-    let _0 = Some(_2.value);
-    SV::_5(_0)
+    let mut _4 = pop!(self.values_stack, _9);
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = ClassDef {
+      loc: _1.get_loc(),
+      name: _2.value,
+      sealed: true,
+      enum_: Some(_4),
+      ..D::default()
+    };
+    SV::_3(_0)
   }
 
   fn _handler8(&mut self) -> SV {
-    let _0 = None;
-    SV::_5(_0)
+    let mut _3 = pop!(self.values_stack, _0);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _9);
+    _1.push((_3.get_loc(), _3.value));
+    let _0 = _1;
+    SV::_9(_0)
   }
 
   fn _handler9(&mut self) -> SV {
-    self.values_stack.pop();
-    let mut _2 = pop!(self.values_stack, _7);
-    let mut _1 = pop!(self.values_stack, _6);
-    _1.push(FieldDef::VarDef(_2));
-    let _0 = _1;
-    SV::_6(_0)
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = vec![(_1.get_loc(), _1.value)];
+    SV::_9(_0)
   }
 
   fn _handler10(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _8);
-    let mut _1 = pop!(self.values_stack, _6);
-    _1.push(FieldDef::MethodDef(_2));
-    let _0 = _1;
-    SV::_6(_0)
+    self.values_stack.pop();
+    let mut _2 = self.values_stack.pop().unwrap();
+    self.values_stack.pop();
+    let _0 = _2;
+    _0
   }
 
   fn _handler11(&mut self) -> SV {
     let _0 = Vec::new();
-    SV::_6(_0)
+    SV::_5(_0)
   }
 
   fn _handler12(&mut self) -> SV {
-    let mut _7 = pop!(self.values_stack, _11);
-    self.values_stack.pop();
-    let mut _5 = pop!(self.values_stack, _10);
+    let mut _3 = pop!(self.values_stack, _10);
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _0);
-    let mut _2 = pop!(self.values_stack, _9);
-    self.values_stack.pop();
-    let _0 = MethodDef::new(_3.get_loc(), _3.value, _2, _5, true, _7);
-    SV::_8(_0)
+    let mut _1 = pop!(self.values_stack, _5);
+    _3.index = _1.len();
+    _1.push(_3);
+    let _0 = _1;
+    SV::_5(_0)
   }
 
   fn _handler13(&mut self) -> SV {
-    let mut _6 = pop!(self.values_stack, _11);
-    self.values_stack.pop();
-    let mut _4 = pop!(self.values_stack, _10);
-    self.values_stack.pop();
-    let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _9);
-    let _0 = MethodDef::new(_2.get_loc(), _2.value, _1, _4, false, _6);
-    SV::_8(_0)
+    let mut _1 = pop!(self.values_stack, _10);
+    let _0 = vec![_1];
+    SV::_5(_0)
   }
 
   fn _handler14(&mut self) -> SV {
-    let mut _1 = self.values_stack.pop().unwrap();
-    let _0 = _1;
-    _0
+    let mut _2 = pop!(self.values_stack, _6);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = TypeParam {
+      loc: _1.get_loc(),
+      name: _1.value,
+      bound: _2.map(|name| Type { loc: _1.get_loc(), sem: SemanticType::Named(name, Vec::new()) }),
+      index: 0,
+    };
+    SV::_10(_0)
   }
 
   fn _handler15(&mut self) -> SV {
-    let _0 = Vec::new();
-    SV::_10(_0)
+    self.values_stack.pop();
+    let _0 = true;
+    SV::_4(_0)
   }
 
   fn _handler16(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _7);
-    self.values_stack.pop();
-    let mut _1 = pop!(self.values_stack, _10);
-    _1.push(_3);
-    let _0 = _1;
-    SV::_10(_0)
+    let _0 = false;
+    SV::_4(_0)
   }
 
   fn _handler17(&mut self) -> SV {
-    let mut _1 = pop!(self.values_stack, _7);
-    let _0 = vec![_1];
-    SV::_10(_0)
+    let mut _2 = pop!(self.values_stack, _0);
+    self.values_stack.pop();
+    let _0 = Some(_2.value);
+    SV::_6(_0)
   }
 
   fn _handler18(&mut self) -> SV {
-    self.values_stack.pop();
-    let mut _2 = pop!(self.values_stack, _12);
-    let mut _1 = pop!(self.values_stack, _0);
-    let _0 = Block {
-      loc: _1.get_loc(),
-      stmt: _2,
-      ..D::default()
-    };
-    SV::_11(_0)
+    let _0 = None;
+    SV::_6(_0)
   }
 
   fn _handler19(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _13);
-    let mut _1 = pop!(self.values_stack, _12);
-    _1.push(_2);
-    let _0 = _1;
-    SV::_12(_0)
+    let mut _2 = self.values_stack.pop().unwrap();
+    self.values_stack.pop();
+    let _0 = _2;
+    _0
   }
 
   fn _handler20(&mut self) -> SV {
     let _0 = Vec::new();
-    SV::_12(_0)
+    SV::_7(_0)
   }
 
   fn _handler21(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _0);
     self.values_stack.pop();
-    let mut _1 = pop!(self.values_stack, _14);
-    let _0 = Stmt::Simple(_1);
-    SV::_13(_0)
-  }
-
+    let mut _1 = pop!(self.values_stack, _7);
+    _1.push(_3.value);
+    let _0 = _1;
+    SV::_7(_0)
+  }
+
   fn _handler22(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = vec![_1.value];
+    SV::_7(_0)
+  }
+
+  fn _handler23(&mut self) -> SV {
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _5 = pop!(self.values_stack, _12);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _0);
+    let mut _2 = pop!(self.values_stack, _11);
+    let mut _1 = pop!(self.values_stack, _8);
+    let mut method_def = MethodDef::new(_3.get_loc(), _3.value, _2, _5, false, D::default());
+    method_def.abstract_ = true;
+    _1.push(FieldDef::MethodDef(method_def));
+    let _0 = _1;
+    SV::_8(_0)
+  }
+
+  fn _handler24(&mut self) -> SV {
+    let _0 = Vec::new();
+    SV::_8(_0)
+  }
+
+  fn _handler25(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _13);
+    let mut _1 = pop!(self.values_stack, _8);
+    _1.push(_2);
+    let _0 = _1;
+    SV::_8(_0)
+  }
+
+  fn _handler26(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _13);
+    let mut _2 = pop!(self.values_stack, _14);
+    let mut _1 = pop!(self.values_stack, _8);
+    _3.set_access(_2);
+    _1.push(_3);
+    let _0 = _1;
+    SV::_8(_0)
+  }
+
+  fn _handler27(&mut self) -> SV {
+    let _0 = Vec::new();
+    SV::_8(_0)
+  }
+
+  fn _handler28(&mut self) -> SV {
+    self.values_stack.pop();
+    let _0 = Access::Public;
+    SV::_14(_0)
+  }
+
+  fn _handler29(&mut self) -> SV {
+    self.values_stack.pop();
+    let _0 = Access::Protected;
+    SV::_14(_0)
+  }
+
+  fn _handler30(&mut self) -> SV {
+    self.values_stack.pop();
+    let _0 = Access::Private;
+    SV::_14(_0)
+  }
+
+  fn _handler31(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _15);
+    let _0 = FieldDef::VarDef(_1);
+    SV::_13(_0)
+  }
+
+  fn _handler32(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _15);
+    self.values_stack.pop();
+    _2.static_ = true;
+    let _0 = FieldDef::VarDef(_2);
+    SV::_13(_0)
+  }
+
+  fn _handler33(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _5 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _0);
+    let mut _2 = pop!(self.values_stack, _11);
+    self.values_stack.pop();
+    let mut var_def = VarDef::new(_3.get_loc(), _3.value, _2, Some(_5), self.get_loc());
+    var_def.static_ = true;
+    let _0 = FieldDef::VarDef(var_def);
+    SV::_13(_0)
+  }
+
+  fn _handler34(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _17);
+    let _0 = FieldDef::MethodDef(_1);
+    SV::_13(_0)
+  }
+
+  fn _handler35(&mut self) -> SV {
+    let mut _5 = pop!(self.values_stack, _18);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _12);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let ret_t = Type { loc: _1.get_loc(), sem: VOID };
+    let _0 = FieldDef::Ctor(MethodDef::new(_1.get_loc(), _1.value, ret_t, _3, false, _5));
+    SV::_13(_0)
+  }
+
+  fn _handler36(&mut self) -> SV {
+    let mut _7 = pop!(self.values_stack, _18);
+    self.values_stack.pop();
+    let mut _5 = pop!(self.values_stack, _12);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _0);
+    let mut _2 = pop!(self.values_stack, _11);
+    self.values_stack.pop();
+    let _0 = MethodDef::new(_3.get_loc(), _3.value, _2, _5, true, _7);
+    SV::_17(_0)
+  }
+
+  fn _handler37(&mut self) -> SV {
+    let mut _6 = pop!(self.values_stack, _18);
+    self.values_stack.pop();
+    let mut _4 = pop!(self.values_stack, _12);
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _11);
+    let _0 = MethodDef::new(_2.get_loc(), _2.value, _1, _4, false, _6);
+    SV::_17(_0)
+  }
+
+  fn _handler38(&mut self) -> SV {
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _5 = pop!(self.values_stack, _12);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _0);
+    let mut _2 = pop!(self.values_stack, _11);
+    self.values_stack.pop();
+    let mut method_def = MethodDef::new(_3.get_loc(), _3.value, _2, _5, false, D::default());
+    method_def.abstract_ = true;
+    let _0 = method_def;
+    SV::_17(_0)
+  }
+
+  fn _handler39(&mut self) -> SV {
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler23(&mut self) -> SV {
+  fn _handler40(&mut self) -> SV {
+    let _0 = Vec::new();
+    SV::_12(_0)
+  }
+
+  fn _handler41(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _15);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _12);
+    _1.push(_3);
+    let _0 = _1;
+    SV::_12(_0)
+  }
+
+  fn _handler42(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _15);
+    let _0 = vec![_1];
+    SV::_12(_0)
+  }
+
+  fn _handler43(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _19);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Block {
+      loc: _1.get_loc(),
+      stmt: _2,
+      ..D::default()
+    };
+    SV::_18(_0)
+  }
+
+  fn _handler44(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _20);
+    let mut _1 = pop!(self.values_stack, _19);
+    _1.push(_2);
+    let _0 = _1;
+    SV::_19(_0)
+  }
+
+  fn _handler45(&mut self) -> SV {
+    let _0 = Vec::new();
+    SV::_19(_0)
+  }
+
+  fn _handler46(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _21);
+    let _0 = Stmt::Simple(_1);
+    SV::_20(_0)
+  }
+
+  fn _handler47(&mut self) -> SV {
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler24(&mut self) -> SV {
+  fn _handler48(&mut self) -> SV {
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler25(&mut self) -> SV {
+  fn _handler49(&mut self) -> SV {
     self.values_stack.pop();
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler26(&mut self) -> SV {
+  fn _handler50(&mut self) -> SV {
     self.values_stack.pop();
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler27(&mut self) -> SV {
+  fn _handler51(&mut self) -> SV {
     self.values_stack.pop();
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler28(&mut self) -> SV {
+  fn _handler52(&mut self) -> SV {
     self.values_stack.pop();
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler29(&mut self) -> SV {
+  fn _handler53(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _20);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    _3.set_label(_1.value);
+    let _0 = _3;
+    SV::_20(_0)
+  }
+
+  fn _handler54(&mut self) -> SV {
+    self.values_stack.pop();
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler30(&mut self) -> SV {
+  fn _handler55(&mut self) -> SV {
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler31(&mut self) -> SV {
-    let mut _1 = pop!(self.values_stack, _11);
+  fn _handler56(&mut self) -> SV {
+    let mut _1 = self.values_stack.pop().unwrap();
+    let _0 = _1;
+    _0
+  }
+
+  fn _handler57(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _18);
     let _0 = Stmt::Block(_1);
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler32(&mut self) -> SV {
-    let mut _1 = pop!(self.values_stack, _13);
+  fn _handler58(&mut self) -> SV {
+    let mut _1 = self.values_stack.pop().unwrap();
+    let _0 = _1;
+    _0
+  }
+
+  fn _handler59(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _16);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Throw(Throw { loc: _1.get_loc(), expr: _2 });
+    SV::_20(_0)
+  }
+
+  fn _handler60(&mut self) -> SV {
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Assert(Assert { loc: _1.get_loc(), cond: _3, msg: None });
+    SV::_20(_0)
+  }
+
+  fn _handler61(&mut self) -> SV {
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _5 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Assert(Assert { loc: _1.get_loc(), cond: _3, msg: Some(_5) });
+    SV::_20(_0)
+  }
+
+  fn _handler62(&mut self) -> SV {
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _22);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::SuperCall(SuperCall {
+      loc: _1.get_loc(),
+      arg: _3,
+      ctor: ptr::null(),
+    });
+    SV::_20(_0)
+  }
+
+  fn _handler63(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _20);
     let _0 = match _1 {
       Stmt::Block(block) => block,
       stmt => Block {
@@ -1629,73 +2441,137 @@ impl Parser {
         ..D::default()
       }
     };
-    SV::_11(_0)
+    SV::_18(_0)
   }
 
-  fn _handler33(&mut self) -> SV {
-    let mut _5 = pop!(self.values_stack, _11);
+  fn _handler64(&mut self) -> SV {
+    let mut _1 = self.values_stack.pop().unwrap();
+    let _0 = _1;
+    _0
+  }
+
+  fn _handler65(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _15);
+    let mut _1 = self.values_stack.pop().unwrap();
+    let _0 = _1;
+    _0
+  }
+
+  fn _handler66(&mut self) -> SV {
+    let mut _1 = self.values_stack.pop().unwrap();
+    let _0 = _1;
+    _0
+  }
+
+  fn _handler67(&mut self) -> SV {
+    let mut _1 = self.values_stack.pop().unwrap();
+    let _0 = _1;
+    _0
+  }
+
+  fn _handler68(&mut self) -> SV {
+    let mut _5 = pop!(self.values_stack, _18);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
     self.values_stack.pop();
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Stmt::While(While {
       loc: _1.get_loc(),
+      label: None,
       cond: _3,
       body: _5,
     });
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler34(&mut self) -> SV {
-    let mut _9 = pop!(self.values_stack, _11);
+  fn _handler69(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _5 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _18);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::DoWhile(DoWhile {
+      loc: _1.get_loc(),
+      label: None,
+      body: _2,
+      cond: _5,
+    });
+    SV::_20(_0)
+  }
+
+  fn _handler70(&mut self) -> SV {
+    let mut _9 = pop!(self.values_stack, _18);
     self.values_stack.pop();
-    let mut _7 = pop!(self.values_stack, _14);
+    let mut _7 = pop!(self.values_stack, _21);
     self.values_stack.pop();
-    let mut _5 = pop!(self.values_stack, _15);
+    let mut _5 = pop!(self.values_stack, _16);
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _14);
+    let mut _3 = pop!(self.values_stack, _21);
     self.values_stack.pop();
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Stmt::For(For {
       loc: _1.get_loc(),
+      label: None,
       init: _3,
       cond: _5,
       update: _7,
       body: _9,
     });
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler35(&mut self) -> SV {
-    let mut _9 = pop!(self.values_stack, _11);
+  fn _handler71(&mut self) -> SV {
+    let mut _9 = pop!(self.values_stack, _18);
     self.values_stack.pop();
-    let mut _7 = pop!(self.values_stack, _16);
-    let mut _6 = pop!(self.values_stack, _15);
+    let mut _7 = pop!(self.values_stack, _23);
+    let mut _6 = pop!(self.values_stack, _16);
     self.values_stack.pop();
     let mut _4 = pop!(self.values_stack, _0);
-    let mut _3 = pop!(self.values_stack, _9);
+    let mut _3 = pop!(self.values_stack, _11);
     self.values_stack.pop();
     self.values_stack.pop();
     let _0 = Stmt::Foreach(Foreach {
+      label: None,
       def: VarDef::new(_4.get_loc(), _4.value, _3, None, _4.get_loc()),
       arr: _6,
       cond: _7,
       body: _9,
     });
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler36(&mut self) -> SV {
+  fn _handler72(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
-    let _0 = Stmt::Break(Break { loc: _1.get_loc() });
-    SV::_13(_0)
+    let _0 = Stmt::Break(Break { loc: _1.get_loc(), label: None });
+    SV::_20(_0)
   }
 
-  fn _handler37(&mut self) -> SV {
-    let mut _6 = pop!(self.values_stack, _17);
-    let mut _5 = pop!(self.values_stack, _11);
+  fn _handler73(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Break(Break { loc: _1.get_loc(), label: Some(_2.value) });
+    SV::_20(_0)
+  }
+
+  fn _handler74(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Continue(Continue { loc: _1.get_loc(), label: None });
+    SV::_20(_0)
+  }
+
+  fn _handler75(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Continue(Continue { loc: _1.get_loc(), label: Some(_2.value) });
+    SV::_20(_0)
+  }
+
+  fn _handler76(&mut self) -> SV {
+    let mut _6 = pop!(self.values_stack, _24);
+    let mut _5 = pop!(self.values_stack, _18);
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _15);
+    let mut _3 = pop!(self.values_stack, _16);
     self.values_stack.pop();
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Stmt::If(If {
@@ -1704,24 +2580,24 @@ impl Parser {
       on_true: _5,
       on_false: _6,
     });
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler38(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _11);
+  fn _handler77(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _18);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Some(_2);
-    SV::_17(_0)
+    SV::_24(_0)
   }
 
-  fn _handler39(&mut self) -> SV {
+  fn _handler78(&mut self) -> SV {
     let _0 = None;
-    SV::_17(_0)
+    SV::_24(_0)
   }
 
-  fn _handler40(&mut self) -> SV {
+  fn _handler79(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _5 = pop!(self.values_stack, _15);
+    let mut _5 = pop!(self.values_stack, _16);
     self.values_stack.pop();
     let mut _3 = pop!(self.values_stack, _0);
     self.values_stack.pop();
@@ -1733,415 +2609,682 @@ impl Parser {
       dst_sym: ptr::null(),
       src: _5,
     });
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler41(&mut self) -> SV {
+  fn _handler80(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Type { loc: _1.get_loc(), sem: VAR };
-    SV::_9(_0)
+    SV::_11(_0)
   }
 
-  fn _handler42(&mut self) -> SV {
+  fn _handler81(&mut self) -> SV {
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler43(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _15);
+  fn _handler82(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _16);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Some(_2);
-    SV::_16(_0)
+    SV::_23(_0)
   }
 
-  fn _handler44(&mut self) -> SV {
+  fn _handler83(&mut self) -> SV {
     let _0 = None;
-    SV::_16(_0)
+    SV::_23(_0)
   }
 
-  fn _handler45(&mut self) -> SV {
+  fn _handler84(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _18);
+    let mut _3 = pop!(self.values_stack, _25);
     self.values_stack.pop();
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Stmt::Guarded(Guarded {
       loc: _1.get_loc(),
       guarded: _3,
     });
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler46(&mut self) -> SV {
+  fn _handler85(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _6 = pop!(self.values_stack, _26);
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Switch(Switch {
+      loc: _1.get_loc(),
+      cond: _3,
+      case: _6,
+    });
+    SV::_20(_0)
+  }
+
+  fn _handler86(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _27);
+    let mut _1 = pop!(self.values_stack, _26);
+    _1.push(_2);
+    let _0 = _1;
+    SV::_26(_0)
+  }
+
+  fn _handler87(&mut self) -> SV {
+    let _0 = Vec::new();
+    SV::_26(_0)
+  }
+
+  fn _handler88(&mut self) -> SV {
+    let mut _4 = pop!(self.values_stack, _19);
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _28);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Case { loc: _1.get_loc(), value: Some(_2), name: None, body: Block { loc: NO_LOC, stmt: _4, ..D::default() } };
+    SV::_27(_0)
+  }
+
+  fn _handler89(&mut self) -> SV {
+    let mut _4 = pop!(self.values_stack, _19);
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Case { loc: _1.get_loc(), value: Some(0), name: Some(_2.value), body: Block { loc: NO_LOC, stmt: _4, ..D::default() } };
+    SV::_27(_0)
+  }
+
+  fn _handler90(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _19);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Case { loc: _1.get_loc(), value: None, name: None, body: Block { loc: NO_LOC, stmt: _3, ..D::default() } };
+    SV::_27(_0)
+  }
+
+  fn _handler91(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = _1.value.parse::<i32>().unwrap_or_else(|_| {
+      self.errors.push(Error::new(_1.get_loc(), IntTooLarge { string: _1.value.to_string() }));
+      0
+    });
+    SV::_28(_0)
+  }
+
+  fn _handler92(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _0);
+    self.values_stack.pop();
+    let _0 = format!("-{}", _2.value).parse::<i32>().unwrap_or_else(|_| {
+      self.errors.push(Error::new(_2.get_loc(), IntTooLarge { string: format!("-{}", _2.value) }));
+      0
+    });
+    SV::_28(_0)
+  }
+
+  fn _handler93(&mut self) -> SV {
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler47(&mut self) -> SV {
+  fn _handler94(&mut self) -> SV {
     let _0 = Vec::new();
-    SV::_18(_0)
+    SV::_25(_0)
   }
 
-  fn _handler48(&mut self) -> SV {
-    let mut _5 = pop!(self.values_stack, _11);
+  fn _handler95(&mut self) -> SV {
+    let mut _5 = pop!(self.values_stack, _18);
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _15);
+    let mut _3 = pop!(self.values_stack, _16);
     self.values_stack.pop();
-    let mut _1 = pop!(self.values_stack, _18);
+    let mut _1 = pop!(self.values_stack, _25);
     _1.push((_3, _5));
     let _0 = _1;
-    SV::_18(_0)
+    SV::_25(_0)
   }
 
-  fn _handler49(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _11);
+  fn _handler96(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _18);
     self.values_stack.pop();
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = vec![(_1, _3)];
-    SV::_18(_0)
+    SV::_25(_0)
   }
 
-  fn _handler50(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _15);
+  fn _handler97(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _29);
+    let mut _2 = pop!(self.values_stack, _18);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Try(Try { loc: _1.get_loc(), body: _2, catch: _3, finally: None });
+    SV::_20(_0)
+  }
+
+  fn _handler98(&mut self) -> SV {
+    let mut _5 = pop!(self.values_stack, _18);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _29);
+    let mut _2 = pop!(self.values_stack, _18);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Try(Try { loc: _1.get_loc(), body: _2, catch: _3, finally: Some(_5) });
+    SV::_20(_0)
+  }
+
+  fn _handler99(&mut self) -> SV {
+    let mut _4 = pop!(self.values_stack, _18);
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _18);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Stmt::Try(Try { loc: _1.get_loc(), body: _2, catch: Vec::new(), finally: Some(_4) });
+    SV::_20(_0)
+  }
+
+  fn _handler100(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _30);
+    let mut _1 = pop!(self.values_stack, _29);
+    _1.push(_2);
+    let _0 = _1;
+    SV::_29(_0)
+  }
+
+  fn _handler101(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _30);
+    let _0 = vec![_1];
+    SV::_29(_0)
+  }
+
+  fn _handler102(&mut self) -> SV {
+    let mut _6 = pop!(self.values_stack, _18);
+    self.values_stack.pop();
+    let mut _4 = pop!(self.values_stack, _0);
+    let mut _3 = pop!(self.values_stack, _11);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Catch {
+      loc: _1.get_loc(),
+      def: VarDef::new(_4.get_loc(), _4.value, _3, None, _4.get_loc()),
+      body: _6,
+    };
+    SV::_30(_0)
+  }
+
+  fn _handler103(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _16);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Stmt::Return(Return {
       loc: _1.get_loc(),
       expr: Some(_2),
     });
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler51(&mut self) -> SV {
+  fn _handler104(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Stmt::Return(Return {
       loc: _1.get_loc(),
       expr: None,
     });
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler52(&mut self) -> SV {
+  fn _handler105(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _19);
+    let mut _3 = pop!(self.values_stack, _22);
     self.values_stack.pop();
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Stmt::Print(Print {
       loc: _1.get_loc(),
       print: _3,
     });
-    SV::_13(_0)
+    SV::_20(_0)
   }
 
-  fn _handler53(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler106(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     self.values_stack.pop();
-    let mut _1 = pop!(self.values_stack, _19);
+    let mut _1 = pop!(self.values_stack, _22);
     _1.push(_3);
     let _0 = _1;
-    SV::_19(_0)
+    SV::_22(_0)
   }
 
-  fn _handler54(&mut self) -> SV {
-    let mut _1 = pop!(self.values_stack, _15);
+  fn _handler107(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = vec![_1];
-    SV::_19(_0)
+    SV::_22(_0)
   }
 
-  fn _handler55(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler108(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = Simple::Assign(Assign {
       loc: _2.get_loc(),
       dst: _1,
       src: _3,
+      op: None,
     });
-    SV::_14(_0)
+    SV::_21(_0)
   }
 
-  fn _handler56(&mut self) -> SV {
-    let mut _4 = pop!(self.values_stack, _15);
+  fn _handler109(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::Add);
+    SV::_21(_0)
+  }
+
+  fn _handler110(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::Sub);
+    SV::_21(_0)
+  }
+
+  fn _handler111(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::Mul);
+    SV::_21(_0)
+  }
+
+  fn _handler112(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::Div);
+    SV::_21(_0)
+  }
+
+  fn _handler113(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::Mod);
+    SV::_21(_0)
+  }
+
+  fn _handler114(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::BAnd);
+    SV::_21(_0)
+  }
+
+  fn _handler115(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::BOr);
+    SV::_21(_0)
+  }
+
+  fn _handler116(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::BXor);
+    SV::_21(_0)
+  }
+
+  fn _handler117(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::Shl);
+    SV::_21(_0)
+  }
+
+  fn _handler118(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_compound(_1, _2, _3, Operator::Shr);
+    SV::_21(_0)
+  }
+
+  fn _handler119(&mut self) -> SV {
+    let mut _4 = pop!(self.values_stack, _16);
     let mut _3 = pop!(self.values_stack, _0);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _9);
+    let mut _1 = pop!(self.values_stack, _11);
     let _0 = Simple::VarDef(VarDef::new(_2.get_loc(), _2.value, _1, Some(_4), self.get_loc()));
-    SV::_14(_0)
+    SV::_21(_0)
   }
 
-  fn _handler57(&mut self) -> SV {
-    let mut _4 = pop!(self.values_stack, _15);
+  fn _handler120(&mut self) -> SV {
+    let mut _4 = pop!(self.values_stack, _16);
     let mut _3 = pop!(self.values_stack, _0);
     let mut _2 = pop!(self.values_stack, _0);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Simple::VarDef(VarDef::new(_2.get_loc(), _2.value, Type { loc: _1.get_loc(), sem: VAR }, Some(_4), self.get_loc()));
-    SV::_14(_0)
+    SV::_21(_0)
   }
 
-  fn _handler58(&mut self) -> SV {
-    let mut _1 = pop!(self.values_stack, _7);
+  fn _handler121(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _15);
     let _0 = Simple::VarDef(_1);
-    SV::_14(_0)
+    SV::_21(_0)
   }
 
-  fn _handler59(&mut self) -> SV {
-    let mut _1 = pop!(self.values_stack, _15);
+  fn _handler122(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = Simple::Expr(_1);
-    SV::_14(_0)
+    SV::_21(_0)
   }
 
-  fn _handler60(&mut self) -> SV {
+  fn _handler123(&mut self) -> SV {
     let _0 = Simple::Skip;
-    SV::_14(_0)
+    SV::_21(_0)
   }
 
-  fn _handler61(&mut self) -> SV {
+  fn _handler124(&mut self) -> SV {
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler62(&mut self) -> SV {
+  fn _handler125(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _4 = pop!(self.values_stack, _19);
+    let mut _4 = pop!(self.values_stack, _22);
     self.values_stack.pop();
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _16);
+    let mut _1 = pop!(self.values_stack, _23);
     let _0 = Expr::new(_2.get_loc(), ExprData::Call(Call {
       owner: _1.map(|s| Box::new(s)),
       name: _2.value,
       arg: _4,
       is_arr_len: false,
+      string_method: None,
       method: ptr::null(),
     }));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler63(&mut self) -> SV {
+  fn _handler126(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _22);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Expr::new(_1.get_loc(), ExprData::Call(Call {
+      owner: None,
+      name: _1.value,
+      arg: _3,
+      is_arr_len: false,
+      string_method: None,
+      method: ptr::null(),
+    }));
+    SV::_16(_0)
+  }
+
+  fn _handler127(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Expr::with_type(_1.get_loc(), INT, ExprData::IntConst(_1.value.parse::<i32>().unwrap_or_else(|_| {
       self.errors.push(Error::new(_1.get_loc(), IntTooLarge { string: _1.value.to_string() }));
       0
     })));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler64(&mut self) -> SV {
+  fn _handler128(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let digits = &_1.value[.._1.value.len() - 1];
+    let _0 = Expr::with_type(_1.get_loc(), LONG, ExprData::LongConst(digits.parse::<i64>().unwrap_or_else(|_| {
+      self.errors.push(Error::new(_1.get_loc(), IntTooLarge { string: _1.value.to_string() }));
+      0
+    })));
+    SV::_16(_0)
+  }
+
+  fn _handler129(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Expr::with_type(_1.get_loc(), DOUBLE, ExprData::DoubleConst(_1.value.parse::<f64>().unwrap()));
+    SV::_16(_0)
+  }
+
+  fn _handler130(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Expr::with_type(_1.get_loc(), CHAR, ExprData::CharConst(parse_char(_1.value).unwrap_or_else(|| {
+      self.errors.push(Error::new(_1.get_loc(), BadCharConst { string: _1.value.to_string() }));
+      0
+    })));
+    SV::_16(_0)
+  }
+
+  fn _handler131(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Expr::with_type(_1.get_loc(), BOOL, ExprData::BoolConst(true));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler65(&mut self) -> SV {
+  fn _handler132(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Expr::with_type(_1.get_loc(), BOOL, ExprData::BoolConst(false));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler66(&mut self) -> SV {
+  fn _handler133(&mut self) -> SV {
     self.values_stack.pop();
     let _0 = Expr::with_type(Loc(self.tokenizer.string_builder.1, self.tokenizer.string_builder.2),
                              STRING, ExprData::StringConst(self.tokenizer.string_builder.0.clone()));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler67(&mut self) -> SV {
+  fn _handler134(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _2 = pop!(self.values_stack, _19);
+    let mut _2 = pop!(self.values_stack, _22);
     self.values_stack.pop();
     let _0 = Expr::new(self.get_loc(), ExprData::ArrayConst(_2));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler68(&mut self) -> SV {
+  fn _handler135(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
-    let _0 = Expr::new(_1.get_loc(), ExprData::Null);
-    SV::_15(_0)
+    let _0 = Expr::with_type(_1.get_loc(), NULL, ExprData::Null);
+    SV::_16(_0)
   }
 
-  fn _handler69(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler136(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Add);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler70(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler137(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Sub);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler71(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler138(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Mul);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler72(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler139(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Div);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler73(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler140(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Mod);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler74(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler141(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Eq);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler75(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler142(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Ne);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler76(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler143(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Lt);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler77(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler144(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Gt);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler78(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler145(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Le);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler79(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler146(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Ge);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler80(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler147(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::And);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler81(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler148(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Or);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler82(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler149(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Repeat);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler83(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler150(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::BAnd);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler84(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler151(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::BOr);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler85(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler152(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::BXor);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler86(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler153(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Shl);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler87(&mut self) -> SV {
-    let mut _3 = pop!(self.values_stack, _15);
+  fn _handler154(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_binary(_1, _2, _3, Operator::Shr);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler88(&mut self) -> SV {
+  fn _handler155(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _5 = pop!(self.values_stack, _15);
+    let mut _5 = pop!(self.values_stack, _16);
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _15);
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = Expr::new(_2.get_loc(),
                        ExprData::Range(Range { arr: Box::new(_1), lb: Box::new(_3), ub: Box::new(_5) }));
-    SV::_15(_0)
+    SV::_16(_0)
+  }
+
+  fn _handler156(&mut self) -> SV {
+    let mut _5 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = Expr::new(_2.get_loc(), ExprData::Conditional(Conditional {
+      cond: Box::new(_1),
+      on_true: Box::new(_3),
+      on_false: Box::new(_5),
+    }));
+    SV::_16(_0)
   }
 
-  fn _handler89(&mut self) -> SV {
-    let mut _6 = pop!(self.values_stack, _15);
+  fn _handler157(&mut self) -> SV {
+    let mut _6 = pop!(self.values_stack, _16);
     self.values_stack.pop();
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _15);
+    let mut _3 = pop!(self.values_stack, _16);
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = Expr::new(_2.get_loc(),
                        ExprData::Default(Default { arr: Box::new(_1), idx: Box::new(_3), dft: Box::new(_6) }));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler90(&mut self) -> SV {
+  fn _handler158(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _6 = pop!(self.values_stack, _15);
+    let mut _6 = pop!(self.values_stack, _16);
     self.values_stack.pop();
     let mut _4 = pop!(self.values_stack, _0);
     self.values_stack.pop();
-    let mut _2 = pop!(self.values_stack, _15);
+    let mut _2 = pop!(self.values_stack, _16);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Expr::new(_1.get_loc(), ExprData::Comprehension(Comprehension {
       expr: Box::new(_2),
@@ -2149,18 +3292,18 @@ impl Parser {
       arr: Box::new(_6),
       cond: None,
     }));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler91(&mut self) -> SV {
+  fn _handler159(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _8 = pop!(self.values_stack, _15);
+    let mut _8 = pop!(self.values_stack, _16);
     self.values_stack.pop();
-    let mut _6 = pop!(self.values_stack, _15);
+    let mut _6 = pop!(self.values_stack, _16);
     self.values_stack.pop();
     let mut _4 = pop!(self.values_stack, _0);
     self.values_stack.pop();
-    let mut _2 = pop!(self.values_stack, _15);
+    let mut _2 = pop!(self.values_stack, _16);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Expr::new(_1.get_loc(), ExprData::Comprehension(Comprehension {
       expr: Box::new(_2),
@@ -2168,10 +3311,10 @@ impl Parser {
       arr: Box::new(_6),
       cond: Some(Box::new(_8)),
     }));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler92(&mut self) -> SV {
+  fn _handler160(&mut self) -> SV {
     self.values_stack.pop();
     let mut _2 = self.values_stack.pop().unwrap();
     self.values_stack.pop();
@@ -2179,201 +3322,409 @@ impl Parser {
     _0
   }
 
-  fn _handler93(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _15);
+  fn _handler161(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _16);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = gen_unary(_1, _2, Operator::Neg);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler94(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _15);
+  fn _handler162(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _16);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = gen_unary(_1, _2, Operator::Not);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler95(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _15);
+  fn _handler163(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _16);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = gen_unary(_1, _2, Operator::PreInc);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler96(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _15);
+  fn _handler164(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _16);
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = gen_unary(_1, _2, Operator::PreDec);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler97(&mut self) -> SV {
+  fn _handler165(&mut self) -> SV {
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_unary(_2, _1, Operator::PostInc);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler98(&mut self) -> SV {
+  fn _handler166(&mut self) -> SV {
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = gen_unary(_2, _1, Operator::PostDec);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler99(&mut self) -> SV {
+  fn _handler167(&mut self) -> SV {
     self.values_stack.pop();
     self.values_stack.pop();
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Expr::with_type(_1.get_loc(), INT, ExprData::ReadInt);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler100(&mut self) -> SV {
+  fn _handler168(&mut self) -> SV {
     self.values_stack.pop();
     self.values_stack.pop();
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Expr::with_type(_1.get_loc(), STRING, ExprData::ReadLine);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler101(&mut self) -> SV {
+  fn _handler169(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = gen_convert(_1, _3, ConvertOp::CharToInt);
+    SV::_16(_0)
+  }
+
+  fn _handler170(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = gen_convert(_1, _3, ConvertOp::IntToChar);
+    SV::_16(_0)
+  }
+
+  fn _handler171(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = gen_convert(_1, _3, ConvertOp::StringToChars);
+    SV::_16(_0)
+  }
+
+  fn _handler172(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = gen_convert(_1, _3, ConvertOp::CharsToString);
+    SV::_16(_0)
+  }
+
+  fn _handler173(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Expr::new(_1.get_loc(), ExprData::This);
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler102(&mut self) -> SV {
+  fn _handler174(&mut self) -> SV {
     self.values_stack.pop();
+    let mut _4 = pop!(self.values_stack, _22);
     self.values_stack.pop();
     let mut _2 = pop!(self.values_stack, _0);
     let mut _1 = pop!(self.values_stack, _0);
-    let _0 = Expr::new(_1.get_loc(), ExprData::NewClass { name: _2.value });
-    SV::_15(_0)
+    let _0 = Expr::new(_1.get_loc(), ExprData::NewClass { name: _2.value, type_arg: Vec::new(), arg: _4 });
+    SV::_16(_0)
   }
 
-  fn _handler103(&mut self) -> SV {
+  fn _handler175(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _7 = pop!(self.values_stack, _22);
+    self.values_stack.pop();
     self.values_stack.pop();
-    let mut _4 = pop!(self.values_stack, _15);
+    let mut _4 = pop!(self.values_stack, _31);
     self.values_stack.pop();
-    let mut _2 = pop!(self.values_stack, _9);
+    let mut _2 = pop!(self.values_stack, _0);
     let mut _1 = pop!(self.values_stack, _0);
-    let _0 = Expr::new(_1.get_loc(), ExprData::NewArray { elem_t: _2, len: Box::new(_4) });
-    SV::_15(_0)
+    let _0 = Expr::new(_1.get_loc(), ExprData::NewClass { name: _2.value, type_arg: _4.into_iter().map(|t| t.sem).collect(), arg: _7 });
+    SV::_16(_0)
   }
 
-  fn _handler104(&mut self) -> SV {
+  fn _handler176(&mut self) -> SV {
+    let mut _1 = self.values_stack.pop().unwrap();
+    let _0 = _1;
+    _0
+  }
+
+  fn _handler177(&mut self) -> SV {
+    let mut _1 = self.values_stack.pop().unwrap();
+    let _0 = _1;
+    _0
+  }
+
+  fn _handler178(&mut self) -> SV {
     self.values_stack.pop();
     let mut _5 = pop!(self.values_stack, _0);
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _15);
+    let mut _3 = pop!(self.values_stack, _16);
     self.values_stack.pop();
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Expr::with_type(_1.get_loc(), BOOL, ExprData::TypeTest { expr: Box::new(_3), name: _5.value, target_class: ptr::null() });
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler105(&mut self) -> SV {
-    let mut _5 = pop!(self.values_stack, _15);
+  fn _handler179(&mut self) -> SV {
+    let mut _4 = pop!(self.values_stack, _16);
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _0);
+    let mut _2 = pop!(self.values_stack, _11);
     self.values_stack.pop();
+    let _0 = gen_cast(_2, _4).unwrap_or_else(|(t, e)| {
+      self.errors.push(Error::new(t.loc, BadCastType { type_: t.sem.to_string() }));
+      e
+    });
+    SV::_16(_0)
+  }
+
+  fn _handler180(&mut self) -> SV {
     self.values_stack.pop();
-    let _0 = Expr::new(_5.loc, ExprData::TypeCast { name: _3.value, expr: Box::new(_5) });
-    SV::_15(_0)
+    let mut _3 = pop!(self.values_stack, _22);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = Expr::new(_1.loc, ExprData::Apply(Apply { func: Box::new(_1), arg: _3 }));
+    SV::_16(_0)
   }
 
-  fn _handler106(&mut self) -> SV {
-    let mut _2 = pop!(self.values_stack, _0);
+  fn _handler181(&mut self) -> SV {
+    let mut _6 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _12);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let body = Block { loc: _6.loc, stmt: vec![Stmt::Simple(Simple::Expr(_6))], ..D::default() };
+    let _0 = gen_lambda(_1.get_loc(), _3, body, true);
+    SV::_16(_0)
+  }
+
+  fn _handler182(&mut self) -> SV {
+    let mut _5 = pop!(self.values_stack, _18);
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _12);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = gen_lambda(_1.get_loc(), _3, _5, false);
+    SV::_16(_0)
+  }
+
+  fn _handler183(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _4 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _11);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Expr::new(_1.get_loc(), ExprData::NewArray { elem_t: _2, len: Box::new(_4), dims: Vec::new() });
+    SV::_16(_0)
+  }
+
+  fn _handler184(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _3 = pop!(self.values_stack, _16);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_new_array_dim(_1, Some(_3));
+    SV::_16(_0)
+  }
+
+  fn _handler185(&mut self) -> SV {
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_new_array_dim(_1, None);
+    SV::_16(_0)
+  }
+
+  fn _handler186(&mut self) -> SV {
+    self.values_stack.pop();
+    self.values_stack.pop();
     let mut _1 = pop!(self.values_stack, _16);
+    let _0 = gen_new_array_dim(_1, None);
+    SV::_16(_0)
+  }
+
+  fn _handler187(&mut self) -> SV {
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _23);
     let _0 = Expr::new(_2.get_loc(), ExprData::Id(Id {
       owner: _1.map(|e| Box::new(e)),
       name: _2.value,
       symbol: ptr::null(),
       for_assign: D::default(),
     }));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler107(&mut self) -> SV {
+  fn _handler188(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Expr::new(_1.get_loc(), ExprData::Id(Id {
+      owner: None,
+      name: _1.value,
+      symbol: ptr::null(),
+      for_assign: D::default(),
+    }));
+    SV::_16(_0)
+  }
+
+  fn _handler189(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _3 = pop!(self.values_stack, _15);
+    let mut _3 = pop!(self.values_stack, _16);
     self.values_stack.pop();
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = Expr::new(_1.loc, ExprData::Indexed(Indexed {
       arr: Box::new(_1),
       idx: Box::new(_3),
       for_assign: D::default(),
     }));
-    SV::_15(_0)
+    SV::_16(_0)
   }
 
-  fn _handler108(&mut self) -> SV {
+  fn _handler190(&mut self) -> SV {
     self.values_stack.pop();
-    let mut _1 = pop!(self.values_stack, _15);
+    let mut _1 = pop!(self.values_stack, _16);
     let _0 = Some(_1);
-    SV::_16(_0)
+    SV::_23(_0)
   }
 
-  fn _handler109(&mut self) -> SV {
-    let _0 = None;
-    SV::_16(_0)
+  fn _handler191(&mut self) -> SV {
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Some(Expr::new(_1.get_loc(), ExprData::Super));
+    SV::_23(_0)
   }
 
-  fn _handler110(&mut self) -> SV {
+  fn _handler192(&mut self) -> SV {
     let mut _1 = self.values_stack.pop().unwrap();
     let _0 = _1;
     _0
   }
 
-  fn _handler111(&mut self) -> SV {
+  fn _handler193(&mut self) -> SV {
     let _0 = Vec::new();
-    SV::_19(_0)
+    SV::_22(_0)
   }
 
-  fn _handler112(&mut self) -> SV {
+  fn _handler194(&mut self) -> SV {
     let mut _2 = pop!(self.values_stack, _0);
-    let mut _1 = pop!(self.values_stack, _9);
+    let mut _1 = pop!(self.values_stack, _11);
     let _0 = VarDef::new(_2.get_loc(), _2.value, _1, None, self.get_loc());
-    SV::_7(_0)
+    SV::_15(_0)
   }
 
-  fn _handler113(&mut self) -> SV {
+  fn _handler195(&mut self) -> SV {
+    let mut _3 = pop!(self.values_stack, _11);
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _31);
+    _1.push(_3);
+    let _0 = _1;
+    SV::_31(_0)
+  }
+
+  fn _handler196(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _11);
+    let _0 = vec![_1];
+    SV::_31(_0)
+  }
+
+  fn _handler197(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Type { loc: _1.get_loc(), sem: INT };
-    SV::_9(_0)
+    SV::_11(_0)
   }
 
-  fn _handler114(&mut self) -> SV {
+  fn _handler198(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Type { loc: _1.get_loc(), sem: VOID };
-    SV::_9(_0)
+    SV::_11(_0)
   }
 
-  fn _handler115(&mut self) -> SV {
+  fn _handler199(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Type { loc: _1.get_loc(), sem: BOOL };
-    SV::_9(_0)
+    SV::_11(_0)
   }
 
-  fn _handler116(&mut self) -> SV {
+  fn _handler200(&mut self) -> SV {
     let mut _1 = pop!(self.values_stack, _0);
     let _0 = Type { loc: _1.get_loc(), sem: STRING };
-    SV::_9(_0)
+    SV::_11(_0)
   }
 
-  fn _handler117(&mut self) -> SV {
+  fn _handler201(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Type { loc: _1.get_loc(), sem: CHAR };
+    SV::_11(_0)
+  }
+
+  fn _handler202(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Type { loc: _1.get_loc(), sem: DOUBLE };
+    SV::_11(_0)
+  }
+
+  fn _handler203(&mut self) -> SV {
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Type { loc: _1.get_loc(), sem: LONG };
+    SV::_11(_0)
+  }
+
+  fn _handler204(&mut self) -> SV {
     let mut _2 = pop!(self.values_stack, _0);
     let mut _1 = pop!(self.values_stack, _0);
-    let _0 = Type { loc: _2.get_loc(), sem: SemanticType::Named(_2.value) };
-    SV::_9(_0)
+    let _0 = Type { loc: _2.get_loc(), sem: SemanticType::Named(_2.value, Vec::new()) };
+    SV::_11(_0)
   }
 
-  fn _handler118(&mut self) -> SV {
+  fn _handler205(&mut self) -> SV {
     self.values_stack.pop();
+    let mut _4 = pop!(self.values_stack, _31);
     self.values_stack.pop();
-    let mut _1 = pop!(self.values_stack, _9);
+    let mut _2 = pop!(self.values_stack, _0);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Type { loc: _2.get_loc(), sem: SemanticType::Named(_2.value, _4.into_iter().map(|t| t.sem).collect()) };
+    SV::_11(_0)
+  }
+
+  fn _handler206(&mut self) -> SV {
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _11);
     let _0 = Type { loc: _1.loc, sem: SemanticType::Array(Box::new(_1.sem)) };
-    SV::_9(_0)
+    SV::_11(_0)
+  }
+
+  fn _handler207(&mut self) -> SV {
+    let mut _4 = pop!(self.values_stack, _11);
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Type { loc: _1.get_loc(), sem: SemanticType::Func(Vec::new(), Box::new(_4.sem)) };
+    SV::_11(_0)
+  }
+
+  fn _handler208(&mut self) -> SV {
+    let mut _5 = pop!(self.values_stack, _11);
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _11);
+    let mut _1 = pop!(self.values_stack, _0);
+    let _0 = Type { loc: _1.get_loc(), sem: SemanticType::Func(vec![_2.sem], Box::new(_5.sem)) };
+    SV::_11(_0)
+  }
+
+  fn _handler209(&mut self) -> SV {
+    let mut _7 = pop!(self.values_stack, _11);
+    self.values_stack.pop();
+    self.values_stack.pop();
+    let mut _4 = pop!(self.values_stack, _31);
+    self.values_stack.pop();
+    let mut _2 = pop!(self.values_stack, _11);
+    let mut _1 = pop!(self.values_stack, _0);
+    let param = iter::once(_2).chain(_4.into_iter()).map(|t| t.sem).collect();
+    let _0 = Type { loc: _1.get_loc(), sem: SemanticType::Func(param, Box::new(_7.sem)) };
+    SV::_11(_0)
   }
 }
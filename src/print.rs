@@ -83,6 +83,11 @@ impl IndentPrinter {
     self
   }
 
+  fn print_label(&mut self, label: Option<&'static str>) -> &mut Self {
+    if let Some(label) = label { self.print(label); }
+    self.newline()
+  }
+
   pub fn flush<W: io::Write>(&mut self, writer: &mut W) {
     self.pop_space();
     writer.write(self.content.as_bytes()).unwrap();
@@ -110,13 +115,33 @@ make_ast_data!(self, p,
     p.dec_indent();
   },
   ClassDef => {
-    if self.sealed { p.print("sealed"); }
-    p.print("class").print(self.name);
-    match self.parent {
-      Some(name) => p.print(&name),
-      None => p.print("<empty>"),
-    };
+    if self.enum_.is_some() {
+      p.print("enum").print(self.name);
+    } else if self.interface {
+      p.print("interface").print(self.name);
+    } else {
+      if self.sealed { p.print("sealed"); }
+      if self.abstract_ { p.print("abstract"); }
+      p.print("class").print(self.name);
+      if !self.type_param.is_empty() {
+        p.print("<");
+        for param in &self.type_param {
+          p.print(param.name);
+          if let Some(bound) = &param.bound { p.print("extends").accept(&bound.sem); }
+        }
+        p.print(">");
+      }
+      match self.parent {
+        Some(name) => p.print(&name),
+        None => p.print("<empty>"),
+      };
+      if !self.implements.is_empty() {
+        p.print("implements");
+        for name in &self.implements { p.print(name); }
+      }
+    }
     p.newline().inc_indent();
+    for (_, name) in self.enum_.iter().flatten() { p.println(name); }
     for field in &self.field { field.print_ast(p); }
     p.dec_indent();
   },
@@ -124,15 +149,25 @@ make_ast_data!(self, p,
     match &self {
       FieldDef::MethodDef(method_def) => method_def.print_ast(p),
       FieldDef::VarDef(var_def) => var_def.print_ast(p),
+      FieldDef::Ctor(ctor) => {
+        if ctor.access != Access::Public { p.print(&ctor.access.to_string()); }
+        p.print("constructor").println(ctor.name).inc_indent().println("formals").inc_indent();
+        for parameter in &ctor.param { parameter.print_ast(p); }
+        p.dec_indent().accept(&ctor.body).dec_indent();
+      }
     }
   },
   MethodDef => {
+    if self.access != Access::Public { p.print(&self.access.to_string()); }
     if self.static_ { p.print("static"); }
+    if self.abstract_ { p.print("abstract"); }
     p.print("func").print(self.name).accept(&self.ret_t.sem).newline().inc_indent().println("formals").inc_indent();
     for parameter in &self.param {
       parameter.print_ast(p);
     }
-    p.dec_indent().accept(&self.body).dec_indent();
+    p.dec_indent();
+    if !self.abstract_ { p.accept(&self.body); }
+    p.dec_indent();
   },
   VarDef => {
     if self.type_.sem == VAR {
@@ -140,6 +175,8 @@ make_ast_data!(self, p,
       if let Some(src) = &self.src { src.print_ast(p); } else { unreachable!(); }
       p.dec_indent();
     } else {
+      if self.access != Access::Protected { p.print(&self.access.to_string()); }
+      if self.static_ { p.print("static"); }
       p.print("vardef").print(self.name).accept(&self.type_.sem).newline();
       if let Some(src) = &self.src {
         p.println("assign").inc_indent().println(self.name).accept(src).dec_indent();
@@ -153,14 +190,21 @@ make_ast_data!(self, p,
       Simple(simple) => simple.print_ast(p),
       If(if_) => if_.print_ast(p),
       While(while_) => while_.print_ast(p),
+      DoWhile(do_while) => do_while.print_ast(p),
       For(for_) => for_.print_ast(p),
       Return(return_) => return_.print_ast(p),
       Print(print) => print.print_ast(p),
       Break(break_) => break_.print_ast(p),
+      Continue(continue_) => continue_.print_ast(p),
+      SuperCall(super_call) => super_call.print_ast(p),
       SCopy(object_copy) => object_copy.print_ast(p),
       Foreach(foreach) => foreach.print_ast(p),
       Guarded(guarded) => guarded.print_ast(p),
+      Switch(switch) => switch.print_ast(p),
       Block(block) => block.print_ast(p),
+      Try(try_) => try_.print_ast(p),
+      Throw(throw) => throw.print_ast(p),
+      Assert(assert) => assert.print_ast(p),
     };
   },
   Simple => {
@@ -182,10 +226,17 @@ make_ast_data!(self, p,
       p.println("else").inc_indent().accept(on_false).dec_indent();
     }
   },
-  While => { p.println("while").inc_indent().accept(&self.cond).accept(&self.body).dec_indent(); },
-  For => { p.println("for").inc_indent().accept(&self.init).accept(&self.cond).accept(&self.update).accept(&self.body).dec_indent(); },
+  While => {
+    p.print("while").print_label(self.label).inc_indent().accept(&self.cond).accept(&self.body).dec_indent();
+  },
+  DoWhile => {
+    p.print("dowhile").print_label(self.label).inc_indent().accept(&self.body).accept(&self.cond).dec_indent();
+  },
+  For => {
+    p.print("for").print_label(self.label).inc_indent().accept(&self.init).accept(&self.cond).accept(&self.update).accept(&self.body).dec_indent();
+  },
   Foreach => {
-    p.println("foreach").inc_indent().print("varbind").print(self.def.name).accept(&self.def.type_.sem).newline().accept(&self.arr);
+    p.print("foreach").print_label(self.label).inc_indent().print("varbind").print(self.def.name).accept(&self.def.type_.sem).newline().accept(&self.arr);
     match &self.cond {
       Some(cond) => cond.print_ast(p),
       None => { p.println("boolconst true"); }
@@ -203,8 +254,19 @@ make_ast_data!(self, p,
     for expr in &self.print { expr.print_ast(p) }
     p.dec_indent();
   },
-  Break => { p.println("break"); },
-  Assign => { p.println("assign").inc_indent().accept(&self.dst).accept(&self.src).dec_indent(); },
+  Break => { p.print("break").print_label(self.label); },
+  Continue => { p.print("continue").print_label(self.label); },
+  SuperCall => {
+    p.println("super").inc_indent();
+    for expr in &self.arg { expr.print_ast(p); }
+    p.dec_indent();
+  },
+  Assign => {
+    match self.op {
+      Some(op) => p.println(&format!("assign {}=", op.to_str())),
+      None => p.println("assign"),
+    }.inc_indent().accept(&self.dst).accept(&self.src).dec_indent();
+  },
   SCopy => { p.println("scopy").inc_indent().println(self.dst).accept(&self.src).dec_indent(); },
   Guarded => {
     p.println("guarded").inc_indent();
@@ -217,12 +279,47 @@ make_ast_data!(self, p,
     }
     p.dec_indent();
   },
+  Switch => {
+    p.println("switch").inc_indent().accept(&self.cond);
+    for case in &self.case {
+      match (case.name, case.value) {
+        (Some(name), _) => { p.print("case").println(name); }
+        (None, Some(value)) => { p.print("case").println(&value.to_string()); }
+        (None, None) => { p.println("default"); }
+      }
+      p.inc_indent().accept(&case.body).dec_indent();
+    }
+    p.dec_indent();
+  },
+  Try => {
+    p.println("try").inc_indent().accept(&self.body);
+    for catch in &self.catch {
+      p.print("catch").print(catch.def.name).accept(&catch.def.type_.sem).newline().inc_indent().accept(&catch.body).dec_indent();
+    }
+    if let Some(finally) = &self.finally {
+      p.println("finally").inc_indent().accept(finally).dec_indent();
+    }
+    p.dec_indent();
+  },
+  Throw => { p.println("throw").inc_indent().accept(&self.expr).dec_indent(); },
+  Assert => {
+    p.println("assert").inc_indent().accept(&self.cond);
+    if let Some(msg) = &self.msg { p.accept(msg); }
+    p.dec_indent();
+  },
   Expr => {
     use ast::ExprData::*;
     match &self.data {
       Id(id) => id.print_ast(p),
       Indexed(indexed) => indexed.print_ast(p),
       IntConst(v) => { p.print("intconst").println(&v.to_string()); }
+      LongConst(v) => { p.print("longconst").println(&v.to_string()); }
+      DoubleConst(v) => { p.print("doubleconst").println(&v.to_string()); }
+      CharConst(v) => {
+        // `quote` only knows the escapes of string literals
+        let v = match *v { b'\0' => "\"\\0\"".to_owned(), b'\r' => "\"\\r\"".to_owned(), v => quote(&(v as char).to_string()) };
+        p.print("charconst").println(&v);
+      }
       BoolConst(v) => { p.print("boolconst").println(&v.to_string()); }
       StringConst(v) => { p.print("stringconst").println(&quote(&v)); }
       ArrayConst(v) => {
@@ -239,15 +336,45 @@ make_ast_data!(self, p,
       Unary(unary) => unary.print_ast(p),
       Binary(binary) => binary.print_ast(p),
       This => { p.println("this"); },
+      Super => { p.println("super"); },
       ReadInt => { p.println("readint"); },
       ReadLine => { p.println("readline"); },
-      NewClass{ name } => { p.print("newobj").println(name); },
-      NewArray{ elem_t, len } => { p.print("newarray").accept(&elem_t.sem).newline().inc_indent().accept(len.as_ref()).dec_indent(); }
+      Convert(convert) => {
+        let opname = match convert.op {
+          ConvertOp::CharToInt => "chartoint",
+          ConvertOp::IntToChar => "inttochar",
+          ConvertOp::StringToChars => "stringtochars",
+          ConvertOp::CharsToString => "charstostring",
+          ConvertOp::ToInt => "inttypecast",
+          ConvertOp::ToDouble => "doubletypecast",
+          ConvertOp::ToLong => "longtypecast",
+        };
+        p.println(opname).inc_indent().accept(convert.expr.as_ref()).dec_indent();
+      }
+      NewClass{ name, type_arg, arg } => {
+        p.print("newobj").print(name);
+        if !type_arg.is_empty() {
+          p.print("<");
+          for t in type_arg { t.print_ast(p); }
+          p.print(">");
+        }
+        p.newline().inc_indent();
+        for expr in arg { expr.print_ast(p); }
+        p.dec_indent();
+      },
+      NewArray{ elem_t, len, dims } => {
+        p.print("newarray").accept(&elem_t.sem).newline().inc_indent().accept(len.as_ref());
+        for dim in dims { dim.print_ast(p); }
+        p.dec_indent();
+      }
       TypeTest{ expr, name, target_class: _ } => { p.println("instanceof").inc_indent().accept(expr.as_ref()).println(name).dec_indent(); }
       TypeCast{ name, expr } => { p.println("classcast").inc_indent().println(name).accept(expr.as_ref()).dec_indent(); }
       Range(range) => range.print_ast(p),
       Default(default) => default.print_ast(p),
+      Conditional(conditional) => conditional.print_ast(p),
       Comprehension(comprehension) => comprehension.print_ast(p),
+      Lambda(lambda) => lambda.print_ast(p),
+      Apply(apply) => apply.print_ast(p),
     };
   },
   Id => {
@@ -308,6 +435,9 @@ make_ast_data!(self, p,
   },
   Range => { p.println("arrref").inc_indent().accept(self.arr.as_ref()).println("range").inc_indent().accept(self.lb.as_ref()).accept(self.ub.as_ref()).dec_indent().dec_indent(); },
   Default => { p.println("arrref").inc_indent().accept(self.arr.as_ref()).accept(self.idx.as_ref()).println("default").inc_indent().accept(self.dft.as_ref()).dec_indent().dec_indent(); },
+  Conditional => {
+    p.println("cond").inc_indent().accept(self.cond.as_ref()).accept(self.on_true.as_ref()).accept(self.on_false.as_ref()).dec_indent();
+  },
   Comprehension => {
     p.println("array comp").inc_indent().print("varbind").println(self.name).accept(self.arr.as_ref());
     match &self.cond {
@@ -315,6 +445,22 @@ make_ast_data!(self, p,
       None => { p.println("boolconst true"); }
     };
     p.accept(self.expr.as_ref()).dec_indent();
+  },
+  Lambda => {
+    p.println("lambda").inc_indent().println("formals").inc_indent();
+    for parameter in &self.def.param { parameter.print_ast(p); }
+    p.dec_indent();
+    // an expression lambda shows its expression instead of the block
+    match (self.expr, self.def.body.stmt.first()) {
+      (true, Some(Stmt::Simple(Simple::Expr(expr)))) => expr.print_ast(p),
+      _ => self.def.body.print_ast(p),
+    }
+    p.dec_indent();
+  },
+  Apply => {
+    p.println("apply").inc_indent().accept(self.func.as_ref());
+    for expr in &self.arg { expr.print_ast(p); }
+    p.dec_indent();
   }
 );
 
@@ -325,10 +471,10 @@ pub trait ScopeData {
 impl ScopeData for Program {
   fn print_scope(&self, p: &mut IndentPrinter) {
     p.println("GLOBAL SCOPE:").inc_indent();
-    for symbol in self.scope.sorted() {
+    for symbol in self.scope.sorted().iter().filter(|symbol| !(symbol.is_class() && symbol.as_class().builtin)) {
       p.println(&symbol.to_string());
     }
-    for class in &self.class {
+    for class in self.class.iter().filter(|class| !class.builtin) {
       class.print_scope(p);
     }
     p.dec_indent();
@@ -342,7 +488,7 @@ impl ScopeData for ClassDef {
       p.println(&symbol.to_string());
     }
     for field_def in &self.field {
-      if let FieldDef::MethodDef(method_def) = field_def {
+      if let FieldDef::MethodDef(method_def) | FieldDef::Ctor(method_def) = field_def {
         method_def.print_scope(p);
       }
     }
@@ -356,8 +502,10 @@ impl ScopeData for MethodDef {
     for symbol in self.scope.sorted() {
       p.println(&symbol.to_string());
     }
-    p.println("LOCAL SCOPE:");
-    self.body.print_scope(p);
+    if !self.abstract_ {
+      p.println("LOCAL SCOPE:");
+      self.body.print_scope(p);
+    }
     p.dec_indent();
   }
 }
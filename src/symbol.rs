@@ -49,7 +49,12 @@ impl Scope {
 
   pub fn sorted(&self) -> Vec<Symbol> {
     let mut symbols: Vec<Symbol> = self.symbols.iter().map(|(_, symbol)| *symbol).collect();
-    symbols.sort_by_key(|x| x.get_loc());
+    for symbol in self.symbols.values() {
+      if let Symbol::Method(method) = symbol {
+        symbols.extend(method.get().overload.iter().map(|&method| Symbol::Method(method)));
+      }
+    }
+    symbols.sort_by_key(|x| (x.get_loc(), x.get_name()));
     symbols
   }
 
@@ -85,20 +90,33 @@ impl fmt::Display for Symbol {
     match self {
       Symbol::Class(class) => {
         let class = class.get();
-        write!(f, "{} -> class {}", class.loc, class.name)?;
-        if (*class).p_ptr.is_null() { Ok(()) } else {
-          write!(f, " : {}", class.p_ptr.get().name)
+        write!(f, "{} -> {} {}", class.loc, if class.interface { "interface" } else if class.abstract_ { "abstract class" } else if class.enum_.is_some() { "enum" } else { "class" }, class.name)?;
+        if !class.type_param.is_empty() {
+          let param = class.type_param.iter().map(|p| match p.bound.as_ref().map(|b| &b.sem) {
+            Some(bound) if bound.is_object() => format!("{} extends {}", p.name, bound.get_class().name),
+            _ => p.name.to_owned(),
+          }).collect::<Vec<_>>();
+          write!(f, "<{}>", param.join(", "))?;
+        }
+        if !class.p_ptr.is_null() {
+          write!(f, " : {}", class.p_ptr.get().name)?;
+        }
+        if !class.i_ptr.is_empty() {
+          write!(f, " implements {}", class.i_ptr.iter().map(|i| i.get().name).collect::<Vec<_>>().join(", "))?;
         }
+        Ok(())
       }
       Symbol::Method(method) => {
         let method = method.get();
-        write!(f, "{} -> {}function {} : {}", method.loc, if method.static_ { "static " } else { "" },
+        let access = if method.access != Access::Public { format!("{} ", method.access) } else { String::new() };
+        write!(f, "{} -> {}{}function {} : {}", method.loc, access, if method.static_ { "static " } else if method.abstract_ { "abstract " } else { "" },
                method.name, SemanticType::Method(method))
       }
       Symbol::Var(var) => {
         let var = var.get();
-        write!(f, "{} -> variable {}{} : {}", var.loc, if var.scope.get().is_parameter() { "@" } else { "" },
-               var.name, var.type_.sem)
+        let access = if var.access != Access::Protected { format!("{} ", var.access) } else { String::new() };
+        write!(f, "{} -> {}{}variable {}{} : {}", var.loc, access, if var.static_ { "static " } else { "" },
+               if var.scope.get().is_parameter() { "@" } else { "" }, var.name, var.type_.sem)
       }
     }
   }
@@ -151,7 +169,7 @@ impl Symbol {
   // for a class symbol, will return the type of its instance
   pub fn get_type(&self) -> SemanticType {
     match self {
-      Symbol::Class(class) => SemanticType::Object(*class),
+      Symbol::Class(class) => SemanticType::Object(*class, Vec::new()),
       Symbol::Method(method) => SemanticType::Method(*method),
       Symbol::Var(var) => var.get().type_.sem.clone(),
     }
@@ -11,6 +11,9 @@ use std::ptr;
 pub struct SymbolBuilder {
   errors: Vec<Error>,
   scopes: ScopeStack,
+  has_try: bool,
+  // the class whose type parameters are visible, null in a static member
+  generic: *const ClassDef,
 }
 
 fn calc_order(class_def: *mut ClassDef) -> i32 {
@@ -24,6 +27,62 @@ fn calc_order(class_def: *mut ClassDef) -> i32 {
   }
 }
 
+// whether `method` can override(or implement) `parent`
+fn override_fit(method: &MethodDef, parent: &MethodDef) -> bool {
+  // int is assignable to long, but they are different in the method signature
+  let fit = |t1: &SemanticType, t2: &SemanticType| t1.assignable_to(t2) && !(t1 == &INT && t2 == &LONG);
+  fit(&method.ret_t, &parent.ret_t)
+    && method.param.len() == parent.param.len()
+    // start from 1, skip this
+    && (1..method.param.len()).all(|i| fit(&parent.param[i].type_, &method.param[i].type_))
+}
+
+// the root `Exception` has a hidden field for the message of a runtime error
+// and `ClassCastException` has the class names of a bad cast, since tac can't concatenate them into the message
+fn builtin_exceptions() -> Vec<ClassDef> {
+  let string_field = |name| FieldDef::VarDef(VarDef::new(NO_LOC, name, Type { loc: NO_LOC, sem: STRING }, None, NO_LOC));
+  BUILTIN_EXCEPTIONS.iter().map(|&(name, _)| {
+    let mut class_def = ClassDef { loc: NO_LOC, name, builtin: true, ..D::default() };
+    if name == EXCEPTION {
+      class_def.field.push(string_field(EXCEPTION_MESSAGE));
+    } else {
+      class_def.parent = Some(EXCEPTION);
+    }
+    if name == CLASS_CAST_EXCEPTION {
+      class_def.field.push(string_field(CAST_FROM));
+      class_def.field.push(string_field(CAST_TO));
+    }
+    class_def
+  }).collect()
+}
+
+// an enum has hidden fields for the ordinal and the name, a constructor setting them, a method returning the ordinal,
+// and a static field for each constant, initialized to `new E(ordinal, "name")`
+fn enum_members(class_def: &ClassDef, consts: &[(Loc, &'static str)]) -> Vec<FieldDef> {
+  let loc = class_def.loc;
+  let type_ = |sem| Type { loc, sem };
+  let id = |name| Expr::new(loc, ExprData::Id(Id { owner: None, name, symbol: ptr::null(), for_assign: false }));
+  let assign = |field, param| Stmt::Simple(Simple::Assign(Assign { loc, dst: id(field), src: id(param), op: None }));
+  let ctor_param = vec![VarDef::new(loc, ORDINAL, type_(INT), None, loc), VarDef::new(loc, "name", type_(STRING), None, loc)];
+  let ctor_body = Block { loc, stmt: vec![assign(ENUM_ORDINAL, ORDINAL), assign(ENUM_NAME, "name")], ..D::default() };
+  let ordinal_body = Block { loc, stmt: vec![Stmt::Return(Return { loc, expr: Some(id(ENUM_ORDINAL)) })], ..D::default() };
+  let mut ret = vec![
+    FieldDef::VarDef(VarDef::new(loc, ENUM_ORDINAL, type_(INT), None, loc)),
+    FieldDef::VarDef(VarDef::new(loc, ENUM_NAME, type_(STRING), None, loc)),
+    FieldDef::Ctor(MethodDef::new(loc, class_def.name, type_(VOID), ctor_param, false, ctor_body)),
+    FieldDef::MethodDef(MethodDef::new(loc, ORDINAL, type_(INT), Vec::new(), false, ordinal_body)),
+  ];
+  for (i, &(loc, name)) in consts.iter().enumerate() {
+    let arg = vec![Expr::new(loc, ExprData::IntConst(i as i32)), Expr::new(loc, ExprData::StringConst(name.to_owned()))];
+    let src = Expr::new(loc, ExprData::NewClass { name: class_def.name, type_arg: Vec::new(), arg });
+    let mut var_def = VarDef::new(loc, name, Type { loc, sem: SemanticType::Named(class_def.name, Vec::new()) }, Some(src), loc);
+    var_def.static_ = true;
+    var_def.access = Access::Public;
+    ret.push(FieldDef::VarDef(var_def));
+  }
+  ret
+}
+
 impl SymbolBuilder {
   pub fn build(mut program: Program) -> Result<Program, Vec<Error>> {
     let mut builder = SymbolBuilder {
@@ -32,8 +91,11 @@ impl SymbolBuilder {
         global_scope: ptr::null_mut(),
         scopes: Vec::new(),
       },
+      has_try: false,
+      generic: ptr::null(),
     };
     builder.program(&mut program);
+    program.has_try = builder.has_try;
     if builder.errors.is_empty() {
       Ok(program)
     } else {
@@ -41,6 +103,30 @@ impl SymbolBuilder {
       Err(builder.errors)
     }
   }
+
+  // a lambda is only met in type check, where its parameters and body are declared on top of the enclosing `scopes`
+  // the hidden env is inserted before others like 'this', but it can't be referred to by name
+  pub fn lambda(scopes: &ScopeStack, generic: *const ClassDef, def: &mut MethodDef) -> (Vec<Error>, bool) {
+    let mut builder = SymbolBuilder {
+      errors: Vec::new(),
+      scopes: ScopeStack { global_scope: scopes.global_scope, scopes: scopes.scopes.clone() },
+      has_try: false,
+      generic,
+    };
+    let env_t = Type { loc: def.loc, sem: SemanticType::Func(Vec::new(), Box::new(VOID)) };
+    let mut env = VarDef::new(def.loc, "env", env_t, None, def.loc);
+    env.scope = &def.scope;
+    def.param.insert(0, env);
+    def.scope = Scope { symbols: D::default(), kind: ScopeKind::Parameter(def) };
+    builder.scopes.open(&mut def.scope);
+    for var_def in &mut def.param[1..] {
+      builder.var_def(var_def);
+    }
+    def.body.is_method = true;
+    builder.block(&mut def.body);
+    builder.scopes.close();
+    (builder.errors, builder.has_try)
+  }
 }
 
 impl SymbolBuilder {
@@ -52,62 +138,122 @@ impl SymbolBuilder {
     if class_def.checked || class_def.p_ptr.is_null() { return; }
     let parent = class_def.p_ptr.get();
     self.check_override(parent);
+    let class_ptr = class_def as *mut ClassDef;
     let self_scope = &mut class_def.scope;
-    self.scopes.open(&mut parent.scope);
     // remove all conflicting fields
     self_scope.retain(|name, symbol| {
-      match self.scopes.lookup(name, true) {
-        Some((parent_symbol, _)) if !parent_symbol.is_class() =>
-          if (parent_symbol.is_var() && symbol.is_method()) ||
-            (parent_symbol.is_method() && symbol.is_var()) {
-            self.issue(symbol.get_loc(), ConflictDeclaration { earlier: parent_symbol.get_loc(), name });
+      match symbol {
+        Symbol::Var(_) => match parent.lookup(name) {
+          Some(Symbol::Method(parent_method)) => {
+            self.issue(symbol.get_loc(), ConflictDeclaration { earlier: parent_method.get().loc, name });
             false
-          } else if parent_symbol.is_method() {
-            // here symbol.is_method() must also be true
-            let parent_symbol = parent_symbol.as_method();
-            let symbol = symbol.as_method();
-            if parent_symbol.static_ || symbol.static_ {
-              self.issue(symbol.loc, ConflictDeclaration { earlier: parent_symbol.loc, name });
+          }
+          Some(Symbol::Var(_)) => {
+            self.issue(symbol.get_loc(), OverrideVar { name });
+            false
+          }
+          _ => true,
+        }
+        Symbol::Method(first) => {
+          let mut methods = vec![*first];
+          methods.extend(&first.get().overload);
+          // interface methods are checked by `check_implements`
+          let parent_methods = class_ptr.get().inherited_overloads(name).into_iter()
+            .filter(|method| !method.get().class.get().interface).collect::<Vec<_>>();
+          let parent_var = match parent.lookup(name) {
+            Some(symbol @ Symbol::Var(_)) => Some(symbol.get_loc()),
+            _ => None,
+          };
+          methods.retain(|&method| {
+            let method = method.get();
+            if let Some(earlier) = parent_var {
+              self.issue(method.loc, ConflictDeclaration { earlier, name });
               false
-            } else if !symbol.ret_t.assignable_to(&parent_symbol.ret_t)
-              || symbol.param.len() != parent_symbol.param.len()
-              || {
-              let mut unfit = false;
-              // start from 1, skip this
-              for i in 1..symbol.param.len() {
-                if !parent_symbol.param[i].type_.assignable_to(&symbol.param[i].type_) {
-                  unfit = true;
-                  break;
-                }
+            } else if let Some(parent_method) = match_override(method, &parent_methods) {
+              let parent_method = parent_method.get();
+              if parent_method.static_ || method.static_ {
+                self.issue(method.loc, ConflictDeclaration { earlier: parent_method.loc, name });
+                false
+              } else if !override_fit(method, parent_method) {
+                self.issue(method.loc, BadOverride { method: name, parent: parent.name });
+                false
+              } else if method.access < parent_method.access {
+                self.issue(method.loc, WeakerAccess { method: name, parent: parent.name, access: parent_method.access.to_string() });
+                false
+              } else {
+                true
               }
-              unfit
-            } {
-              self.issue(symbol.loc, BadOverride { method: name, parent: parent.name });
-              false
             } else {
+              // a new overload
               true
             }
-          } else if parent_symbol.is_var() {
-            self.issue(symbol.get_loc(), OverrideVar { name });
-            false
-          } else {
+          });
+          if methods.is_empty() { false } else {
+            *symbol = Symbol::Method(methods[0]);
+            methods[0].get().overload = methods[1..].to_vec();
             true
           }
+        }
         _ => true,
       }
     });
-    self.scopes.close();
     class_def.checked = true;
   }
 
+  // an abstract class may leave interface methods to its concrete subclasses
+  fn check_implements(&mut self, class_def: &ClassDef) {
+    if class_def.abstract_ { return; }
+    let mut interfaces = class_def.i_ptr.clone();
+    let mut p = class_def.p_ptr;
+    while !p.is_null() && p.get().abstract_ {
+      interfaces.extend(&p.get().i_ptr);
+      p = p.get().p_ptr;
+    }
+    for interface in interfaces {
+      let interface = interface.get();
+      for field_def in &interface.field {
+        if let FieldDef::MethodDef(method_def) = field_def {
+          let fit = match class_def.lookup_impl(method_def) {
+            Some(method) => {
+              let method = method.get();
+              !method.static_ && method.access == Access::Public && override_fit(method, method_def)
+            }
+            None => false,
+          };
+          if !fit {
+            self.issue(class_def.loc, NoImplementation { class: class_def.name, method: method_def.name, interface: interface.name });
+          }
+        }
+      }
+    }
+  }
+
+  fn check_abstract(&mut self, class_def: &ClassDef) {
+    if class_def.abstract_ || class_def.interface { return; }
+    let mut class = class_def as *const ClassDef;
+    while !class.is_null() {
+      for field_def in &class.get().field {
+        if let FieldDef::MethodDef(method_def) = field_def {
+          // only report the overriding-most one
+          if method_def.abstract_ && class_def.lookup_overloads(method_def.name).iter()
+            .any(|&method| method as *const MethodDef == method_def) {
+            self.issue(class_def.loc, AbstractNotImplemented { class: class_def.name, method: method_def.name, owner: class.get().name });
+          }
+        }
+      }
+      class = class.get().p_ptr;
+    }
+  }
+
   // check whether a declaration is valid
   // if valid, return true, but it is not declared yet
   fn check_var_declaration(&mut self, name: &'static str, loc: Loc) -> bool {
     if let Some((symbol, scope)) = self.scopes.lookup(name, true) {
       if {
         let cur = self.scopes.cur_scope();
+        // the parameters of an enclosing method can be shadowed in a lambda
         cur as *const _ == scope || (scope.get().is_parameter() && match cur.kind {
-          ScopeKind::Local(block) => block.get().is_method,
+          ScopeKind::Local(block) => block.get().is_method && self.scopes.scopes[self.scopes.scopes.len() - 2] as *const _ == scope,
           _ => false,
         })
       } {
@@ -138,14 +284,34 @@ impl SemanticTypeVisitor for SymbolBuilder {
   fn lookup_class(&self, name: &'static str) -> Option<Symbol> {
     self.scopes.lookup_class(name)
   }
+
+  fn lookup_type_param(&self, name: &'static str) -> Option<*const TypeParam> {
+    if self.generic.is_null() { None } else { self.generic.get().lookup_type_param(name) }
+  }
 }
 
 impl SymbolBuilder {
   fn program(&mut self, program: &mut Program) {
+    program.class.splice(0..0, builtin_exceptions());
+    for class_def in &mut program.class {
+      if let Some(consts) = &mut class_def.enum_ {
+        for &(loc, name) in consts.iter().filter(|c| c.1 == ORDINAL) {
+          self.issue(loc, ReservedEnumConst { name });
+        }
+        consts.retain(|c| c.1 != ORDINAL);
+      }
+      if let Some(field) = class_def.enum_.as_ref().map(|consts| enum_members(class_def, consts)) {
+        class_def.field = field;
+      }
+    }
     self.scopes.open(&mut program.scope);
     for class_def in &mut program.class {
       if let Some(earlier) = self.scopes.lookup_class(class_def.name) {
-        self.issue(class_def.loc, ConflictDeclaration { earlier: earlier.get_loc(), name: class_def.name });
+        if earlier.as_class().builtin {
+          self.issue(class_def.loc, BuiltinClass { name: class_def.name });
+        } else {
+          self.issue(class_def.loc, ConflictDeclaration { earlier: earlier.get_loc(), name: class_def.name });
+        }
       } else {
         self.scopes.declare(Symbol::Class(class_def));
       }
@@ -162,17 +328,47 @@ impl SymbolBuilder {
           } else if parent_ref.sealed {
             self.issue(class_def.loc, SealedInheritance {});
             class_def.p_ptr = ptr::null_mut();
+          } else if parent_ref.interface {
+            self.issue(class_def.loc, ExtendInterface { name: parent });
+            class_def.p_ptr = ptr::null_mut();
+          } else if !parent_ref.type_param.is_empty() {
+            self.issue(class_def.loc, ExtendGeneric { name: parent });
+            class_def.p_ptr = ptr::null_mut();
           }
         } else {
           self.issue(class_def.loc, NoSuchClass { name: parent });
         }
       }
+      for &interface in &class_def.implements {
+        if let Some(interface_ref) = self.scopes.lookup_class(interface) {
+          let interface_ref = interface_ref.as_class();
+          if interface_ref.interface {
+            class_def.i_ptr.push(interface_ref);
+          } else {
+            self.issue(class_def.loc, NotInterface { name: interface });
+          }
+        } else {
+          self.issue(class_def.loc, NoSuchClass { name: interface });
+        }
+      }
     }
 
     for class_def in &mut program.class {
       class_def.scope = Scope { symbols: D::default(), kind: ScopeKind::Class(class_def) };
     }
 
+    // bounds are needed to check type arguments, so they are resolved before any member
+    for class_def in &mut program.class {
+      for i in 0..class_def.type_param.len() {
+        let (earlier, param) = class_def.type_param.split_at_mut(i);
+        let param = &mut param[0];
+        if let Some(earlier) = earlier.iter().find(|p| p.name == param.name) {
+          self.issue(param.loc, ConflictDeclaration { earlier: earlier.loc, name: param.name });
+        }
+        if let Some(bound) = &mut param.bound { self.type_(bound); }
+      }
+    }
+
     for class_def in &mut program.class {
       self.class_def(class_def);
       if class_def.name == MAIN_CLASS {
@@ -182,35 +378,90 @@ impl SymbolBuilder {
 
     for class_def in &mut program.class { self.check_override(class_def); }
 
+    for class_def in &program.class {
+      self.check_abstract(class_def);
+      self.check_implements(class_def);
+    }
+
+    // interface method calls are dispatched by searching the implementors
+    let classes = program.class.iter_mut().map(|class_def| class_def as *mut ClassDef).collect::<Vec<_>>();
+    for &interface in classes.iter().filter(|i| i.get().interface) {
+      for &class_def in classes.iter().filter(|c| !c.get().interface && !c.get().abstract_) {
+        if class_def.get().implements(interface) {
+          interface.get().implementors.push(class_def);
+        }
+      }
+    }
+
     if !self.check_main(program.main) { self.issue(NO_LOC, NoMainClass {}); }
   }
 
   fn class_def(&mut self, class_def: &mut ClassDef) {
     self.scopes.open(&mut class_def.scope);
+    let class_ptr = class_def as *mut ClassDef;
     for field_def in &mut class_def.field {
+      self.generic = match field_def {
+        FieldDef::MethodDef(MethodDef { static_: true, .. }) | FieldDef::VarDef(VarDef { static_: true, .. }) => ptr::null(),
+        _ => class_ptr,
+      };
       match field_def {
         FieldDef::MethodDef(method_def) => self.method_def(method_def),
         FieldDef::VarDef(var_def) => self.var_def(var_def),
+        FieldDef::Ctor(ctor) => {
+          let class_def = class_ptr.get();
+          if ctor.name != class_def.name {
+            self.issue(ctor.loc, BadCtorName { name: ctor.name });
+          } else if !class_def.ctor.is_null() {
+            self.issue(ctor.loc, ConflictDeclaration { earlier: class_def.ctor.get().loc, name: ctor.name });
+          } else {
+            class_def.ctor = ctor;
+          }
+          // a constructor is not declared in class scope, so that it can't be called like a method
+          self.method_scope(ctor);
+        }
       };
     }
+    self.generic = ptr::null();
     self.scopes.close();
   }
 
   fn method_def(&mut self, method_def: &mut MethodDef) {
+    // or it may be the same as the mangled name of an overload, see `MethodDef::mangled_name`
+    if method_def.name.contains("__") {
+      self.issue(method_def.loc, ReservedMethodName { name: method_def.name });
+    }
     self.type_(&mut method_def.ret_t);
-    if let Some((earlier, _)) = self.scopes.lookup(method_def.name, false) {
-      self.issue(method_def.loc, ConflictDeclaration { earlier: earlier.get_loc(), name: method_def.name });
-    } else {
-      self.scopes.declare(Symbol::Method(method_def as *mut _));
+    // parameter types are needed to tell overloads apart
+    self.method_scope(method_def);
+    match self.scopes.lookup(method_def.name, false) {
+      Some((Symbol::Method(first), _)) => {
+        let first = first.get();
+        let mut methods = vec![first as *mut MethodDef];
+        methods.extend(&first.overload);
+        if let Some(earlier) = methods.iter().find(|method| method.get().same_param(method_def)) {
+          self.issue(method_def.loc, ConflictDeclaration { earlier: earlier.get().loc, name: method_def.name });
+        } else {
+          method_def.overload_idx = methods.len() as u32;
+          first.overload.push(method_def);
+        }
+      }
+      Some((earlier, _)) => self.issue(method_def.loc, ConflictDeclaration { earlier: earlier.get_loc(), name: method_def.name }),
+      None => self.scopes.declare(Symbol::Method(method_def as *mut _)),
     }
+  }
+
+  fn method_scope(&mut self, method_def: &mut MethodDef) {
+    let class = self.scopes.cur_scope().get_class();
+    method_def.class = class;
     if !method_def.static_ {
-      let class = self.scopes.cur_scope().get_class();
       method_def.param.insert(0, VarDef {
         loc: method_def.loc,
         name: "this",
-        type_: Type { loc: method_def.loc, sem: SemanticType::Object(class) },
+        type_: Type { loc: method_def.loc, sem: class.get_object_type() },
         src: None,
         finish_loc: method_def.loc,
+        static_: false,
+        access: Access::Protected,
         scope: &method_def.scope,
         jvm_index: 0, // 'this' is at 0
         offset: -1,
@@ -235,6 +486,7 @@ impl SymbolBuilder {
         if let Some(on_false) = &mut if_.on_false { self.block(on_false); }
       }
       Stmt::While(while_) => self.block(&mut while_.body),
+      Stmt::DoWhile(do_while) => self.block(&mut do_while.body),
       Stmt::For(for_) => {
         let block = &mut for_.body;
         block.scope = Scope { symbols: D::default(), kind: ScopeKind::Local(block) };
@@ -252,7 +504,20 @@ impl SymbolBuilder {
         self.scopes.close();
       }
       Stmt::Guarded(guarded) => for (_, stmt) in &mut guarded.guarded { self.block(stmt); },
+      Stmt::Switch(switch) => for case in &mut switch.case { self.block(&mut case.body); },
       Stmt::Block(block) => self.block(block),
+      Stmt::Try(try_) => {
+        self.has_try = true;
+        self.block(&mut try_.body);
+        for catch in &mut try_.catch {
+          catch.body.scope.kind = ScopeKind::Local(&mut catch.body);
+          self.scopes.open(&mut catch.body.scope);
+          self.var_def(&mut catch.def);
+          for stmt in &mut catch.body.stmt { self.stmt(stmt); }
+          self.scopes.close();
+        }
+        if let Some(finally) = &mut try_.finally { self.block(finally); }
+      }
       _ => {}
     };
   }
@@ -18,11 +18,14 @@ pub struct TacMethod {
   pub name: String,
   pub code: Vec<Tac>,
   pub method: *const MethodDef,
+  // the register of the static field block, passed after the other parameters, -1 if not passed
+  pub base: i32,
 }
 
 pub struct TacProgram {
   pub v_tables: Vec<VTable>,
   pub methods: Vec<TacMethod>,
+  pub lambdas: Vec<*const Lambda>,
   // there maybe labels / temps in the future
 }
 
@@ -35,18 +38,32 @@ impl TacProgram {
         .println(&if let Some(parent) = class.parent { format!("_{}", parent) } else { "<empty>".to_owned() })
         .println(class.name);
       for method in &vt.methods {
-        printer.println(&format!("_{}.{};", method.get().class.get().name, method.get().name));
+        printer.println(&format!("_{}.{};", method.get().class.get().name, method.get().mangled_name()));
       }
       printer.dec_indent().println("}").println("");
     }
+    for lambda in &self.lambdas {
+      let def = &lambda.get().def;
+      printer.println(&format!("VTABLE(_{}) {}", lambda_v_tbl(def), "{"))
+        .inc_indent()
+        .println("<empty>")
+        .println(&lambda_v_tbl(def))
+        .println(&format!("_{}.{};", def.class.get().name, def.mangled_name()))
+        .dec_indent().println("}").println("");
+    }
     for method in &self.methods {
       printer.println(&format!("FUNCTION({}) {}", method.name, "{")) // the name is already mangled
         .print("memo").println(&{
         let mut memo = "'".to_owned();
+        let mut param_cnt = 0;
         if !method.method.is_null() { // for ctor
           for (offset, param) in method.method.get().param.iter().enumerate() {
             memo += &format!("_T{}:{} ", param.offset, (offset + 1) * INT_SIZE as usize);
           }
+          param_cnt = method.method.get().param.len();
+        }
+        if method.base >= 0 {
+          memo += &format!("_T{}:{} ", method.base, (param_cnt + 1) * INT_SIZE as usize);
         }
         memo += "'";
         memo
@@ -137,6 +154,11 @@ impl fmt::Display for Tac {
   }
 }
 
+// each lambda has a class-like v-table, whose only method is the lambda
+pub fn lambda_v_tbl(def: &MethodDef) -> String {
+  format!("{}_{}", def.class.get().name, def.mangled_name())
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct IntrinsicCall {
   pub name: &'static str,
@@ -147,7 +169,25 @@ pub const ALLOCATE: IntrinsicCall = IntrinsicCall { name: "_Alloc", ret: true };
 pub const READ_LINE: IntrinsicCall = IntrinsicCall { name: "_ReadLine", ret: true };
 pub const READ_INT: IntrinsicCall = IntrinsicCall { name: "_ReadInteger", ret: true };
 pub const STRING_EQUAL: IntrinsicCall = IntrinsicCall { name: "_StringEqual", ret: true };
+pub const STRING_COMPARE: IntrinsicCall = IntrinsicCall { name: "_StringCompare", ret: true };
+pub const STRING_CONCAT: IntrinsicCall = IntrinsicCall { name: "_StringConcat", ret: true };
+pub const STRING_LENGTH: IntrinsicCall = IntrinsicCall { name: "_StringLength", ret: true };
+pub const STRING_CHAR_AT: IntrinsicCall = IntrinsicCall { name: "_StringCharAt", ret: true };
+pub const STRING_SUBSTRING: IntrinsicCall = IntrinsicCall { name: "_StringSubstring", ret: true };
+pub const STRING_TO_CHARS: IntrinsicCall = IntrinsicCall { name: "_StringToChars", ret: true };
+pub const CHARS_TO_STRING: IntrinsicCall = IntrinsicCall { name: "_CharsToString", ret: true };
+pub const DOUBLE_ADD: IntrinsicCall = IntrinsicCall { name: "_DoubleAdd", ret: true };
+pub const DOUBLE_SUB: IntrinsicCall = IntrinsicCall { name: "_DoubleSub", ret: true };
+pub const DOUBLE_MUL: IntrinsicCall = IntrinsicCall { name: "_DoubleMul", ret: true };
+pub const DOUBLE_DIV: IntrinsicCall = IntrinsicCall { name: "_DoubleDiv", ret: true };
+pub const DOUBLE_MOD: IntrinsicCall = IntrinsicCall { name: "_DoubleMod", ret: true };
+pub const DOUBLE_COMPARE: IntrinsicCall = IntrinsicCall { name: "_DoubleCompare", ret: true };
+pub const DOUBLE_FROM_BITS: IntrinsicCall = IntrinsicCall { name: "_DoubleFromBits", ret: true };
+pub const INT_TO_DOUBLE: IntrinsicCall = IntrinsicCall { name: "_IntToDouble", ret: true };
+pub const DOUBLE_TO_INT: IntrinsicCall = IntrinsicCall { name: "_DoubleToInt", ret: true };
 pub const PRINT_INT: IntrinsicCall = IntrinsicCall { name: "_PrintInt", ret: false };
 pub const PRINT_STRING: IntrinsicCall = IntrinsicCall { name: "_PrintString", ret: false };
 pub const PRINT_BOOL: IntrinsicCall = IntrinsicCall { name: "_PrintBool", ret: false };
+pub const PRINT_CHAR: IntrinsicCall = IntrinsicCall { name: "_PrintChar", ret: false };
+pub const PRINT_DOUBLE: IntrinsicCall = IntrinsicCall { name: "_PrintDouble", ret: false };
 pub const HALT: IntrinsicCall = IntrinsicCall { name: "_Halt", ret: false };
\ No newline at end of file
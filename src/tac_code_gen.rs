@@ -5,36 +5,89 @@ use super::util::*;
 use super::tac::*;
 use super::config::*;
 use super::print::quote;
+use super::errors::*;
+use super::loc::*;
 
 use std::ptr;
+use std::iter;
 
 pub struct TacCodeGen {
   cur_method: *mut Vec<Tac>,
-  break_stack: Vec<i32>,
+  loop_stack: Vec<(Option<&'static str>, i32, i32)>,
   methods: Vec<TacMethod>,
   reg_cnt: i32,
   label_cnt: i32,
   cur_this: i32,
   // for one method, merge all 'array_index_out_of_bound' together for better performance
   out_of_bound_to_fill: Vec<i32>,
+  // static fields of all classes, in declaration order
+  statics: Vec<*mut VarDef>,
+  // see `Program::has_try`, if so the thrown exception is put in `pending`, and checked after each call
+  has_try: bool,
+  // whether there is a static field block, which holds the static fields and the pending exception
+  static_block: bool,
+  // the register holding the address of the static field block in the current method
+  base: i32,
+  // offset of the pending exception in the static field block
+  pending: i32,
+  // the label an exception goes to in the current code, -1 for leaving the method
+  handler: i32,
+  // the label where an exception leaves the method, -1 if not used yet
+  propagate: i32,
+  // trys being generated, (finally block, length of loop_stack when entering it, handler outside it)
+  try_stack: Vec<(*mut Block, usize, i32)>,
+  no_asserts: bool,
+  // lambdas created in the current method, their bodies are generated after it
+  lambdas: Vec<*mut Lambda>,
+  // all lambdas generated, each has a v-table
+  all_lambdas: Vec<*const Lambda>,
+  // for what the tac vm can't do
+  errors: Vec<Error>,
 }
 
+// `_message` is the only field of Exception, the root of all exceptions
+const MESSAGE_OFFSET: i32 = INT_SIZE;
+// `_from` and `_to` of ClassCastException follow it
+const CAST_FROM_OFFSET: i32 = MESSAGE_OFFSET + INT_SIZE;
+const CAST_TO_OFFSET: i32 = CAST_FROM_OFFSET + INT_SIZE;
+
 impl TacCodeGen {
-  pub fn gen(program: &mut Program) -> TacProgram {
+  pub fn gen(program: &mut Program) -> Result<TacProgram, Vec<Error>> {
     let mut code_gen = TacCodeGen {
       cur_method: ptr::null_mut(),
-      break_stack: Vec::new(),
+      loop_stack: Vec::new(),
       methods: Vec::new(),
       reg_cnt: -1,
       label_cnt: -1,
       cur_this: -1,
       out_of_bound_to_fill: Vec::new(),
+      statics: Vec::new(),
+      has_try: program.has_try,
+      static_block: false,
+      base: -1,
+      pending: 0,
+      handler: -1,
+      propagate: -1,
+      try_stack: Vec::new(),
+      no_asserts: program.no_asserts,
+      lambdas: Vec::new(),
+      all_lambdas: Vec::new(),
+      errors: Vec::new(),
     };
     code_gen.program(program);
-    TacProgram {
-      v_tables: program.class.iter().map(|class| class.v_tbl.clone()).collect(),
-      methods: code_gen.methods,
+    if !code_gen.errors.is_empty() {
+      code_gen.errors.sort_by_key(|x| x.loc);
+      return Err(code_gen.errors);
     }
+    Ok(TacProgram {
+      v_tables: program.class.iter().filter(|class| !class.interface).map(|class| class.v_tbl.clone()).collect(),
+      methods: code_gen.methods,
+      lambdas: code_gen.all_lambdas,
+    })
+  }
+
+  fn issue<E: IError + 'static>(&mut self, loc: Loc, error: E) {
+    self.errors.push(Error::new(loc, error));
   }
 
   fn new_reg(&mut self) -> i32 {
@@ -44,10 +97,27 @@ impl TacCodeGen {
 
   fn int_const(&mut self, value: i32) -> i32 {
     let ret = self.new_reg();
-    self.push(Tac::IntConst(ret, value));
+    if value >= 0 {
+      self.push(Tac::IntConst(ret, value));
+    } else {
+      // tac doesn't accept negative literals, compute -(!value) - 1, which doesn't overflow
+      let one = self.int_const(1);
+      self.push(Tac::IntConst(ret, !value));
+      self.push(Tac::Neg(ret, ret));
+      self.push(Tac::Sub(ret, ret, one));
+    }
     ret
   }
 
+  // tac can't lex a double literal, so build it from the high and low 32 bits of its bit pattern
+  fn double_const(&mut self, value: f64) -> i32 {
+    let bits = value.to_bits();
+    let (high, low) = (self.int_const((bits >> 32) as i32), self.int_const(bits as i32));
+    self.push(Tac::Param(high));
+    self.push(Tac::Param(low));
+    self.intrinsic_call(DOUBLE_FROM_BITS)
+  }
+
   fn new_label(&mut self) -> i32 {
     self.label_cnt += 1;
     self.label_cnt
@@ -59,6 +129,12 @@ impl TacCodeGen {
     ret
   }
 
+  fn string_concat(&mut self, l: i32, r: i32) -> i32 {
+    self.push(Tac::Param(l));
+    self.push(Tac::Param(r));
+    self.intrinsic_call(STRING_CONCAT)
+  }
+
   fn array_at(&mut self, array: i32, index: i32) -> i32 {
     let (ret, int_size, offset) = (self.new_reg(), self.int_const(INT_SIZE), self.new_reg());
     self.push(Tac::Mul(offset, index, int_size));
@@ -81,10 +157,137 @@ impl TacCodeGen {
     let msg = self.new_reg();
     self.push(Tac::Jne(r, ok));
     self.push(Tac::StrConst(msg, quote(DIV_0)));
+    self.runtime_error(ARITHMETIC_EXCEPTION, msg);
+    self.push(Tac::Label(ok));
+  }
+
+  fn check_new_array_len(&mut self, len: i32) {
+    let ok = self.new_label();
+    let (zero, cmp, msg) = (self.int_const(0), self.new_reg(), self.new_reg());
+    self.push(Tac::Lt(cmp, len, zero));
+    self.push(Tac::Je(cmp, ok));
+    self.push(Tac::StrConst(msg, quote(NEW_ARR_NEG)));
+    self.runtime_error(NEGATIVE_ARRAY_SIZE_EXCEPTION, msg);
+    self.push(Tac::Label(ok));
+  }
+
+  // print `msg` and halt, or raise a built-in exception carrying it if the program has any try
+  fn runtime_error(&mut self, class: &'static str, msg: i32) {
+    if self.has_try {
+      let e = self.new_builtin(class, msg);
+      self.raise(e);
+    } else {
+      self.push(Tac::Param(msg));
+      self.intrinsic_call(PRINT_STRING);
+      self.intrinsic_call(HALT);
+    }
+  }
+
+  fn new_builtin(&mut self, class: &'static str, msg: i32) -> i32 {
+    let e = self.new_reg();
+    self.push(Tac::DirectCall(e, format!("_{}_New", class)));
+    self.push(Tac::Store(e, MESSAGE_OFFSET, msg));
+    e
+  }
+
+  fn raise(&mut self, e: i32) {
+    let (base, handler) = (self.base, self.handler_label());
+    self.push(Tac::Store(base, self.pending, e));
+    self.push(Tac::Jmp(handler));
+  }
+
+  fn handler_label(&mut self) -> i32 {
+    if self.handler >= 0 { return self.handler; }
+    if self.propagate < 0 { self.propagate = self.new_label(); }
+    self.propagate
+  }
+
+  // the callee may have left an exception pending
+  fn check_exception(&mut self) {
+    if !self.has_try { return; }
+    let (base, e) = (self.base, self.new_reg());
+    let handler = self.handler_label();
+    self.push(Tac::Load(e, base, self.pending));
+    self.push(Tac::Jne(e, handler));
+  }
+
+  // print the message of a runtime error, or the class name of others, and halt
+  // the message of a bad cast is completed by the class names in the exception
+  fn uncaught(&mut self, e: i32) {
+    let (msg, v_tbl, cast_v_tbl, cmp) = (self.new_reg(), self.new_reg(), self.new_reg(), self.new_reg());
+    let (not_runtime, after) = (self.new_label(), self.new_label());
+    self.push(Tac::Load(msg, e, MESSAGE_OFFSET));
+    self.push(Tac::Je(msg, not_runtime));
+    self.push(Tac::Param(msg));
+    self.intrinsic_call(PRINT_STRING);
+    self.push(Tac::Load(v_tbl, e, 0));
+    self.push(Tac::LoadVTbl(cast_v_tbl, CLASS_CAST_EXCEPTION));
+    self.push(Tac::Eq(cmp, v_tbl, cast_v_tbl));
+    self.push(Tac::Je(cmp, after));
+    self.push(Tac::Load(msg, e, CAST_FROM_OFFSET));
+    self.push(Tac::Param(msg));
+    self.intrinsic_call(PRINT_STRING);
+    self.push(Tac::StrConst(msg, quote(BAD_CAST2)));
+    self.push(Tac::Param(msg));
+    self.intrinsic_call(PRINT_STRING);
+    self.push(Tac::Load(msg, e, CAST_TO_OFFSET));
+    self.push(Tac::Param(msg));
+    self.intrinsic_call(PRINT_STRING);
+    self.push(Tac::StrConst(msg, quote(BAD_CAST3)));
     self.push(Tac::Param(msg));
     self.intrinsic_call(PRINT_STRING);
+    self.push(Tac::Jmp(after));
+    self.push(Tac::Label(not_runtime));
+    self.push(Tac::StrConst(msg, quote(UNCAUGHT1)));
+    self.push(Tac::Param(msg));
+    self.intrinsic_call(PRINT_STRING);
+    self.push(Tac::Load(v_tbl, e, 0));
+    self.push(Tac::Load(msg, v_tbl, INT_SIZE)); // name info is in v-table[1]
+    self.push(Tac::Param(msg));
+    self.intrinsic_call(PRINT_STRING);
+    self.push(Tac::StrConst(msg, quote(UNCAUGHT2)));
+    self.push(Tac::Param(msg));
+    self.intrinsic_call(PRINT_STRING);
+    self.push(Tac::Label(after));
     self.intrinsic_call(HALT);
-    self.push(Tac::Label(ok));
+  }
+
+  // lens[0] is the length of this array, its elements are arrays of lens[1..] if there are more
+  fn new_array(&mut self, elem_t: &SemanticType, lens: &[i32]) -> i32 {
+    let (before_cond, finish) = (self.new_label(), self.new_label());
+    let (zero, int_size, cmp, i, ret) = (self.int_const(0), self.int_const(INT_SIZE), self.new_reg(), self.new_reg(), self.new_reg());
+    self.push(Tac::Mul(i, lens[0], int_size));
+    self.push(Tac::Add(i, i, int_size)); // allocate (len + 1) * INT_SIZE
+    self.push(Tac::Param(i));
+    let arr = self.intrinsic_call(ALLOCATE);
+    self.push(Tac::Add(i, arr, i));
+    self.push(Tac::Label(before_cond));
+    self.push(Tac::Sub(i, i, int_size));
+    self.push(Tac::Eq(cmp, i, arr));
+    self.push(Tac::Jne(cmp, finish));
+    self.push(Tac::Store(i, 0, zero));
+    self.push(Tac::Jmp(before_cond));
+    self.push(Tac::Label(finish));
+    self.push(Tac::Store(i, 0, lens[0])); // array[-1] = len
+    self.push(Tac::Add(ret, arr, int_size));
+    if lens.len() > 1 {
+      // for (i = 0; i < len; i += 1) array[i] = new elem[lens[1]]...
+      let (before_cond, after_body) = (self.new_label(), self.new_label());
+      let inner_t = if let SemanticType::Array(inner_t) = elem_t { inner_t.as_ref() } else { unreachable!() };
+      let (one, addr) = (self.int_const(1), self.new_reg());
+      self.push(Tac::Assign(i, zero));
+      self.push(Tac::Label(before_cond));
+      self.push(Tac::Lt(cmp, i, lens[0]));
+      self.push(Tac::Je(cmp, after_body));
+      let inner = self.new_array(inner_t, &lens[1..]);
+      self.push(Tac::Mul(addr, i, int_size));
+      self.push(Tac::Add(addr, ret, addr));
+      self.push(Tac::Store(addr, 0, inner));
+      self.push(Tac::Add(i, i, one));
+      self.push(Tac::Jmp(before_cond));
+      self.push(Tac::Label(after_body));
+    }
+    ret
   }
 
   fn intrinsic_call(&mut self, call: IntrinsicCall) -> i32 {
@@ -93,7 +296,8 @@ impl TacCodeGen {
     ret
   }
 
-  fn instance_of(&mut self, object: i32, class: &'static str) -> i32 {
+  fn instance_of(&mut self, object: i32, class: &ClassDef) -> i32 {
+    if class.interface { return self.implements(object, class); }
     // ret = 0
     // while (cur)
     //   if cur == target
@@ -103,7 +307,7 @@ impl TacCodeGen {
     let (ret, cur, target) = (self.new_reg(), self.new_reg(), self.new_reg());
     let (before_cond, after_body) = (self.new_label(), self.new_label());
     self.push(Tac::IntConst(ret, 0));
-    self.push(Tac::LoadVTbl(target, class));
+    self.push(Tac::LoadVTbl(target, class.name));
     self.push(Tac::Load(cur, object, 0));
     self.push(Tac::Label(before_cond));
     self.push(Tac::Je(cur, after_body));
@@ -115,6 +319,75 @@ impl TacCodeGen {
     ret
   }
 
+  // an object implements the interface iff its exact class is one of the implementors
+  fn implements(&mut self, object: i32, interface: &ClassDef) -> i32 {
+    let (ret, v_tbl, target, cmp) = (self.int_const(0), self.new_reg(), self.new_reg(), self.new_reg());
+    self.push(Tac::Load(v_tbl, object, 0));
+    for &class in &interface.implementors {
+      self.push(Tac::LoadVTbl(target, class.get().name));
+      self.push(Tac::Eq(cmp, v_tbl, target));
+      self.push(Tac::Or(ret, ret, cmp));
+    }
+    ret
+  }
+
+  // interface method `_I.f` searches the implementors for the exact class of 'this'
+  // and calls `f` through that class's v-table slot
+  // (the vm doesn't allow calling a method in v-table directly)
+  fn dispatch(&mut self, method_def: &MethodDef) {
+    let interface = method_def.class.get();
+    let this = method_def.param[0].offset;
+    let (v_tbl, target, cmp, slot) = (self.new_reg(), self.new_reg(), self.new_reg(), self.new_reg());
+    let ret = if method_def.ret_t.sem != VOID { self.new_reg() } else { -1 };
+    self.push(Tac::Load(v_tbl, this, 0));
+    for &class in &interface.implementors {
+      let next = self.new_label();
+      let class = class.get();
+      let method = class.lookup_impl(method_def).unwrap().get();
+      self.push(Tac::LoadVTbl(target, class.name));
+      self.push(Tac::Eq(cmp, v_tbl, target));
+      self.push(Tac::Je(cmp, next));
+      for param in &method_def.param { self.push(Tac::Param(param.offset)); }
+      self.pass_base();
+      self.push(Tac::Load(slot, v_tbl, (method.offset + 2) * INT_SIZE));
+      self.push(Tac::IndirectCall(ret, slot));
+      self.push(Tac::Ret(ret));
+      self.push(Tac::Label(next));
+    }
+  }
+
+  // the tac vm has no global data, so main allocates the static field block, and passes it to every method it calls
+  // as a hidden parameter after the others, which they pass on in turn
+  fn receive_base(&mut self) {
+    self.base = if self.static_block { self.new_reg() } else { -1 };
+    self.methods.last_mut().unwrap().base = self.base;
+  }
+
+  fn pass_base(&mut self) {
+    if self.static_block { self.push(Tac::Param(self.base)); }
+  }
+
+  fn static_init(&mut self) {
+    self.base = -1;
+    if !self.static_block { return; }
+    let size = self.new_reg();
+    // the pending exception is after all static fields
+    self.push(Tac::IntConst(size, self.statics.len() as i32 * INT_SIZE + if self.has_try { INT_SIZE } else { 0 }));
+    self.push(Tac::Param(size));
+    let base = self.intrinsic_call(ALLOCATE);
+    self.base = base;
+    let zero = self.int_const(0);
+    if self.has_try { self.push(Tac::Store(base, self.pending, zero)); }
+    for var_def in self.statics.clone() {
+      let var_def = var_def.get();
+      let src = if let Some(src) = &mut var_def.src {
+        self.expr(src);
+        src.tac_reg
+      } else { zero };
+      self.push(Tac::Store(base, var_def.offset * INT_SIZE, src));
+    }
+  }
+
   fn push(&mut self, tac: Tac) {
     self.cur_method.get().push(tac);
   }
@@ -130,14 +403,17 @@ pub fn resolve_field_order(class_def: &mut ClassDef) {
     p.field_cnt
   };
   class_def.v_tbl.class = class_def;
+  let class_ptr = class_def as *const ClassDef;
   'out: for field in &mut class_def.field {
     match field {
       FieldDef::MethodDef(method_def) => if !method_def.static_ {
         if !class_def.p_ptr.is_null() {
-          let p = class_def.p_ptr.get();
-          for p_method in &p.v_tbl.methods {
-            if p_method.get().name == method_def.name {
-              method_def.offset = p_method.get().offset;
+          // a private method is not overridden, a method with the same signature in subclass gets a new slot
+          if let Some(p_method) = match_override(method_def, &class_ptr.get().inherited_overloads(method_def.name)) {
+            let p_method = p_method.get();
+            // an interface method left unimplemented by an abstract parent has no slot
+            if !p_method.static_ && !p_method.class.get().interface {
+              method_def.offset = p_method.offset;
               class_def.v_tbl.methods[method_def.offset as usize] = method_def;
               continue 'out;
             }
@@ -146,28 +422,51 @@ pub fn resolve_field_order(class_def: &mut ClassDef) {
         method_def.offset = class_def.v_tbl.methods.len() as i32;
         class_def.v_tbl.methods.push(method_def);
       }
-      FieldDef::VarDef(var_def) => {
+      FieldDef::VarDef(var_def) => if !var_def.static_ {
         var_def.offset = class_def.field_cnt;
         class_def.field_cnt += 1;
       }
+      FieldDef::Ctor(_) => {}
     }
   }
 }
 
 impl TacCodeGen {
   fn program(&mut self, program: &mut Program) {
+    // static fields are initialized in this order, enum constants go first since other initializers may use them
+    let mut classes = program.class.iter_mut().collect::<Vec<_>>();
+    classes.sort_by_key(|class_def| class_def.enum_.is_none());
+    for class_def in classes {
+      for field_def in &mut class_def.field {
+        if let FieldDef::VarDef(var_def) = field_def {
+          if var_def.static_ {
+            var_def.offset = self.statics.len() as i32;
+            self.statics.push(var_def);
+          }
+        }
+      }
+    }
+    self.static_block = !self.statics.is_empty() || self.has_try;
+    self.pending = self.statics.len() as i32 * INT_SIZE;
+    // a method may use the fields and methods of a class after it
+    for class_def in &mut program.class { resolve_field_order(class_def); }
     for class_def in &mut program.class {
-      resolve_field_order(class_def);
       for field_def in &mut class_def.field {
-        if let FieldDef::MethodDef(method_def) = field_def {
+        if let FieldDef::MethodDef(method_def) | FieldDef::Ctor(method_def) = field_def {
           // "this" is already inserted as 1st by symbol builder
           for param in &mut method_def.param {
             param.offset = self.new_reg();
           }
         }
       }
-      self.methods.push(TacMethod { name: format!("_{}_New", class_def.name), code: Vec::new(), method: ptr::null() });
+      if class_def.interface { continue; }
+      self.methods.push(TacMethod { name: format!("_{}_New", class_def.name), code: Vec::new(), method: ptr::null(), base: -1 });
       self.cur_method = &mut self.methods.last_mut().unwrap().code;
+      if self.methods.len() == 1 {
+        // the tac vm gives the 1st string literal in the file the value 0, which equals null, so waste one here
+        let unused = self.new_reg();
+        self.push(Tac::StrConst(unused, quote("")));
+      }
       let size = self.new_reg();
       self.push(Tac::IntConst(size, (class_def.field_cnt + 1) * INT_SIZE));
       self.push(Tac::Param(size));
@@ -183,39 +482,131 @@ impl TacCodeGen {
       self.push(Tac::Ret(ret));
       let class_def_ptr = class_def as *const _;
       for field_def in &mut class_def.field {
-        if let FieldDef::MethodDef(method_def) = field_def {
-          self.methods.push(TacMethod {
-            name: if class_def_ptr == program.main && method_def.name == MAIN_METHOD { "main".to_owned() } else { format!("_{}.{}", class_def.name, method_def.name) },
-            code: Vec::new(),
-            method: method_def,
-          });
-          self.cur_method = &mut self.methods.last_mut().unwrap().code;
-          if !method_def.static_ {
-            self.cur_this = method_def.param[0].offset;
+        match field_def {
+          FieldDef::MethodDef(method_def) => {
+            let is_main = class_def_ptr == program.main && method_def.name == MAIN_METHOD && method_def.overload_idx == 0;
+            self.methods.push(TacMethod {
+              name: if is_main { "main".to_owned() } else { format!("_{}.{}", class_def.name, method_def.mangled_name()) },
+              code: Vec::new(),
+              method: method_def,
+              base: -1,
+            });
+            self.cur_method = &mut self.methods.last_mut().unwrap().code;
+            if !method_def.static_ {
+              self.cur_this = method_def.param[0].offset;
+            }
+            self.out_of_bound_to_fill.clear();
+            self.propagate = -1;
+            if is_main { self.static_init(); } else { self.receive_base(); }
+            self.method_body(method_def, is_main);
+            self.lambda_bodies();
           }
-          self.out_of_bound_to_fill.clear();
-          self.block(&mut method_def.body);
-          if !self.out_of_bound_to_fill.is_empty() {
-            let (halt, after) = (self.new_label(), self.new_label());
-            let msg = self.new_reg();
-            self.push(Tac::Jmp(after));
-            self.push(Tac::Label(halt));
-            self.push(Tac::StrConst(msg, quote(INDEX_OUT_OF_BOUND)));
-            self.push(Tac::Param(msg));
-            self.intrinsic_call(PRINT_STRING);
-            self.intrinsic_call(HALT);
-            self.push(Tac::Label(after));
-            for to_fill in &self.out_of_bound_to_fill {
-              if let Tac::Je(_, label) = &mut self.cur_method.get()[*to_fill as usize] {
-                *label = halt;
-              } else { unreachable!(); }
+          FieldDef::Ctor(ctor) => {
+            // `_A_New` only allocates, and `new` calls `_A.new` on the new object
+            // the vm only accepts parameters for names like `_A.f`, and no method can be named `new`
+            self.methods.push(TacMethod { name: format!("_{}.new", class_def.name), code: Vec::new(), method: ctor, base: -1 });
+            self.cur_method = &mut self.methods.last_mut().unwrap().code;
+            self.receive_base();
+            self.cur_this = ctor.param[0].offset;
+            self.out_of_bound_to_fill.clear();
+            self.propagate = -1;
+            if let Some(p_ctor) = ctor.implicit_super() {
+              self.push(Tac::Param(self.cur_this));
+              self.pass_base();
+              self.push(Tac::DirectCall(-1, format!("_{}.new", p_ctor.class.get().name)));
+              self.check_exception();
             }
+            self.method_body(ctor, false);
+            self.lambda_bodies();
           }
+          FieldDef::VarDef(_) => {}
+        }
+      }
+    }
+    // interface methods are dispatchers, see `dispatch`
+    for class_def in program.class.iter().filter(|class_def| class_def.interface) {
+      for field_def in &class_def.field {
+        if let FieldDef::MethodDef(method_def) = field_def {
+          self.methods.push(TacMethod { name: format!("_{}.{}", class_def.name, method_def.mangled_name()), code: Vec::new(), method: method_def, base: -1 });
+          self.cur_method = &mut self.methods.last_mut().unwrap().code;
+          self.receive_base();
+          self.dispatch(method_def);
         }
       }
     }
   }
 
+  fn method_body(&mut self, method_def: &mut MethodDef, is_main: bool) {
+    self.block(&mut method_def.body);
+    if self.propagate >= 0 {
+      // the caller will find the exception pending, and the return value is not used
+      let after = self.new_label();
+      self.push(Tac::Jmp(after));
+      self.push(Tac::Label(self.propagate));
+      if is_main {
+        let (base, e) = (self.base, self.new_reg());
+        self.push(Tac::Load(e, base, self.pending));
+        self.uncaught(e);
+      } else if method_def.ret_t.sem == VOID {
+        self.push(Tac::Ret(-1));
+      } else {
+        let zero = self.int_const(0);
+        self.push(Tac::Ret(zero));
+      }
+      self.push(Tac::Label(after));
+    }
+    if !self.out_of_bound_to_fill.is_empty() {
+      let (halt, after) = (self.new_label(), self.new_label());
+      let msg = self.new_reg();
+      self.push(Tac::Jmp(after));
+      self.push(Tac::Label(halt));
+      self.push(Tac::StrConst(msg, quote(INDEX_OUT_OF_BOUND)));
+      self.push(Tac::Param(msg));
+      self.intrinsic_call(PRINT_STRING);
+      self.intrinsic_call(HALT);
+      self.push(Tac::Label(after));
+      for to_fill in &self.out_of_bound_to_fill {
+        if let Tac::Je(_, label) = &mut self.cur_method.get()[*to_fill as usize] {
+          *label = halt;
+        } else { unreachable!(); }
+      }
+    }
+  }
+
+  // a lambda is a method whose 'this' is the env object: [v-table, captured values...]
+  fn lambda_bodies(&mut self) {
+    while !self.lambdas.is_empty() {
+      let lambda = self.lambdas.remove(0).get();
+      let def = &mut lambda.def;
+      self.methods.push(TacMethod { name: format!("_{}.{}", def.class.get().name, def.mangled_name()), code: Vec::new(), method: def, base: -1 });
+      self.cur_method = &mut self.methods.last_mut().unwrap().code;
+      self.receive_base();
+      for param in &mut def.param {
+        param.offset = self.new_reg();
+      }
+      let (env, mut offset) = (def.param[0].offset, INT_SIZE);
+      for (_, copy) in &mut lambda.capture {
+        copy.offset = self.new_reg();
+        self.push(Tac::Load(copy.offset, env, offset));
+        offset += INT_SIZE;
+      }
+      self.out_of_bound_to_fill.clear();
+      self.propagate = -1;
+      self.method_body(def, false);
+      self.all_lambdas.push(lambda);
+    }
+  }
+
+  // `this` is already evaluated, only `arg` need evaluating
+  fn call_ctor(&mut self, this: i32, ctor: &MethodDef, arg: &mut Vec<Expr>) {
+    for arg in arg.iter_mut() { self.expr(arg); }
+    self.push(Tac::Param(this));
+    for arg in arg.iter() { self.push(Tac::Param(arg.tac_reg)); }
+    self.pass_base();
+    self.push(Tac::DirectCall(-1, format!("_{}.new", ctor.class.get().name)));
+    self.check_exception();
+  }
+
   fn stmt(&mut self, stmt: &mut Stmt) {
     use ast::Stmt::*;
     match stmt {
@@ -240,29 +631,50 @@ impl TacCodeGen {
         self.push(Tac::Label(before_cond));
         self.expr(&mut while_.cond);
         self.push(Tac::Je(while_.cond.tac_reg, after_body));
-        self.break_stack.push(after_body);
+        self.loop_stack.push((while_.label, after_body, before_cond));
         self.block(&mut while_.body);
-        self.break_stack.pop();
+        self.loop_stack.pop();
         self.push(Tac::Jmp(before_cond));
         self.push(Tac::Label(after_body));
       }
+      DoWhile(do_while) => {
+        let (before_body, before_cond, after_body) = (self.new_label(), self.new_label(), self.new_label());
+        self.push(Tac::Label(before_body));
+        self.loop_stack.push((do_while.label, after_body, before_cond));
+        self.block(&mut do_while.body);
+        self.loop_stack.pop();
+        self.push(Tac::Label(before_cond));
+        self.expr(&mut do_while.cond);
+        self.push(Tac::Jne(do_while.cond.tac_reg, before_body));
+        self.push(Tac::Label(after_body));
+      }
       For(for_) => {
-        let (before_cond, after_body) = (self.new_label(), self.new_label());
+        let (before_cond, before_update, after_body) = (self.new_label(), self.new_label(), self.new_label());
         self.simple(&mut for_.init);
         self.push(Tac::Label(before_cond));
         self.expr(&mut for_.cond);
         self.push(Tac::Je(for_.cond.tac_reg, after_body));
-        self.break_stack.push(after_body);
+        self.loop_stack.push((for_.label, after_body, before_update));
         self.block(&mut for_.body);
-        self.break_stack.pop();
+        self.loop_stack.pop();
+        self.push(Tac::Label(before_update));
         self.simple(&mut for_.update);
         self.push(Tac::Jmp(before_cond));
         self.push(Tac::Label(after_body));
       }
       Return(return_) => if let Some(expr) = &mut return_.expr {
         self.expr(expr);
-        self.push(Tac::Ret(expr.tac_reg));
+        if self.try_stack.iter().any(|t| !t.0.is_null()) {
+          // the finally blocks may modify a variable being returned
+          let ret = self.new_reg();
+          self.push(Tac::Assign(ret, expr.tac_reg));
+          self.leave_try(0);
+          self.push(Tac::Ret(ret));
+        } else {
+          self.push(Tac::Ret(expr.tac_reg));
+        }
       } else {
+        self.leave_try(0);
         self.push(Tac::Ret(-1));
       }
       Print(print) => for expr in &mut print.print {
@@ -271,14 +683,26 @@ impl TacCodeGen {
         match &expr.type_ {
           SemanticType::Int => { self.intrinsic_call(PRINT_INT); }
           SemanticType::Bool => { self.intrinsic_call(PRINT_BOOL); }
+          SemanticType::Char => { self.intrinsic_call(PRINT_CHAR); }
+          // already reported in `expr`
+          SemanticType::Long => {}
+          SemanticType::Double => { self.intrinsic_call(PRINT_DOUBLE); }
           SemanticType::String => { self.intrinsic_call(PRINT_STRING); }
           _ => unreachable!(),
         }
       }
-      Break(_) => {
-        let after_loop = *self.break_stack.last().unwrap();
+      Break(break_) => {
+        let (after_loop, _) = loop_target(&self.loop_stack, break_.label);
+        let first = self.first_try_in(loop_index(&self.loop_stack, break_.label, false));
+        self.leave_try(first);
         self.push(Tac::Jmp(after_loop));
       }
+      Continue(continue_) => {
+        let (_, next_iter) = loop_target(&self.loop_stack, continue_.label);
+        let first = self.first_try_in(loop_index(&self.loop_stack, continue_.label, true));
+        self.leave_try(first);
+        self.push(Tac::Jmp(next_iter));
+      }
       SCopy(s_copy) => {
         self.expr(&mut s_copy.src);
         let new_obj = self.new_reg();
@@ -295,7 +719,7 @@ impl TacCodeGen {
         self.expr(&mut foreach.arr);
         foreach.def.offset = self.new_reg();
         let (i, int_size, cmp) = (self.new_reg(), self.int_const(INT_SIZE), self.new_reg());
-        let (before_cond, after_body) = (self.new_label(), self.new_label());
+        let (before_cond, before_inc, after_body) = (self.new_label(), self.new_label(), self.new_label());
         self.push(Tac::IntConst(i, 0));
         let end = self.array_length(foreach.arr.tac_reg);
         self.push(Tac::Mul(end, end, int_size));
@@ -309,9 +733,10 @@ impl TacCodeGen {
           self.expr(cond);
           self.push(Tac::Je(cond.tac_reg, after_body));
         }
-        self.break_stack.push(after_body);
+        self.loop_stack.push((foreach.label, after_body, before_inc));
         self.block(&mut foreach.body);
-        self.break_stack.pop();
+        self.loop_stack.pop();
+        self.push(Tac::Label(before_inc));
         self.push(Tac::Add(i, i, int_size));
         self.push(Tac::Jmp(before_cond));
         self.push(Tac::Label(after_body));
@@ -323,8 +748,136 @@ impl TacCodeGen {
         self.block(b);
         self.push(Tac::Label(after_body));
       }
+      Switch(switch) => {
+        self.expr(&mut switch.cond);
+        let after_switch = self.new_label();
+        let mut label = Vec::new();
+        let (mut table, mut default) = (Vec::new(), after_switch);
+        for case in &switch.case {
+          let l = self.new_label();
+          match case.value {
+            Some(value) => table.push((value, l)),
+            None => default = l,
+          }
+          label.push(l);
+        }
+        table.sort();
+        self.switch_search(switch.cond.tac_reg, &table, default);
+        // 'continue' in a switch goes to the enclosing loop
+        let next_iter = self.loop_stack.last().map_or(after_switch, |l| l.2);
+        self.loop_stack.push((None, after_switch, next_iter));
+        for (case, l) in switch.case.iter_mut().zip(label) {
+          self.push(Tac::Label(l));
+          self.block(&mut case.body);
+        }
+        self.loop_stack.pop();
+        self.push(Tac::Label(after_switch));
+      }
       Block(block) => self.block(block),
+      SuperCall(super_call) => if !super_call.ctor.is_null() {
+        let this = self.cur_this;
+        self.call_ctor(this, super_call.ctor.get(), &mut super_call.arg);
+      }
+      Try(try_) => self.try_(try_),
+      Throw(throw) => {
+        self.expr(&mut throw.expr);
+        if self.has_try { self.raise(throw.expr.tac_reg); } else { self.uncaught(throw.expr.tac_reg); }
+      }
+      Assert(assert) => if !self.no_asserts {
+        let (ok, str) = (self.new_label(), self.new_reg());
+        self.expr(&mut assert.cond);
+        self.push(Tac::Jne(assert.cond.tac_reg, ok));
+        self.push(Tac::StrConst(str, quote(&format!("{}{}", ASSERT1, assert.loc))));
+        self.push(Tac::Param(str));
+        self.intrinsic_call(PRINT_STRING);
+        // msg is only evaluated when the assertion fails
+        if let Some(msg) = &mut assert.msg {
+          self.expr(msg);
+          self.push(Tac::StrConst(str, quote(ASSERT2)));
+          self.push(Tac::Param(str));
+          self.intrinsic_call(PRINT_STRING);
+          self.push(Tac::Param(msg.tac_reg));
+          self.intrinsic_call(PRINT_STRING);
+        }
+        self.push(Tac::StrConst(str, quote(ASSERT3)));
+        self.push(Tac::Param(str));
+        self.intrinsic_call(PRINT_STRING);
+        self.intrinsic_call(HALT);
+        self.push(Tac::Label(ok));
+      }
+    }
+  }
+
+  // an exception in the body goes to `dispatch`, which tests the catches in order
+  // the finally block is inlined after the body and each catch, and before each return/break/continue out of it
+  // an exception in a catch, or matching no catch goes to `rethrow`: e = pending; pending = 0; <finally>; pending = e
+  fn try_(&mut self, try_: &mut Try) {
+    let outer = self.handler;
+    let finally = try_.finally.as_mut().map_or(ptr::null_mut(), |f| f as *mut Block);
+    let (dispatch, after) = (self.new_label(), self.new_label());
+    let rethrow = if finally.is_null() { outer } else { self.new_label() };
+    self.handler = dispatch;
+    self.try_stack.push((finally, self.loop_stack.len(), outer));
+    self.block(&mut try_.body);
+    self.try_stack.pop();
+    self.handler = outer;
+    self.inline_finally(finally);
+    self.push(Tac::Jmp(after));
+    self.push(Tac::Label(dispatch));
+    let (base, e, zero) = (self.base, self.new_reg(), self.int_const(0));
+    self.push(Tac::Load(e, base, self.pending));
+    for catch in &mut try_.catch {
+      let next = self.new_label();
+      let check = self.instance_of(e, catch.def.type_.get_class());
+      self.push(Tac::Je(check, next));
+      self.push(Tac::Store(base, self.pending, zero));
+      catch.def.offset = self.new_reg();
+      self.push(Tac::Assign(catch.def.offset, e));
+      self.handler = rethrow;
+      self.try_stack.push((finally, self.loop_stack.len(), outer));
+      self.block(&mut catch.body);
+      self.try_stack.pop();
+      self.handler = outer;
+      self.inline_finally(finally);
+      self.push(Tac::Jmp(after));
+      self.push(Tac::Label(next));
+    }
+    if !finally.is_null() {
+      self.push(Tac::Label(rethrow));
+      let (base, e, zero) = (self.base, self.new_reg(), self.int_const(0));
+      self.push(Tac::Load(e, base, self.pending));
+      self.push(Tac::Store(base, self.pending, zero));
+      self.inline_finally(finally);
+      self.push(Tac::Store(base, self.pending, e));
     }
+    let handler = self.handler_label();
+    self.push(Tac::Jmp(handler));
+    self.push(Tac::Label(after));
+  }
+
+  fn inline_finally(&mut self, finally: *mut Block) {
+    if !finally.is_null() { self.block(finally.get()); }
+  }
+
+  // the index in try_stack of the outermost try inside the loop at `loop_index`
+  fn first_try_in(&self, loop_index: usize) -> usize {
+    self.try_stack.iter().position(|t| t.1 > loop_index).unwrap_or(self.try_stack.len())
+  }
+
+  // before jumping out of try_stack[first..], run their finally blocks from the innermost one
+  // the inlined finally of a try is only protected by the trys outside it
+  fn leave_try(&mut self, first: usize) {
+    let handler = self.handler;
+    for i in (first..self.try_stack.len()).rev() {
+      let (finally, _, outer) = self.try_stack[i];
+      if !finally.is_null() {
+        let inner = self.try_stack.split_off(i);
+        self.handler = outer;
+        self.inline_finally(finally);
+        self.try_stack.extend(inner);
+      }
+    }
+    self.handler = handler;
   }
 
   fn simple(&mut self, simple: &mut Simple) {
@@ -333,24 +886,40 @@ impl TacCodeGen {
         if let ExprData::Id(id) = &mut assign.dst.data { id.for_assign = true; }
         if let ExprData::Indexed(indexed) = &mut assign.dst.data { indexed.for_assign = true; }
         self.expr(&mut assign.dst);
+        // for indexed dst the address is computed once, and shared by the load of compound assignment
+        let addr = if let ExprData::Indexed(indexed) = &assign.dst.data {
+          let (int_size, addr) = (self.int_const(INT_SIZE), self.new_reg());
+          self.push(Tac::Mul(addr, indexed.idx.tac_reg, int_size));
+          self.push(Tac::Add(addr, indexed.arr.tac_reg, addr));
+          addr
+        } else { -1 };
+        let cur = if assign.op.is_some() { self.load_lvalue(&assign.dst, addr) } else { -1 };
         self.expr(&mut assign.src);
+        let src = match assign.op {
+          Some(_) if assign.dst.type_ == STRING => self.string_concat(cur, assign.src.tac_reg),
+          Some(op) => {
+            let d = self.new_reg();
+            self.arith(op, &assign.dst.type_, assign.loc, d, cur, assign.src.tac_reg);
+            d
+          }
+          None => assign.src.tac_reg,
+        };
         match &assign.dst.data {
           ExprData::Id(id) => {
             let var_def = id.symbol.get();
             match var_def.scope.get().kind {
-              ScopeKind::Local(_) | ScopeKind::Parameter(_) => { self.push(Tac::Assign(var_def.offset, assign.src.tac_reg)); }
+              ScopeKind::Local(_) | ScopeKind::Parameter(_) => { self.push(Tac::Assign(var_def.offset, src)); }
+              ScopeKind::Class(_) if var_def.static_ => {
+                let base = self.base;
+                self.push(Tac::Store(base, var_def.offset * INT_SIZE, src));
+              }
               ScopeKind::Class(_) => {
-                self.push(Tac::Store(id.owner.as_ref().unwrap().tac_reg, (var_def.offset + 1) * INT_SIZE, assign.src.tac_reg));
+                self.push(Tac::Store(id.owner.as_ref().unwrap().tac_reg, (var_def.offset + 1) * INT_SIZE, src));
               }
               _ => unreachable!(),
             }
           }
-          ExprData::Indexed(indexed) => {
-            let (int_size, offset) = (self.int_const(INT_SIZE), self.new_reg());
-            self.push(Tac::Mul(offset, indexed.idx.tac_reg, int_size));
-            self.push(Tac::Add(offset, indexed.arr.tac_reg, offset));
-            self.push(Tac::Store(offset, 0, assign.src.tac_reg));
-          }
+          ExprData::Indexed(_) => { self.push(Tac::Store(addr, 0, src)); }
           _ => unreachable!(),
         }
       }
@@ -366,17 +935,104 @@ impl TacCodeGen {
     }
   }
 
+  // the current value of an lvalue whose owner / index(`addr` for indexed) is already evaluated
+  fn load_lvalue(&mut self, dst: &Expr, addr: i32) -> i32 {
+    match &dst.data {
+      ExprData::Id(id) => {
+        let var_def = id.symbol.get();
+        match var_def.scope.get().kind {
+          ScopeKind::Local(_) | ScopeKind::Parameter(_) => var_def.offset,
+          ScopeKind::Class(_) => {
+            let (base, offset, cur) = if var_def.static_ {
+              (self.base, var_def.offset * INT_SIZE, self.new_reg())
+            } else {
+              (id.owner.as_ref().unwrap().tac_reg, (var_def.offset + 1) * INT_SIZE, self.new_reg())
+            };
+            self.push(Tac::Load(cur, base, offset));
+            cur
+          }
+          _ => unreachable!(),
+        }
+      }
+      ExprData::Indexed(_) => {
+        let cur = self.new_reg();
+        self.push(Tac::Load(cur, addr, 0));
+        cur
+      }
+      _ => unreachable!(),
+    }
+  }
+
+  // the tac vm has no bitwise operators, so they are reported at `loc`
+  fn arith(&mut self, op: Operator, t: &SemanticType, loc: Loc, d: i32, l: i32, r: i32) {
+    use ast::Operator::*;
+    if t == &DOUBLE {
+      self.push(Tac::Param(l));
+      self.push(Tac::Param(r));
+      let res = self.intrinsic_call(match op { Add => DOUBLE_ADD, Sub => DOUBLE_SUB, Mul => DOUBLE_MUL, Div => DOUBLE_DIV, _ => DOUBLE_MOD });
+      self.push(Tac::Assign(d, res));
+      return;
+    }
+    match op {
+      Add => self.push(Tac::Add(d, l, r)),
+      Sub => self.push(Tac::Sub(d, l, r)),
+      Mul => self.push(Tac::Mul(d, l, r)),
+      Div => {
+        self.check_div_0(r);
+        self.push(Tac::Div(d, l, r));
+      }
+      Mod => {
+        self.check_div_0(r);
+        self.push(Tac::Mod(d, l, r));
+      }
+      _ => self.issue(loc, TacBitOp { op: op.to_str() }),
+    }
+  }
+
+  // binary search in `table` of (value, label) sorted by value, jump to `default` if `reg` matches none
+  fn switch_search(&mut self, reg: i32, table: &[(i32, i32)], default: i32) {
+    let cmp = self.new_reg();
+    if table.len() <= 3 {
+      for &(value, label) in table {
+        let value = self.int_const(value);
+        self.push(Tac::Eq(cmp, reg, value));
+        self.push(Tac::Jne(cmp, label));
+      }
+      self.push(Tac::Jmp(default));
+    } else {
+      let (mid, upper) = (table.len() / 2, self.new_label());
+      let value = self.int_const(table[mid].0);
+      self.push(Tac::Lt(cmp, reg, value));
+      self.push(Tac::Je(cmp, upper));
+      self.switch_search(reg, &table[..mid], default);
+      self.push(Tac::Label(upper));
+      self.switch_search(reg, &table[mid..], default);
+    }
+  }
+
   fn block(&mut self, block: &mut Block) {
     for stmt in &mut block.stmt { self.stmt(stmt); }
   }
 
   fn expr(&mut self, expr: &mut Expr) {
     use ast::ExprData::*;
+    // the tac vm only has 32 bit registers, so an expression producing or converting a long is reported, and not generated
+    let from_long = if let Convert(convert) = &expr.data { convert.expr.type_ == LONG } else { false };
+    if expr.type_ == LONG || from_long {
+      self.issue(expr.loc, TacLong {});
+      expr.tac_reg = self.new_reg();
+      return;
+    }
     match &mut expr.data {
       Id(id) => {
         let var_def = id.symbol.get();
         match var_def.scope.get().kind {
           ScopeKind::Local(_) | ScopeKind::Parameter(_) => expr.tac_reg = var_def.offset,
+          ScopeKind::Class(_) if var_def.static_ => if !id.for_assign {
+            let base = self.base;
+            expr.tac_reg = self.new_reg();
+            self.push(Tac::Load(expr.tac_reg, base, var_def.offset * INT_SIZE));
+          }
           ScopeKind::Class(_) => {
             let owner = id.owner.as_mut().unwrap();
             self.expr(owner);
@@ -392,8 +1048,17 @@ impl TacCodeGen {
         self.expr(&mut indexed.arr);
         self.expr(&mut indexed.idx);
         let check = self.check_array_index(indexed.arr.tac_reg, indexed.idx.tac_reg);
-        self.out_of_bound_to_fill.push(self.cur_method.get().len() as i32);
-        self.push(Tac::Je(check, -1)); // jump where not determined yet
+        if self.has_try {
+          // each site raises its own exception
+          let (ok, msg) = (self.new_label(), self.new_reg());
+          self.push(Tac::Jne(check, ok));
+          self.push(Tac::StrConst(msg, quote(INDEX_OUT_OF_BOUND)));
+          self.runtime_error(INDEX_OUT_OF_BOUNDS_EXCEPTION, msg);
+          self.push(Tac::Label(ok));
+        } else {
+          self.out_of_bound_to_fill.push(self.cur_method.get().len() as i32);
+          self.push(Tac::Je(check, -1)); // jump where not determined yet
+        }
         if !indexed.for_assign { // not used, but still check index here
           expr.tac_reg = self.array_at(indexed.arr.tac_reg, indexed.idx.tac_reg);
         }
@@ -402,6 +1067,9 @@ impl TacCodeGen {
         expr.tac_reg = self.new_reg();
         self.push(Tac::IntConst(expr.tac_reg, *v));
       }
+      CharConst(v) => expr.tac_reg = self.int_const(*v as i32),
+      LongConst(_) => unreachable!(),
+      DoubleConst(v) => expr.tac_reg = self.double_const(*v),
       BoolConst(v) => expr.tac_reg = if *v { self.int_const(1) } else { self.int_const(0) },
       StringConst(v) => {
         expr.tac_reg = self.new_reg();
@@ -413,34 +1081,63 @@ impl TacCodeGen {
         let owner = call.owner.as_mut().unwrap();
         self.expr(owner);
         expr.tac_reg = self.array_length(owner.tac_reg);
+      } else if let Some(method) = call.string_method {
+        let owner = call.owner.as_mut().unwrap();
+        self.expr(owner);
+        for arg in &mut call.arg { self.expr(arg); }
+        // the runtime checks the index, so all params are consecutive
+        self.push(Tac::Param(owner.tac_reg));
+        for arg in &call.arg { self.push(Tac::Param(arg.tac_reg)); }
+        expr.tac_reg = self.intrinsic_call(match method {
+          StringMethod::Length => STRING_LENGTH,
+          StringMethod::CharAt => STRING_CHAR_AT,
+          StringMethod::Substring => STRING_SUBSTRING,
+        });
       } else {
         let method = call.method.get();
         let class = method.class.get();
         expr.tac_reg = if method.ret_t.sem != VOID { self.new_reg() } else { -1 };
-        if method.static_ {
+        if method.static_ || class.interface {
           // fuck vm spec
           // 'parm' can only be consecutive for one call, there cannot be other call in this process
           // e.g. my old version: f(1, x[0]) : parm 1 => (if <out of bound> call _Halt) => parm x[0]
           // which caused error
+          // an interface method is called like a static one, with 'this' as the 1st argument
+          if class.interface { self.expr(call.owner.as_mut().unwrap()); }
           for arg in &mut call.arg { self.expr(arg); }
+          if class.interface { self.push(Tac::Param(call.owner.as_ref().unwrap().tac_reg)); }
           for arg in &mut call.arg { self.push(Tac::Param(arg.tac_reg)); }
-          self.push(Tac::DirectCall(expr.tac_reg, format!("_{}.{}", class.name, method.name)));
+          self.pass_base();
+          self.push(Tac::DirectCall(expr.tac_reg, format!("_{}.{}", class.name, method.mangled_name())));
+          self.check_exception();
         } else {
           let owner = call.owner.as_mut().unwrap();
           self.expr(owner);
           for arg in &mut call.arg { self.expr(arg); }
           self.push(Tac::Param(owner.tac_reg));
           for arg in &mut call.arg { self.push(Tac::Param(arg.tac_reg)); }
+          self.pass_base();
           let slot = self.new_reg();
-          self.push(Tac::Load(slot, owner.tac_reg, 0));
+          if let Super = owner.data {
+            // a super call is bound statically, but the vm only allows calling a virtual method through a v table
+            // so take the method from the parent's v table, rather than the object's
+            self.push(Tac::LoadVTbl(slot, owner.type_.get_class().name));
+          } else {
+            self.push(Tac::Load(slot, owner.tac_reg, 0));
+          }
           self.push(Tac::Load(slot, slot, (method.offset + 2) * INT_SIZE));
           self.push(Tac::IndirectCall(expr.tac_reg, slot));
+          self.check_exception();
         }
       }
       Unary(unary) => {
         self.expr(&mut unary.r);
         expr.tac_reg = self.new_reg();
         match unary.op {
+          Operator::Neg if unary.r.type_ == DOUBLE => {
+            let zero = self.double_const(0.0);
+            self.arith(Operator::Sub, &DOUBLE, expr.loc, expr.tac_reg, zero, unary.r.tac_reg);
+          }
           Operator::Neg => self.push(Tac::Neg(expr.tac_reg, unary.r.tac_reg)),
           Operator::Not => self.push(Tac::Not(expr.tac_reg, unary.r.tac_reg)),
           _ => unimplemented!(),
@@ -451,19 +1148,18 @@ impl TacCodeGen {
         self.expr(&mut binary.l);
         self.expr(&mut binary.r);
         expr.tac_reg = self.new_reg();
-        let (l, r, d) = (binary.l.tac_reg, binary.r.tac_reg, expr.tac_reg);
+        let (mut l, mut r, d) = (binary.l.tac_reg, binary.r.tac_reg, expr.tac_reg);
+        let is_cmp = binary.op == Lt || binary.op == Le || binary.op == Gt || binary.op == Ge;
+        if (binary.l.type_ == STRING && is_cmp) || (binary.l.type_ == DOUBLE && (is_cmp || binary.op == Eq || binary.op == Ne)) {
+          // compare the result of _StringCompare / _DoubleCompare with 0 instead
+          self.push(Tac::Param(l));
+          self.push(Tac::Param(r));
+          l = self.intrinsic_call(if binary.l.type_ == STRING { STRING_COMPARE } else { DOUBLE_COMPARE });
+          r = self.int_const(0);
+        }
         match binary.op {
-          Add => self.push(Tac::Add(d, l, r)),
-          Sub => self.push(Tac::Sub(d, l, r)),
-          Mul => self.push(Tac::Mul(d, l, r)),
-          Div => {
-            self.check_div_0(r);
-            self.push(Tac::Div(d, l, r));
-          }
-          Mod => {
-            self.check_div_0(r);
-            self.push(Tac::Mod(d, l, r));
-          }
+          Add if binary.l.type_ == STRING => expr.tac_reg = self.string_concat(l, r),
+          Add | Sub | Mul | Div | Mod | BAnd | BOr | BXor | Shl | Shr => self.arith(binary.op, &binary.l.type_, expr.loc, d, l, r),
           Lt => self.push(Tac::Lt(d, l, r)),
           Le => self.push(Tac::Le(d, l, r)),
           Gt => self.push(Tac::Gt(d, l, r)),
@@ -484,9 +1180,7 @@ impl TacCodeGen {
             self.push(Tac::Ge(cmp, r, zero));
             self.push(Tac::Jne(cmp, ok));
             self.push(Tac::StrConst(msg, quote(REPEAT_NEG)));
-            self.push(Tac::Param(msg));
-            self.intrinsic_call(PRINT_STRING);
-            self.intrinsic_call(HALT);
+            self.runtime_error(NEGATIVE_ARRAY_SIZE_EXCEPTION, msg);
             self.push(Tac::Label(ok));
             self.push(Tac::Mul(i, r, int_size));
             self.push(Tac::Add(i, i, int_size));
@@ -498,7 +1192,7 @@ impl TacCodeGen {
             self.push(Tac::Eq(cmp, i, expr.tac_reg));
             self.push(Tac::Jne(cmp, finish));
             match &binary.l.type_ {
-              SemanticType::Object(class) => {
+              SemanticType::Object(class, _) => {
                 // perform s_copy
                 let (new_obj, tmp) = (self.new_reg(), self.new_reg());
                 let class = class.get();
@@ -519,67 +1213,92 @@ impl TacCodeGen {
           _ => unimplemented!(),
         }
       }
-      This => expr.tac_reg = self.cur_this,
+      This | Super => expr.tac_reg = self.cur_this,
       ReadInt => expr.tac_reg = self.intrinsic_call(READ_INT),
       ReadLine => expr.tac_reg = self.intrinsic_call(READ_LINE),
-      NewClass { name } => {
+      Convert(convert) => {
+        self.expr(&mut convert.expr);
+        let src = convert.expr.tac_reg;
+        expr.tac_reg = match convert.op {
+          // a char is just an int in 0..256 here
+          ConvertOp::CharToInt => src,
+          ConvertOp::IntToChar => {
+            // (src % 256 + 256) % 256, which is never negative
+            let (d, c256) = (self.new_reg(), self.int_const(256));
+            self.push(Tac::Mod(d, src, c256));
+            self.push(Tac::Add(d, d, c256));
+            self.push(Tac::Mod(d, d, c256));
+            d
+          }
+          // (int) 1 and (double) 1.0 do nothing
+          ConvertOp::ToInt | ConvertOp::ToDouble if convert.expr.type_ == expr.type_ => src,
+          _ => {
+            self.push(Tac::Param(src));
+            self.intrinsic_call(match convert.op {
+              ConvertOp::StringToChars => STRING_TO_CHARS,
+              ConvertOp::CharsToString => CHARS_TO_STRING,
+              ConvertOp::ToInt => DOUBLE_TO_INT,
+              _ => INT_TO_DOUBLE,
+            })
+          }
+        };
+      }
+      NewClass { name, type_arg: _, arg } => {
         expr.tac_reg = self.new_reg();
         self.push(Tac::DirectCall(expr.tac_reg, format!("_{}_New", name)));
+        if let Some(ctor) = expr.type_.get_class().lookup_ctor() {
+          self.call_ctor(expr.tac_reg, ctor, arg);
+        }
       }
-      NewArray { elem_t: _, len } => {
+      NewArray { elem_t, len, dims } => {
         self.expr(len);
-        let (halt, before_cond, finish) = (self.new_label(), self.new_label(), self.new_label());
-        let (zero, int_size, cmp, i, msg) = (self.int_const(0), self.int_const(INT_SIZE), self.new_reg(), self.new_reg(), self.new_reg());
-        self.push(Tac::Lt(cmp, len.tac_reg, zero));
-        self.push(Tac::Jne(cmp, halt));
-        self.push(Tac::Mul(i, len.tac_reg, int_size));
-        self.push(Tac::Add(i, i, int_size)); // allocate (len + 1) * INT_SIZE
-        self.push(Tac::Param(i));
-        expr.tac_reg = self.intrinsic_call(ALLOCATE);
-        self.push(Tac::Add(i, expr.tac_reg, i));
-        self.push(Tac::Label(before_cond));
-        self.push(Tac::Sub(i, i, int_size));
-        self.push(Tac::Eq(cmp, i, expr.tac_reg));
-        self.push(Tac::Jne(cmp, finish));
-        self.push(Tac::Store(i, 0, zero));
-        self.push(Tac::Jmp(before_cond));
-        self.push(Tac::Label(halt));
-        self.push(Tac::StrConst(msg, quote(NEW_ARR_NEG)));
-        self.push(Tac::Param(msg));
-        self.intrinsic_call(PRINT_STRING);
-        self.intrinsic_call(HALT);
-        self.push(Tac::Label(finish));
-        self.push(Tac::Store(i, 0, len.tac_reg)); // array[-1] = len
-        self.push(Tac::Add(expr.tac_reg, expr.tac_reg, int_size));
+        for dim in dims.iter_mut() { self.expr(dim); }
+        // like java, all lengths are checked before anything is allocated
+        let lens = iter::once(len.tac_reg).chain(dims.iter().map(|dim| dim.tac_reg)).collect::<Vec<_>>();
+        for &len in &lens { self.check_new_array_len(len); }
+        expr.tac_reg = self.new_array(&elem_t.sem, &lens);
       }
-      TypeTest { expr: src, name, target_class: _ } => {
+      TypeTest { expr: src, name: _, target_class } => {
         self.expr(src);
-        expr.tac_reg = self.instance_of(src.tac_reg, name);
+        expr.tac_reg = self.instance_of(src.tac_reg, target_class.get());
       }
       TypeCast { name, expr: src } => {
         self.expr(src);
         expr.tac_reg = src.tac_reg;
-        let check = self.instance_of(src.tac_reg, name);
+        let check = self.instance_of(src.tac_reg, expr.type_.get_class());
         let ok = self.new_label();
         let (msg, v_tbl) = (self.new_reg(), self.new_reg());
         self.push(Tac::Jne(check, ok));
-        self.push(Tac::StrConst(msg, quote(BAD_CAST1)));
-        self.push(Tac::Param(msg));
-        self.intrinsic_call(PRINT_STRING);
-        self.push(Tac::Load(v_tbl, src.tac_reg, 0));
-        self.push(Tac::Load(msg, v_tbl, INT_SIZE)); // name info is in v-table[1]
-        self.push(Tac::Param(msg));
-        self.intrinsic_call(PRINT_STRING);
-        self.push(Tac::StrConst(msg, quote(BAD_CAST2)));
-        self.push(Tac::Param(msg));
-        self.intrinsic_call(PRINT_STRING);
-        self.push(Tac::StrConst(msg, quote(name)));
-        self.push(Tac::Param(msg));
-        self.intrinsic_call(PRINT_STRING);
-        self.push(Tac::StrConst(msg, quote(BAD_CAST3)));
-        self.push(Tac::Param(msg));
-        self.intrinsic_call(PRINT_STRING);
-        self.intrinsic_call(HALT);
+        if self.has_try {
+          // the exception carries the beginning of the message and the class names, see `uncaught`
+          let part = self.new_reg();
+          self.push(Tac::StrConst(msg, quote(BAD_CAST1)));
+          let e = self.new_builtin(CLASS_CAST_EXCEPTION, msg);
+          self.push(Tac::Load(v_tbl, src.tac_reg, 0));
+          self.push(Tac::Load(part, v_tbl, INT_SIZE)); // name info is in v-table[1]
+          self.push(Tac::Store(e, CAST_FROM_OFFSET, part));
+          self.push(Tac::StrConst(part, quote(name)));
+          self.push(Tac::Store(e, CAST_TO_OFFSET, part));
+          self.raise(e);
+        } else {
+          self.push(Tac::StrConst(msg, quote(BAD_CAST1)));
+          self.push(Tac::Param(msg));
+          self.intrinsic_call(PRINT_STRING);
+          self.push(Tac::Load(v_tbl, src.tac_reg, 0));
+          self.push(Tac::Load(msg, v_tbl, INT_SIZE)); // name info is in v-table[1]
+          self.push(Tac::Param(msg));
+          self.intrinsic_call(PRINT_STRING);
+          self.push(Tac::StrConst(msg, quote(BAD_CAST2)));
+          self.push(Tac::Param(msg));
+          self.intrinsic_call(PRINT_STRING);
+          self.push(Tac::StrConst(msg, quote(name)));
+          self.push(Tac::Param(msg));
+          self.intrinsic_call(PRINT_STRING);
+          self.push(Tac::StrConst(msg, quote(BAD_CAST3)));
+          self.push(Tac::Param(msg));
+          self.intrinsic_call(PRINT_STRING);
+          self.intrinsic_call(HALT);
+        }
         self.push(Tac::Label(ok));
       }
       Range(_) => unimplemented!(),
@@ -598,7 +1317,50 @@ impl TacCodeGen {
         self.push(Tac::Assign(expr.tac_reg, default.dft.tac_reg));
         self.push(Tac::Label(after));
       }
+      Conditional(conditional) => {
+        self.expr(&mut conditional.cond);
+        expr.tac_reg = self.new_reg();
+        let (before_false, after) = (self.new_label(), self.new_label());
+        self.push(Tac::Je(conditional.cond.tac_reg, before_false));
+        self.expr(&mut conditional.on_true);
+        self.push(Tac::Assign(expr.tac_reg, conditional.on_true.tac_reg));
+        self.push(Tac::Jmp(after));
+        self.push(Tac::Label(before_false));
+        self.expr(&mut conditional.on_false);
+        self.push(Tac::Assign(expr.tac_reg, conditional.on_false.tac_reg));
+        self.push(Tac::Label(after));
+      }
       Comprehension(_) => unimplemented!(),
+      Lambda(lambda) => {
+        let size = self.int_const((lambda.capture.len() as i32 + 1) * INT_SIZE);
+        let v_tbl = self.new_reg();
+        self.push(Tac::Param(size));
+        expr.tac_reg = self.intrinsic_call(ALLOCATE);
+        // LoadVTbl only takes names living as long as the program, like class names
+        let name = Box::leak(lambda_v_tbl(&lambda.def).into_boxed_str());
+        self.push(Tac::LoadVTbl(v_tbl, name));
+        self.push(Tac::Store(expr.tac_reg, 0, v_tbl));
+        let mut offset = INT_SIZE;
+        for (src, _) in &lambda.capture {
+          self.push(Tac::Store(expr.tac_reg, offset, src.get().offset));
+          offset += INT_SIZE;
+        }
+        self.lambdas.push(lambda.as_mut());
+      }
+      Apply(apply) => {
+        self.expr(&mut apply.func);
+        for arg in &mut apply.arg { self.expr(arg); }
+        self.push(Tac::Param(apply.func.tac_reg));
+        for arg in &apply.arg { self.push(Tac::Param(arg.tac_reg)); }
+        self.pass_base();
+        expr.tac_reg = if expr.type_ != VOID { self.new_reg() } else { -1 };
+        // the only method in the v-table of the env object
+        let slot = self.new_reg();
+        self.push(Tac::Load(slot, apply.func.tac_reg, 0));
+        self.push(Tac::Load(slot, slot, 2 * INT_SIZE));
+        self.push(Tac::IndirectCall(expr.tac_reg, slot));
+        self.check_exception();
+      }
     }
   }
 }
\ No newline at end of file
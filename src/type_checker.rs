@@ -4,35 +4,60 @@ use super::errors::*;
 use super::symbol::*;
 use super::util::*;
 use super::loc::*;
+use super::config::*;
+use super::symbol_builder::SymbolBuilder;
 
 use std::ptr;
+use std::mem;
+use std::iter;
+use std::collections::HashSet;
 
 pub struct TypeChecker {
   errors: Vec<Error>,
+  warnings: Vec<Warning>,
   scopes: ScopeStack,
-  loop_cnt: i32,
+  // labels of the enclosing loops, None for an unlabeled one
+  loop_label: Vec<Option<&'static str>>,
+  // 'break' is also allowed in a switch
+  switch_cnt: i32,
   cur_method: *const MethodDef,
   cur_class: *const ClassDef,
+  // the static field whose initializer is being checked, cur_method is null meanwhile
+  cur_static_field: *const VarDef,
   cur_id_used_for_ref: bool,
+  // the lambdas being checked from the outermost, with the index of their parameter scopes in `scopes`
+  lambdas: Vec<(*mut Lambda, usize)>,
+  // lambdas are numbered in each class
+  lambda_cnt: u32,
+  // whether any lambda has a try, see `Program::has_try`
+  has_try: bool,
 }
 
 impl TypeChecker {
   pub fn check(mut program: Program) -> Result<Program, Vec<Error>> {
     let mut checker = TypeChecker {
       errors: Vec::new(),
+      warnings: Vec::new(),
       scopes: ScopeStack {
         global_scope: ptr::null_mut(),
         scopes: Vec::new(),
       },
-      loop_cnt: 0,
+      loop_label: Vec::new(),
+      switch_cnt: 0,
       cur_method: ptr::null(),
       cur_class: ptr::null(),
+      cur_static_field: ptr::null(),
       cur_id_used_for_ref: false,
+      lambdas: Vec::new(),
+      lambda_cnt: 0,
+      has_try: false,
     };
     checker.scopes.open(&mut program.scope);
     for class_def in &mut program.class { checker.class_def(class_def); }
     checker.scopes.close();
+    program.has_try |= checker.has_try;
     if checker.errors.is_empty() {
+      program.warnings = checker.warnings;
       Ok(program)
     } else {
       checker.errors.sort_by_key(|x| x.loc);
@@ -44,6 +69,20 @@ impl TypeChecker {
     self.errors.push(Error::new(loc, error));
   }
 
+  fn in_static(&self) -> bool {
+    self.cur_method.is_null() || self.cur_method.get().static_
+  }
+
+  fn ref_in_static(&mut self, loc: Loc, field: &'static str) {
+    if self.cur_method.is_null() {
+      let var = self.cur_static_field.get().name;
+      self.issue(loc, RefInStaticInit { field, var });
+    } else {
+      let method = self.cur_method.get().name;
+      self.issue(loc, RefInStatic { field, method });
+    }
+  }
+
   fn check_bool(&mut self, expr: &mut Expr) {
     self.expr(expr);
     if !expr.type_.error_or(&BOOL) {
@@ -59,46 +98,47 @@ impl TypeChecker {
     match symbol {
       Some(symbol) => {
         match symbol {
-          Symbol::Method(method) => {
-            let method = method.get();
+          Symbol::Method(_) => {
+            for expr in &mut call.arg { self.expr(expr); }
+            let candidates = owner_t.get_class().lookup_overloads(call.name);
+            // only private methods of some parent are found
+            if candidates.is_empty() {
+              self.issue(expr_loc, PrivateFieldAccess { name: call.name, owner_t: owner_t.to_string() });
+              return;
+            }
+            let type_arg = match &owner_t { SemanticType::Object(_, arg) => arg.clone(), _ => Vec::new() };
+            let method = match self.resolve_overload(call.name, &call.arg, candidates, &type_arg, expr_loc) {
+              Some(method) => method.get(),
+              None => return,
+            };
             call.method = method;
-            *expr_type = method.ret_t.sem.clone();
+            *expr_type = method.ret_t.sem.subst(&type_arg);
+            let sig = iter::once(&method.ret_t.sem).chain(method.param.iter().map(|p| &p.type_.sem)).collect::<Vec<_>>();
+            self.check_param_array(call.name, &sig, &owner_t, expr_loc);
+            if !self.accessible(method.access, method.class, owner_t.get_class()) {
+              self.issue(expr_loc, PrivateFieldAccess { name: call.name, owner_t: owner_t.to_string() });
+            }
             match &call.owner {
               Some(_) => {
                 if owner_t.is_class() && !method.static_ {
                   // call a instance method through class reference
                   self.issue(expr_loc, BadFieldAccess { name: call.name, owner_t: owner_t.to_string() });
                 }
+                if let Some(ExprData::Super) = call.owner.as_ref().map(|owner| &owner.data) {
+                  if method.abstract_ { self.issue(expr_loc, AbstractSuperCall { name: call.name }); }
+                }
                 if method.static_ { call.owner = None; }
               }
               None => {
-                match (self.cur_method.get().static_, method.static_) {
-                  (true, false) => {
-                    let cur_method = self.cur_method.get().name;
-                    self.issue(expr_loc, RefInStatic { field: method.name, method: cur_method });
-                  }
-                  (false, false) => call.owner = Some(Box::new(Expr::with_type(expr_loc, self.cur_class.get().get_object_type(), ExprData::This))),
+                match (self.in_static(), method.static_) {
+                  (true, false) => self.ref_in_static(expr_loc, method.name),
+                  (false, false) => call.owner = Some(Box::new(self.this(expr_loc))),
                   _ => {}
                 }
               }
             };
-            for expr in &mut call.arg { self.expr(expr); }
             let this_offset = if method.static_ { 0 } else { 1 };
-            let argc = call.arg.len();
-            if argc != method.param.len() - this_offset {
-              self.issue(expr_loc, WrongArgc { name: call.name, expect: method.param.len() as i32, actual: argc as i32 });
-            } else {
-              for i in this_offset..argc + this_offset {
-                let arg_t = &call.arg[i - this_offset].type_;
-                if !arg_t.assignable_to(&method.param[i].type_.sem) {
-                  self.issue(call.arg[i - this_offset].loc, WrongArgType {
-                    loc: (i + 1 - this_offset) as i32,
-                    arg_t: arg_t.to_string(),
-                    param_t: method.param[i].type_.sem.to_string(),
-                  });
-                }
-              }
-            }
+            self.check_args(call.name, &mut call.arg, &method.param[this_offset..], method.param.len(), &type_arg, expr_loc);
           }
           _ => self.issue(expr_loc, NotMethod { name: call.name, owner_t: owner_t.to_string() }),
         }
@@ -106,6 +146,182 @@ impl TypeChecker {
       None => self.issue(expr_loc, NoSuchField { name: call.name, owner_t: owner_t.to_string() }),
     };
   }
+
+  // builtin methods of string: length(), charAt(int) and substring(int, int)
+  fn string_call(&mut self, call: &mut Call, expr_loc: Loc, expr_type: &mut SemanticType) {
+    let (method, param_cnt, ret_t) = match call.name {
+      "length" => (StringMethod::Length, 0, INT),
+      "charAt" => (StringMethod::CharAt, 1, CHAR),
+      "substring" => (StringMethod::Substring, 2, STRING),
+      _ => return self.issue(expr_loc, BadFieldAccess { name: call.name, owner_t: STRING.to_string() }),
+    };
+    for expr in &mut call.arg { self.expr(expr); }
+    call.string_method = Some(method);
+    *expr_type = ret_t;
+    if call.arg.len() != param_cnt {
+      self.issue(expr_loc, WrongArgc { name: call.name, expect: param_cnt as i32, actual: call.arg.len() as i32 });
+    } else {
+      for (i, arg) in call.arg.iter().enumerate() {
+        if !arg.type_.assignable_to(&INT) {
+          self.issue(arg.loc, WrongArgType { loc: i as i32 + 1, arg_t: arg.type_.to_string(), param_t: INT.to_string() });
+        }
+      }
+    }
+  }
+
+  // whether a member declared in `class` with `access` can be accessed through `owner` here
+  fn accessible(&self, access: Access, class: *const ClassDef, owner: *const ClassDef) -> bool {
+    match access {
+      Access::Public => true,
+      Access::Protected => self.cur_class.get().extends(owner),
+      Access::Private => self.cur_class == class,
+    }
+  }
+
+  // pick the most specific one from the overloads applicable to `arg`
+  // with only one candidate, it is picked anyway, and `check_args` reports the mismatch
+  fn resolve_overload(&mut self, name: &'static str, arg: &[Expr], candidates: Vec<*mut MethodDef>, type_arg: &[SemanticType], expr_loc: Loc) -> Option<*mut MethodDef> {
+    if candidates.len() == 1 { return Some(candidates[0]); }
+    let applicable = candidates.into_iter().filter(|method| {
+      let param = method.get().explicit_param();
+      param.len() == arg.len() && arg.iter().zip(param.iter()).all(|(arg, param)| arg.type_.assignable_to(&param.type_.sem.subst(type_arg)))
+    }).collect::<Vec<_>>();
+    if applicable.is_empty() {
+      let arg_t = arg.iter().map(|arg| arg.type_.to_string()).collect::<Vec<_>>().join(", ");
+      self.issue(expr_loc, NoApplicableOverload { name, arg_t });
+      return None;
+    }
+    // `m1` is more specific than `m2` if every parameter of `m1` can be passed to `m2`
+    let specific = applicable.iter().filter(|m1| applicable.iter().all(|m2| {
+      m1.get().explicit_param().iter().zip(m2.get().explicit_param().iter()).all(|(p1, p2)| p1.type_.assignable_to(&p2.type_))
+    })).collect::<Vec<_>>();
+    if specific.len() == 1 {
+      Some(*specific[0])
+    } else {
+      self.issue(expr_loc, AmbiguousCall { name });
+      None
+    }
+  }
+
+  // `param` doesn't include 'this', `arg` is already checked, `type_arg` are those of the owner's type
+  fn check_args(&mut self, name: &'static str, arg: &mut Vec<Expr>, param: &[VarDef], expect: usize, type_arg: &[SemanticType], expr_loc: Loc) {
+    if arg.len() != param.len() {
+      self.issue(expr_loc, WrongArgc { name, expect: expect as i32, actual: arg.len() as i32 });
+    } else {
+      for (i, (arg, param)) in arg.iter_mut().zip(param.iter()).enumerate() {
+        let param_t = param.type_.sem.subst(type_arg);
+        widen(arg, &param_t);
+        if !arg.type_.assignable_to(&param_t) {
+          self.issue(arg.loc, WrongArgType {
+            loc: i as i32 + 1,
+            arg_t: arg.type_.to_string(),
+            param_t: param_t.to_string(),
+          });
+        }
+      }
+    }
+  }
+
+  // a constructor call, `ctor` is None for the default constructor
+  fn check_ctor_args(&mut self, name: &'static str, arg: &mut Vec<Expr>, ctor: Option<&MethodDef>, type_arg: &[SemanticType], expr_loc: Loc) {
+    for expr in arg.iter_mut() { self.expr(expr); }
+    if let Some(ctor) = ctor {
+      if !self.accessible(ctor.access, ctor.class, ctor.class) {
+        self.issue(expr_loc, PrivateFieldAccess { name, owner_t: ctor.class.get().get_object_type().to_string() });
+      }
+    }
+    let param = match ctor { Some(ctor) => &ctor.param[1..], None => &[] };
+    self.check_args(name, arg, param, param.len(), type_arg, expr_loc);
+  }
+
+  // the class in `new` or a cast, which can't be a type parameter
+  fn named_class(&mut self, name: &'static str, type_arg: Vec<SemanticType>, loc: Loc) -> SemanticType {
+    let mut type_ = SemanticType::Named(name, type_arg);
+    self.semantic_type(&mut type_, loc);
+    if type_.is_param() {
+      self.issue(loc, NoSuchClass { name });
+      ERROR
+    } else { type_ }
+  }
+
+  // a local variable declared outside some lambdas being checked is captured by each of them, from the outermost one
+  // return the copy in the innermost one, or `var` itself if it is not captured
+  fn capture(&mut self, var: *const VarDef) -> *const VarDef {
+    let scope = var.get().scope;
+    if !scope.get().is_local() && !scope.get().is_parameter() { return var; }
+    let mut index = match self.scopes.scopes.iter().position(|&s| s as *const Scope == scope) {
+      Some(index) => index,
+      None => return var,
+    };
+    let mut var = var;
+    for &(lambda, lambda_index) in &self.lambdas {
+      if index >= lambda_index { continue; }
+      let lambda = lambda.get();
+      var = match lambda.capture.iter().find(|c| c.0 == var) {
+        Some(c) => c.1.as_ref(),
+        None => {
+          let src = var.get();
+          let mut copy = VarDef::new(src.loc, src.name, Type { loc: src.type_.loc, sem: src.type_.sem.clone() }, None, src.finish_loc);
+          copy.scope = &lambda.def.scope;
+          lambda.capture.push((var, Box::new(copy)));
+          lambda.capture.last().unwrap().1.as_ref()
+        }
+      };
+      index = lambda_index;
+    }
+    var
+  }
+
+  // 'this' in a lambda is a captured variable
+  fn this(&mut self, loc: Loc) -> Expr {
+    let type_ = self.cur_class.get().get_object_type();
+    if self.lambdas.is_empty() {
+      Expr::with_type(loc, type_, ExprData::This)
+    } else {
+      let this = self.capture(&self.cur_method.get().param[0]);
+      Expr::with_type(loc, type_, ExprData::Id(Id { owner: None, name: "this", symbol: this, for_assign: false }))
+    }
+  }
+
+  // a lambda only has a copy of a captured variable, so assigning to it is not allowed
+  fn check_captured(&mut self, expr: &Expr, loc: Loc) {
+    if let ExprData::Id(Id { owner: None, name, symbol, .. }) = &expr.data {
+      if self.lambdas.iter().any(|l| l.0.get().capture.iter().any(|c| c.1.as_ref() as *const VarDef == *symbol)) {
+        self.issue(loc, AssignCaptured { name });
+      }
+    }
+  }
+
+  // an array created in a generic class is an array of the bound, so it can't be used as an array of the type argument
+  fn check_param_array(&mut self, name: &'static str, types: &[&SemanticType], owner_t: &SemanticType, loc: Loc) {
+    if let SemanticType::Object(_, type_arg) = owner_t {
+      if types.iter().any(|t| t.has_param_array() && &&t.subst(type_arg) != t) {
+        self.issue(loc, GenericArrayMember { name, owner_t: owner_t.to_string() });
+      }
+    }
+  }
+
+  // the constants of an enum are its only static fields, and they are read-only
+  fn check_enum_const(&mut self, expr: &Expr, loc: Loc) {
+    if let ExprData::Id(Id { name, symbol, .. }) = &expr.data {
+      if symbol.is_null() || !symbol.get().static_ { return; }
+      if let ScopeKind::Class(class) = symbol.get().scope.get().kind {
+        if class.get().enum_.is_some() { self.issue(loc, AssignEnumConst { name }); }
+      }
+    }
+  }
+
+  // the 1st return in a lambda decides its return type
+  fn lambda_ret_t(&mut self, expr: Option<&Expr>, loc: Loc) -> SemanticType {
+    match expr.map(|expr| &expr.type_) {
+      Some(t @ SemanticType::Void) | Some(t @ SemanticType::Null) | Some(t @ SemanticType::Method(_)) => {
+        self.issue(loc, BadLambdaReturn { ret_t: t.to_string() });
+        ERROR
+      }
+      Some(t) => t.clone(),
+      None => VOID,
+    }
+  }
 }
 
 impl SemanticTypeVisitor for TypeChecker {
@@ -116,26 +332,56 @@ impl SemanticTypeVisitor for TypeChecker {
   fn lookup_class(&self, name: &'static str) -> Option<Symbol> {
     self.scopes.lookup_class(name)
   }
+
+  fn lookup_type_param(&self, name: &'static str) -> Option<*const TypeParam> {
+    if self.in_static() { None } else { self.cur_class.get().lookup_type_param(name) }
+  }
 }
 
 impl TypeChecker {
   fn class_def(&mut self, class_def: &mut ClassDef) {
     self.cur_class = class_def;
+    self.lambda_cnt = 0;
     self.scopes.open(&mut class_def.scope);
     for field_def in &mut class_def.field {
-      if let FieldDef::MethodDef(method_def) = field_def {
-        self.cur_method = method_def;
-        method_def.class = self.cur_class;
-        self.scopes.open(&mut method_def.scope);
-        self.block(&mut method_def.body);
-        self.scopes.close();
-      };
+      match field_def {
+        FieldDef::MethodDef(method_def) | FieldDef::Ctor(method_def) => {
+          self.cur_method = method_def;
+          self.scopes.open(&mut method_def.scope);
+          self.block(&mut method_def.body);
+          self.scopes.close();
+        }
+        // only static fields can have an initializer, it is checked like the body of a static method
+        FieldDef::VarDef(var_def) => if var_def.src.is_some() {
+          self.cur_method = ptr::null();
+          self.cur_static_field = var_def;
+          let src = var_def.src.as_mut().unwrap();
+          self.expr(src);
+          widen(src, &var_def.type_.sem);
+          if !src.type_.assignable_to(&var_def.type_) {
+            self.issue(var_def.loc, IncompatibleBinary { l_t: var_def.type_.sem.to_string(), op: "=", r_t: src.type_.to_string() })
+          }
+          self.cur_static_field = ptr::null();
+        }
+      }
+    }
+    // the parent constructor called implicitly must have no parameter
+    let (implicit, loc) = if class_def.ctor.is_null() {
+      (if class_def.p_ptr.is_null() { None } else { class_def.p_ptr.get().lookup_ctor() }, class_def.loc)
+    } else {
+      (class_def.ctor.get().implicit_super(), class_def.ctor.get().loc)
+    };
+    if let Some(ctor) = implicit {
+      if ctor.param.len() > 1 {
+        self.issue(loc, NoDefaultCtor { parent: ctor.class.get().name });
+      }
     }
     self.scopes.close();
   }
 
   fn stmt(&mut self, stmt: &mut Stmt) {
     use super::ast::Stmt::*;
+    let stmt_ptr = stmt as *const Stmt;
     match stmt {
       Simple(simple) => self.simple(simple),
       If(if_) => {
@@ -145,23 +391,39 @@ impl TypeChecker {
       }
       While(while_) => {
         self.check_bool(&mut while_.cond);
-        self.loop_cnt += 1;
+        self.enter_loop(while_.label, while_.loc);
         self.block(&mut while_.body);
-        self.loop_cnt -= 1;
+        self.loop_label.pop();
+      }
+      DoWhile(do_while) => {
+        self.enter_loop(do_while.label, do_while.loc);
+        self.block(&mut do_while.body);
+        self.loop_label.pop();
+        self.check_bool(&mut do_while.cond);
       }
       For(for_) => {
         self.scopes.open(&mut for_.body.scope);
         self.simple(&mut for_.init);
         self.check_bool(&mut for_.cond);
         self.simple(&mut for_.update);
+        self.enter_loop(for_.label, for_.loc);
         for stmt in &mut for_.body.stmt { self.stmt(stmt); }
+        self.loop_label.pop();
         self.scopes.close();
       }
       Return(return_) => {
-        let expect = &self.cur_method.get().ret_t.sem;
+        if let Some(expr) = &mut return_.expr { self.expr(expr); }
+        let expect = match self.lambdas.last() {
+          Some(&(lambda, _)) => {
+            let ret_t = &mut lambda.get().def.ret_t.sem;
+            if ret_t == &VAR { *ret_t = self.lambda_ret_t(return_.expr.as_ref(), return_.loc); }
+            ret_t
+          }
+          None => &self.cur_method.get().ret_t.sem,
+        };
         match &mut return_.expr {
           Some(expr) => {
-            self.expr(expr);
+            widen(expr, expect);
             if !expr.type_.assignable_to(expect) {
               self.issue(return_.loc, WrongReturnType { ret_t: expr.type_.to_string(), expect_t: expect.to_string() });
             }
@@ -175,38 +437,104 @@ impl TypeChecker {
       }
       Print(print) => for (i, expr) in print.print.iter_mut().enumerate() {
         self.expr(expr);
-        if expr.type_ != ERROR && expr.type_ != BOOL && expr.type_ != INT && expr.type_ != STRING {
+        if is_enum(&expr.type_) {
+          // an enum is printed as its name
+          enum_field(expr, ENUM_NAME);
+        } else if expr.type_ != ERROR && expr.type_ != BOOL && expr.type_ != INT && expr.type_ != CHAR && expr.type_ != LONG
+          && expr.type_ != DOUBLE && expr.type_ != STRING {
           self.issue(expr.loc, BadPrintArg { loc: i as i32 + 1, type_: expr.type_.to_string() });
         }
       },
-      Break(break_) => if self.loop_cnt == 0 { self.issue(break_.loc, BreakOutOfLoop {}); },
+      Break(break_) => if self.loop_label.is_empty() && self.switch_cnt == 0 {
+        self.issue(break_.loc, BreakOutOfLoop {});
+      } else {
+        self.check_label(break_.label, break_.loc);
+      },
+      Continue(continue_) => if self.loop_label.is_empty() {
+        self.issue(continue_.loc, ContinueOutOfLoop {});
+      } else {
+        self.check_label(continue_.label, continue_.loc);
+      },
+      SuperCall(super_call) => {
+        let class = self.cur_class.get();
+        if class.ctor != self.cur_method || class.ctor.get().body.stmt.as_ptr() != stmt_ptr {
+          // its arguments are not checked, since it may not be in a constructor at all
+          self.issue(super_call.loc, SuperCallNotFirst {});
+          for expr in &mut super_call.arg { self.expr(expr); }
+        } else {
+          let ctor = if class.p_ptr.is_null() { None } else { class.p_ptr.get().lookup_ctor() };
+          if let Some(ctor) = ctor { super_call.ctor = ctor; }
+          self.check_ctor_args("super", &mut super_call.arg, ctor, &[], super_call.loc);
+        }
+      }
       SCopy(s_copy) => self.s_copy(s_copy),
       Foreach(foreach) => self.foreach(foreach),
       Guarded(guarded) => for (e, b) in &mut guarded.guarded {
         self.check_bool(e);
         self.block(b);
       },
+      Switch(switch) => self.switch(switch),
       Block(block) => self.block(block),
+      Try(try_) => {
+        self.block(&mut try_.body);
+        for catch in &mut try_.catch {
+          let def_t = &catch.def.type_.sem;
+          if def_t != &ERROR && !self.is_exception(def_t) {
+            self.issue(catch.def.loc, NotException { type_: def_t.to_string() });
+          }
+          self.scopes.open(&mut catch.body.scope);
+          for stmt in &mut catch.body.stmt { self.stmt(stmt); }
+          self.scopes.close();
+        }
+        if let Some(finally) = &mut try_.finally { self.block(finally); }
+      }
+      Throw(throw) => {
+        self.expr(&mut throw.expr);
+        if throw.expr.type_ != ERROR && !self.is_exception(&throw.expr.type_) {
+          self.issue(throw.expr.loc, NotException { type_: throw.expr.type_.to_string() });
+        }
+      }
+      Assert(assert) => {
+        self.check_bool(&mut assert.cond);
+        if let Some(msg) = &mut assert.msg {
+          self.expr(msg);
+          if !msg.type_.error_or(&STRING) {
+            self.issue(msg.loc, WrongArgType { loc: 2, arg_t: msg.type_.to_string(), param_t: STRING.to_string() });
+          }
+        }
+      }
     };
   }
 
   fn simple(&mut self, simple: &mut Simple) {
     match simple {
       Simple::Assign(assign) => {
-        let Assign { dst, src, loc: _ } = assign;
+        let Assign { dst, src, loc: _, op } = assign;
         self.expr(dst);
+        self.check_captured(dst, assign.loc);
+        self.check_enum_const(dst, assign.loc);
         self.expr(src);
-        // error check is contained in extends
-        if dst.type_.is_method() || !src.type_.assignable_to(&dst.type_) {
-          self.issue(assign.loc, IncompatibleBinary { l_t: dst.type_.to_string(), op: "=", r_t: src.type_.to_string() })
+        widen(src, &dst.type_);
+        match op {
+          // checked like the binary operator, so string += string is also allowed
+          Some(op) => if dst.type_ != ERROR && src.type_ != ERROR && !(arith_operands(*op, &dst.type_) && src.type_ == dst.type_) {
+            self.issue(assign.loc, IncompatibleBinary { l_t: dst.type_.to_string(), op: op.to_str(), r_t: src.type_.to_string() })
+          }
+          // error check is contained in extends
+          None => if dst.type_.is_method() || !src.type_.assignable_to(&dst.type_) {
+            self.issue(assign.loc, IncompatibleBinary { l_t: dst.type_.to_string(), op: "=", r_t: src.type_.to_string() })
+          }
         }
       }
       Simple::VarDef(var_def) => if let Some(src) = &mut var_def.src {
         self.expr(src);
         if var_def.type_.sem == VAR {
           var_def.type_.sem = src.type_.clone();
-        } else if !src.type_.assignable_to(&var_def.type_) {
-          self.issue(var_def.loc, IncompatibleBinary { l_t: var_def.type_.sem.to_string(), op: "=", r_t: src.type_.to_string() })
+        } else {
+          widen(src, &var_def.type_.sem);
+          if !src.type_.assignable_to(&var_def.type_) {
+            self.issue(var_def.loc, IncompatibleBinary { l_t: var_def.type_.sem.to_string(), op: "=", r_t: src.type_.to_string() })
+          }
         }
       }
       Simple::Expr(expr) => self.expr(expr),
@@ -231,28 +559,62 @@ impl TypeChecker {
           self.issue(expr.loc, ArrayIndexNotInt {});
         }
       }
-      Call(call) => self.call(call, expr.loc, &mut expr.type_),
+      Call(call) => if let Some(func) = self.call(call, expr.loc, &mut expr.type_) {
+        // `f(x)` with a variable `f` of function type
+        let mut apply = super::ast::Apply { func: Box::new(func), arg: mem::replace(&mut call.arg, Vec::new()) };
+        self.apply(&mut apply, expr.loc, &mut expr.type_);
+        expr.data = ExprData::Apply(apply);
+      }
       Unary(unary) => self.unary(unary, expr.loc, &mut expr.type_),
       Binary(binary) => self.binary(binary, expr.loc, &mut expr.type_),
-      This => if self.cur_method.get().static_ {
+      This => if self.in_static() {
         self.issue(expr.loc, ThisInStatic {});
       } else {
-        expr.type_ = self.cur_class.get().get_object_type();
+        *expr = self.this(expr.loc);
       }
-      NewClass { name } => match self.scopes.lookup_class(name) {
-        Some(class) => expr.type_ = class.get_type(),
-        None => self.issue(expr.loc, NoSuchClass { name }),
+      Super => if self.in_static() {
+        self.issue(expr.loc, SuperInStatic {});
+      } else if !self.lambdas.is_empty() {
+        self.issue(expr.loc, SuperInLambda {});
+      } else if self.cur_class.get().p_ptr.is_null() {
+        self.issue(expr.loc, NoParent { class: self.cur_class.get().name });
+      } else {
+        expr.type_ = self.cur_class.get().p_ptr.get().get_object_type();
+      }
+      NewClass { name, type_arg, arg } => match self.named_class(name, type_arg.clone(), expr.loc) {
+        type_ @ SemanticType::Object(_, _) => {
+          let class = type_.get_class() as *const ClassDef;
+          let class = class.get();
+          // only its own constructor, or the default one if it declares none
+          let ctor = if class.ctor.is_null() { None } else { Some(class.ctor.get()) };
+          if class.enum_.is_some() && !(self.cur_method.is_null() && self.cur_class == class as *const _) {
+            // only the generated initializers of its constants can, and its hidden constructor is not checked
+            for arg in arg.iter_mut() { self.expr(arg); }
+            self.issue(expr.loc, NewEnum { name });
+          } else {
+            if let SemanticType::Object(_, type_arg) = &type_ { self.check_ctor_args(name, arg, ctor, type_arg, expr.loc); }
+            if class.interface {
+              self.issue(expr.loc, NewInterface { name });
+            } else if class.abstract_ {
+              self.issue(expr.loc, NewAbstract { name });
+            }
+          }
+          expr.type_ = type_;
+        }
+        _ => {}
       },
-      NewArray { elem_t, len } => {
+      NewArray { elem_t, len, dims } => {
         expr.type_ = SemanticType::Array(Box::new(elem_t.sem.clone()));
         self.semantic_type(&mut expr.type_, elem_t.loc);
-        elem_t.sem = if let SemanticType::Array(elem) = &expr.type_ { elem.as_ref().clone() } else { unreachable!() };
-        self.expr(len);
-        if !len.type_.error_or(&INT) { self.issue(len.loc, BadNewArrayLen {}); }
+        elem_t.sem = if let SemanticType::Array(elem) = &expr.type_ { elem.as_ref().clone() } else { ERROR };
+        for len in iter::once(len.as_mut()).chain(dims.iter_mut()) {
+          self.expr(len);
+          if !len.type_.error_or(&INT) { self.issue(len.loc, BadNewArrayLen {}); }
+        }
       }
       TypeTest { expr: src, name, target_class } => {
         self.expr(src);
-        if src.type_ != ERROR && !src.type_.is_object() {
+        if src.type_ != ERROR && !src.type_.is_object() && !src.type_.is_param() {
           self.issue(expr.loc, NotObject { type_: src.type_.to_string() });
         }
         match self.scopes.lookup_class(name) {
@@ -262,16 +624,32 @@ impl TypeChecker {
       }
       TypeCast { name, expr: src } => {
         self.expr(src);
-        if src.type_ != ERROR && !src.type_.is_object() {
+        if src.type_ != ERROR && !src.type_.is_object() && !src.type_.is_param() {
           self.issue(expr.loc, NotObject { type_: src.type_.to_string() });
         }
-        // doesn't need to set type to error because it originally was
-        match self.scopes.lookup_class(name) {
-          Some(class) => expr.type_ = class.get_type(),
-          None => self.issue(expr.loc, NoSuchClass { name }),
+        expr.type_ = self.named_class(name, Vec::new(), expr.loc);
+      }
+      Convert(convert) => {
+        self.expr(&mut convert.expr);
+        let (arg_t, ret_t) = convert.op.types();
+        let src_t = &convert.expr.type_;
+        match convert.op {
+          ConvertOp::ToInt | ConvertOp::ToDouble | ConvertOp::ToLong => if !src_t.error_or(&INT) && src_t != &LONG && src_t != &DOUBLE {
+            self.issue(expr.loc, BadNumCast { type_: src_t.to_string(), target: ret_t.to_string() });
+          }
+          _ => if !src_t.error_or(&arg_t) {
+            self.issue(convert.expr.loc, WrongArgType { loc: 1, arg_t: src_t.to_string(), param_t: arg_t.to_string() });
+          }
         }
+        expr.type_ = ret_t;
       }
       Default(default) => self.default(default, expr.loc, &mut expr.type_),
+      Conditional(conditional) => self.conditional(conditional, expr.loc, &mut expr.type_),
+      Lambda(lambda) => self.lambda(lambda, &mut expr.type_),
+      Apply(apply) => {
+        self.expr(&mut apply.func);
+        self.apply(apply, expr.loc, &mut expr.type_);
+      }
       _ => {}
     };
   }
@@ -297,8 +675,24 @@ impl TypeChecker {
           };
         } else if !src_t.error_or(&dst_t) {
           self.issue(s_copy.loc, SCopyMismatch { dst_t: dst_t.to_string(), src_t: src_t.to_string() });
+        } else if let SemanticType::Object(class, _) = src_t {
+          // copying reads all fields, which is allowed anywhere except for private ones
+          let mut class = *class;
+          while !class.is_null() {
+            for field in &class.get().field {
+              if let FieldDef::VarDef(var_def) = field {
+                if !var_def.static_ && var_def.access == Access::Private && self.cur_class != class {
+                  self.issue(s_copy.loc, PrivateFieldAccess { name: var_def.name, owner_t: src_t.to_string() });
+                }
+              }
+            }
+            class = class.get().p_ptr;
+          }
+        }
+        if let Symbol::Var(var) = symbol {
+          s_copy.dst_sym = self.capture(var);
+          if s_copy.dst_sym != var { self.issue(s_copy.loc, AssignCaptured { name: s_copy.dst }); }
         }
-        if let Symbol::Var(var) = symbol { s_copy.dst_sym = var; }
       }
       None => {
         self.issue(s_copy.dst_loc, UndeclaredVar { name: s_copy.dst });
@@ -319,7 +713,8 @@ impl TypeChecker {
           foreach.body.scope.kind = ScopeKind::Local(&mut foreach.body);
           foreach.def.scope = &foreach.body.scope;
         }
-        ref t if !elem.assignable_to(t) => self.issue(foreach.def.loc, ForeachMismatch { elem_t: elem.to_string(), def_t: t.to_string() }),
+        // the elements are not widened
+        ref t if !elem.assignable_to(t) || (**elem == INT && t == &LONG) => self.issue(foreach.def.loc, ForeachMismatch { elem_t: elem.to_string(), def_t: t.to_string() }),
         _ => {}
       }
       SemanticType::Error => if &foreach.def.type_.sem == &VAR {
@@ -334,12 +729,87 @@ impl TypeChecker {
       }
     };
     // first open the scope, where the loop variable is defined
-    self.loop_cnt += 1;
+    self.enter_loop(foreach.label, foreach.def.loc);
     self.scopes.open(&mut foreach.body.scope);
     if let Some(cond) = &mut foreach.cond { self.check_bool(cond); }
     for stmt in &mut foreach.body.stmt { self.stmt(stmt); }
     self.scopes.close();
-    self.loop_cnt -= 1;
+    self.loop_label.pop();
+  }
+
+  // a switch on an enum is a switch on its ordinal, and it warns about the constants without a case if there is no default
+  fn switch(&mut self, switch: &mut Switch) {
+    self.expr(&mut switch.cond);
+    let cond_t = switch.cond.type_.clone();
+    let consts = if is_enum(&cond_t) { cond_t.get_class().enum_.as_ref() } else { None };
+    if consts.is_none() && cond_t != INT && cond_t != ERROR {
+      self.issue(switch.cond.loc, SwitchNotInt { type_: cond_t.to_string() });
+    }
+    let mut values = HashSet::new();
+    for case in &mut switch.case {
+      let valid = match (case.name, consts) {
+        (Some(name), Some(consts)) => match consts.iter().position(|c| c.1 == name) {
+          Some(i) => {
+            case.value = Some(i as i32);
+            true
+          }
+          None => {
+            self.issue(case.loc, NoSuchEnumConst { name, enum_: cond_t.get_class().name });
+            false
+          }
+        }
+        (Some(name), None) => {
+          if cond_t != ERROR { self.issue(case.loc, CaseMismatch { value: name.to_owned(), type_: cond_t.to_string() }); }
+          false
+        }
+        (None, Some(_)) => if let Some(value) = case.value {
+          self.issue(case.loc, CaseMismatch { value: value.to_string(), type_: cond_t.to_string() });
+          false
+        } else { true }
+        (None, None) => true,
+      };
+      if valid && !values.insert(case.value) {
+        let value = match (case.name, case.value) {
+          (Some(name), _) => name.to_owned(),
+          (None, Some(value)) => value.to_string(),
+          (None, None) => "default".to_owned(),
+        };
+        self.issue(case.loc, DuplicateCase { value });
+      }
+    }
+    if let Some(consts) = consts {
+      if !values.contains(&None) {
+        let missing = consts.iter().enumerate().filter(|&(i, _)| !values.contains(&Some(i as i32)))
+          .map(|(_, c)| format!("'{}'", c.1)).collect::<Vec<_>>();
+        if !missing.is_empty() {
+          let enum_ = cond_t.get_class().name;
+          self.warnings.push(Warning(Error::new(switch.loc, MissingEnumCase { enum_, names: missing.join(", ") })));
+        }
+      }
+      enum_field(&mut switch.cond, ENUM_ORDINAL);
+    }
+    self.switch_cnt += 1;
+    for case in &mut switch.case { self.block(&mut case.body); }
+    self.switch_cnt -= 1;
+  }
+
+  fn enter_loop(&mut self, label: Option<&'static str>, loc: Loc) {
+    if let Some(label) = label {
+      if self.loop_label.contains(&Some(label)) { self.issue(loc, DuplicateLabel { name: label }); }
+    }
+    self.loop_label.push(label);
+  }
+
+  // only objects of the built-in `Exception` and its subclasses can be thrown and caught
+  fn is_exception(&self, t: &SemanticType) -> bool {
+    t.is_object() && t.get_class().extends(self.scopes.lookup_class(EXCEPTION).unwrap().as_class())
+  }
+
+  // `label` of break/continue must be one of the enclosing loops
+  fn check_label(&mut self, label: Option<&'static str>, loc: Loc) {
+    if let Some(label) = label {
+      if !self.loop_label.contains(&Some(label)) { self.issue(loc, UndeclaredLabel { name: label }); }
+    }
   }
 
   fn unary(&mut self, unary: &mut Unary, expr_loc: Loc, expr_type: &mut SemanticType) {
@@ -348,10 +818,10 @@ impl TypeChecker {
     let r = &unary.r.type_;
     match unary.op {
       Neg => {
-        if !r.error_or(&INT) {
+        if !r.error_or(&INT) && r != &LONG && r != &DOUBLE {
           self.issue(expr_loc, IncompatibleUnary { op: "-", r_t: r.to_string() });
         }
-        *expr_type = INT;
+        *expr_type = if r == &LONG || r == &DOUBLE { r.clone() } else { INT };
       }
       Not => {
         if !r.error_or(&BOOL) {
@@ -364,6 +834,7 @@ impl TypeChecker {
         if !unary.r.is_lvalue() {
           self.issue(expr_loc, NotLValue { op });
         }
+        self.check_captured(&unary.r, expr_loc);
         if !r.error_or(&INT) {
           self.issue(expr_loc, IncompatibleUnary { op, r_t: r.to_string() });
         }
@@ -378,12 +849,20 @@ impl TypeChecker {
     self.expr(&mut binary.l);
     self.expr(&mut binary.r);
     let (l, r) = (&mut binary.l, &mut binary.r);
+    if binary.op != Repeat && binary.op != Concat {
+      // int op long => long op long
+      let (l_t, r_t) = (l.type_.clone(), r.type_.clone());
+      widen(l, &r_t);
+      widen(r, &l_t);
+    }
     let (l_t, r_t) = (&l.type_, &r.type_);
     match binary.op {
       Repeat => {
         if !r_t.error_or(&INT) { self.issue(r.loc, ArrayRepeatNotInt {}); }
         // l_t cannot be void here
-        if l_t != &ERROR {
+        if let SemanticType::Param(param) = l_t {
+          self.issue(l.loc, GenericArray { name: param.get().name });
+        } else if l_t != &ERROR {
           *expr_type = SemanticType::Array(Box::new(l_t.clone()));
         }
       }
@@ -405,13 +884,15 @@ impl TypeChecker {
           };
         }
         if !match binary.op {
+          // string + string is concatenation
           Add | Sub | Mul | Div | Mod | BAnd | BOr | BXor | Shl | Shr => {
             *expr_type = l_t.clone();
-            l_t == &INT && r_t == &INT
+            arith_operands(binary.op, l_t) && r_t == l_t
           }
+          // strings are compared lexicographically
           Lt | Le | Gt | Ge => {
             *expr_type = BOOL;
-            l_t == &INT && r_t == &INT
+            (l_t == &INT || l_t == &LONG || l_t == &DOUBLE || l_t == &CHAR || l_t == &STRING) && r_t == l_t
           }
           Eq | Ne => {
             *expr_type = BOOL;
@@ -433,14 +914,20 @@ impl TypeChecker {
     }
   }
 
-  fn call(&mut self, call: &mut Call, expr_loc: Loc, expr_type: &mut SemanticType) {
+  // if `call` is actually calling a variable of function type, return that variable, which is already checked
+  fn call(&mut self, call: &mut Call, expr_loc: Loc, expr_type: &mut SemanticType) -> Option<Expr> {
     let call_ptr = call as *mut Call;
     match { &mut call_ptr.get().owner } {
       Some(owner) => {
         self.cur_id_used_for_ref = true;
         self.expr(owner);
+        erase_owner(owner);
         let owner_t = &owner.type_;
-        if owner_t == &ERROR { return; }
+        if owner_t == &ERROR { return None; }
+        if owner_t == &STRING {
+          self.string_call(call_ptr.get(), expr_loc, expr_type);
+          return None;
+        }
         // check array length call, quite a dirty implementation
         if call.name == "length" {
           if owner_t.is_array() {
@@ -449,26 +936,109 @@ impl TypeChecker {
             }
             *expr_type = INT;
             call.is_arr_len = true;
-            return;
+            return None;
           } else if !owner_t.is_object() && !owner_t.is_class() {
             self.issue(expr_loc, BadLength {});
-            return;
+            return None;
           }
         }
         if !owner_t.is_object() && !owner_t.is_class() {
           self.issue(expr_loc, BadFieldAccess { name: call.name, owner_t: owner_t.to_string() });
         } else {
           let symbol = owner_t.get_class().lookup(call.name);
+          if let Some(Symbol::Var(var)) = symbol {
+            if var.get().type_.sem.is_func() {
+              let mut id = Id { owner: call.owner.take(), name: call.name, symbol: ptr::null(), for_assign: false };
+              let mut type_ = ERROR;
+              self.field(&mut id, expr_loc, &mut type_);
+              return Some(Expr::with_type(expr_loc, type_, ExprData::Id(id)));
+            }
+          }
           self.check_call(call, symbol, expr_loc, expr_type);
         }
       }
       None => {
+        let local = match self.scopes.lookup_before(call.name, expr_loc) {
+          Some(Symbol::Var(var)) if var.get().type_.sem.is_func() => Some(true),
+          Some(Symbol::Var(var)) if !var.get().scope.get().is_class() => Some(false),
+          _ => None,
+        };
         let symbol = self.cur_class.get().lookup(call.name);
-        self.check_call(call, symbol, expr_loc, expr_type);
+        // a local variable or parameter not of function type is applied if no method has its name, so `apply` reports it
+        match (local, &symbol) {
+          (Some(true), _) | (Some(false), None) => {
+            let mut func = Expr::new(expr_loc, ExprData::Id(Id { owner: None, name: call.name, symbol: ptr::null(), for_assign: false }));
+            self.expr(&mut func);
+            return Some(func);
+          }
+          _ => self.check_call(call, symbol, expr_loc, expr_type),
+        }
+      }
+    }
+    None
+  }
+
+  fn apply(&mut self, apply: &mut Apply, expr_loc: Loc, expr_type: &mut SemanticType) {
+    for arg in &mut apply.arg { self.expr(arg); }
+    let func_t = apply.func.type_.clone();
+    match &func_t {
+      SemanticType::Func(param, ret) => {
+        *expr_type = ret.as_ref().clone();
+        if param.len() != apply.arg.len() {
+          self.issue(expr_loc, FuncArgc { type_: func_t.to_string(), expect: param.len() as i32, actual: apply.arg.len() as i32 });
+        } else {
+          for (i, (arg, param_t)) in apply.arg.iter_mut().zip(param.iter()).enumerate() {
+            widen(arg, param_t);
+            if !arg.type_.assignable_to(param_t) {
+              self.issue(arg.loc, WrongArgType { loc: i as i32 + 1, arg_t: arg.type_.to_string(), param_t: param_t.to_string() });
+            }
+          }
+        }
       }
+      SemanticType::Error => {}
+      _ => self.issue(expr_loc, NotFunction { type_: func_t.to_string() }),
     }
   }
 
+  // the parameters and body are declared here, the body is checked with its own return type, loops and switches
+  fn lambda(&mut self, lambda: &mut Lambda, expr_type: &mut SemanticType) {
+    self.lambda_cnt += 1;
+    let lambda_ptr = lambda as *mut Lambda;
+    let def = &mut lambda.def;
+    def.class = self.cur_class;
+    def.overload_idx = self.lambda_cnt;
+    let generic = if self.in_static() { ptr::null() } else { self.cur_class };
+    let (errors, has_try) = SymbolBuilder::lambda(&self.scopes, generic, def);
+    self.errors.extend(errors);
+    self.has_try |= has_try;
+    self.scopes.open(&mut def.scope);
+    self.lambdas.push((lambda_ptr, self.scopes.scopes.len() - 1));
+    let loop_label = mem::replace(&mut self.loop_label, Vec::new());
+    let switch_cnt = mem::replace(&mut self.switch_cnt, 0);
+    self.block(&mut def.body);
+    self.loop_label = loop_label;
+    self.switch_cnt = switch_cnt;
+    if lambda.expr {
+      // `=> e` returns e, unless e is void
+      let expr = match def.body.stmt.pop() { Some(Stmt::Simple(Simple::Expr(expr))) => expr, _ => unreachable!() };
+      def.body.stmt.push(if expr.type_ == VOID { Stmt::Simple(Simple::Expr(expr)) } else {
+        def.ret_t.sem = self.lambda_ret_t(Some(&expr), expr.loc);
+        Stmt::Return(Return { loc: expr.loc, expr: Some(expr) })
+      });
+    }
+    self.lambdas.pop();
+    self.scopes.close();
+    if def.ret_t.sem == VAR { def.ret_t.sem = VOID; }
+    let param = def.param[1..].iter().map(|p| p.type_.sem.clone()).collect::<Vec<_>>();
+    let error = param.iter().any(|t| t == &ERROR || t == &VOID) || def.ret_t.sem == ERROR;
+    let type_ = SemanticType::Func(param, Box::new(def.ret_t.sem.clone()));
+    def.param[0].type_.sem = type_.clone();
+    *expr_type = if error { ERROR } else if type_.has_param() {
+      self.issue(lambda.loc, GenericFunc {});
+      ERROR
+    } else { type_ };
+  }
+
   fn id(&mut self, id: &mut Id, expr_loc: Loc, expr_type: &mut SemanticType) {
     // not found(no owner) or sole ClassName => UndeclaredVar
     // refer to field in static function => RefInStatic
@@ -483,30 +1053,8 @@ impl TypeChecker {
       Some(owner) => {
         self.cur_id_used_for_ref = true;
         self.expr(owner);
-        let owner_t = &owner.type_;
-        match owner_t {
-          SemanticType::Object(class) => {
-            let class = class.get();
-            // lookup through inheritance chain
-            match class.lookup(id.name) {
-              Some(symbol) => {
-                match symbol {
-                  Symbol::Var(var_def) => {
-                    *expr_type = var_def.get().type_.clone();
-                    id.symbol = var_def;
-                    if !self.cur_class.get().extends(class) {
-                      self.issue(expr_loc, PrivateFieldAccess { name: id.name, owner_t: owner_t.to_string() });
-                    }
-                  }
-                  _ => *expr_type = symbol.get_type(),
-                }
-              }
-              None => self.issue(expr_loc, NoSuchField { name: id.name, owner_t: owner_t.to_string() }),
-            }
-          }
-          SemanticType::Error => {}
-          _ => self.issue(expr_loc, BadFieldAccess { name: id.name, owner_t: owner_t.to_string() }),
-        }
+        erase_owner(owner);
+        self.field(id, expr_loc, expr_type);
       }
       None => {
         match self.scopes.lookup_before(id.name, expr_loc) {
@@ -521,14 +1069,17 @@ impl TypeChecker {
               Symbol::Var(var) => {
                 let var = var.get();
                 *expr_type = var.type_.clone();
-                id.symbol = var;
-                if var.scope.get().is_class() {
-                  if self.cur_method.get().static_ {
-                    let method = self.cur_method.get().name;
-                    self.issue(expr_loc, RefInStatic { field: id.name, method });
+                id.symbol = self.capture(var);
+                // a private field of the parent is found, but not accessible
+                if var.scope.get().is_class() && !self.accessible(var.access, var.scope.get().get_class(), self.cur_class) {
+                  self.issue(expr_loc, PrivateFieldAccess { name: id.name, owner_t: self.cur_class.get().get_object_type().to_string() });
+                }
+                if var.scope.get().is_class() && !var.static_ {
+                  if self.in_static() {
+                    self.ref_in_static(expr_loc, id.name);
                   } else {
                     // add a virtual `this`, it doesn't need visit
-                    *owner_ptr.get() = Some(Box::new(Expr::with_type(expr_loc, SemanticType::Object(self.cur_class), ExprData::This)));
+                    *owner_ptr.get() = Some(Box::new(self.this(expr_loc)));
                   }
                 }
               }
@@ -541,6 +1092,71 @@ impl TypeChecker {
     }
   }
 
+  // `id` with an owner, which is already checked
+  fn field(&mut self, id: &mut Id, expr_loc: Loc, expr_type: &mut SemanticType) {
+    let owner_t = &id.owner.as_ref().unwrap().type_;
+    match owner_t {
+      SemanticType::Object(class, type_arg) => {
+        let class = class.get();
+        // lookup through inheritance chain
+        match class.lookup(id.name) {
+          Some(symbol) => {
+            match symbol {
+              Symbol::Var(var_def) => {
+                *expr_type = var_def.get().type_.sem.subst(type_arg);
+                self.check_param_array(id.name, &[&var_def.get().type_.sem], owner_t, expr_loc);
+                id.symbol = var_def;
+                if !self.accessible(var_def.get().access, var_def.get().scope.get().get_class(), class) {
+                  self.issue(expr_loc, PrivateFieldAccess { name: id.name, owner_t: owner_t.to_string() });
+                }
+              }
+              _ => *expr_type = symbol.get_type(),
+            }
+          }
+          None => self.issue(expr_loc, NoSuchField { name: id.name, owner_t: owner_t.to_string() }),
+        }
+      }
+      // only static fields can be accessed through class name
+      SemanticType::Class(class) => match class.get().lookup(id.name) {
+        Some(Symbol::Var(var_def)) if var_def.get().static_ => {
+          *expr_type = var_def.get().type_.clone();
+          id.symbol = var_def;
+          if !self.accessible(var_def.get().access, var_def.get().scope.get().get_class(), *class) {
+            self.issue(expr_loc, PrivateFieldAccess { name: id.name, owner_t: owner_t.to_string() });
+          }
+        }
+        _ => self.issue(expr_loc, BadFieldAccess { name: id.name, owner_t: owner_t.to_string() }),
+      }
+      SemanticType::Error => {}
+      _ => self.issue(expr_loc, BadFieldAccess { name: id.name, owner_t: owner_t.to_string() }),
+    }
+    // like static method calls, the owner of a static field is discarded
+    if !id.symbol.is_null() && id.symbol.get().static_ { id.owner = None; }
+  }
+
+  // the result type is the one of the two branches that the other is assignable to
+  fn conditional(&mut self, conditional: &mut Conditional, expr_loc: Loc, expr_type: &mut SemanticType) {
+    let Conditional { cond, on_true, on_false } = conditional;
+    self.check_bool(cond);
+    (self.expr(on_true), self.expr(on_false));
+    let (t1, t2) = (&on_true.type_, &on_false.type_);
+    *expr_type = if t1 == &ERROR || t2 == &ERROR {
+      ERROR
+    } else if t1 == &VOID || t2 == &VOID {
+      self.issue(expr_loc, ConditionalMismatch { t1: t1.to_string(), t2: t2.to_string() });
+      ERROR
+    } else if t1.assignable_to(t2) {
+      t2.clone()
+    } else if t2.assignable_to(t1) {
+      t1.clone()
+    } else {
+      self.issue(expr_loc, ConditionalMismatch { t1: t1.to_string(), t2: t2.to_string() });
+      ERROR
+    };
+    widen(on_true, expr_type);
+    widen(on_false, expr_type);
+  }
+
   fn default(&mut self, default: &mut Default, expr_loc: Loc, expr_type: &mut SemanticType) {
     let Default { arr, idx, dft } = default;
     (self.expr(arr), self.expr(idx), self.expr(dft));
@@ -561,4 +1177,46 @@ impl TypeChecker {
       self.issue(idx.loc, ArrayIndexNotInt {});
     }
   }
-}
\ No newline at end of file
+}
+
+// whether `t op t` is a valid arithmetic expression: int/long support all, double all but bitwise ones, and string only '+'
+fn arith_operands(op: Operator, t: &SemanticType) -> bool {
+  use super::ast::Operator::*;
+  match t {
+    SemanticType::Int | SemanticType::Long => true,
+    SemanticType::Double => op == Add || op == Sub || op == Mul || op == Div || op == Mod,
+    SemanticType::String => op == Add,
+    _ => false,
+  }
+}
+
+// the members of a type parameter are those of its bound
+fn erase_owner(owner: &mut Expr) {
+  if let Some(bound) = owner.type_.bound() { owner.type_ = bound; }
+}
+
+fn is_enum(t: &SemanticType) -> bool {
+  t.is_object() && t.get_class().enum_.is_some()
+}
+
+// replace an enum expr with a read of its hidden field `name`
+fn enum_field(expr: &mut Expr, name: &'static str) {
+  let var_def = match expr.type_.get_class().scope.get(name) {
+    Some(Symbol::Var(var_def)) => *var_def,
+    _ => unreachable!(),
+  };
+  let data = mem::replace(&mut expr.data, ExprData::Null);
+  let owner = Expr::with_type(expr.loc, expr.type_.clone(), data);
+  expr.data = ExprData::Id(Id { owner: Some(Box::new(owner)), name, symbol: var_def, for_assign: false });
+  expr.type_ = var_def.get().type_.sem.clone();
+}
+
+// wrap an int expr in an implicit conversion when a long is expected
+fn widen(expr: &mut Expr, t: &SemanticType) {
+  if expr.type_ == INT && t == &LONG {
+    let data = mem::replace(&mut expr.data, ExprData::Null);
+    let int = Expr::with_type(expr.loc, INT, data);
+    expr.data = ExprData::Convert(Convert { op: ConvertOp::ToLong, expr: Box::new(int) });
+    expr.type_ = LONG;
+  }
+}
@@ -1,4 +1,4 @@
-use super::ast::{ClassDef, MethodDef};
+use super::ast::{ClassDef, MethodDef, TypeParam};
 use super::print::*;
 use super::errors::*;
 use super::loc::*;
@@ -7,6 +7,7 @@ use super::util::*;
 
 use std::default::Default as D;
 use std::fmt;
+use std::mem;
 
 // the struct Type in ast.rs is syntactic type(so it have field `loc`)
 #[derive(Debug, Clone)]
@@ -16,18 +17,25 @@ pub enum SemanticType {
   Null,
   Int,
   Bool,
+  Char,
+  Long,
+  Double,
   String,
   Void,
-  // only a name, generated while parsing, whether it can become an object depends on type check process
-  Named(&'static str),
-  // a class object
-  Object(*const ClassDef),
+  // only a name(and the type arguments), generated while parsing, whether it can become an object depends on type check process
+  Named(&'static str, Vec<SemanticType>),
+  // a class object, with the type arguments if the class is generic
+  Object(*const ClassDef, Vec<SemanticType>),
+  // a type parameter used inside its generic class
+  Param(*const TypeParam),
   // a class, e.g., the type of `Main` in Main.f()
   Class(*const ClassDef),
   // type [][]...
   Array(Box<SemanticType>),
   // refer to a method, only possible in semantic analysis
   Method(*const MethodDef),
+  // a function value(parameter types and return type), e.g., the type of a lambda
+  Func(Vec<SemanticType>, Box<SemanticType>),
 }
 
 pub const ERROR: SemanticType = SemanticType::Error;
@@ -37,6 +45,9 @@ pub const INT: SemanticType = SemanticType::Int;
 pub const BOOL: SemanticType = SemanticType::Bool;
 pub const VOID: SemanticType = SemanticType::Void;
 pub const STRING: SemanticType = SemanticType::String;
+pub const CHAR: SemanticType = SemanticType::Char;
+pub const LONG: SemanticType = SemanticType::Long;
+pub const DOUBLE: SemanticType = SemanticType::Double;
 
 impl D for SemanticType {
   fn default() -> Self {
@@ -53,10 +64,14 @@ impl fmt::Display for SemanticType {
       Null => write!(f, "null"),
       Int => write!(f, "int"),
       Bool => write!(f, "bool"),
+      Char => write!(f, "char"),
+      Long => write!(f, "long"),
+      Double => write!(f, "double"),
       String => write!(f, "string"),
       Void => write!(f, "void"),
-      Named(name) => write!(f, "class : {}", name),
-      Object(class) => write!(f, "class : {}", class.get().name),
+      Named(name, arg) => write!(f, "class : {}{}", name, TypeArgs(arg)),
+      Object(class, arg) => write!(f, "class : {}{}", class.get().name, TypeArgs(arg)),
+      Param(param) => write!(f, "class : {}", param.get().name),
       Class(class) => write!(f, "class : {}", class.get().name),
       Array(elem) => write!(f, "{}[]", elem),
       Method(method) => {
@@ -66,10 +81,20 @@ impl fmt::Display for SemanticType {
         }
         write!(f, "{}", method.ret_t.sem)
       }
+      Func(param, ret) => write!(f, "({}) => {}", param.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "), ret),
     }
   }
 }
 
+struct TypeArgs<'a>(&'a [SemanticType]);
+
+impl<'a> fmt::Display for TypeArgs<'a> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    if self.0.is_empty() { return Ok(()); }
+    write!(f, "<{}>", self.0.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "))
+  }
+}
+
 impl SemanticType {
   pub fn assignable_to(&self, rhs: &SemanticType) -> bool {
     use self::SemanticType::*;
@@ -78,11 +103,21 @@ impl SemanticType {
       (_, Error) => true,
       (Int, Int) => true,
       (Bool, Bool) => true,
+      (Char, Char) => true,
+      (Long, Long) => true,
+      // an int is implicitly widened to long
+      (Int, Long) => true,
+      (Double, Double) => true,
       (String, String) => true,
       (Void, Void) => true,
-      (SemanticType::Object(class1), SemanticType::Object(class2)) => class1.get().extends(*class2),
+      // a generic class can't be extended, so different type arguments only appear with the same class, and they must be the same
+      (SemanticType::Object(class1, arg1), SemanticType::Object(class2, arg2)) =>
+        (class1.get().extends(*class2) || class1.get().implements(*class2)) && (class1 != class2 || arg1 == arg2),
+      (SemanticType::Param(param1), SemanticType::Param(param2)) => param1 == param2,
+      (SemanticType::Param(_), _) => self.bound().map_or(false, |bound| bound.assignable_to(rhs)),
       (SemanticType::Array(elem1), SemanticType::Array(elem2)) => elem1 == elem2,
-      (SemanticType::Null, SemanticType::Object(_)) => true,
+      (SemanticType::Func(_, _), SemanticType::Func(_, _)) => self == rhs,
+      (SemanticType::Null, SemanticType::Object(_, _)) | (SemanticType::Null, SemanticType::Param(_)) | (SemanticType::Null, SemanticType::Func(_, _)) => true,
       _ => false,
     }
   }
@@ -92,7 +127,7 @@ impl SemanticType {
   }
 
   pub fn is_object(&self) -> bool {
-    if let SemanticType::Object(_) = self { true } else { false }
+    if let SemanticType::Object(_, _) = self { true } else { false }
   }
 
   pub fn is_method(&self) -> bool {
@@ -103,9 +138,55 @@ impl SemanticType {
     if let SemanticType::Array(_) = self { true } else { false }
   }
 
+  pub fn is_param(&self) -> bool {
+    if let SemanticType::Param(_) = self { true } else { false }
+  }
+
+  pub fn is_func(&self) -> bool {
+    if let SemanticType::Func(_, _) = self { true } else { false }
+  }
+
+  // the bound of a type parameter
+  pub fn bound(&self) -> Option<SemanticType> {
+    match self {
+      SemanticType::Param(param) => param.get().bound.as_ref().map(|bound| bound.sem.clone()),
+      _ => None,
+    }
+  }
+
+  // replace the type parameters with the type arguments of the object the member is accessed through
+  // there is no generic parent class, so all type parameters in a member belong to the class of the object
+  pub fn subst(&self, arg: &[SemanticType]) -> SemanticType {
+    match self {
+      SemanticType::Param(param) if !arg.is_empty() => arg[param.get().index].clone(),
+      SemanticType::Object(class, class_arg) => SemanticType::Object(*class, class_arg.iter().map(|t| t.subst(arg)).collect()),
+      SemanticType::Array(elem) => SemanticType::Array(Box::new(elem.subst(arg))),
+      _ => self.clone(),
+    }
+  }
+
+  // whether the type is an array of some type parameter, which is erased to an array of its bound
+  pub fn has_param_array(&self) -> bool {
+    match self {
+      SemanticType::Array(elem) => elem.is_param() || elem.has_param_array(),
+      _ => false,
+    }
+  }
+
+  // whether the type depends on some type parameter
+  pub fn has_param(&self) -> bool {
+    match self {
+      SemanticType::Param(_) => true,
+      SemanticType::Object(_, arg) => arg.iter().any(|t| t.has_param()),
+      SemanticType::Array(elem) => elem.has_param(),
+      SemanticType::Func(param, ret) => param.iter().any(|t| t.has_param()) || ret.has_param(),
+      _ => false,
+    }
+  }
+
   pub fn get_class(&self) -> &ClassDef {
     match self {
-      SemanticType::Object(class) => class.get(),
+      SemanticType::Object(class, _) => class.get(),
       SemanticType::Class(class) => class.get(),
       _ => panic!("call get_class on non-class & non-object type"),
     }
@@ -130,16 +211,29 @@ impl ASTData for SemanticType {
       Var => { printer.print("var"); }
       Int => { printer.print("inttype"); }
       Bool => { printer.print("booltype"); }
+      Char => { printer.print("chartype"); }
+      Long => { printer.print("longtype"); }
+      Double => { printer.print("doubletype"); }
       String => { printer.print("stringtype"); }
       Void => { printer.print("voidtype"); }
-      Named(name) => {
+      Named(name, arg) => {
         printer.print("classtype");
         printer.print(name);
+        if !arg.is_empty() {
+          printer.print("<");
+          for t in arg { t.print_ast(printer); }
+          printer.print(">");
+        }
       }
       Array(elem) => {
         printer.print("arrtype");
         elem.print_ast(printer);
       }
+      Func(param, ret) => {
+        printer.print("functype");
+        for t in param { t.print_ast(printer); }
+        ret.print_ast(printer);
+      }
       _ => unreachable!()
     };
   }
@@ -154,10 +248,15 @@ impl PartialEq for SemanticType {
       (Error, Error) => true,
       (Int, Int) => true,
       (Bool, Bool) => true,
+      (Char, Char) => true,
+      (Long, Long) => true,
+      (Double, Double) => true,
       (String, String) => true,
       (Void, Void) => true,
-      (Object(class1), Object(class2)) => class1 == class2,
+      (Object(class1, arg1), Object(class2, arg2)) => class1 == class2 && arg1 == arg2,
+      (Param(param1), Param(param2)) => param1 == param2,
       (Array(elem1), Array(elem2)) => elem1 == elem2,
+      (Func(param1, ret1), Func(param2, ret2)) => param1 == param2 && ret1 == ret2,
       _ => false,
     }
   }
@@ -170,17 +269,28 @@ pub trait SemanticTypeVisitor {
 
   fn lookup_class(&self, name: &'static str) -> Option<Symbol>;
 
+  // the type parameters of the current class are not visible in static members
+  fn lookup_type_param(&self, name: &'static str) -> Option<*const TypeParam>;
+
   fn semantic_type(&mut self, type_: &mut SemanticType, loc: Loc) {
     let type_ptr = type_ as *mut SemanticType;
     if match type_ { // work around with borrow check
-      SemanticType::Named(name) =>
-        if let Some(class_symbol) = self.lookup_class(name) {
-          *type_ptr.get() = SemanticType::Object(class_symbol.as_class());
+      SemanticType::Named(name, arg) => match self.lookup_type_param(name) {
+        Some(param) if arg.is_empty() => {
+          *type_ptr.get() = SemanticType::Param(param);
           false
+        }
+        _ => if let Some(class_symbol) = self.lookup_class(name) {
+          let class = class_symbol.as_class();
+          if self.type_args(class, arg, loc) {
+            *type_ptr.get() = SemanticType::Object(class, mem::replace(arg, Vec::new()));
+            false
+          } else { true }
         } else {
           self.push_error(Error::new(loc, NoSuchClass { name }));
           true
         }
+      }
       SemanticType::Array(elem) => {
         self.semantic_type(elem, loc);
         if elem.as_ref() == &ERROR {
@@ -190,7 +300,50 @@ pub trait SemanticTypeVisitor {
           true
         } else { false }
       }
+      SemanticType::Func(param, ret) => {
+        let mut error = false;
+        for t in param.iter_mut().chain(Some(ret.as_mut())) {
+          self.semantic_type(t, loc);
+          error |= t == &ERROR;
+        }
+        if param.iter().any(|t| t == &VOID) {
+          self.push_error(Error::new(loc, VoidFuncParam {}));
+          error = true;
+        } else if !error && (param.iter().any(|t| t.has_param()) || ret.has_param()) {
+          // it would be erased differently from the instantiated one
+          self.push_error(Error::new(loc, GenericFunc {}));
+          error = true;
+        }
+        error
+      }
       _ => false,
     } { *type_ = ERROR; }
   }
+
+  // check the instantiation of `class` with `arg`, return whether it is valid
+  // a type argument must be pointer-sized, so that the erased code works for all of them
+  fn type_args(&mut self, class: &ClassDef, arg: &mut [SemanticType], loc: Loc) -> bool {
+    if arg.len() != class.type_param.len() {
+      self.push_error(Error::new(loc, WrongTypeArgc { name: class.name, expect: class.type_param.len() as i32, actual: arg.len() as i32 }));
+      return false;
+    }
+    let mut ok = true;
+    for (arg, param) in arg.iter_mut().zip(class.type_param.iter()) {
+      self.semantic_type(arg, loc);
+      match arg {
+        SemanticType::Error => ok = false,
+        SemanticType::Object(_, _) | SemanticType::Param(_) | SemanticType::String | SemanticType::Array(_) => if let Some(bound) = &param.bound {
+          if !arg.assignable_to(&bound.sem) {
+            self.push_error(Error::new(loc, BoundMismatch { arg_t: arg.to_string(), param: param.name, bound_t: bound.sem.to_string() }));
+            ok = false;
+          }
+        }
+        _ => {
+          self.push_error(Error::new(loc, BadTypeArg { arg_t: arg.to_string() }));
+          ok = false;
+        }
+      }
+    }
+    ok
+  }
 }
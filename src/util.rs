@@ -26,3 +26,16 @@ impl<T> GetMut for *mut T {
   }
 }
 
+// each loop is (label, break target, continue target)
+// find the targets of the loop named `label`, or of the innermost loop if `label` is None
+pub fn loop_target<T: Copy>(loop_stack: &[(Option<&'static str>, T, T)], label: Option<&'static str>) -> (T, T) {
+  let &(_, break_target, continue_target) = loop_stack.iter().rev().find(|l| label.is_none() || l.0 == label).unwrap();
+  (break_target, continue_target)
+}
+
+// the index of the loop a break/continue with `label` leaves, every try entered after it is left too
+// a 'continue' in a switch goes to the enclosing loop, which is the outermost one sharing its continue target
+pub fn loop_index<T: Copy + PartialEq>(loop_stack: &[(Option<&'static str>, T, T)], label: Option<&'static str>, continue_: bool) -> usize {
+  let index = loop_stack.iter().rposition(|l| label.is_none() || l.0 == label).unwrap();
+  if continue_ { loop_stack.iter().position(|l| l.2 == loop_stack[index].2).unwrap() } else { index }
+}
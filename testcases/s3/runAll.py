@@ -29,8 +29,10 @@ if __name__ == '__main__':
             stdout=open(os.path.join('output', bname + '.ll'), 'w'),
             stderr=subprocess.STDOUT)
 
+        # a test reading from stdin has its input in bname.in
         subprocess.call(
             ['lli-6.0', os.path.join('output', bname + '.ll')],
+            stdin=open(bname + '.in') if os.path.exists(bname + '.in') else None,
             stdout=open(os.path.join('output', bname + '.result'), 'w'),
             stderr=subprocess.STDOUT)
 
@@ -0,0 +1,52 @@
+#!/usr/bin/env python
+# -*- coding: utf-8 -*-
+
+import os
+import subprocess
+import sys
+
+
+def read_txt_file(filename):
+    with open(filename, 'r') as f:
+        txt = f.read().strip()
+    # Python should be able to do it automatically, but just in case...
+    txt = txt.replace('\r', '')
+    return txt
+
+
+# the same testcases as runAll.py, but compiled by the jvm backend into a runnable jar, and run by 'java -jar'
+if __name__ == '__main__':
+    decaf = os.path.join('..', '..', 'target', 'release', 'decaf')
+    names = sys.argv[1:]
+    if len(names) == 0:
+        names = sorted(os.listdir('.'))
+        names.remove('blackjack.decaf')
+    for name in names:
+        bname, ext = os.path.splitext(name)
+        if ext != '.decaf':
+            continue
+        jar = os.path.join('output', bname + '.jar')
+        fw = open(os.path.join('output', bname + '.result'), 'w')
+        code = subprocess.call(
+            [decaf, '-j', name, '-o', jar],
+            stdout=fw,
+            stderr=subprocess.STDOUT)
+        if code == 0 and os.path.exists(jar):
+            subprocess.call(
+                ['java', '-jar', jar],
+                stdin=open(bname + '.in') if os.path.exists(bname + '.in') else None,
+                stdout=fw,
+                stderr=subprocess.STDOUT)
+        fw.close()
+
+        try:
+            reference = read_txt_file(os.path.join('result', bname + '.result'))
+            our_result = read_txt_file(os.path.join('output', bname + '.result'))
+        except IOError:
+            info = 'What the hell??'
+        else:
+            if reference == our_result:
+                info = 'OK :)'
+            else:
+                info = 'ERROR!'
+        print ('{0:<20}{1}'.format(name, info))